@@ -0,0 +1,45 @@
+//! Helpers for constant-time scalar multiplication, available with the `ct`
+//! feature.
+
+use ark_ff::fields::constant_time::{ConditionallySelectable, ConstantTimeEq};
+
+/// The number of scalar bits processed per window by [`fixed_window_mul`].
+const WINDOW_SIZE: usize = 4;
+
+/// Computes `scalar * base`, where `scalar` is given as little-endian limbs,
+/// using a fixed window of `WINDOW_SIZE` bits.
+///
+/// Every limb of `scalar` is processed, leading zeros included, and the table
+/// of multiples of `base` is read in full for every window, so the sequence of
+/// group operations only depends on `scalar.len()`. The caller must supply
+/// `add` and `double` closures that are themselves constant-time, and that
+/// handle the identity and doubling cases.
+pub(crate) fn fixed_window_mul<G: ConditionallySelectable>(
+    base: &G,
+    zero: G,
+    scalar: &[u64],
+    add: impl Fn(&G, &G) -> G,
+    double: impl Fn(&G) -> G,
+) -> G {
+    // table[i] = i * base
+    let mut table = [zero; 1 << WINDOW_SIZE];
+    for i in 1..table.len() {
+        table[i] = add(&table[i - 1], base);
+    }
+
+    let mut res = zero;
+    for limb in scalar.iter().rev() {
+        for window in (0..64 / WINDOW_SIZE).rev() {
+            for _ in 0..WINDOW_SIZE {
+                res = double(&res);
+            }
+            let digit = (limb >> (window * WINDOW_SIZE)) & ((1 << WINDOW_SIZE) - 1);
+            let mut entry = zero;
+            for (i, multiple) in table.iter().enumerate() {
+                entry.conditional_assign(multiple, (i as u64).ct_eq(&digit));
+            }
+            res = add(&res, &entry);
+        }
+    }
+    res
+}
@@ -0,0 +1,169 @@
+//! Scalar multiplication accelerated by an efficiently computable
+//! endomorphism, following Gallant, Lambert and Vanstone
+//! ([GLV01](https://www.iacr.org/archive/crypto2001/21390189.pdf)).
+//!
+//! For curves such as BLS12 and BN G1, `φ(x, y) = (β * x, y)`, where `β` is a
+//! primitive cube root of unity in the base field, acts on the prime order
+//! subgroup as multiplication by a cube root of unity `λ` in the scalar field.
+//! A scalar `k` can then be written as `k = k1 + k2 * λ` with `k1` and `k2` of
+//! about half the bit length of `k`, so that `k * P = k1 * P + k2 * φ(P)`
+//! needs only half as many doublings.
+//!
+//! Since `φ(P) = λ * P` only holds on the prime order subgroup, plain scalar
+//! multiplication never uses the endomorphism. It is only used by
+//! `AffineCurve::glv_mul`, `WnafContext::glv_mul`,
+//! `WnafContext::glv_multi_scalar_mul` and
+//! `VariableBaseMSM::glv_multi_scalar_mul`, whose inputs must be in that
+//! subgroup.
+
+use crate::{
+    models::{
+        short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+        SWModelParameters,
+    },
+    AffineCurve, ProjectiveCurve,
+};
+use ark_ff::{BigInteger, BitIteratorBE, FpParameters, PrimeField, Zero};
+
+/// Parameters of the GLV endomorphism of a short Weierstrass curve.
+///
+/// Implementing this trait does not by itself enable the `glv_*` methods:
+/// the curve must also override `SWModelParameters::glv_split_affine` and
+/// `SWModelParameters::glv_split_projective`, with `Self::split_affine` and
+/// `Self::split_projective` respectively.
+pub trait GLVParameters: SWModelParameters {
+    /// The coefficient `β` of the endomorphism `φ(x, y) = (β * x, y)`.
+    const ENDO_COEFF: Self::BaseField;
+
+    /// The eigenvalue `λ` of `φ`, such that `φ(P) = λ * P` for every `P` in
+    /// the prime order subgroup.
+    const LAMBDA: Self::ScalarField;
+
+    /// A reduced basis `((n11, n12), (n21, n22))` of the lattice of pairs
+    /// `(a, b)` with `a + b * λ = 0` modulo the group order, in the order
+    /// `[n11, n12, n21, n22]`. Each coefficient is given as a sign (`true`
+    /// for negative) and its little-endian limbs.
+    const SCALAR_DECOMP_COEFFS: [(bool, &'static [u64]); 4];
+
+    /// The rounding coefficients `[g1, g2]` of the decomposition, with
+    /// `g1 = round(n22 * 2^(64 * L) / d)` and `g2 = round(-n12 * 2^(64 * L) / d)`,
+    /// where `d = n11 * n22 - n12 * n21` and `L` is the number of limbs of
+    /// the scalar field. They are given in the same form as
+    /// `Self::SCALAR_DECOMP_COEFFS`.
+    const SCALAR_DECOMP_ROUNDING: [(bool, &'static [u64]); 2];
+
+    /// Applies `φ` to `p`.
+    #[inline]
+    fn endomorphism(p: &GroupAffine<Self>) -> GroupAffine<Self> {
+        let mut res = *p;
+        res.x *= &Self::ENDO_COEFF;
+        res
+    }
+
+    /// Applies `φ` to `p`. In Jacobian coordinates `x = X / Z^2`, so it
+    /// suffices to scale `X`.
+    #[inline]
+    fn endomorphism_projective(p: &GroupProjective<Self>) -> GroupProjective<Self> {
+        let mut res = *p;
+        res.x *= &Self::ENDO_COEFF;
+        res
+    }
+
+    /// Decomposes `k` as `k = k1 + k2 * λ`, returning `[(s1, |k1|), (s2, |k2|)]`
+    /// where `s1` and `s2` are `true` when `k1` and `k2` respectively are
+    /// negative.
+    fn scalar_decomposition(k: &Self::ScalarField) -> [(bool, Self::ScalarField); 2] {
+        let [n11, n12, n21, n22] = Self::SCALAR_DECOMP_COEFFS.map(|(negative, limbs)| {
+            let n = Self::ScalarField::from(to_bigint::<Self::ScalarField>(limbs));
+            if negative {
+                -n
+            } else {
+                n
+            }
+        });
+
+        // Babai rounding: write (k, 0) = b1 * (n11, n12) + b2 * (n21, n22)
+        // over the rationals, and subtract the nearest lattice point. Each
+        // `b_i = k * g_i / 2^(64 * L)` is the rounded high half of a product.
+        let k_repr = k.into_repr();
+        let top_bit = 64 * k_repr.as_ref().len() - 1;
+        let [b1, b2] = Self::SCALAR_DECOMP_ROUNDING.map(|(negative, limbs)| {
+            let (lo, mut hi) = k_repr.mul_wide(&to_bigint::<Self::ScalarField>(limbs));
+            if lo.get_bit(top_bit) {
+                hi.add_nocarry(&1u64.into());
+            }
+            let b = Self::ScalarField::from(hi);
+            if negative {
+                -b
+            } else {
+                b
+            }
+        });
+        let k1 = *k - &(b1 * &n11) - &(b2 * &n21);
+        let k2 = -(b1 * &n12) - &(b2 * &n22);
+
+        // `k1` and `k2` are short, so the larger half of the field holds the
+        // negative ones.
+        [k1, k2].map(|k| {
+            if k.into_repr() > <Self::ScalarField as PrimeField>::Params::MODULUS_MINUS_ONE_DIV_TWO
+            {
+                (true, -k)
+            } else {
+                (false, k)
+            }
+        })
+    }
+
+    /// Splits `k * p` into `k1 * p1 + k2 * p2`, with `p1 = ±p`,
+    /// `p2 = ±φ(p)` and `k1`, `k2` as in `Self::scalar_decomposition`.
+    fn split_affine(
+        p: &GroupAffine<Self>,
+        k: &Self::ScalarField,
+    ) -> [(GroupAffine<Self>, Self::ScalarField); 2] {
+        let [(s1, k1), (s2, k2)] = Self::scalar_decomposition(k);
+        let p1 = if s1 { -*p } else { *p };
+        let p2 = Self::endomorphism(p);
+        let p2 = if s2 { -p2 } else { p2 };
+        [(p1, k1), (p2, k2)]
+    }
+
+    /// The projective counterpart of `Self::split_affine`.
+    fn split_projective(
+        p: &GroupProjective<Self>,
+        k: &Self::ScalarField,
+    ) -> [(GroupProjective<Self>, Self::ScalarField); 2] {
+        let [(s1, k1), (s2, k2)] = Self::scalar_decomposition(k);
+        let p1 = if s1 { -*p } else { *p };
+        let p2 = Self::endomorphism_projective(p);
+        let p2 = if s2 { -p2 } else { p2 };
+        [(p1, k1), (p2, k2)]
+    }
+}
+
+fn to_bigint<F: PrimeField>(limbs: &[u64]) -> F::BigInt {
+    let mut res = F::BigInt::default();
+    res.as_mut()[..limbs.len()].copy_from_slice(limbs);
+    res
+}
+
+/// Computes `k1 * p1 + k2 * p2` with a joint double-and-add, as used after
+/// splitting a scalar multiplication with `AffineCurve::glv_split`.
+pub(crate) fn mul_split<G: AffineCurve>(split: &[(G, G::ScalarField); 2]) -> G::Projective {
+    let [(p1, k1), (p2, k2)] = split;
+    let p12 = p1.into_projective().add_mixed(p2);
+    let (k1, k2) = (k1.into_repr(), k2.into_repr());
+    let mut res = G::Projective::zero();
+    for (b1, b2) in BitIteratorBE::new(k1)
+        .zip(BitIteratorBE::new(k2))
+        .skip_while(|(b1, b2)| !b1 && !b2)
+    {
+        res.double_in_place();
+        match (b1, b2) {
+            (true, true) => res += &p12,
+            (true, false) => res.add_assign_mixed(p1),
+            (false, true) => res.add_assign_mixed(p2),
+            (false, false) => {},
+        }
+    }
+    res
+}
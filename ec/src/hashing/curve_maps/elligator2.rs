@@ -0,0 +1,97 @@
+use crate::{
+    hashing::{curve_maps::sgn0, HashToCurveError, MapToCurve},
+    models::{twisted_edwards_extended::GroupAffine, MontgomeryModelParameters, TEModelParameters},
+};
+use ark_ff::{Field, One, SquareRootField, Zero};
+use ark_std::marker::PhantomData;
+
+/// Parameters of the Elligator 2 map of
+/// [RFC 9380, Section 6.7.1](https://www.rfc-editor.org/rfc/rfc9380.html#section-6.7.1)
+/// for twisted Edwards curves. Field elements are mapped to the Montgomery
+/// curve `K * t^2 = s^3 + J * s^2 + s` given by `Self::MontgomeryModelParameters`
+/// (with `J = COEFF_A` and `K = COEFF_B`), and then to `Self` through the
+/// rational map `(s, t) -> (s / t, (s - 1) / (s + 1))` of RFC 9380,
+/// Appendix D.1. That map requires the twisted Edwards coefficients to be
+/// `a = (J + 2) / K` and `d = (J - 2) / K`.
+pub trait Elligator2Parameters: TEModelParameters {
+    /// The constant `Z` of the map: a non-square in `Self::BaseField`, chosen
+    /// as in RFC 9380, Appendix H.3.
+    const ZETA: Self::BaseField;
+}
+
+/// The Elligator 2 map to the twisted Edwards curve defined by `P`.
+pub struct Elligator2Map<P: Elligator2Parameters>(PhantomData<fn() -> P>);
+
+impl<P: Elligator2Parameters> MapToCurve<GroupAffine<P>> for Elligator2Map<P> {
+    fn new() -> Result<Self, HashToCurveError> {
+        let j = <P::MontgomeryModelParameters as MontgomeryModelParameters>::COEFF_A;
+        let k = <P::MontgomeryModelParameters as MontgomeryModelParameters>::COEFF_B;
+        if j.is_zero() || k.is_zero() {
+            return Err(HashToCurveError::UnsupportedCurve(
+                "Elligator 2 requires a Montgomery curve with nonzero COEFF_A and COEFF_B",
+            ));
+        }
+        let two = P::BaseField::from(2u8);
+        if !(j.square() - &two.double()).legendre().is_qnr() {
+            return Err(HashToCurveError::UnsupportedCurve(
+                "Elligator 2 requires COEFF_A^2 - 4 to be a non-square",
+            ));
+        }
+        if k * &P::COEFF_A != j + &two || k * &P::COEFF_D != j - &two {
+            return Err(HashToCurveError::UnsupportedCurve(
+                "the Montgomery and twisted Edwards parameters are not related by the rational map",
+            ));
+        }
+        if !P::ZETA.legendre().is_qnr() {
+            return Err(HashToCurveError::UnsupportedCurve(
+                "ZETA must be a non-square",
+            ));
+        }
+        Ok(Elligator2Map(PhantomData))
+    }
+
+    fn map_to_curve(&self, u: P::BaseField) -> GroupAffine<P> {
+        let j = <P::MontgomeryModelParameters as MontgomeryModelParameters>::COEFF_A;
+        let k = <P::MontgomeryModelParameters as MontgomeryModelParameters>::COEFF_B;
+        let one = P::BaseField::one();
+
+        // We work on the curve y^2 = x^3 + c1 * x^2 + c2 * x, with c1 = J / K
+        // and c2 = 1 / K^2, whose points (x, y) give the points (x * K, y * K)
+        // of the Montgomery curve.
+        let k_inv = k.inverse().unwrap();
+        let c1 = j * &k_inv;
+        let c2 = k_inv.square();
+
+        let mut tv1 = P::ZETA * &u.square();
+        if tv1 == -one {
+            tv1 = P::BaseField::zero();
+        }
+        // x1 = -c1 / (1 + Z * u^2)
+        let x1 = -c1 * &(one + &tv1).inverse().unwrap();
+        let gx1 = ((x1 + &c1) * &x1 + &c2) * &x1;
+        let gx1_is_square = !gx1.legendre().is_qnr();
+        let (x, y2) = if gx1_is_square {
+            (x1, gx1)
+        } else {
+            (-x1 - &c1, tv1 * &gx1)
+        };
+        // y is chosen with sgn0(y) == 1 exactly when gx1 is a square.
+        let mut y = y2.sqrt().expect("one of gx1 and gx2 is a square");
+        if sgn0(&y) != gx1_is_square {
+            y = -y;
+        }
+        let s = x * &k;
+        let t = y * &k;
+
+        // (s, t) -> (s / t, (s - 1) / (s + 1)), sending the exceptional
+        // points t = 0 and s = -1 to the identity.
+        let point = match (t.inverse(), (s + &one).inverse()) {
+            (Some(t_inv), Some(s_plus_one_inv)) => {
+                GroupAffine::new(s * &t_inv, (s - &one) * &s_plus_one_inv)
+            },
+            _ => GroupAffine::zero(),
+        };
+        debug_assert!(point.is_on_curve());
+        point
+    }
+}
@@ -0,0 +1,26 @@
+//! The maps from field elements to curve points of
+//! [RFC 9380, Section 6](https://www.rfc-editor.org/rfc/rfc9380.html#section-6).
+
+use crate::models::SWModelParameters;
+use ark_ff::{BigInteger, Field, PrimeField, Zero};
+
+pub mod elligator2;
+pub mod svdw;
+pub mod swu;
+pub mod wb;
+
+/// The `sgn0` function of RFC 9380, which is `true` for "negative" field
+/// elements: those whose first nonzero coefficient over the base prime field
+/// is odd.
+pub fn sgn0<F: Field>(x: &F) -> bool {
+    x.to_base_prime_field_elems()
+        .iter()
+        .find(|c| !c.is_zero())
+        .map_or(false, |c| c.into_repr().is_odd())
+}
+
+/// Evaluates the right-hand side `g(x) = x^3 + a * x + b` of the equation of
+/// the short Weierstrass curve `P`.
+pub(crate) fn curve_rhs<P: SWModelParameters>(x: &P::BaseField) -> P::BaseField {
+    P::add_b(&((x.square() + &P::COEFF_A) * x))
+}
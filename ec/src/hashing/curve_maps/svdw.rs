@@ -0,0 +1,103 @@
+use crate::{
+    hashing::{
+        curve_maps::{curve_rhs, sgn0},
+        HashToCurveError, MapToCurve,
+    },
+    models::{short_weierstrass_jacobian::GroupAffine, SWModelParameters},
+};
+use ark_ff::{Field, One, SquareRootField, Zero};
+use ark_std::marker::PhantomData;
+
+/// Parameters of the Shallue–van de Woestijne map of
+/// [RFC 9380, Section 6.6.1](https://www.rfc-editor.org/rfc/rfc9380.html#section-6.6.1),
+/// which applies to every short Weierstrass curve. It is slower than
+/// [`SWUMap`](super::swu::SWUMap) and [`WBMap`](super::wb::WBMap), but needs
+/// neither `COEFF_A * COEFF_B != 0` nor an isogenous curve.
+pub trait SvdWParameters: SWModelParameters {
+    /// The constant `Z` of the map, chosen as in RFC 9380, Appendix H.1.
+    const ZETA: Self::BaseField;
+}
+
+/// The Shallue–van de Woestijne map to the curve defined by `P`.
+pub struct SvdWMap<P: SvdWParameters> {
+    c1: P::BaseField,
+    c2: P::BaseField,
+    c3: P::BaseField,
+    c4: P::BaseField,
+    _params: PhantomData<fn() -> P>,
+}
+
+impl<P: SvdWParameters> MapToCurve<GroupAffine<P>> for SvdWMap<P> {
+    fn new() -> Result<Self, HashToCurveError> {
+        let z = P::ZETA;
+        let g_z = curve_rhs::<P>(&z);
+        // 3 * Z^2 + 4 * A
+        let t = z.square() * &P::BaseField::from(3u8) + &P::COEFF_A.double().double();
+        let four_g_z = g_z.double().double();
+        let h_z = match four_g_z.inverse() {
+            Some(inv) => -t * &inv,
+            None => {
+                return Err(HashToCurveError::UnsupportedCurve(
+                    "g(ZETA) must be nonzero",
+                ))
+            },
+        };
+        if h_z.is_zero() || !h_z.legendre().is_qr() {
+            return Err(HashToCurveError::UnsupportedCurve(
+                "-(3 * ZETA^2 + 4 * COEFF_A) / (4 * g(ZETA)) must be a nonzero square",
+            ));
+        }
+        let c2 = -z / &P::BaseField::from(2u8);
+        if g_z.legendre().is_qnr() && curve_rhs::<P>(&c2).legendre().is_qnr() {
+            return Err(HashToCurveError::UnsupportedCurve(
+                "one of g(ZETA) and g(-ZETA / 2) must be a square",
+            ));
+        }
+
+        // c3 = sqrt(-g(Z) * (3 * Z^2 + 4 * A)), with sgn0(c3) == 0
+        let mut c3 = (-g_z * &t)
+            .sqrt()
+            .expect("-g(Z) * (3 * Z^2 + 4 * A) = h(Z) * (2 * g(Z))^2 is a square");
+        if sgn0(&c3) {
+            c3 = -c3;
+        }
+        // c4 = -4 * g(Z) / (3 * Z^2 + 4 * A)
+        let c4 = -four_g_z / &t;
+
+        Ok(SvdWMap {
+            c1: g_z,
+            c2,
+            c3,
+            c4,
+            _params: PhantomData,
+        })
+    }
+
+    fn map_to_curve(&self, u: P::BaseField) -> GroupAffine<P> {
+        let one = P::BaseField::one();
+        let tv1 = u.square() * &self.c1;
+        let tv2 = one + &tv1;
+        let tv1 = one - &tv1;
+        // inv0(tv1 * tv2)
+        let tv3 = (tv1 * &tv2).inverse().unwrap_or_else(P::BaseField::zero);
+        let tv4 = u * &tv1 * &tv3 * &self.c3;
+
+        let x1 = self.c2 - &tv4;
+        let x2 = self.c2 + &tv4;
+        let x = if !curve_rhs::<P>(&x1).legendre().is_qnr() {
+            x1
+        } else if !curve_rhs::<P>(&x2).legendre().is_qnr() {
+            x2
+        } else {
+            (tv2.square() * &tv3).square() * &self.c4 + &P::ZETA
+        };
+        let y = curve_rhs::<P>(&x)
+            .sqrt()
+            .expect("one of g(x1), g(x2) and g(x3) is a square");
+        let y = if sgn0(&u) != sgn0(&y) { -y } else { y };
+
+        let point = GroupAffine::new(x, y, false);
+        debug_assert!(point.is_on_curve());
+        point
+    }
+}
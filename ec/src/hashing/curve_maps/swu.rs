@@ -0,0 +1,79 @@
+use crate::{
+    hashing::{
+        curve_maps::{curve_rhs, sgn0},
+        HashToCurveError, MapToCurve,
+    },
+    models::{short_weierstrass_jacobian::GroupAffine, SWModelParameters},
+};
+use ark_ff::{Field, One, SquareRootField, Zero};
+use ark_std::marker::PhantomData;
+
+/// Parameters of the simplified Shallue–van de Woestijne–Ulas map of
+/// [RFC 9380, Section 6.6.2](https://www.rfc-editor.org/rfc/rfc9380.html#section-6.6.2),
+/// which applies to short Weierstrass curves with `COEFF_A * COEFF_B != 0`.
+/// For curves with `COEFF_A == 0`, such as BLS12 G1 and G2, use
+/// [`WBMap`](super::wb::WBMap) with an isogenous curve implementing this trait.
+pub trait SWUParameters: SWModelParameters {
+    /// The constant `Z` of the map: a non-square in `Self::BaseField`, not
+    /// equal to -1, such that `g(COEFF_B / (Z * COEFF_A))` is a square.
+    /// RFC 9380, Appendix H.2 gives a procedure for choosing it.
+    const ZETA: Self::BaseField;
+}
+
+/// The simplified SWU map to the curve defined by `P`.
+pub struct SWUMap<P: SWUParameters>(PhantomData<fn() -> P>);
+
+impl<P: SWUParameters> MapToCurve<GroupAffine<P>> for SWUMap<P> {
+    fn new() -> Result<Self, HashToCurveError> {
+        if P::COEFF_A.is_zero() || P::COEFF_B.is_zero() {
+            return Err(HashToCurveError::UnsupportedCurve(
+                "the simplified SWU map requires COEFF_A * COEFF_B != 0",
+            ));
+        }
+        if !P::ZETA.legendre().is_qnr() || P::ZETA == -P::BaseField::one() {
+            return Err(HashToCurveError::UnsupportedCurve(
+                "ZETA must be a non-square other than -1",
+            ));
+        }
+        let x = P::COEFF_B / &(P::ZETA * &P::COEFF_A);
+        if curve_rhs::<P>(&x).legendre().is_qnr() {
+            return Err(HashToCurveError::UnsupportedCurve(
+                "g(COEFF_B / (ZETA * COEFF_A)) must be a square",
+            ));
+        }
+        Ok(SWUMap(PhantomData))
+    }
+
+    fn map_to_curve(&self, u: P::BaseField) -> GroupAffine<P> {
+        // 1. tv1 = inv0(Z^2 * u^4 + Z * u^2)
+        // 2.  x1 = (-B / A) * (1 + tv1)
+        // 3.  If tv1 == 0, set x1 = B / (Z * A)
+        // 4. gx1 = x1^3 + A * x1 + B
+        // 5.  x2 = Z * u^2 * x1
+        // 6. gx2 = x2^3 + A * x2 + B
+        // 7.  If is_square(gx1), set x = x1 and y = sqrt(gx1)
+        // 8.  Else set x = x2 and y = sqrt(gx2)
+        // 9.  If sgn0(u) != sgn0(y), set y = -y
+        let z_u2 = P::ZETA * &u.square();
+        let x1 = match (z_u2.square() + &z_u2).inverse() {
+            Some(tv1) => -P::COEFF_B / &P::COEFF_A * &(P::BaseField::one() + &tv1),
+            None => P::COEFF_B / &(P::ZETA * &P::COEFF_A),
+        };
+        let gx1 = curve_rhs::<P>(&x1);
+        let (x, y) = match gx1.sqrt() {
+            Some(y1) => (x1, y1),
+            None => {
+                let x2 = z_u2 * &x1;
+                let y2 = curve_rhs::<P>(&x2)
+                    .sqrt()
+                    .expect("gx2 = Z^3 * u^6 * gx1 is a square when gx1 is not");
+                (x2, y2)
+            },
+        };
+        let y = if sgn0(&u) != sgn0(&y) { -y } else { y };
+
+        let point = GroupAffine::new(x, y, false);
+        debug_assert!(point.is_on_curve());
+        point
+    }
+}
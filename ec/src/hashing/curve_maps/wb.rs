@@ -0,0 +1,90 @@
+use crate::{
+    hashing::{
+        curve_maps::swu::{SWUMap, SWUParameters},
+        HashToCurveError, MapToCurve,
+    },
+    models::{short_weierstrass_jacobian::GroupAffine, SWModelParameters},
+    AffineCurve,
+};
+use ark_ff::{Field, Zero};
+use ark_std::marker::PhantomData;
+
+/// A rational map `(x, y) -> (x_num(x) / x_den(x), y * y_num(x) / y_den(x))`
+/// between short Weierstrass curves over the field `F`, as used for the
+/// isogenies of [RFC 9380, Appendix E](https://www.rfc-editor.org/rfc/rfc9380.html#appendix-E).
+/// Polynomials are given by their coefficients, starting with the constant
+/// term.
+pub struct IsogenyMap<'a, F: Field> {
+    pub x_map_numerator: &'a [F],
+    pub x_map_denominator: &'a [F],
+    pub y_map_numerator: &'a [F],
+    pub y_map_denominator: &'a [F],
+}
+
+impl<'a, F: Field> IsogenyMap<'a, F> {
+    /// Applies the map to `point`. Points where either denominator vanishes,
+    /// which make up the kernel of the isogeny, are sent to the identity.
+    pub fn apply<Domain, Codomain>(&self, point: &GroupAffine<Domain>) -> GroupAffine<Codomain>
+    where
+        Domain: SWModelParameters<BaseField = F>,
+        Codomain: SWModelParameters<BaseField = F>,
+    {
+        if point.infinity {
+            return GroupAffine::zero();
+        }
+        let x_den = evaluate(self.x_map_denominator, &point.x);
+        let y_den = evaluate(self.y_map_denominator, &point.x);
+        match (x_den.inverse(), y_den.inverse()) {
+            (Some(x_den_inv), Some(y_den_inv)) => {
+                let x = evaluate(self.x_map_numerator, &point.x) * &x_den_inv;
+                let y = evaluate(self.y_map_numerator, &point.x) * &y_den_inv * &point.y;
+                GroupAffine::new(x, y, false)
+            },
+            _ => GroupAffine::zero(),
+        }
+    }
+}
+
+/// Evaluates the polynomial with coefficients `coeffs` at `x`.
+fn evaluate<F: Field>(coeffs: &[F], x: &F) -> F {
+    coeffs.iter().rev().fold(F::zero(), |acc, c| acc * x + c)
+}
+
+/// Parameters of the Wahby–Boneh variant of the simplified SWU map
+/// ([WB19](https://eprint.iacr.org/2019/403)), for curves with
+/// `COEFF_A * COEFF_B == 0`. Field elements are first mapped to the isogenous
+/// curve `IsogenousCurve`, and then to `Self` through `ISOGENY_MAP`.
+pub trait WBParameters: SWModelParameters {
+    /// A curve isogenous to `Self` to which the simplified SWU map applies.
+    type IsogenousCurve: SWUParameters<BaseField = Self::BaseField>;
+
+    /// The isogeny from `IsogenousCurve` to `Self`.
+    const ISOGENY_MAP: IsogenyMap<'static, Self::BaseField>;
+}
+
+/// The simplified SWU map composed with an isogeny, for the curve defined by
+/// `P`.
+pub struct WBMap<P: WBParameters> {
+    swu_map: SWUMap<P::IsogenousCurve>,
+    _params: PhantomData<fn() -> P>,
+}
+
+impl<P: WBParameters> MapToCurve<GroupAffine<P>> for WBMap<P> {
+    fn new() -> Result<Self, HashToCurveError> {
+        let generator = GroupAffine::<P::IsogenousCurve>::prime_subgroup_generator();
+        let image: GroupAffine<P> = P::ISOGENY_MAP.apply(&generator);
+        if !image.is_on_curve() {
+            return Err(HashToCurveError::UnsupportedCurve(
+                "ISOGENY_MAP does not map IsogenousCurve to the curve",
+            ));
+        }
+        Ok(WBMap {
+            swu_map: SWUMap::new()?,
+            _params: PhantomData,
+        })
+    }
+
+    fn map_to_curve(&self, u: P::BaseField) -> GroupAffine<P> {
+        P::ISOGENY_MAP.apply(&self.swu_map.map_to_curve(u))
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{
+    hashing::{HashToCurve, HashToCurveError, MapToCurve},
+    AffineCurve, ProjectiveCurve,
+};
+use ark_ff::field_hashers::HashToField;
+use ark_std::marker::PhantomData;
+
+/// The `hash_to_curve` and `encode_to_curve` constructions of RFC 9380,
+/// built from the field hasher `H2F` and the map `M2C`.
+pub struct MapToCurveBasedHasher<T, H2F, M2C>
+where
+    T: AffineCurve,
+    H2F: HashToField<T::BaseField>,
+    M2C: MapToCurve<T>,
+{
+    field_hasher: H2F,
+    curve_mapper: M2C,
+    _curve: PhantomData<fn() -> T>,
+}
+
+impl<T, H2F, M2C> HashToCurve<T> for MapToCurveBasedHasher<T, H2F, M2C>
+where
+    T: AffineCurve,
+    H2F: HashToField<T::BaseField>,
+    M2C: MapToCurve<T>,
+{
+    fn new(dst: &[u8]) -> Result<Self, HashToCurveError> {
+        Ok(Self {
+            field_hasher: H2F::new(dst),
+            curve_mapper: M2C::new()?,
+            _curve: PhantomData,
+        })
+    }
+
+    fn hash(&self, msg: &[u8]) -> Result<T, HashToCurveError> {
+        // 1. u = hash_to_field(msg, 2)
+        // 2. Q0 = map_to_curve(u[0])
+        // 3. Q1 = map_to_curve(u[1])
+        // 4. R = Q0 + Q1
+        // 5. P = clear_cofactor(R)
+        let u = self.field_hasher.hash_to_field(msg, 2)?;
+        let q0 = self.curve_mapper.map_to_curve(u[0]);
+        let q1 = self.curve_mapper.map_to_curve(u[1]);
+        let r = q0.into_projective().add_mixed(&q1).into_affine();
+        Ok(r.clear_cofactor())
+    }
+
+    fn encode(&self, msg: &[u8]) -> Result<T, HashToCurveError> {
+        // 1. u = hash_to_field(msg, 1)
+        // 2. Q = map_to_curve(u[0])
+        // 3. P = clear_cofactor(Q)
+        let u = self.field_hasher.hash_to_field(msg, 1)?;
+        Ok(self.curve_mapper.map_to_curve(u[0]).clear_cofactor())
+    }
+}
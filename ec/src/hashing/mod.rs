@@ -0,0 +1,71 @@
+//! Hashing arbitrary messages to elliptic curve points, following
+//! [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380.html).
+//!
+//! A message is first hashed to one or two field elements with a
+//! [`HashToField`](ark_ff::field_hashers::HashToField) implementation, each of
+//! which is then sent to the curve by a [`MapToCurve`] implementation from
+//! [`curve_maps`]. [`MapToCurveBasedHasher`] combines the two and finishes by
+//! clearing the cofactor with [`AffineCurve::clear_cofactor`].
+
+use crate::AffineCurve;
+use ark_ff::field_hashers::HashToFieldError;
+use ark_std::fmt;
+
+pub mod curve_maps;
+
+mod map_to_curve_hasher;
+pub use map_to_curve_hasher::MapToCurveBasedHasher;
+
+/// Hashes arbitrary messages to points in the prime order subgroup of `T`.
+pub trait HashToCurve<T: AffineCurve>: Sized {
+    /// Creates a hasher with the domain separation tag `dst`.
+    fn new(dst: &[u8]) -> Result<Self, HashToCurveError>;
+
+    /// Hashes `msg` to a point whose distribution is indistinguishable from
+    /// uniform. This is `hash_to_curve` in RFC 9380, and is the variant to use
+    /// for random oracle (`_RO_`) suites.
+    fn hash(&self, msg: &[u8]) -> Result<T, HashToCurveError>;
+
+    /// Encodes `msg` as a point, which is cheaper than [`HashToCurve::hash`]
+    /// but whose distribution is not uniform. This is `encode_to_curve` in
+    /// RFC 9380, used by nonuniform (`_NU_`) suites.
+    fn encode(&self, msg: &[u8]) -> Result<T, HashToCurveError>;
+}
+
+/// Maps field elements to points on the curve `T`. The output is not
+/// necessarily in the prime order subgroup.
+pub trait MapToCurve<T: AffineCurve>: Sized {
+    /// Creates the map, checking that the curve parameters are suitable for
+    /// it.
+    fn new() -> Result<Self, HashToCurveError>;
+
+    /// Maps `u` to a point on the curve.
+    fn map_to_curve(&self, u: T::BaseField) -> T;
+}
+
+/// This is an error that could occur while hashing to a curve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashToCurveError {
+    /// The curve parameters do not satisfy the requirements of the chosen
+    /// map; the payload describes the requirement that is violated.
+    UnsupportedCurve(&'static str),
+    /// Hashing the message to field elements failed.
+    FieldHashing(HashToFieldError),
+}
+
+impl ark_std::error::Error for HashToCurveError {}
+
+impl fmt::Display for HashToCurveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            HashToCurveError::UnsupportedCurve(s) => write!(f, "unsupported curve: {}", s),
+            HashToCurveError::FieldHashing(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<HashToFieldError> for HashToCurveError {
+    fn from(e: HashToFieldError) -> Self {
+        HashToCurveError::FieldHashing(e)
+    }
+}
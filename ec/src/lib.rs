@@ -16,7 +16,7 @@ extern crate ark_std;
 use crate::group::Group;
 use ark_ff::{
     bytes::{FromBytes, ToBytes},
-    fields::{Field, PrimeField, SquareRootField},
+    fields::{CyclotomicMultSubgroup, Field, PrimeField, SquareRootField},
     UniformRand,
 };
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
@@ -26,7 +26,7 @@ use ark_std::{
     ops::{Add, AddAssign, MulAssign, Neg, Sub, SubAssign},
     vec::Vec,
 };
-use num_traits::Zero;
+use num_traits::{One, Zero};
 use zeroize::Zeroize;
 
 pub mod models;
@@ -34,10 +34,20 @@ pub use self::models::*;
 
 pub mod group;
 
+pub mod pairing;
+pub use self::pairing::PairingOutput;
+
 pub mod msm;
 
 pub mod wnaf;
 
+pub mod glv;
+
+pub mod hashing;
+
+#[cfg(feature = "ct")]
+pub(crate) mod constant_time;
+
 pub trait PairingEngine: Sized + 'static + Copy + Debug + Sync + Send + Eq + PartialEq {
     /// This is the scalar field of the G1/G2 groups.
     type Fr: PrimeField + SquareRootField;
@@ -55,7 +65,15 @@ pub trait PairingEngine: Sized + 'static + Copy + Debug + Sync + Send + Eq + Par
         + Into<Self::G1Prepared>;
 
     /// A G1 element that has been preprocessed for use in a pairing.
-    type G1Prepared: ToBytes + Default + Clone + Send + Sync + Debug + From<Self::G1Affine>;
+    type G1Prepared: ToBytes
+        + CanonicalSerialize
+        + CanonicalDeserialize
+        + Default
+        + Clone
+        + Send
+        + Sync
+        + Debug
+        + From<Self::G1Affine>;
 
     /// The projective representation of an element in G2.
     type G2Projective: ProjectiveCurve<BaseField = Self::Fqe, ScalarField = Self::Fr, Affine = Self::G2Affine>
@@ -70,7 +88,15 @@ pub trait PairingEngine: Sized + 'static + Copy + Debug + Sync + Send + Eq + Par
         + Into<Self::G2Prepared>;
 
     /// A G2 element that has been preprocessed for use in a pairing.
-    type G2Prepared: ToBytes + Default + Clone + Send + Sync + Debug + From<Self::G2Affine>;
+    type G2Prepared: ToBytes
+        + CanonicalSerialize
+        + CanonicalDeserialize
+        + Default
+        + Clone
+        + Send
+        + Sync
+        + Debug
+        + From<Self::G2Affine>;
 
     /// The base field that hosts G1.
     type Fq: PrimeField + SquareRootField;
@@ -79,7 +105,7 @@ pub trait PairingEngine: Sized + 'static + Copy + Debug + Sync + Send + Eq + Par
     type Fqe: SquareRootField;
 
     /// The extension field that hosts the target group of the pairing.
-    type Fqk: Field;
+    type Fqk: CyclotomicMultSubgroup;
 
     /// Compute the product of miller loops for some number of (G1, G2) pairs.
     #[must_use]
@@ -111,6 +137,31 @@ pub trait PairingEngine: Sized + 'static + Copy + Debug + Sync + Send + Eq + Par
         let g2_prep = Self::G2Prepared::from(q.into());
         Self::product_of_pairings(core::iter::once(&(g1_prep, g2_prep)))
     }
+
+    /// Checks whether the product of the pairings of the pairs in `i` is one,
+    /// with a single Miller loop and a single final exponentiation.
+    #[must_use]
+    fn multi_pairing_is_one<'a, I>(i: I) -> bool
+    where
+        I: IntoIterator<Item = &'a (Self::G1Prepared, Self::G2Prepared)>,
+    {
+        Self::final_exponentiation(&Self::miller_loop(i)).map_or(false, |f| f.is_one())
+    }
+
+    /// Checks whether `e(a, b) = e(c, d)`, by checking that
+    /// `e(a, b) * e(-c, d) = 1` with `Self::multi_pairing_is_one`.
+    #[must_use]
+    fn pairing_check<G1, G2>(a: G1, b: G2, c: G1, d: G2) -> bool
+    where
+        G1: Into<Self::G1Affine>,
+        G2: Into<Self::G2Affine>,
+    {
+        let neg_c = -c.into();
+        Self::multi_pairing_is_one(&[
+            (a.into().into(), b.into().into()),
+            (neg_c.into(), d.into().into()),
+        ])
+    }
 }
 
 /// Projective representation of an elliptic curve point guaranteed to be
@@ -216,6 +267,17 @@ pub trait ProjectiveCurve:
         self = res;
         self
     }
+
+    /// Splits the scalar multiplication `scalar * self` into
+    /// `k1 * p1 + k2 * p2` with `k1`, `k2` of about half the bit length of
+    /// `scalar`, using an endomorphism of the curve (see `crate::glv`).
+    /// Returns `None` for curves without such an endomorphism.
+    ///
+    /// The split is only correct for `self` in the prime order subgroup,
+    /// where the endomorphism acts as a scalar multiplication.
+    fn glv_split(&self, _scalar: &Self::ScalarField) -> Option<[(Self, Self::ScalarField); 2]> {
+        None
+    }
 }
 
 /// Affine representation of an elliptic curve point guaranteed to be
@@ -281,10 +343,66 @@ pub trait AffineCurve:
         self.mul_by_cofactor_to_projective().into()
     }
 
+    /// Maps this element into the prime order subgroup. This defaults to
+    /// `mul_by_cofactor`, but curve models may use a cheaper multiple of the
+    /// cofactor that clears it just as well.
+    #[must_use]
+    fn clear_cofactor(&self) -> Self {
+        self.mul_by_cofactor()
+    }
+
     /// Multiply this element by the inverse of the cofactor in
     /// `Self::ScalarField`.
     #[must_use]
     fn mul_by_cofactor_inv(&self) -> Self;
+
+    /// The affine counterpart of `ProjectiveCurve::glv_split`.
+    fn glv_split(&self, _scalar: &Self::ScalarField) -> Option<[(Self, Self::ScalarField); 2]> {
+        None
+    }
+
+    /// Computes `scalar * self` like `Self::mul`, but with half as many
+    /// doublings on curves for which `Self::glv_split` is available.
+    ///
+    /// `self` must be in the prime order subgroup: for other points, the
+    /// result is not `scalar * self`.
+    fn glv_mul(&self, scalar: &Self::ScalarField) -> Self::Projective {
+        match self.glv_split(scalar) {
+            Some(split) => crate::glv::mul_split(&split),
+            None => self.mul(scalar.into_repr()),
+        }
+    }
+
+    /// Computes `sum_i digit_i * base_i` for the nonzero digits in
+    /// `[-num_buckets, num_buckets]` yielded by `bases_and_digits`, as
+    /// required by a single window of `VariableBaseMSM`.
+    ///
+    /// The default implementation accumulates one projective bucket per digit
+    /// magnitude and combines them with a running sum. Curve models override
+    /// it when a cheaper bucket representation is available.
+    fn msm_window_sum<'a>(
+        bases_and_digits: impl Iterator<Item = (&'a Self, i64)>,
+        num_buckets: usize,
+    ) -> Self::Projective {
+        let mut buckets = vec![Self::Projective::zero(); num_buckets];
+        for (base, digit) in bases_and_digits {
+            if digit > 0 {
+                buckets[(digit - 1) as usize].add_assign_mixed(base);
+            } else {
+                buckets[(-digit - 1) as usize].add_assign_mixed(&-*base);
+            }
+        }
+
+        // `running_sum` = sum_{j in i..num_buckets} bucket[j],
+        // where we iterate backward from i = num_buckets to 0.
+        let mut res = Self::Projective::zero();
+        let mut running_sum = Self::Projective::zero();
+        buckets.into_iter().rev().for_each(|b| {
+            running_sum += &b;
+            res += &running_sum;
+        });
+        res
+    }
 }
 
 impl<C: ProjectiveCurve> Group for C {
@@ -1,11 +1,14 @@
 use crate::{
     bls12::Bls12Parameters,
+    glv::GLVParameters,
+    models::ModelParameters,
     short_weierstrass_jacobian::{GroupAffine, GroupProjective},
-    AffineCurve,
+    short_weierstrass_projective, AffineCurve, ProjectiveCurve,
 };
-use ark_ff::bytes::ToBytes;
-use ark_std::io::{Result as IoResult, Write};
-use num_traits::Zero;
+use ark_ff::{bytes::ToBytes, BitIteratorBE, Field};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Result as IoResult, Write};
+use num_traits::{One, Zero};
 
 pub type G1Affine<P> = GroupAffine<<P as Bls12Parameters>::G1Parameters>;
 pub type G1Projective<P> = GroupProjective<<P as Bls12Parameters>::G1Parameters>;
@@ -25,6 +28,14 @@ impl<P: Bls12Parameters> From<G1Affine<P>> for G1Prepared<P> {
     }
 }
 
+impl<P: Bls12Parameters> From<short_weierstrass_projective::GroupAffine<P::G1Parameters>>
+    for G1Prepared<P>
+{
+    fn from(other: short_weierstrass_projective::GroupAffine<P::G1Parameters>) -> Self {
+        G1Prepared(other.into())
+    }
+}
+
 impl<P: Bls12Parameters> G1Prepared<P> {
     pub fn is_zero(&self) -> bool {
         self.0.is_zero()
@@ -42,3 +53,100 @@ impl<P: Bls12Parameters> ToBytes for G1Prepared<P> {
         self.0.write(writer)
     }
 }
+
+impl<P: Bls12Parameters> CanonicalSerialize for G1Prepared<P> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.0.serialize(writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.0.serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.0.serialize_uncompressed(writer)
+    }
+
+    #[inline]
+    fn serialize_unchecked<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.0.serialize_unchecked(writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.0.uncompressed_size()
+    }
+}
+
+impl<P: Bls12Parameters> CanonicalDeserialize for G1Prepared<P> {
+    #[inline]
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        G1Affine::<P>::deserialize(reader).map(G1Prepared)
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        G1Affine::<P>::deserialize_uncompressed(reader).map(G1Prepared)
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        G1Affine::<P>::deserialize_unchecked(reader).map(G1Prepared)
+    }
+}
+
+/// Checks that `p`, which is assumed to be on the curve, lies in G1, using
+/// the endomorphism `φ(x, y) = (β * x, y)` of `P::G1Parameters`: by Section 6
+/// of [Sco21](https://eprint.iacr.org/2021/1130), `p` is in G1 if and only if
+/// `φ(p) = -x^2 * p`, for the `φ` whose eigenvalue on G1 is `-x^2`. The other
+/// choice of `β`, with eigenvalue `x^2 - 1`, is handled as well.
+///
+/// Curves can implement `SWModelParameters::is_in_correct_subgroup_assuming_on_curve`
+/// with this function.
+pub fn is_in_correct_subgroup_assuming_on_curve<P: Bls12Parameters>(p: &G1Affine<P>) -> bool
+where
+    P::G1Parameters: GLVParameters,
+{
+    let x = limbs_to_field::<ScalarField<P>>(P::X);
+    let x_squared = x.square();
+    let lambda = <P::G1Parameters as GLVParameters>::LAMBDA;
+    let eigenvalue_is_minus_x_squared = lambda + &x_squared == ScalarField::<P>::zero();
+    if !eigenvalue_is_minus_x_squared && lambda != x_squared - &ScalarField::<P>::one() {
+        return p
+            .into_projective()
+            .mul(ScalarField::<P>::characteristic())
+            .is_zero();
+    }
+
+    let p_proj = p.into_projective();
+    let x_times_p = p_proj.mul(P::X);
+    // The early exit of Section 6: points with `x * p = p` are not in G1.
+    if x_times_p == p_proj && !p.is_zero() {
+        return false;
+    }
+    let x_squared_times_p = x_times_p.mul(P::X);
+    let phi_p = <P::G1Parameters as GLVParameters>::endomorphism(p).into_projective();
+    if eigenvalue_is_minus_x_squared {
+        phi_p == -x_squared_times_p
+    } else {
+        // φ' = φ^2 = -φ - 1, so φ'(p) = (x^2 - 1) * p if and only if φ(p) = -x^2 * p.
+        phi_p + &p_proj == x_squared_times_p
+    }
+}
+
+type ScalarField<P> = <<P as Bls12Parameters>::G1Parameters as ModelParameters>::ScalarField;
+
+/// Reads little-endian `limbs` into `F` bit by bit, without allocating.
+fn limbs_to_field<F: Field>(limbs: &[u64]) -> F {
+    BitIteratorBE::without_leading_zeros(limbs).fold(F::zero(), |acc, bit| {
+        let acc = acc.double();
+        if bit {
+            acc + &F::one()
+        } else {
+            acc
+        }
+    })
+}
@@ -1,20 +1,24 @@
 use ark_std::{
-    io::{Result as IoResult, Write},
+    io::{Read, Result as IoResult, Write},
     vec::Vec,
 };
 
 use ark_ff::{
     bytes::ToBytes,
-    fields::{BitIteratorBE, Field, Fp2},
+    fields::{
+        fp12_2over3over2::Fp12Parameters, fp6_3over2::Fp6Parameters, BitIteratorBE, Field, Fp2,
+    },
 };
 
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+
 use num_traits::{One, Zero};
 
 use crate::{
     bls12::{Bls12Parameters, TwistType},
     models::SWModelParameters,
     short_weierstrass_jacobian::{GroupAffine, GroupProjective},
-    AffineCurve,
+    AffineCurve, ProjectiveCurve,
 };
 
 pub type G2Affine<P> = GroupAffine<<P as Bls12Parameters>::G2Parameters>;
@@ -100,6 +104,90 @@ impl<P: Bls12Parameters> G2Prepared<P> {
     pub fn is_zero(&self) -> bool {
         self.infinity
     }
+
+    fn check_ell_coeffs_len(self) -> Result<Self, SerializationError> {
+        let expected_len = if self.infinity {
+            0
+        } else {
+            ell_coeffs_len::<P>()
+        };
+        if self.ell_coeffs.len() != expected_len {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(self)
+    }
+}
+
+/// Returns the number of line coefficients in the `G2Prepared` form of a point
+/// other than the point at infinity: one per doubling step and one per
+/// addition step of the Miller loop.
+fn ell_coeffs_len<P: Bls12Parameters>() -> usize {
+    BitIteratorBE::new(P::X)
+        .skip(1)
+        .map(|bit| 1 + bit as usize)
+        .sum()
+}
+
+impl<P: Bls12Parameters> CanonicalSerialize for G2Prepared<P> {
+    #[inline]
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.ell_coeffs.serialize(&mut writer)?;
+        self.infinity.serialize(writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.ell_coeffs.serialized_size() + self.infinity.serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.ell_coeffs.serialize_uncompressed(&mut writer)?;
+        self.infinity.serialize_uncompressed(writer)
+    }
+
+    #[inline]
+    fn serialize_unchecked<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.ell_coeffs.serialize_unchecked(&mut writer)?;
+        self.infinity.serialize_unchecked(writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.ell_coeffs.uncompressed_size() + self.infinity.uncompressed_size()
+    }
+}
+
+/// The line coefficients are field elements, so the compressed and
+/// uncompressed encodings agree. Deserialization always checks that there are
+/// as many coefficients as the Miller loop consumes.
+impl<P: Bls12Parameters> CanonicalDeserialize for G2Prepared<P> {
+    #[inline]
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        Self {
+            ell_coeffs: CanonicalDeserialize::deserialize(&mut reader)?,
+            infinity: CanonicalDeserialize::deserialize(reader)?,
+        }
+        .check_ell_coeffs_len()
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        Self {
+            ell_coeffs: CanonicalDeserialize::deserialize_uncompressed(&mut reader)?,
+            infinity: CanonicalDeserialize::deserialize_uncompressed(reader)?,
+        }
+        .check_ell_coeffs_len()
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        Self {
+            ell_coeffs: CanonicalDeserialize::deserialize_unchecked(&mut reader)?,
+            infinity: CanonicalDeserialize::deserialize_unchecked(reader)?,
+        }
+        .check_ell_coeffs_len()
+    }
 }
 
 fn doubling_step<B: Bls12Parameters>(
@@ -155,3 +243,71 @@ fn addition_step<B: Bls12Parameters>(
         TwistType::D => (lambda, -theta, j),
     }
 }
+
+/// Checks that `p`, which is assumed to be on the curve, lies in G2, using
+/// the test of Section 4 of [Sco21](https://eprint.iacr.org/2021/1130): `p`
+/// is in G2 if and only if `ψ(p) = x * p`, where `ψ` is the untwist-Frobenius-twist
+/// endomorphism.
+///
+/// Curves can implement `SWModelParameters::is_in_correct_subgroup_assuming_on_curve`
+/// with this function.
+pub fn is_in_correct_subgroup_assuming_on_curve<P: Bls12Parameters>(p: &G2Affine<P>) -> bool {
+    let p = p.into_projective();
+    let mut x_times_p = p.mul(P::X);
+    if P::X_IS_NEGATIVE {
+        x_times_p = -x_times_p;
+    }
+    psi::<P>(&p) == x_times_p
+}
+
+/// Maps `p` into G2 by multiplying it by the effective cofactor `h_eff` of
+/// [BP17](https://eprint.iacr.org/2017/419), which is also the one used for
+/// BLS12-381 in RFC 9380. Using `ψ`, the multiplication becomes
+/// `h_eff * p = (x^2 - x - 1) * p + (x - 1) * ψ(p) + ψ^2(2 * p)`, which needs
+/// two multiplications by `x` instead of one by the much larger cofactor.
+///
+/// Curves can implement `SWModelParameters::clear_cofactor` with this
+/// function.
+pub fn clear_cofactor<P: Bls12Parameters>(p: &G2Affine<P>) -> G2Affine<P> {
+    let mul_by_x = |q: &G2Projective<P>| {
+        let res = q.mul(P::X);
+        if P::X_IS_NEGATIVE {
+            -res
+        } else {
+            res
+        }
+    };
+    let p = p.into_projective();
+    let x_p = mul_by_x(&p);
+    let psi_p = psi::<P>(&p);
+    let psi2_2p = psi::<P>(&psi::<P>(&p.double()));
+    // x * (x * p + ψ(p)) - x * p - p = (x^2 - x - 1) * p + x * ψ(p)
+    let res = mul_by_x(&(x_p + &psi_p)) - &x_p - &p - &psi_p + &psi2_2p;
+    res.into_affine()
+}
+
+/// Computes `ψ(p) = (x^q * c_x, y^q * c_y)`, where `q` is the characteristic
+/// and `(c_x, c_y) = (ξ^((q - 1) / 3), ξ^((q - 1) / 2))` for a D-type twist, or
+/// their inverses for an M-type twist, with `ξ = Fp6Params::NONRESIDUE`.
+///
+/// In Jacobian coordinates the same map applies to `(X, Y)`, with `Z` raised
+/// to the power `q`.
+pub(crate) fn psi<P: Bls12Parameters>(p: &G2Projective<P>) -> G2Projective<P> {
+    // ξ^((q - 1) / 3) and ξ^((q - 1) / 6) are the first Frobenius coefficients
+    // of Fp6 and Fp12 respectively.
+    let c_x = P::Fp6Params::FROBENIUS_COEFF_FP6_C1[1];
+    let c_y = P::Fp12Params::FROBENIUS_COEFF_FP12_C1[1];
+    let c_y = c_y.square() * &c_y;
+    let (c_x, c_y) = match P::TWIST_TYPE {
+        TwistType::M => (c_x.inverse().unwrap(), c_y.inverse().unwrap()),
+        TwistType::D => (c_x, c_y),
+    };
+
+    let mut res = *p;
+    res.x.frobenius_map(1);
+    res.y.frobenius_map(1);
+    res.z.frobenius_map(1);
+    res.x *= &c_x;
+    res.y *= &c_y;
+    res
+}
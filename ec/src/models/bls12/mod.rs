@@ -1,5 +1,5 @@
 use crate::{
-    models::{ModelParameters, SWModelParameters},
+    models::{ModelParameters, SWCurveModel, SWJacobian, SWModelParameters},
     PairingEngine,
 };
 use ark_ff::fields::{
@@ -54,11 +54,15 @@ pub use self::{
     g2::{G2Affine, G2Prepared, G2Projective},
 };
 
+/// The pairing engine of a BLS12 curve, whose G1 points use the coordinates of
+/// `M`: `SWJacobian` by default, or `SWProjective` for complete formulas.
 #[derive(Derivative)]
 #[derivative(Copy, Clone, PartialEq, Eq, Debug, Hash)]
-pub struct Bls12<P: Bls12Parameters>(PhantomData<fn() -> P>);
+pub struct Bls12<P: Bls12Parameters, M: SWCurveModel<P::G1Parameters> = SWJacobian>(
+    PhantomData<fn() -> (P, M)>,
+);
 
-impl<P: Bls12Parameters> Bls12<P> {
+impl<P: Bls12Parameters, M: SWCurveModel<P::G1Parameters>> Bls12<P, M> {
     // Evaluate the line function at point p.
     fn ell(f: &mut Fp12<P::Fp12Params>, coeffs: &g2::EllCoeff<Fp2<P::Fp2Params>>, p: &G1Affine<P>) {
         let mut c0 = coeffs.0;
@@ -86,12 +90,85 @@ impl<P: Bls12Parameters> Bls12<P> {
             result.conjugate();
         }
     }
+
+    /// Computes the final exponentiation of `f` as a product of two factors,
+    /// which lets `multi_pairing_is_one` skip the last multiplication.
+    fn final_exponentiation_factors(
+        f: &Fp12<P::Fp12Params>,
+    ) -> Option<(Fp12<P::Fp12Params>, Fp12<P::Fp12Params>)> {
+        // Computing the final exponentation following
+        // https://eprint.iacr.org/2020/875
+        // Adapted from the implementation in https://github.com/ConsenSys/gurvy/pull/29
+
+        // f1 = r.conjugate() = f^(p^6)
+        let mut f1 = *f;
+        f1.conjugate();
+
+        f.inverse().map(|mut f2| {
+            // f2 = f^(-1);
+            // r = f^(p^6 - 1)
+            let mut r = f1 * &f2;
+
+            // f2 = f^(p^6 - 1)
+            f2 = r;
+            // r = f^((p^6 - 1)(p^2))
+            r.frobenius_map(2);
+
+            // r = f^((p^6 - 1)(p^2) + (p^6 - 1))
+            // r = f^((p^6 - 1)(p^2 + 1))
+            r *= &f2;
+
+            // Hard part of the final exponentation:
+            // t[0].CyclotomicSquare(&result)
+            let mut y0 = r.cyclotomic_square();
+            // t[1].Expt(&result)
+            let mut y1 = Fp12::zero();
+            Self::exp_by_x(&r, &mut y1);
+            // t[2].InverseUnitary(&result)
+            let mut y2 = r;
+            y2.conjugate();
+            // t[1].Mul(&t[1], &t[2])
+            y1 *= &y2;
+            // t[2].Expt(&t[1])
+            Self::exp_by_x(&y1, &mut y2);
+            // t[1].InverseUnitary(&t[1])
+            y1.conjugate();
+            // t[1].Mul(&t[1], &t[2])
+            y1 *= &y2;
+            // t[2].Expt(&t[1])
+            Self::exp_by_x(&y1, &mut y2);
+            // t[1].Frobenius(&t[1])
+            y1.frobenius_map(1);
+            // t[1].Mul(&t[1], &t[2])
+            y1 *= &y2;
+            // result.Mul(&result, &t[0])
+            r *= &y0;
+            // t[0].Expt(&t[1])
+            Self::exp_by_x(&y1, &mut y0);
+            // t[2].Expt(&t[0])
+            Self::exp_by_x(&y0, &mut y2);
+            // t[0].FrobeniusSquare(&t[1])
+            y0 = y1;
+            y0.frobenius_map(2);
+            // t[1].InverseUnitary(&t[1])
+            y1.conjugate();
+            // t[1].Mul(&t[1], &t[2])
+            y1 *= &y2;
+            // t[1].Mul(&t[1], &t[0])
+            y1 *= &y0;
+            // result.Mul(&result, &t[1])
+            (r, y1)
+        })
+    }
 }
 
-impl<P: Bls12Parameters> PairingEngine for Bls12<P> {
+impl<P: Bls12Parameters, M: SWCurveModel<P::G1Parameters>> PairingEngine for Bls12<P, M>
+where
+    G1Prepared<P>: From<M::Affine>,
+{
     type Fr = <P::G1Parameters as ModelParameters>::ScalarField;
-    type G1Projective = G1Projective<P>;
-    type G1Affine = G1Affine<P>;
+    type G1Projective = M::Projective;
+    type G1Affine = M::Affine;
     type G1Prepared = G1Prepared<P>;
     type G2Projective = G2Projective<P>;
     type G2Affine = G2Affine<P>;
@@ -188,69 +265,18 @@ impl<P: Bls12Parameters> PairingEngine for Bls12<P> {
     }
 
     fn final_exponentiation(f: &Self::Fqk) -> Option<Self::Fqk> {
-        // Computing the final exponentation following
-        // https://eprint.iacr.org/2020/875
-        // Adapted from the implementation in https://github.com/ConsenSys/gurvy/pull/29
-
-        // f1 = r.conjugate() = f^(p^6)
-        let mut f1 = *f;
-        f1.conjugate();
-
-        f.inverse().map(|mut f2| {
-            // f2 = f^(-1);
-            // r = f^(p^6 - 1)
-            let mut r = f1 * &f2;
-
-            // f2 = f^(p^6 - 1)
-            f2 = r;
-            // r = f^((p^6 - 1)(p^2))
-            r.frobenius_map(2);
-
-            // r = f^((p^6 - 1)(p^2) + (p^6 - 1))
-            // r = f^((p^6 - 1)(p^2 + 1))
-            r *= &f2;
+        Self::final_exponentiation_factors(f).map(|(r, y)| r * &y)
+    }
 
-            // Hard part of the final exponentation:
-            // t[0].CyclotomicSquare(&result)
-            let mut y0 = r.cyclotomic_square();
-            // t[1].Expt(&result)
-            let mut y1 = Fp12::zero();
-            Self::exp_by_x(&r, &mut y1);
-            // t[2].InverseUnitary(&result)
-            let mut y2 = r;
-            y2.conjugate();
-            // t[1].Mul(&t[1], &t[2])
-            y1 *= &y2;
-            // t[2].Expt(&t[1])
-            Self::exp_by_x(&y1, &mut y2);
-            // t[1].InverseUnitary(&t[1])
-            y1.conjugate();
-            // t[1].Mul(&t[1], &t[2])
-            y1 *= &y2;
-            // t[2].Expt(&t[1])
-            Self::exp_by_x(&y1, &mut y2);
-            // t[1].Frobenius(&t[1])
-            y1.frobenius_map(1);
-            // t[1].Mul(&t[1], &t[2])
-            y1 *= &y2;
-            // result.Mul(&result, &t[0])
-            r *= &y0;
-            // t[0].Expt(&t[1])
-            Self::exp_by_x(&y1, &mut y0);
-            // t[2].Expt(&t[0])
-            Self::exp_by_x(&y0, &mut y2);
-            // t[0].FrobeniusSquare(&t[1])
-            y0 = y1;
-            y0.frobenius_map(2);
-            // t[1].InverseUnitary(&t[1])
-            y1.conjugate();
-            // t[1].Mul(&t[1], &t[2])
-            y1 *= &y2;
-            // t[1].Mul(&t[1], &t[0])
-            y1 *= &y0;
-            // result.Mul(&result, &t[1])
-            r *= &y1;
-            r
+    fn multi_pairing_is_one<'a, I>(i: I) -> bool
+    where
+        I: IntoIterator<Item = &'a (Self::G1Prepared, Self::G2Prepared)>,
+    {
+        // Both factors are unitary, so their product is one if and only if
+        // one is the conjugate of the other.
+        Self::final_exponentiation_factors(&Self::miller_loop(i)).map_or(false, |(r, mut y)| {
+            y.conjugate();
+            r == y
         })
     }
 }
@@ -0,0 +1,89 @@
+use crate::{
+    bls24::Bls24Parameters,
+    short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    AffineCurve,
+};
+use ark_ff::bytes::ToBytes;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Result as IoResult, Write};
+use num_traits::Zero;
+
+pub type G1Affine<P> = GroupAffine<<P as Bls24Parameters>::G1Parameters>;
+pub type G1Projective<P> = GroupProjective<<P as Bls24Parameters>::G1Parameters>;
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "P: Bls24Parameters"),
+    Debug(bound = "P: Bls24Parameters"),
+    PartialEq(bound = "P: Bls24Parameters"),
+    Eq(bound = "P: Bls24Parameters")
+)]
+pub struct G1Prepared<P: Bls24Parameters>(pub G1Affine<P>);
+
+impl<P: Bls24Parameters> From<G1Affine<P>> for G1Prepared<P> {
+    fn from(other: G1Affine<P>) -> Self {
+        G1Prepared(other)
+    }
+}
+
+impl<P: Bls24Parameters> G1Prepared<P> {
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<P: Bls24Parameters> Default for G1Prepared<P> {
+    fn default() -> Self {
+        G1Prepared(G1Affine::<P>::prime_subgroup_generator())
+    }
+}
+
+impl<P: Bls24Parameters> ToBytes for G1Prepared<P> {
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write(writer)
+    }
+}
+
+impl<P: Bls24Parameters> CanonicalSerialize for G1Prepared<P> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.0.serialize(writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.0.serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.0.serialize_uncompressed(writer)
+    }
+
+    #[inline]
+    fn serialize_unchecked<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.0.serialize_unchecked(writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.0.uncompressed_size()
+    }
+}
+
+impl<P: Bls24Parameters> CanonicalDeserialize for G1Prepared<P> {
+    #[inline]
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        G1Affine::<P>::deserialize(reader).map(G1Prepared)
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        G1Affine::<P>::deserialize_uncompressed(reader).map(G1Prepared)
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        G1Affine::<P>::deserialize_unchecked(reader).map(G1Prepared)
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{
+    models::{ModelParameters, SWModelParameters},
+    PairingEngine,
+};
+use ark_ff::fields::{
+    fp12_3over2over2::Fp12Parameters,
+    fp2::Fp2Parameters,
+    fp24_2over3over2over2::{Fp24, Fp24Parameters},
+    BitIteratorBE, Field, Fp4, Fp4Parameters, PrimeField, SquareRootField,
+};
+use core::marker::PhantomData;
+use num_traits::One;
+
+/// A particular BLS24 group can have G2 being either a multiplicative or a
+/// divisive twist.
+pub enum TwistType {
+    M,
+    D,
+}
+
+/// The parameters of a curve in the BLS24 family, whose embedding degree is
+/// 24. G2 lies on a sextic twist defined over `Fp4`, and the target group in
+/// `Fp24`, built as a quadratic extension of a cubic extension of `Fp4`.
+pub trait Bls24Parameters: 'static {
+    /// Parameterizes the BLS24 family.
+    const X: &'static [u64];
+    /// Is `Self::X` negative?
+    const X_IS_NEGATIVE: bool;
+    /// What kind of twist is this?
+    const TWIST_TYPE: TwistType;
+
+    type Fp: PrimeField + SquareRootField + Into<<Self::Fp as PrimeField>::BigInt>;
+    type Fp2Params: Fp2Parameters<Fp = Self::Fp>;
+    type Fp4Params: Fp4Parameters<Fp2Params = Self::Fp2Params>;
+    type Fp12Params: Fp12Parameters<Fp4Params = Self::Fp4Params>;
+    type Fp24Params: Fp24Parameters<Fp12Params = Self::Fp12Params>;
+    type G1Parameters: SWModelParameters<BaseField = Self::Fp>;
+    type G2Parameters: SWModelParameters<
+        BaseField = Fp4<Self::Fp4Params>,
+        ScalarField = <Self::G1Parameters as ModelParameters>::ScalarField,
+    >;
+}
+
+pub mod g1;
+pub mod g2;
+
+pub use self::{
+    g1::{G1Affine, G1Prepared, G1Projective},
+    g2::{G2Affine, G2Prepared, G2Projective},
+};
+
+#[derive(Derivative)]
+#[derivative(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Bls24<P: Bls24Parameters>(PhantomData<fn() -> P>);
+
+impl<P: Bls24Parameters> Bls24<P> {
+    // Evaluate the line function at point p.
+    fn ell(f: &mut Fp24<P::Fp24Params>, coeffs: &g2::EllCoeff<Fp4<P::Fp4Params>>, p: &G1Affine<P>) {
+        let mut c0 = coeffs.0;
+        let mut c1 = coeffs.1;
+        let mut c2 = coeffs.2;
+
+        match P::TWIST_TYPE {
+            TwistType::M => {
+                c2.mul_by_fp(&p.y);
+                c1.mul_by_fp(&p.x);
+                f.mul_by_014(&c0, &c1, &c2);
+            },
+            TwistType::D => {
+                c0.mul_by_fp(&p.y);
+                c1.mul_by_fp(&p.x);
+                f.mul_by_034(&c0, &c1, &c2);
+            },
+        }
+    }
+
+    // Exponentiates `f` by `Self::X`.
+    fn exp_by_x(f: &Fp24<P::Fp24Params>) -> Fp24<P::Fp24Params> {
+        let mut result = f.cyclotomic_exp(P::X);
+        if P::X_IS_NEGATIVE {
+            result.conjugate();
+        }
+        result
+    }
+
+    /// Computes the final exponentiation of `f` as a product of two factors,
+    /// which lets `multi_pairing_is_one` skip the last multiplication.
+    fn final_exponentiation_factors(
+        f: &Fp24<P::Fp24Params>,
+    ) -> Option<(Fp24<P::Fp24Params>, Fp24<P::Fp24Params>)> {
+        // Easy part: f^((p^12 - 1)(p^4 + 1)).
+        let mut f1 = *f;
+        f1.conjugate();
+
+        f.inverse().map(|f2| {
+            // r = f^(p^12 - 1)
+            let mut r = f1 * &f2;
+            let f2 = r;
+            // r = f^((p^12 - 1)(p^4 + 1))
+            r.frobenius_map(4);
+            r *= &f2;
+
+            // Hard part: r^(3 * (p^8 - p^4 + 1) / q), where q is the group
+            // order, using the decomposition
+            // 3 * (p^8 - p^4 + 1) / q
+            //   = (x - 1)^2 * (x + p) * (x^2 + p^2) * (x^4 + p^4 - 1) + 3
+            // which generalizes the one for BLS12 of
+            // https://eprint.iacr.org/2020/875.
+            //
+            // y = r^(x - 1)
+            let mut r_inv = r;
+            r_inv.conjugate();
+            let mut y = Self::exp_by_x(&r) * &r_inv;
+            // y = r^((x - 1)^2)
+            let mut y_inv = y;
+            y_inv.conjugate();
+            y = Self::exp_by_x(&y) * &y_inv;
+            // y = y^(x + p)
+            let mut y_p = y;
+            y_p.frobenius_map(1);
+            y = Self::exp_by_x(&y) * &y_p;
+            // y = y^(x^2 + p^2)
+            let mut y_p2 = y;
+            y_p2.frobenius_map(2);
+            y = Self::exp_by_x(&Self::exp_by_x(&y)) * &y_p2;
+            // y = y^(x^4 + p^4 - 1)
+            let mut y_p4 = y;
+            y_p4.frobenius_map(4);
+            let mut y_inv = y;
+            y_inv.conjugate();
+            let mut y_x4 = y;
+            for _ in 0..4 {
+                y_x4 = Self::exp_by_x(&y_x4);
+            }
+            y = y_x4 * &y_p4 * &y_inv;
+
+            // r^3
+            let r_cubed = r.cyclotomic_square() * &r;
+            (y, r_cubed)
+        })
+    }
+}
+
+impl<P: Bls24Parameters> PairingEngine for Bls24<P> {
+    type Fr = <P::G1Parameters as ModelParameters>::ScalarField;
+    type G1Projective = G1Projective<P>;
+    type G1Affine = G1Affine<P>;
+    type G1Prepared = G1Prepared<P>;
+    type G2Projective = G2Projective<P>;
+    type G2Affine = G2Affine<P>;
+    type G2Prepared = G2Prepared<P>;
+    type Fq = P::Fp;
+    type Fqe = Fp4<P::Fp4Params>;
+    type Fqk = Fp24<P::Fp24Params>;
+
+    fn miller_loop<'a, I>(i: I) -> Self::Fqk
+    where
+        I: IntoIterator<Item = &'a (Self::G1Prepared, Self::G2Prepared)>,
+    {
+        let mut pairs = vec![];
+        for (p, q) in i {
+            if !p.is_zero() && !q.is_zero() {
+                pairs.push((p, q.ell_coeffs.iter()));
+            }
+        }
+        let mut f = Self::Fqk::one();
+        for i in BitIteratorBE::without_leading_zeros(P::X).skip(1) {
+            f.square_in_place();
+            for (p, ref mut coeffs) in &mut pairs {
+                Self::ell(&mut f, coeffs.next().unwrap(), &p.0);
+            }
+            if i {
+                for &mut (p, ref mut coeffs) in &mut pairs {
+                    Self::ell(&mut f, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+        if P::X_IS_NEGATIVE {
+            f.conjugate();
+        }
+        f
+    }
+
+    fn final_exponentiation(f: &Self::Fqk) -> Option<Self::Fqk> {
+        Self::final_exponentiation_factors(f).map(|(r, y)| r * &y)
+    }
+
+    fn multi_pairing_is_one<'a, I>(i: I) -> bool
+    where
+        I: IntoIterator<Item = &'a (Self::G1Prepared, Self::G2Prepared)>,
+    {
+        // Both factors are unitary, so their product is one if and only if
+        // one is the conjugate of the other.
+        Self::final_exponentiation_factors(&Self::miller_loop(i)).map_or(false, |(r, mut y)| {
+            y.conjugate();
+            r == y
+        })
+    }
+}
@@ -1,10 +1,11 @@
 use crate::{
     bn::BnParameters,
     short_weierstrass_jacobian::{GroupAffine, GroupProjective},
-    AffineCurve,
+    short_weierstrass_projective, AffineCurve,
 };
 use ark_ff::bytes::ToBytes;
-use ark_std::io::{Result as IoResult, Write};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Result as IoResult, Write};
 use num_traits::Zero;
 
 pub type G1Affine<P> = GroupAffine<<P as BnParameters>::G1Parameters>;
@@ -25,6 +26,14 @@ impl<P: BnParameters> From<G1Affine<P>> for G1Prepared<P> {
     }
 }
 
+impl<P: BnParameters> From<short_weierstrass_projective::GroupAffine<P::G1Parameters>>
+    for G1Prepared<P>
+{
+    fn from(other: short_weierstrass_projective::GroupAffine<P::G1Parameters>) -> Self {
+        G1Prepared(other.into())
+    }
+}
+
 impl<P: BnParameters> G1Prepared<P> {
     pub fn is_zero(&self) -> bool {
         self.0.is_zero()
@@ -42,3 +51,47 @@ impl<P: BnParameters> ToBytes for G1Prepared<P> {
         self.0.write(writer)
     }
 }
+
+impl<P: BnParameters> CanonicalSerialize for G1Prepared<P> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.0.serialize(writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.0.serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.0.serialize_uncompressed(writer)
+    }
+
+    #[inline]
+    fn serialize_unchecked<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.0.serialize_unchecked(writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.0.uncompressed_size()
+    }
+}
+
+impl<P: BnParameters> CanonicalDeserialize for G1Prepared<P> {
+    #[inline]
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        G1Affine::<P>::deserialize(reader).map(G1Prepared)
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        G1Affine::<P>::deserialize_uncompressed(reader).map(G1Prepared)
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        G1Affine::<P>::deserialize_unchecked(reader).map(G1Prepared)
+    }
+}
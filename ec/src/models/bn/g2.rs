@@ -1,5 +1,5 @@
 use ark_std::{
-    io::{Result as IoResult, Write},
+    io::{Read, Result as IoResult, Write},
     vec::Vec,
 };
 
@@ -8,13 +8,15 @@ use ark_ff::{
     fields::{Field, Fp2},
 };
 
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+
 use num_traits::{One, Zero};
 
 use crate::{
     bn::{BnParameters, TwistType},
     models::SWModelParameters,
     short_weierstrass_jacobian::{GroupAffine, GroupProjective},
-    AffineCurve,
+    AffineCurve, ProjectiveCurve,
 };
 
 pub type G2Affine<P> = GroupAffine<<P as BnParameters>::G2Parameters>;
@@ -122,6 +124,53 @@ impl<P: BnParameters> G2Prepared<P> {
     pub fn is_zero(&self) -> bool {
         self.infinity
     }
+
+    fn check_ell_coeffs_len(self) -> Result<Self, SerializationError> {
+        let expected_len = if self.infinity {
+            0
+        } else {
+            ell_coeffs_len::<P>()
+        };
+        if self.ell_coeffs.len() != expected_len {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(self)
+    }
+}
+
+/// Returns the number of line coefficients in the `G2Prepared` form of a point
+/// other than the point at infinity: one per doubling step and one per
+/// addition step of the Miller loop, and two for the final addition steps.
+fn ell_coeffs_len<P: BnParameters>() -> usize {
+    P::ATE_LOOP_COUNT[..P::ATE_LOOP_COUNT.len() - 1]
+        .iter()
+        .map(|bit| 1 + (*bit != 0) as usize)
+        .sum::<usize>()
+        + 2
+}
+
+impl<P: BnParameters> CanonicalSerialize for G2Prepared<P> {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.ell_coeffs.serialize(&mut writer)?;
+        self.infinity.serialize(writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.ell_coeffs.serialized_size() + self.infinity.serialized_size()
+    }
+}
+
+/// The line coefficients are field elements, so the compressed and
+/// uncompressed encodings agree. Deserialization always checks that there are
+/// as many coefficients as the Miller loop consumes.
+impl<P: BnParameters> CanonicalDeserialize for G2Prepared<P> {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        Self {
+            ell_coeffs: CanonicalDeserialize::deserialize(&mut reader)?,
+            infinity: CanonicalDeserialize::deserialize(reader)?,
+        }
+        .check_ell_coeffs_len()
+    }
 }
 
 fn mul_by_char<P: BnParameters>(r: G2Affine<P>) -> G2Affine<P> {
@@ -189,3 +238,35 @@ fn addition_step<B: BnParameters>(
         TwistType::D => (lambda, -theta, j),
     }
 }
+
+/// Maps `p` into G2 by multiplying it by a multiple of the cofactor, using
+/// the method of [FKR11](https://doi.org/10.1007/978-3-642-28496-0_25) for
+/// BN curves: with the endomorphism `ψ`, this is
+/// `x * p + ψ(3x * p) + ψ^2(x * p) + ψ^3(p)`, which needs one multiplication
+/// by `x` instead of one by the cofactor.
+///
+/// Curves can implement `SWModelParameters::clear_cofactor` with this
+/// function.
+pub fn clear_cofactor<P: BnParameters>(p: &G2Affine<P>) -> G2Affine<P> {
+    let p = p.into_projective();
+    let mut x_p = p.mul(P::X);
+    if P::X_IS_NEGATIVE {
+        x_p = -x_p;
+    }
+    let psi_3x_p = psi::<P>(&(x_p.double() + &x_p));
+    let psi2_x_p = psi::<P>(&psi::<P>(&x_p));
+    let psi3_p = psi::<P>(&psi::<P>(&psi::<P>(&p)));
+    (x_p + &psi_3x_p + &psi2_x_p + &psi3_p).into_affine()
+}
+
+/// The projective counterpart of `mul_by_char`: in Jacobian coordinates,
+/// `Z` is raised to the power `q` as well.
+fn psi<P: BnParameters>(p: &G2Projective<P>) -> G2Projective<P> {
+    let mut res = *p;
+    res.x.frobenius_map(1);
+    res.x *= &P::TWIST_MUL_BY_Q_X;
+    res.y.frobenius_map(1);
+    res.y *= &P::TWIST_MUL_BY_Q_Y;
+    res.z.frobenius_map(1);
+    res
+}
@@ -1,5 +1,5 @@
 use crate::{
-    models::{ModelParameters, SWModelParameters},
+    models::{ModelParameters, SWCurveModel, SWJacobian, SWModelParameters},
     PairingEngine,
 };
 use ark_ff::fields::{
@@ -48,11 +48,15 @@ pub use self::{
     g2::{G2Affine, G2Prepared, G2Projective},
 };
 
+/// The pairing engine of a BN curve, whose G1 points use the coordinates of
+/// `M`: `SWJacobian` by default, or `SWProjective` for complete formulas.
 #[derive(Derivative)]
 #[derivative(Copy, Clone, PartialEq, Eq, Debug, Hash)]
-pub struct Bn<P: BnParameters>(PhantomData<fn() -> P>);
+pub struct Bn<P: BnParameters, M: SWCurveModel<P::G1Parameters> = SWJacobian>(
+    PhantomData<fn() -> (P, M)>,
+);
 
-impl<P: BnParameters> Bn<P> {
+impl<P: BnParameters, M: SWCurveModel<P::G1Parameters>> Bn<P, M> {
     // Evaluate the line function at point p.
     fn ell(f: &mut Fp12<P::Fp12Params>, coeffs: &g2::EllCoeff<Fp2<P::Fp2Params>>, p: &G1Affine<P>) {
         let mut c0 = coeffs.0;
@@ -80,12 +84,79 @@ impl<P: BnParameters> Bn<P> {
         }
         f
     }
+
+    /// Computes the final exponentiation of `f` as a product of two factors,
+    /// which lets `multi_pairing_is_one` skip the last multiplication.
+    fn final_exponentiation_factors(
+        f: &Fp12<P::Fp12Params>,
+    ) -> Option<(Fp12<P::Fp12Params>, Fp12<P::Fp12Params>)> {
+        // Easy part: result = elt^((q^6-1)*(q^2+1)).
+        // Follows, e.g., Beuchat et al page 9, by computing result as follows:
+        //   elt^((q^6-1)*(q^2+1)) = (conj(elt) * elt^(-1))^(q^2+1)
+
+        // f1 = r.conjugate() = f^(p^6)
+        let mut f1 = *f;
+        f1.conjugate();
+
+        f.inverse().map(|mut f2| {
+            // f2 = f^(-1);
+            // r = f^(p^6 - 1)
+            let mut r = f1 * &f2;
+
+            // f2 = f^(p^6 - 1)
+            f2 = r;
+            // r = f^((p^6 - 1)(p^2))
+            r.frobenius_map(2);
+
+            // r = f^((p^6 - 1)(p^2) + (p^6 - 1))
+            // r = f^((p^6 - 1)(p^2 + 1))
+            r *= &f2;
+
+            // Hard part follows Laura Fuentes-Castaneda et al. "Faster hashing to G2"
+            // by computing:
+            //
+            // result = elt^(q^3 * (12*z^3 + 6z^2 + 4z - 1) +
+            //               q^2 * (12*z^3 + 6z^2 + 6z) +
+            //               q   * (12*z^3 + 6z^2 + 4z) +
+            //               1   * (12*z^3 + 12z^2 + 6z + 1))
+            // which equals
+            //
+            // result = elt^( 2z * ( 6z^2 + 3z + 1 ) * (q^4 - q^2 + 1)/r ).
+
+            let y0 = Self::exp_by_neg_x(r);
+            let y1 = y0.cyclotomic_square();
+            let y2 = y1.cyclotomic_square();
+            let mut y3 = y2 * &y1;
+            let y4 = Self::exp_by_neg_x(y3);
+            let y5 = y4.cyclotomic_square();
+            let mut y6 = Self::exp_by_neg_x(y5);
+            y3.conjugate();
+            y6.conjugate();
+            let y7 = y6 * &y4;
+            let mut y8 = y7 * &y3;
+            let y9 = y8 * &y1;
+            let y10 = y8 * &y4;
+            let y11 = y10 * &r;
+            let mut y12 = y9;
+            y12.frobenius_map(1);
+            let y13 = y12 * &y11;
+            y8.frobenius_map(2);
+            let y14 = y8 * &y13;
+            r.conjugate();
+            let mut y15 = r * &y9;
+            y15.frobenius_map(3);
+            (y15, y14)
+        })
+    }
 }
 
-impl<P: BnParameters> PairingEngine for Bn<P> {
+impl<P: BnParameters, M: SWCurveModel<P::G1Parameters>> PairingEngine for Bn<P, M>
+where
+    G1Prepared<P>: From<M::Affine>,
+{
     type Fr = <P::G1Parameters as ModelParameters>::ScalarField;
-    type G1Projective = G1Projective<P>;
-    type G1Affine = G1Affine<P>;
+    type G1Projective = M::Projective;
+    type G1Affine = M::Affine;
     type G1Prepared = G1Prepared<P>;
     type G2Projective = G2Projective<P>;
     type G2Affine = G2Affine<P>;
@@ -149,64 +220,18 @@ impl<P: BnParameters> PairingEngine for Bn<P> {
 
     #[allow(clippy::let_and_return)]
     fn final_exponentiation(f: &Self::Fqk) -> Option<Self::Fqk> {
-        // Easy part: result = elt^((q^6-1)*(q^2+1)).
-        // Follows, e.g., Beuchat et al page 9, by computing result as follows:
-        //   elt^((q^6-1)*(q^2+1)) = (conj(elt) * elt^(-1))^(q^2+1)
-
-        // f1 = r.conjugate() = f^(p^6)
-        let mut f1 = *f;
-        f1.conjugate();
-
-        f.inverse().map(|mut f2| {
-            // f2 = f^(-1);
-            // r = f^(p^6 - 1)
-            let mut r = f1 * &f2;
-
-            // f2 = f^(p^6 - 1)
-            f2 = r;
-            // r = f^((p^6 - 1)(p^2))
-            r.frobenius_map(2);
-
-            // r = f^((p^6 - 1)(p^2) + (p^6 - 1))
-            // r = f^((p^6 - 1)(p^2 + 1))
-            r *= &f2;
-
-            // Hard part follows Laura Fuentes-Castaneda et al. "Faster hashing to G2"
-            // by computing:
-            //
-            // result = elt^(q^3 * (12*z^3 + 6z^2 + 4z - 1) +
-            //               q^2 * (12*z^3 + 6z^2 + 6z) +
-            //               q   * (12*z^3 + 6z^2 + 4z) +
-            //               1   * (12*z^3 + 12z^2 + 6z + 1))
-            // which equals
-            //
-            // result = elt^( 2z * ( 6z^2 + 3z + 1 ) * (q^4 - q^2 + 1)/r ).
-
-            let y0 = Self::exp_by_neg_x(r);
-            let y1 = y0.cyclotomic_square();
-            let y2 = y1.cyclotomic_square();
-            let mut y3 = y2 * &y1;
-            let y4 = Self::exp_by_neg_x(y3);
-            let y5 = y4.cyclotomic_square();
-            let mut y6 = Self::exp_by_neg_x(y5);
-            y3.conjugate();
-            y6.conjugate();
-            let y7 = y6 * &y4;
-            let mut y8 = y7 * &y3;
-            let y9 = y8 * &y1;
-            let y10 = y8 * &y4;
-            let y11 = y10 * &r;
-            let mut y12 = y9;
-            y12.frobenius_map(1);
-            let y13 = y12 * &y11;
-            y8.frobenius_map(2);
-            let y14 = y8 * &y13;
-            r.conjugate();
-            let mut y15 = r * &y9;
-            y15.frobenius_map(3);
-            let y16 = y15 * &y14;
+        Self::final_exponentiation_factors(f).map(|(y15, y14)| y15 * &y14)
+    }
 
-            y16
+    fn multi_pairing_is_one<'a, I>(i: I) -> bool
+    where
+        I: IntoIterator<Item = &'a (Self::G1Prepared, Self::G2Prepared)>,
+    {
+        // Both factors are unitary, so their product is one if and only if
+        // one is the conjugate of the other.
+        Self::final_exponentiation_factors(&Self::miller_loop(i)).map_or(false, |(y15, mut y14)| {
+            y14.conjugate();
+            y15 == y14
         })
     }
 }
@@ -1,10 +1,12 @@
 use crate::{
     bw6::BW6Parameters,
+    glv::GLVParameters,
     short_weierstrass_jacobian::{GroupAffine, GroupProjective},
     AffineCurve,
 };
 use ark_ff::bytes::ToBytes;
-use ark_std::io::{Result as IoResult, Write};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Result as IoResult, Write};
 use num_traits::Zero;
 
 pub type G1Affine<P> = GroupAffine<<P as BW6Parameters>::G1Parameters>;
@@ -42,3 +44,60 @@ impl<P: BW6Parameters> ToBytes for G1Prepared<P> {
         self.0.write(writer)
     }
 }
+
+impl<P: BW6Parameters> CanonicalSerialize for G1Prepared<P> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.0.serialize(writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.0.serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.0.serialize_uncompressed(writer)
+    }
+
+    #[inline]
+    fn serialize_unchecked<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.0.serialize_unchecked(writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.0.uncompressed_size()
+    }
+}
+
+impl<P: BW6Parameters> CanonicalDeserialize for G1Prepared<P> {
+    #[inline]
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        G1Affine::<P>::deserialize(reader).map(G1Prepared)
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        G1Affine::<P>::deserialize_uncompressed(reader).map(G1Prepared)
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        G1Affine::<P>::deserialize_unchecked(reader).map(G1Prepared)
+    }
+}
+
+/// Checks that `p`, which is assumed to be on the curve, lies in G1, using
+/// the endomorphism of `P::G1Parameters` as in Section 4 of
+/// [HGP22](https://eprint.iacr.org/2022/352).
+///
+/// Curves can implement `SWModelParameters::is_in_correct_subgroup_assuming_on_curve`
+/// with this function.
+pub fn is_in_correct_subgroup_assuming_on_curve<P: BW6Parameters>(p: &G1Affine<P>) -> bool
+where
+    P::G1Parameters: GLVParameters,
+{
+    super::is_in_correct_subgroup_with_endomorphism::<P, _>(p)
+}
@@ -1,5 +1,5 @@
 use ark_std::{
-    io::{Result as IoResult, Write},
+    io::{Read, Result as IoResult, Write},
     vec::Vec,
 };
 
@@ -8,10 +8,13 @@ use ark_ff::{
     fields::{BitIteratorBE, Field},
 };
 
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+
 use num_traits::{One, Zero};
 
 use crate::{
     bw6::{BW6Parameters, TwistType},
+    glv::GLVParameters,
     models::SWModelParameters,
     short_weierstrass_jacobian::{GroupAffine, GroupProjective},
     AffineCurve,
@@ -132,6 +135,61 @@ impl<P: BW6Parameters> G2Prepared<P> {
     pub fn is_zero(&self) -> bool {
         self.infinity
     }
+
+    fn check_ell_coeffs_len(self) -> Result<Self, SerializationError> {
+        let (expected_len_1, expected_len_2) = if self.infinity {
+            (0, 0)
+        } else {
+            ell_coeffs_len::<P>()
+        };
+        if self.ell_coeffs_1.len() != expected_len_1 || self.ell_coeffs_2.len() != expected_len_2 {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(self)
+    }
+}
+
+/// Returns the number of line coefficients in each of the two Miller loops for
+/// the `G2Prepared` form of a point other than the point at infinity: one per
+/// doubling step and one per addition step.
+fn ell_coeffs_len<P: BW6Parameters>() -> (usize, usize) {
+    let len_1 = BitIteratorBE::new(P::ATE_LOOP_COUNT_1)
+        .skip(1)
+        .map(|bit| 1 + bit as usize)
+        .sum();
+    let len_2 = P::ATE_LOOP_COUNT_2[..P::ATE_LOOP_COUNT_2.len() - 1]
+        .iter()
+        .map(|bit| 1 + (*bit != 0) as usize)
+        .sum();
+    (len_1, len_2)
+}
+
+impl<P: BW6Parameters> CanonicalSerialize for G2Prepared<P> {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.ell_coeffs_1.serialize(&mut writer)?;
+        self.ell_coeffs_2.serialize(&mut writer)?;
+        self.infinity.serialize(writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.ell_coeffs_1.serialized_size()
+            + self.ell_coeffs_2.serialized_size()
+            + self.infinity.serialized_size()
+    }
+}
+
+/// The line coefficients are field elements, so the compressed and
+/// uncompressed encodings agree. Deserialization always checks that there are
+/// as many coefficients as the Miller loops consume.
+impl<P: BW6Parameters> CanonicalDeserialize for G2Prepared<P> {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        Self {
+            ell_coeffs_1: CanonicalDeserialize::deserialize(&mut reader)?,
+            ell_coeffs_2: CanonicalDeserialize::deserialize(&mut reader)?,
+            infinity: CanonicalDeserialize::deserialize(reader)?,
+        }
+        .check_ell_coeffs_len()
+    }
 }
 
 fn doubling_step<B: BW6Parameters>(r: &mut G2HomProjective<B>) -> (B::Fp, B::Fp, B::Fp) {
@@ -183,3 +241,16 @@ fn addition_step<B: BW6Parameters>(
         TwistType::D => (lambda, -theta, j),
     }
 }
+
+/// Checks that `p`, which is assumed to be on the curve, lies in G2, using
+/// the endomorphism of `P::G2Parameters` as in Section 4 of
+/// [HGP22](https://eprint.iacr.org/2022/352).
+///
+/// Curves can implement `SWModelParameters::is_in_correct_subgroup_assuming_on_curve`
+/// with this function.
+pub fn is_in_correct_subgroup_assuming_on_curve<P: BW6Parameters>(p: &G2Affine<P>) -> bool
+where
+    P::G2Parameters: GLVParameters,
+{
+    super::is_in_correct_subgroup_with_endomorphism::<P, _>(p)
+}
@@ -1,13 +1,18 @@
 use crate::{
-    models::{ModelParameters, SWModelParameters},
-    PairingEngine,
+    glv::GLVParameters,
+    models::{
+        short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+        ModelParameters, SWModelParameters,
+    },
+    AffineCurve, PairingEngine, ProjectiveCurve,
 };
 use ark_ff::fields::{
     fp3::Fp3Parameters,
     fp6_2over3::{Fp6, Fp6Parameters},
     BitIteratorBE, Field, PrimeField, SquareRootField,
 };
-use num_traits::One;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
 
 use core::marker::PhantomData;
 
@@ -78,7 +83,8 @@ impl<P: BW6Parameters> BW6<P> {
     pub fn final_exponentiation(value: &Fp6<P::Fp6Params>) -> Fp6<P::Fp6Params> {
         let value_inv = value.inverse().unwrap();
         let value_to_first_chunk = Self::final_exponentiation_first_chunk(value, &value_inv);
-        Self::final_exponentiation_last_chunk(&value_to_first_chunk)
+        let (result, last_factor) = Self::final_exponentiation_last_chunk(&value_to_first_chunk);
+        result * &last_factor
     }
 
     fn final_exponentiation_first_chunk(
@@ -99,8 +105,12 @@ impl<P: BW6Parameters> BW6<P> {
         alpha * &elt_q3_over_elt
     }
 
-    #[allow(clippy::let_and_return)]
-    fn final_exponentiation_last_chunk(f: &Fp6<P::Fp6Params>) -> Fp6<P::Fp6Params> {
+    /// Computes the hard part of the final exponentiation as a product of two
+    /// factors, which lets `multi_pairing_is_one` skip the last
+    /// multiplication.
+    fn final_exponentiation_last_chunk(
+        f: &Fp6<P::Fp6Params>,
+    ) -> (Fp6<P::Fp6Params>, Fp6<P::Fp6Params>) {
         // hard_part
         // From https://eprint.iacr.org/2020/351.pdf, Alg.6
         // R0(x) := (-103*x^7 + 70*x^6 + 269*x^5 - 197*x^4 - 314*x^3 - 73*x^2 - 263*x - 220)
@@ -201,9 +211,7 @@ impl<P: BW6Parameters> BW6<P> {
         let result18 = result17.square();
         let mut tmp8_p3 = f2_4p * &f4_2p_5p * &f9p;
         tmp8_p3.conjugate();
-        let result19 = result18 * &f1_7 * &f5_7p * &f0p * &tmp8_p3;
-
-        result19
+        (result18 * &f1_7 * &f5_7p * &f0p, tmp8_p3)
     }
 }
 
@@ -294,4 +302,66 @@ impl<P: BW6Parameters> PairingEngine for BW6<P> {
     fn final_exponentiation(f: &Self::Fqk) -> Option<Self::Fqk> {
         Some(Self::final_exponentiation(f))
     }
+
+    fn multi_pairing_is_one<'a, I>(i: I) -> bool
+    where
+        I: IntoIterator<Item = &'a (Self::G1Prepared, Self::G2Prepared)>,
+    {
+        let f = Self::miller_loop(i);
+        f.inverse().map_or(false, |f_inv| {
+            let f = Self::final_exponentiation_first_chunk(&f, &f_inv);
+            // Both factors are unitary, so their product is one if and only
+            // if one is the conjugate of the other.
+            let (result, mut last_factor) = Self::final_exponentiation_last_chunk(&f);
+            last_factor.conjugate();
+            result == last_factor
+        })
+    }
+}
+
+/// The subgroup test of [HGP22](https://eprint.iacr.org/2022/352), Section 4,
+/// shared by G1 and G2: a point `p` of the curve `C` lies in the prime order
+/// subgroup if and only if `(x + 1) * p + φ((x^3 - x^2 + 1) * p) = 0`, where
+/// `φ(x, y) = (β * x, y)` and the eigenvalue `λ` of `φ` on the subgroup
+/// satisfies `(x + 1) + (x^3 - x^2 + 1) * λ = 0`. For the conjugate
+/// eigenvalue `-1 - λ`, the first term becomes `(x^3 - x^2 - x) * p`.
+/// Curves whose `LAMBDA` satisfies neither relation fall back to
+/// multiplication by the group order.
+fn is_in_correct_subgroup_with_endomorphism<P: BW6Parameters, C: GLVParameters>(
+    p: &GroupAffine<C>,
+) -> bool {
+    let x: BigUint = P::X.into();
+    let x = C::ScalarField::from(x);
+    let x = if P::X_IS_NEGATIVE { -x } else { x };
+    let one = C::ScalarField::one();
+    let x_squared = x.square();
+    let a = x + &one;
+    let b = x_squared * &x - &x_squared + &one;
+    let eigenvalue_matches = a + &(b * &C::LAMBDA) == C::ScalarField::zero();
+    if !eigenvalue_matches && (b - &a) + &(b * &C::LAMBDA) != C::ScalarField::zero() {
+        return p
+            .into_projective()
+            .mul(C::ScalarField::characteristic())
+            .is_zero();
+    }
+
+    let mul_by_x = |q: &GroupProjective<C>| {
+        let res = q.mul(P::X);
+        if P::X_IS_NEGATIVE {
+            -res
+        } else {
+            res
+        }
+    };
+    let p = p.into_projective();
+    let x_p = mul_by_x(&p);
+    let x2_p = mul_by_x(&x_p);
+    let x3_p = mul_by_x(&x2_p);
+    let b_p = x3_p - &x2_p + &p;
+    let a_p = if eigenvalue_matches {
+        x_p + &p
+    } else {
+        x3_p - &x2_p - &x_p
+    };
+    (a_p + &C::endomorphism_projective(&b_p)).is_zero()
 }
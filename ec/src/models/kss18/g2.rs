@@ -0,0 +1,249 @@
+use ark_std::{
+    io::{Read, Result as IoResult, Write},
+    vec::Vec,
+};
+
+use ark_ff::{
+    bytes::ToBytes,
+    fields::{Field, Fp3},
+};
+
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+
+use num_traits::{One, Zero};
+
+use crate::{
+    kss18::{Kss18Parameters, TwistType},
+    models::SWModelParameters,
+    short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    AffineCurve,
+};
+
+pub type G2Affine<P> = GroupAffine<<P as Kss18Parameters>::G2Parameters>;
+pub type G2Projective<P> = GroupProjective<<P as Kss18Parameters>::G2Parameters>;
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "P: Kss18Parameters"),
+    Debug(bound = "P: Kss18Parameters"),
+    PartialEq(bound = "P: Kss18Parameters"),
+    Eq(bound = "P: Kss18Parameters")
+)]
+pub struct G2Prepared<P: Kss18Parameters> {
+    // Stores the coefficients of the line evaluations as calculated in
+    // https://eprint.iacr.org/2013/722.pdf
+    pub ell_coeffs: Vec<EllCoeff<Fp3<P::Fp3Params>>>,
+    pub infinity: bool,
+}
+
+pub(crate) type EllCoeff<F> = (F, F, F);
+
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "P: Kss18Parameters"),
+    Copy(bound = "P: Kss18Parameters"),
+    Debug(bound = "P: Kss18Parameters")
+)]
+struct G2HomProjective<P: Kss18Parameters> {
+    x: Fp3<P::Fp3Params>,
+    y: Fp3<P::Fp3Params>,
+    z: Fp3<P::Fp3Params>,
+}
+
+impl<P: Kss18Parameters> Default for G2Prepared<P> {
+    fn default() -> Self {
+        Self::from(G2Affine::<P>::prime_subgroup_generator())
+    }
+}
+
+impl<P: Kss18Parameters> ToBytes for G2Prepared<P> {
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        for coeff in &self.ell_coeffs {
+            coeff.0.write(&mut writer)?;
+            coeff.1.write(&mut writer)?;
+            coeff.2.write(&mut writer)?;
+        }
+        self.infinity.write(writer)
+    }
+}
+
+impl<P: Kss18Parameters> From<G2Affine<P>> for G2Prepared<P> {
+    fn from(q: G2Affine<P>) -> Self {
+        let two_inv = P::Fp::one().double().inverse().unwrap();
+        if q.is_zero() {
+            return Self {
+                ell_coeffs: vec![],
+                infinity: true,
+            };
+        }
+
+        let mut ell_coeffs = vec![];
+        let mut r = G2HomProjective {
+            x: q.x,
+            y: q.y,
+            z: Fp3::one(),
+        };
+
+        let negq = -q;
+
+        for i in (1..P::ATE_LOOP_COUNT.len()).rev() {
+            ell_coeffs.push(doubling_step::<P>(&mut r, &two_inv));
+
+            let bit = P::ATE_LOOP_COUNT[i - 1];
+
+            match bit {
+                1 => {
+                    ell_coeffs.push(addition_step::<P>(&mut r, &q));
+                },
+                -1 => {
+                    ell_coeffs.push(addition_step::<P>(&mut r, &negq));
+                },
+                _ => continue,
+            }
+        }
+
+        if P::X_IS_NEGATIVE {
+            r.y = -r.y;
+        }
+
+        // The lines of `f_{3, π(Q)}`, which equals `f_{3, Q}^p` up to factors
+        // that the final exponentiation removes.
+        let q1 = mul_by_char::<P>(q);
+        let mut t = G2HomProjective {
+            x: q1.x,
+            y: q1.y,
+            z: Fp3::one(),
+        };
+        ell_coeffs.push(doubling_step::<P>(&mut t, &two_inv));
+        ell_coeffs.push(addition_step::<P>(&mut t, &q1));
+
+        // The line through `[x]Q` and `3π(Q)`.
+        let z_inv = t.z.inverse().unwrap();
+        let q3 = G2Affine::<P>::new(t.x * &z_inv, t.y * &z_inv, false);
+        ell_coeffs.push(addition_step::<P>(&mut r, &q3));
+
+        Self {
+            ell_coeffs,
+            infinity: false,
+        }
+    }
+}
+
+impl<P: Kss18Parameters> G2Prepared<P> {
+    pub fn is_zero(&self) -> bool {
+        self.infinity
+    }
+
+    fn check_ell_coeffs_len(self) -> Result<Self, SerializationError> {
+        let expected_len = if self.infinity {
+            0
+        } else {
+            ell_coeffs_len::<P>()
+        };
+        if self.ell_coeffs.len() != expected_len {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(self)
+    }
+}
+
+/// Returns the number of line coefficients in the `G2Prepared` form of a point
+/// other than the point at infinity: one per doubling step and one per
+/// addition step of the Miller loop, and three for the lines that follow it.
+fn ell_coeffs_len<P: Kss18Parameters>() -> usize {
+    P::ATE_LOOP_COUNT[..P::ATE_LOOP_COUNT.len() - 1]
+        .iter()
+        .map(|bit| 1 + (*bit != 0) as usize)
+        .sum::<usize>()
+        + 3
+}
+
+impl<P: Kss18Parameters> CanonicalSerialize for G2Prepared<P> {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.ell_coeffs.serialize(&mut writer)?;
+        self.infinity.serialize(writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.ell_coeffs.serialized_size() + self.infinity.serialized_size()
+    }
+}
+
+/// The line coefficients are field elements, so the compressed and
+/// uncompressed encodings agree. Deserialization always checks that there are
+/// as many coefficients as the Miller loop consumes.
+impl<P: Kss18Parameters> CanonicalDeserialize for G2Prepared<P> {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        Self {
+            ell_coeffs: CanonicalDeserialize::deserialize(&mut reader)?,
+            infinity: CanonicalDeserialize::deserialize(reader)?,
+        }
+        .check_ell_coeffs_len()
+    }
+}
+
+fn mul_by_char<P: Kss18Parameters>(r: G2Affine<P>) -> G2Affine<P> {
+    // multiply by field characteristic
+
+    let mut s = r;
+    s.x.frobenius_map(1);
+    s.x *= &P::TWIST_MUL_BY_Q_X;
+    s.y.frobenius_map(1);
+    s.y *= &P::TWIST_MUL_BY_Q_Y;
+
+    s
+}
+
+fn doubling_step<B: Kss18Parameters>(
+    r: &mut G2HomProjective<B>,
+    two_inv: &B::Fp,
+) -> EllCoeff<Fp3<B::Fp3Params>> {
+    // Formula for line function when working with
+    // homogeneous projective coordinates.
+
+    let mut a = r.x * &r.y;
+    a.mul_assign_by_fp(two_inv);
+    let b = r.y.square();
+    let c = r.z.square();
+    let e = B::G2Parameters::COEFF_B * &(c.double() + &c);
+    let f = e.double() + &e;
+    let mut g = b + &f;
+    g.mul_assign_by_fp(two_inv);
+    let h = (r.y + &r.z).square() - &(b + &c);
+    let i = e - &b;
+    let j = r.x.square();
+    let e_square = e.square();
+
+    r.x = a * &(b - &f);
+    r.y = g.square() - &(e_square.double() + &e_square);
+    r.z = b * &h;
+    match B::TWIST_TYPE {
+        TwistType::M => (i, j.double() + &j, -h),
+        TwistType::D => (-h, j.double() + &j, i),
+    }
+}
+
+fn addition_step<B: Kss18Parameters>(
+    r: &mut G2HomProjective<B>,
+    q: &G2Affine<B>,
+) -> EllCoeff<Fp3<B::Fp3Params>> {
+    // Formula for line function when working with
+    // homogeneous projective coordinates.
+    let theta = r.y - &(q.y * &r.z);
+    let lambda = r.x - &(q.x * &r.z);
+    let c = theta.square();
+    let d = lambda.square();
+    let e = lambda * &d;
+    let f = r.z * &c;
+    let g = r.x * &d;
+    let h = e + &f - &g.double();
+    r.x = lambda * &h;
+    r.y = theta * &(g - &h) - &(e * &r.y);
+    r.z *= &e;
+    let j = theta * &q.x - &(lambda * &q.y);
+
+    match B::TWIST_TYPE {
+        TwistType::M => (j, -theta, lambda),
+        TwistType::D => (lambda, -theta, j),
+    }
+}
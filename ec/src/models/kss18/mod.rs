@@ -0,0 +1,229 @@
+use crate::{
+    models::{ModelParameters, SWModelParameters},
+    PairingEngine,
+};
+use ark_ff::fields::{
+    fp18_2over3over3::{Fp18, Fp18Parameters},
+    fp3::Fp3Parameters,
+    fp9_3over3::Fp9Parameters,
+    Field, Fp3, PrimeField, SquareRootField,
+};
+use num_traits::One;
+
+use core::marker::PhantomData;
+
+/// A particular KSS18 group can have G2 being either a multiplicative or a
+/// divisive twist.
+pub enum TwistType {
+    M,
+    D,
+}
+
+/// The parameters of a curve in the KSS18 family of
+/// [KSS08](https://eprint.iacr.org/2007/452), whose embedding degree is 18.
+/// G2 lies on a sextic twist defined over `Fp3`, and the target group in
+/// `Fp18`, built as a quadratic extension of a cubic extension of `Fp3`.
+pub trait Kss18Parameters: 'static {
+    /// The absolute value of the KSS18 curve parameter `X`, as in
+    /// `q = (X^8 + 5X^7 + 7X^6 + 37X^5 + 188X^4 + 259X^3 + 343X^2 + 1763X + 2401) / 21`.
+    const X: &'static [u64];
+    /// Whether or not `X` is negative.
+    const X_IS_NEGATIVE: bool;
+
+    /// The absolute value of `X` in non-adjacent form, least significant
+    /// digit first.
+    const ATE_LOOP_COUNT: &'static [i8];
+
+    const TWIST_TYPE: TwistType;
+    const TWIST_MUL_BY_Q_X: Fp3<Self::Fp3Params>;
+    const TWIST_MUL_BY_Q_Y: Fp3<Self::Fp3Params>;
+    type Fp: PrimeField + SquareRootField + Into<<Self::Fp as PrimeField>::BigInt>;
+    type Fp3Params: Fp3Parameters<Fp = Self::Fp>;
+    type Fp9Params: Fp9Parameters<Fp3Params = Self::Fp3Params>;
+    type Fp18Params: Fp18Parameters<Fp9Params = Self::Fp9Params>;
+    type G1Parameters: SWModelParameters<BaseField = Self::Fp>;
+    type G2Parameters: SWModelParameters<
+        BaseField = Fp3<Self::Fp3Params>,
+        ScalarField = <Self::G1Parameters as ModelParameters>::ScalarField,
+    >;
+}
+
+pub mod g1;
+pub mod g2;
+
+pub use self::{
+    g1::{G1Affine, G1Prepared, G1Projective},
+    g2::{G2Affine, G2Prepared, G2Projective},
+};
+
+/// The coefficients `λ[j][i]` such that
+/// `3 * (q^6 - q^3 + 1) / r = Σ λ[j][i] * X^j * q^i`, which hold for every
+/// curve of the family.
+#[rustfmt::skip]
+const HARD_PART_COEFFS: [[i64; 6]; 8] = [
+    [   3,    0, -6517,   54,   0, 343],
+    [   0, 1911, -4802,    0, 784, 245],
+    [-434, 1407,  -931, -609, 581,  49],
+    [-319,  273,  -343, -450, 112,   0],
+    [ -62,   98,  -245,  -87,  49,   0],
+    [ -21,   70,   -49,  -35,  35,   0],
+    [ -15,   14,     0,  -25,   7,   0],
+    [  -3,    0,     0,   -5,   0,   0],
+];
+
+#[derive(Derivative)]
+#[derivative(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+pub struct Kss18<P: Kss18Parameters>(PhantomData<fn() -> P>);
+
+impl<P: Kss18Parameters> Kss18<P> {
+    // Evaluate the line function at point p.
+    fn ell(f: &mut Fp18<P::Fp18Params>, coeffs: &g2::EllCoeff<Fp3<P::Fp3Params>>, p: &G1Affine<P>) {
+        let mut c0 = coeffs.0;
+        let mut c1 = coeffs.1;
+        let mut c2 = coeffs.2;
+
+        match P::TWIST_TYPE {
+            TwistType::M => {
+                c2.mul_assign_by_fp(&p.y);
+                c1.mul_assign_by_fp(&p.x);
+                f.mul_by_014(&c0, &c1, &c2);
+            },
+            TwistType::D => {
+                c0.mul_assign_by_fp(&p.y);
+                c1.mul_assign_by_fp(&p.x);
+                f.mul_by_034(&c0, &c1, &c2);
+            },
+        }
+    }
+
+    // Exponentiates `f` by `Self::X`.
+    fn exp_by_x(f: &Fp18<P::Fp18Params>) -> Fp18<P::Fp18Params> {
+        let mut result = f.cyclotomic_exp(P::X);
+        if P::X_IS_NEGATIVE {
+            result.conjugate();
+        }
+        result
+    }
+
+    // Computes `Π_i f_frob[i]^λ[j][i]`.
+    fn hard_part_term(f_frob: &[Fp18<P::Fp18Params>], j: usize) -> Fp18<P::Fp18Params> {
+        let mut result = Fp18::one();
+        for (f, &lambda) in f_frob.iter().zip(&HARD_PART_COEFFS[j]) {
+            if lambda != 0 {
+                let mut term = f.cyclotomic_exp([lambda.unsigned_abs()]);
+                if lambda < 0 {
+                    term.conjugate();
+                }
+                result *= &term;
+            }
+        }
+        result
+    }
+
+    /// Computes the final exponentiation of `f` as a product of two factors,
+    /// which lets `multi_pairing_is_one` skip the last multiplication.
+    fn final_exponentiation_factors(
+        f: &Fp18<P::Fp18Params>,
+    ) -> Option<(Fp18<P::Fp18Params>, Fp18<P::Fp18Params>)> {
+        // Easy part: f^((q^9 - 1)(q^3 + 1)).
+        let mut f1 = *f;
+        f1.conjugate();
+
+        f.inverse().map(|f2| {
+            // r = f^(q^9 - 1)
+            let mut r = f1 * &f2;
+            let f2 = r;
+            // r = f^((q^9 - 1)(q^3 + 1))
+            r.frobenius_map(3);
+            r *= &f2;
+
+            // Hard part: r^(3 * (q^6 - q^3 + 1) / r), by Horner's rule in `X`
+            // over the decomposition in `HARD_PART_COEFFS`.
+            let mut r_frob = [r; 6];
+            for (i, r_i) in r_frob.iter_mut().enumerate() {
+                r_i.frobenius_map(i);
+            }
+            let mut y = Fp18::one();
+            for j in (1..HARD_PART_COEFFS.len()).rev() {
+                y *= &Self::hard_part_term(&r_frob, j);
+                y = Self::exp_by_x(&y);
+            }
+            (y, Self::hard_part_term(&r_frob, 0))
+        })
+    }
+}
+
+impl<P: Kss18Parameters> PairingEngine for Kss18<P> {
+    type Fr = <P::G1Parameters as ModelParameters>::ScalarField;
+    type G1Projective = G1Projective<P>;
+    type G1Affine = G1Affine<P>;
+    type G1Prepared = G1Prepared<P>;
+    type G2Projective = G2Projective<P>;
+    type G2Affine = G2Affine<P>;
+    type G2Prepared = G2Prepared<P>;
+    type Fq = P::Fp;
+    type Fqe = Fp3<P::Fp3Params>;
+    type Fqk = Fp18<P::Fp18Params>;
+
+    /// Computes the optimal ate Miller loop
+    /// `f_{X, Q}(P) * f_{3, Q}(P)^q * l_{[X]Q, [3q]Q}(P)`, as in
+    /// [AFK+12](https://eprint.iacr.org/2012/232).
+    fn miller_loop<'a, I>(i: I) -> Self::Fqk
+    where
+        I: IntoIterator<Item = &'a (Self::G1Prepared, Self::G2Prepared)>,
+    {
+        let mut pairs = vec![];
+        for (p, q) in i {
+            if !p.is_zero() && !q.is_zero() {
+                pairs.push((p, q.ell_coeffs.iter()));
+            }
+        }
+
+        let mut f = Self::Fqk::one();
+
+        for i in (1..P::ATE_LOOP_COUNT.len()).rev() {
+            if i != P::ATE_LOOP_COUNT.len() - 1 {
+                f.square_in_place();
+            }
+
+            for (p, ref mut coeffs) in &mut pairs {
+                Self::ell(&mut f, coeffs.next().unwrap(), &p.0);
+            }
+
+            let bit = P::ATE_LOOP_COUNT[i - 1];
+            if bit != 0 {
+                for &mut (p, ref mut coeffs) in &mut pairs {
+                    Self::ell(&mut f, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        if P::X_IS_NEGATIVE {
+            f.conjugate();
+        }
+
+        for &mut (p, ref mut coeffs) in &mut pairs {
+            for _ in 0..3 {
+                Self::ell(&mut f, coeffs.next().unwrap(), &p.0);
+            }
+        }
+
+        f
+    }
+
+    fn final_exponentiation(f: &Self::Fqk) -> Option<Self::Fqk> {
+        Self::final_exponentiation_factors(f).map(|(y, z)| y * &z)
+    }
+
+    fn multi_pairing_is_one<'a, I>(i: I) -> bool
+    where
+        I: IntoIterator<Item = &'a (Self::G1Prepared, Self::G2Prepared)>,
+    {
+        // Both factors are unitary, so their product is one if and only if
+        // one is the conjugate of the other.
+        Self::final_exponentiation_factors(&Self::miller_loop(i)).map_or(false, |(y, mut z)| {
+            z.conjugate();
+            y == z
+        })
+    }
+}
@@ -4,7 +4,9 @@ use crate::{
     AffineCurve,
 };
 use ark_ff::{bytes::ToBytes, Fp3};
-use ark_std::io::{Result as IoResult, Write};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::io::{Read, Result as IoResult, Write};
+use num_traits::{One, Zero};
 
 pub type G1Affine<P> = GroupAffine<<P as MNT6Parameters>::G1Parameters>;
 pub type G1Projective<P> = GroupProjective<<P as MNT6Parameters>::G1Parameters>;
@@ -55,3 +57,59 @@ impl<P: MNT6Parameters> ToBytes for G1Prepared<P> {
         self.y_twist.write(&mut writer)
     }
 }
+
+impl<P: MNT6Parameters> G1Prepared<P> {
+    /// Returns the point that `self` was prepared from. `G1Prepared` does not
+    /// record the point at infinity explicitly, but prepares it from the
+    /// coordinates `(0, 1)`.
+    fn to_affine(&self) -> G1Affine<P> {
+        let infinity = self.x.is_zero() && self.y.is_one();
+        G1Affine::<P>::new(self.x, self.y, infinity)
+    }
+}
+
+/// `G1Prepared` is serialized as the point it was prepared from, and the
+/// twisted coordinates are recomputed on deserialization.
+impl<P: MNT6Parameters> CanonicalSerialize for G1Prepared<P> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.to_affine().serialize(writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.to_affine().serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.to_affine().serialize_uncompressed(writer)
+    }
+
+    #[inline]
+    fn serialize_unchecked<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.to_affine().serialize_unchecked(writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.to_affine().uncompressed_size()
+    }
+}
+
+impl<P: MNT6Parameters> CanonicalDeserialize for G1Prepared<P> {
+    #[inline]
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        G1Affine::<P>::deserialize(reader).map(Self::from)
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        G1Affine::<P>::deserialize_uncompressed(reader).map(Self::from)
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        G1Affine::<P>::deserialize_unchecked(reader).map(Self::from)
+    }
+}
@@ -6,10 +6,11 @@ use crate::{
 };
 use ark_ff::{
     bytes::ToBytes,
-    fields::{Field, Fp3},
+    fields::{BitIteratorBE, Field, Fp3},
 };
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use ark_std::{
-    io::{Result as IoResult, Write},
+    io::{Read, Result as IoResult, Write},
     vec::Vec,
 };
 use num_traits::One;
@@ -45,6 +46,63 @@ impl<P: MNT6Parameters> ToBytes for G2Prepared<P> {
     }
 }
 
+impl<P: MNT6Parameters> G2Prepared<P> {
+    fn check_coefficients_len(self) -> Result<Self, SerializationError> {
+        // One doubling step per bit of the loop count after the first, one
+        // addition step per set bit, and a final one if the loop count is
+        // negative.
+        let mut double_len = 0;
+        let mut addition_len = P::ATE_IS_LOOP_COUNT_NEG as usize;
+        for bit in BitIteratorBE::without_leading_zeros(P::ATE_LOOP_COUNT).skip(1) {
+            double_len += 1;
+            addition_len += bit as usize;
+        }
+        if self.double_coefficients.len() != double_len
+            || self.addition_coefficients.len() != addition_len
+        {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(self)
+    }
+}
+
+impl<P: MNT6Parameters> CanonicalSerialize for G2Prepared<P> {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.x.serialize(&mut writer)?;
+        self.y.serialize(&mut writer)?;
+        self.x_over_twist.serialize(&mut writer)?;
+        self.y_over_twist.serialize(&mut writer)?;
+        self.double_coefficients.serialize(&mut writer)?;
+        self.addition_coefficients.serialize(writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.x.serialized_size()
+            + self.y.serialized_size()
+            + self.x_over_twist.serialized_size()
+            + self.y_over_twist.serialized_size()
+            + self.double_coefficients.serialized_size()
+            + self.addition_coefficients.serialized_size()
+    }
+}
+
+/// The coefficients are field elements, so the compressed and uncompressed
+/// encodings agree. Deserialization always checks that there are as many
+/// coefficients as the Miller loop consumes.
+impl<P: MNT6Parameters> CanonicalDeserialize for G2Prepared<P> {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        Self {
+            x: CanonicalDeserialize::deserialize(&mut reader)?,
+            y: CanonicalDeserialize::deserialize(&mut reader)?,
+            x_over_twist: CanonicalDeserialize::deserialize(&mut reader)?,
+            y_over_twist: CanonicalDeserialize::deserialize(&mut reader)?,
+            double_coefficients: CanonicalDeserialize::deserialize(&mut reader)?,
+            addition_coefficients: CanonicalDeserialize::deserialize(reader)?,
+        }
+        .check_coefficients_len()
+    }
+}
+
 impl<P: MNT6Parameters> From<G2Affine<P>> for G2Prepared<P> {
     fn from(g2: G2Affine<P>) -> Self {
         let twist_inv = P::TWIST.inverse().unwrap();
@@ -145,3 +203,50 @@ pub struct AteAdditionCoefficients<P: MNT6Parameters> {
     pub c_l1: Fp3<P::Fp3Params>,
     pub c_rz: Fp3<P::Fp3Params>,
 }
+
+impl<P: MNT6Parameters> CanonicalSerialize for AteDoubleCoefficients<P> {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.c_h.serialize(&mut writer)?;
+        self.c_4c.serialize(&mut writer)?;
+        self.c_j.serialize(&mut writer)?;
+        self.c_l.serialize(writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.c_h.serialized_size()
+            + self.c_4c.serialized_size()
+            + self.c_j.serialized_size()
+            + self.c_l.serialized_size()
+    }
+}
+
+impl<P: MNT6Parameters> CanonicalDeserialize for AteDoubleCoefficients<P> {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        Ok(Self {
+            c_h: CanonicalDeserialize::deserialize(&mut reader)?,
+            c_4c: CanonicalDeserialize::deserialize(&mut reader)?,
+            c_j: CanonicalDeserialize::deserialize(&mut reader)?,
+            c_l: CanonicalDeserialize::deserialize(reader)?,
+        })
+    }
+}
+
+impl<P: MNT6Parameters> CanonicalSerialize for AteAdditionCoefficients<P> {
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        self.c_l1.serialize(&mut writer)?;
+        self.c_rz.serialize(writer)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.c_l1.serialized_size() + self.c_rz.serialized_size()
+    }
+}
+
+impl<P: MNT6Parameters> CanonicalDeserialize for AteAdditionCoefficients<P> {
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        Ok(Self {
+            c_l1: CanonicalDeserialize::deserialize(&mut reader)?,
+            c_rz: CanonicalDeserialize::deserialize(reader)?,
+        })
+    }
+}
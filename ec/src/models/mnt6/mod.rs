@@ -154,7 +154,9 @@ impl<P: MNT6Parameters> MNT6<P> {
         let value_inv = value.inverse().unwrap();
         let value_to_first_chunk = Self::final_exponentiation_first_chunk(value, &value_inv);
         let value_inv_to_first_chunk = Self::final_exponentiation_first_chunk(&value_inv, value);
-        Self::final_exponentiation_last_chunk(&value_to_first_chunk, &value_inv_to_first_chunk)
+        let (w1_part, w0_part) =
+            Self::final_exponentiation_last_chunk(&value_to_first_chunk, &value_inv_to_first_chunk);
+        w1_part * &w0_part
     }
 
     fn final_exponentiation_first_chunk(
@@ -175,10 +177,13 @@ impl<P: MNT6Parameters> MNT6<P> {
         alpha * &elt_q3_over_elt
     }
 
+    /// Computes the last chunk of the final exponentiation as a product of
+    /// two factors, which lets `multi_pairing_is_one` skip the last
+    /// multiplication.
     fn final_exponentiation_last_chunk(
         elt: &Fp6<P::Fp6Params>,
         elt_inv: &Fp6<P::Fp6Params>,
-    ) -> Fp6<P::Fp6Params> {
+    ) -> (Fp6<P::Fp6Params>, Fp6<P::Fp6Params>) {
         let elt_clone = *elt;
         let elt_inv_clone = *elt_inv;
 
@@ -192,7 +197,7 @@ impl<P: MNT6Parameters> MNT6<P> {
             elt_clone.cyclotomic_exp(&P::FINAL_EXPONENT_LAST_CHUNK_ABS_OF_W0)
         };
 
-        w1_part * &w0_part
+        (w1_part, w0_part)
     }
 }
 
@@ -222,4 +227,24 @@ impl<P: MNT6Parameters> PairingEngine for MNT6<P> {
     fn final_exponentiation(r: &Self::Fqk) -> Option<Self::Fqk> {
         Some(Self::final_exponentiation(r))
     }
+
+    fn multi_pairing_is_one<'a, I>(i: I) -> bool
+    where
+        I: IntoIterator<Item = &'a (Self::G1Prepared, Self::G2Prepared)>,
+    {
+        let value = Self::miller_loop(i);
+        value.inverse().map_or(false, |value_inv| {
+            let value_to_first_chunk = Self::final_exponentiation_first_chunk(&value, &value_inv);
+            let value_inv_to_first_chunk =
+                Self::final_exponentiation_first_chunk(&value_inv, &value);
+            // Both factors are unitary, so their product is one if and only
+            // if one is the conjugate of the other.
+            let (w1_part, mut w0_part) = Self::final_exponentiation_last_chunk(
+                &value_to_first_chunk,
+                &value_inv_to_first_chunk,
+            );
+            w0_part.conjugate();
+            w1_part == w0_part
+        })
+    }
 }
@@ -1,14 +1,20 @@
-use ark_ff::{Field, PrimeField, SquareRootField, Zero};
+use crate::{AffineCurve, ProjectiveCurve};
+use ark_ff::{BitIteratorBE, Field, PrimeField, SquareRootField, Zero};
+use ark_std::ops::MulAssign;
 
 pub mod bls12;
+pub mod bls24;
 pub mod bn;
 pub mod bw6;
+pub mod kss18;
 pub mod mnt4;
 pub mod mnt6;
+pub mod montgomery;
 pub mod short_weierstrass_jacobian;
+pub mod short_weierstrass_projective;
 pub mod twisted_edwards_extended;
 
-pub trait ModelParameters: Send + Sync + 'static {
+pub trait ModelParameters: Send + Sync + Sized + 'static {
     type BaseField: Field + SquareRootField;
     type ScalarField: PrimeField + SquareRootField + Into<<Self::ScalarField as PrimeField>::BigInt>;
 }
@@ -36,6 +42,103 @@ pub trait SWModelParameters: ModelParameters {
         }
         *elem
     }
+
+    /// Maps `item` into the prime order subgroup. By default this multiplies
+    /// by `COFACTOR`; curves that admit a cheaper multiple of the cofactor
+    /// (the effective cofactor of hash-to-curve) can override this.
+    #[inline]
+    fn clear_cofactor(
+        item: &short_weierstrass_jacobian::GroupAffine<Self>,
+    ) -> short_weierstrass_jacobian::GroupAffine<Self> {
+        item.mul_by_cofactor()
+    }
+
+    /// Checks that `item`, which is assumed to be on the curve, lies in the
+    /// prime order subgroup. By default this multiplies `item` by the group
+    /// order, unless `COFACTOR` is one; curves with a cheaper test, such as
+    /// those in `crate::models::bls12::g1` or `crate::models::bw6::g1`, can
+    /// override this.
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(
+        item: &short_weierstrass_jacobian::GroupAffine<Self>,
+    ) -> bool {
+        if Self::COFACTOR == [1] {
+            return true;
+        }
+        item.mul_bits(BitIteratorBE::new(Self::ScalarField::characteristic()))
+            .is_zero()
+    }
+
+    /// Splits the scalar multiplication `k * p` into `k1 * p1 + k2 * p2`
+    /// with half-length `k1` and `k2`, for curves with an efficient
+    /// endomorphism. Curves implementing `crate::glv::GLVParameters` should
+    /// override this to return `Some(Self::split_affine(p, k))`.
+    #[inline]
+    fn glv_split_affine(
+        _p: &short_weierstrass_jacobian::GroupAffine<Self>,
+        _k: &Self::ScalarField,
+    ) -> Option<
+        [(
+            short_weierstrass_jacobian::GroupAffine<Self>,
+            Self::ScalarField,
+        ); 2],
+    > {
+        None
+    }
+
+    /// The projective counterpart of `Self::glv_split_affine`; GLV curves
+    /// should override this to return `Some(Self::split_projective(p, k))`.
+    #[inline]
+    fn glv_split_projective(
+        _p: &short_weierstrass_jacobian::GroupProjective<Self>,
+        _k: &Self::ScalarField,
+    ) -> Option<
+        [(
+            short_weierstrass_jacobian::GroupProjective<Self>,
+            Self::ScalarField,
+        ); 2],
+    > {
+        None
+    }
+}
+
+/// A choice of coordinates for the points of a short Weierstrass curve with
+/// parameters `P`. The pairing engines of `bls12` and `bn` take one for G1,
+/// so that a curve can use the complete formulas of
+/// `short_weierstrass_projective` instead of `short_weierstrass_jacobian`.
+pub trait SWCurveModel<P: SWModelParameters>: 'static {
+    type Affine: AffineCurve<
+            BaseField = P::BaseField,
+            ScalarField = P::ScalarField,
+            Projective = Self::Projective,
+        > + From<Self::Projective>
+        + Into<Self::Projective>
+        + From<short_weierstrass_jacobian::GroupAffine<P>>
+        + Into<short_weierstrass_jacobian::GroupAffine<P>>;
+    type Projective: ProjectiveCurve<
+            BaseField = P::BaseField,
+            ScalarField = P::ScalarField,
+            Affine = Self::Affine,
+        > + From<Self::Affine>
+        + Into<Self::Affine>
+        + MulAssign<P::ScalarField>;
+}
+
+/// Jacobian coordinates, as in `short_weierstrass_jacobian`.
+pub struct SWJacobian;
+
+impl<P: SWModelParameters> SWCurveModel<P> for SWJacobian {
+    type Affine = short_weierstrass_jacobian::GroupAffine<P>;
+    type Projective = short_weierstrass_jacobian::GroupProjective<P>;
+}
+
+/// Homogeneous projective coordinates with complete formulas, as in
+/// `short_weierstrass_projective`.
+pub struct SWProjective;
+
+impl<P: SWModelParameters> SWCurveModel<P> for SWProjective {
+    type Affine = short_weierstrass_projective::GroupAffine<P>;
+    type Projective = short_weierstrass_projective::GroupProjective<P>;
 }
 
 pub trait TEModelParameters: ModelParameters {
@@ -53,6 +156,15 @@ pub trait TEModelParameters: ModelParameters {
         copy *= &Self::COEFF_A;
         copy
     }
+
+    /// Maps `item` into the prime order subgroup. By default this multiplies
+    /// by `COFACTOR`.
+    #[inline]
+    fn clear_cofactor(
+        item: &twisted_edwards_extended::GroupAffine<Self>,
+    ) -> twisted_edwards_extended::GroupAffine<Self> {
+        item.mul_by_cofactor()
+    }
 }
 
 pub trait MontgomeryModelParameters: ModelParameters {
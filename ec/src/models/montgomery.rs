@@ -0,0 +1,495 @@
+//! Elliptic curves in Montgomery form `B * y^2 = x^3 + A * x^2 + x`.
+//!
+//! Besides affine points, this provides x-only points in `(X : Z)`
+//! coordinates, whose scalar multiplication is the Montgomery ladder, and
+//! conversions to and from the birationally equivalent twisted Edwards curve
+//! given by `MontgomeryModelParameters::TEModelParameters`.
+
+use crate::models::{twisted_edwards_extended, MontgomeryModelParameters as Parameters};
+use ark_std::{
+    convert::TryFrom,
+    fmt::{Display, Formatter, Result as FmtResult},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Neg, Sub, SubAssign},
+};
+use num_traits::{One, Zero};
+use zeroize::Zeroize;
+
+use ark_ff::fields::{BitIteratorBE, Field, PrimeField, SquareRootField};
+
+#[cfg(feature = "ct")]
+use ark_ff::fields::{
+    constant_time::{Choice, ConditionallySelectable},
+    ConstantTimeField,
+};
+
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "P: Parameters"),
+    Clone(bound = "P: Parameters"),
+    PartialEq(bound = "P: Parameters"),
+    Eq(bound = "P: Parameters"),
+    Debug(bound = "P: Parameters"),
+    Hash(bound = "P: Parameters")
+)]
+#[must_use]
+pub struct GroupAffine<P: Parameters> {
+    pub x: P::BaseField,
+    pub y: P::BaseField,
+    pub infinity: bool,
+    #[derivative(Debug = "ignore")]
+    _params: PhantomData<P>,
+}
+
+impl<P: Parameters> Display for GroupAffine<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.infinity {
+            write!(f, "GroupAffine(Infinity)")
+        } else {
+            write!(f, "GroupAffine(x={}, y={})", self.x, self.y)
+        }
+    }
+}
+
+impl<P: Parameters> GroupAffine<P> {
+    pub fn new(x: P::BaseField, y: P::BaseField, infinity: bool) -> Self {
+        Self {
+            x,
+            y,
+            infinity,
+            _params: PhantomData,
+        }
+    }
+
+    /// Computes `x^3 + A * x^2 + x`, which equals `B * y^2` for points on the
+    /// curve.
+    fn rhs(x: &P::BaseField) -> P::BaseField {
+        let mut rhs = *x + &P::COEFF_A;
+        rhs *= x;
+        rhs += &P::BaseField::one();
+        rhs *= x;
+        rhs
+    }
+
+    /// Attempts to construct an affine point given an x-coordinate. The
+    /// point is not guaranteed to be in the prime order subgroup.
+    ///
+    /// If and only if `greatest` is set will the lexicographically
+    /// largest y-coordinate be selected.
+    pub fn get_point_from_x(x: P::BaseField, greatest: bool) -> Option<Self> {
+        let y2 = P::COEFF_B.inverse().map(|b_inv| Self::rhs(&x) * &b_inv);
+        y2.and_then(|y2| y2.sqrt()).map(|y| {
+            let negy = -y;
+            let y = if (y < negy) ^ greatest { y } else { negy };
+            Self::new(x, y, false)
+        })
+    }
+
+    /// Checks that the current point is on the elliptic curve.
+    pub fn is_on_curve(&self) -> bool {
+        self.infinity || P::COEFF_B * &self.y.square() == Self::rhs(&self.x)
+    }
+
+    #[must_use]
+    pub fn double(&self) -> Self {
+        let mut copy = *self;
+        copy += self;
+        copy
+    }
+
+    /// Multiplies `self` by `by` with the Montgomery ladder on
+    /// `XZProjective`, and recovers the y-coordinate of the result with
+    /// the method of [OS01](https://doi.org/10.1007/3-540-44709-1_12).
+    pub fn mul<S: Into<<P::ScalarField as PrimeField>::BigInt>>(&self, by: S) -> Self {
+        let by = by.into();
+        if self.infinity {
+            return *self;
+        }
+        if self.y.is_zero() {
+            // `self` has order two.
+            return if by.as_ref()[0] & 1 == 1 {
+                *self
+            } else {
+                Self::zero()
+            };
+        }
+
+        let (q, r) = XZProjective::from(*self).ladder(
+            BitIteratorBE::without_leading_zeros(by),
+            |a, b, bit| {
+                if bit {
+                    ark_std::mem::swap(a, b)
+                }
+            },
+        );
+        self.recover_y(&q, &r)
+    }
+
+    /// Given `q = k * self` and `r = (k + 1) * self` in x-only coordinates,
+    /// returns `k * self`. `self` must not have order two.
+    fn recover_y(&self, q: &XZProjective<P>, r: &XZProjective<P>) -> Self {
+        if q.is_zero() {
+            return Self::zero();
+        }
+        if r.is_zero() {
+            return -*self;
+        }
+
+        let two_a = P::COEFF_A.double();
+        let mut v1 = self.x * &q.z;
+        let mut v2 = q.x + &v1;
+        let mut v3 = q.x - &v1;
+        v3.square_in_place();
+        v3 *= &r.x;
+        v1 = two_a * &q.z;
+        v2 += &v1;
+        let v4 = self.x * &q.x + &q.z;
+        v2 *= &v4;
+        v1 *= &q.z;
+        v2 -= &v1;
+        v2 *= &r.z;
+        let y = v2 - &v3;
+        let v1 = P::COEFF_B.double() * &self.y * &q.z * &r.z;
+        let x = v1 * &q.x;
+        let z = v1 * &q.z;
+
+        let z_inv = z.inverse().unwrap();
+        Self::new(x * &z_inv, y * &z_inv, false)
+    }
+}
+
+impl<P: Parameters> Zero for GroupAffine<P> {
+    fn zero() -> Self {
+        Self::new(P::BaseField::zero(), P::BaseField::one(), true)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.infinity
+    }
+}
+
+impl<P: Parameters> Zeroize for GroupAffine<P> {
+    // The phantom data does not contain element-specific data
+    // and thus does not need to be zeroized.
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+        self.infinity.zeroize();
+    }
+}
+
+impl<P: Parameters> Neg for GroupAffine<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.infinity {
+            self
+        } else {
+            Self::new(self.x, -self.y, false)
+        }
+    }
+}
+
+ark_ff::impl_additive_ops_from_ref!(GroupAffine, Parameters);
+
+impl<'a, P: Parameters> Add<&'a Self> for GroupAffine<P> {
+    type Output = Self;
+    fn add(self, other: &'a Self) -> Self {
+        let mut copy = self;
+        copy += other;
+        copy
+    }
+}
+
+impl<'a, P: Parameters> AddAssign<&'a Self> for GroupAffine<P> {
+    fn add_assign(&mut self, other: &'a Self) {
+        if other.infinity {
+            return;
+        }
+        if self.infinity {
+            *self = *other;
+            return;
+        }
+
+        let lambda = if self.x == other.x {
+            if self.y != other.y || self.y.is_zero() {
+                *self = Self::zero();
+                return;
+            }
+            // lambda = (3 * x^2 + 2 * A * x + 1) / (2 * B * y)
+            let x2 = self.x.square();
+            let numerator =
+                x2.double() + &x2 + &(P::COEFF_A * &self.x).double() + &P::BaseField::one();
+            numerator / &(P::COEFF_B * &self.y).double()
+        } else {
+            (other.y - &self.y) / &(other.x - &self.x)
+        };
+
+        // x3 = B * lambda^2 - A - x1 - x2
+        // y3 = lambda * (x1 - x3) - y1
+        let x3 = P::COEFF_B * &lambda.square() - &P::COEFF_A - &self.x - &other.x;
+        self.y = lambda * &(self.x - &x3) - &self.y;
+        self.x = x3;
+    }
+}
+
+impl<'a, P: Parameters> Sub<&'a Self> for GroupAffine<P> {
+    type Output = Self;
+    fn sub(self, other: &'a Self) -> Self {
+        let mut copy = self;
+        copy -= other;
+        copy
+    }
+}
+
+impl<'a, P: Parameters> SubAssign<&'a Self> for GroupAffine<P> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        *self += &(-(*other));
+    }
+}
+
+impl<P: Parameters> Default for GroupAffine<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+/// Maps a twisted Edwards point `(x, y)` to `(u, v) = ((1 + y) / (1 - y), u / x)`.
+///
+/// This is the inverse of the map in `TryFrom<GroupAffine<P>>` for
+/// `twisted_edwards_extended::GroupAffine`, and requires that the twisted
+/// Edwards coefficients are `a = (A + 2) / B` and `d = (A - 2) / B`. The
+/// identity `(0, 1)` maps to the point at infinity, and `(0, -1)` to the
+/// point `(0, 0)` of order two.
+impl<P: Parameters> From<twisted_edwards_extended::GroupAffine<P::TEModelParameters>>
+    for GroupAffine<P>
+{
+    fn from(p: twisted_edwards_extended::GroupAffine<P::TEModelParameters>) -> Self {
+        if p.x.is_zero() {
+            return if p.y.is_one() {
+                Self::zero()
+            } else {
+                Self::new(P::BaseField::zero(), P::BaseField::zero(), false)
+            };
+        }
+        let one = P::BaseField::one();
+        let u = (one + &p.y) / &(one - &p.y);
+        let v = u / &p.x;
+        Self::new(u, v, false)
+    }
+}
+
+/// Maps a Montgomery point `(u, v)` to `(x, y) = (u / v, (u - 1) / (u + 1))`.
+///
+/// Fails on the points with `v = 0` other than `(0, 0)`, and on the points
+/// with `u = -1`, which have no affine image on the twisted Edwards curve.
+impl<P: Parameters> TryFrom<GroupAffine<P>>
+    for twisted_edwards_extended::GroupAffine<P::TEModelParameters>
+{
+    type Error = ();
+
+    fn try_from(p: GroupAffine<P>) -> Result<Self, ()> {
+        if p.infinity {
+            return Ok(Self::zero());
+        }
+        if p.x.is_zero() {
+            return Ok(Self::new(P::BaseField::zero(), -P::BaseField::one()));
+        }
+        let one = P::BaseField::one();
+        let x = p.x * &p.y.inverse().ok_or(())?;
+        let y = (p.x - &one) * &(p.x + &one).inverse().ok_or(())?;
+        Ok(Self::new(x, y))
+    }
+}
+
+/// A point in x-only projective coordinates `(X : Z)`, representing the pair
+/// of affine points with `x = X / Z`; the identity is `(1 : 0)`.
+///
+/// These points do not form a group, but support doubling, differential
+/// addition, and scalar multiplication with the Montgomery ladder.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "P: Parameters"),
+    Clone(bound = "P: Parameters"),
+    Debug(bound = "P: Parameters")
+)]
+#[must_use]
+pub struct XZProjective<P: Parameters> {
+    pub x: P::BaseField,
+    pub z: P::BaseField,
+    #[derivative(Debug = "ignore")]
+    _params: PhantomData<P>,
+}
+
+impl<P: Parameters> Display for XZProjective<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "XZProjective(x={}, z={})", self.x, self.z)
+    }
+}
+
+impl<P: Parameters> PartialEq for XZProjective<P> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_zero() {
+            return other.is_zero();
+        }
+        if other.is_zero() {
+            return false;
+        }
+        self.x * &other.z == other.x * &self.z
+    }
+}
+
+impl<P: Parameters> Eq for XZProjective<P> {}
+
+impl<P: Parameters> XZProjective<P> {
+    pub fn new(x: P::BaseField, z: P::BaseField) -> Self {
+        Self {
+            x,
+            z,
+            _params: PhantomData,
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self::new(P::BaseField::one(), P::BaseField::zero())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Returns the affine x-coordinate `X / Z`, or `None` for the identity.
+    pub fn x_coordinate(&self) -> Option<P::BaseField> {
+        self.z.inverse().map(|z_inv| self.x * &z_inv)
+    }
+
+    /// Computes `2 * self`.
+    pub fn double(&self) -> Self {
+        // These are the formulas of [[Mon87]](https://doi.org/10.2307/2007888),
+        // scaled by 4 to avoid computing (A + 2) / 4.
+        let t1 = (self.x + &self.z).square();
+        let t2 = (self.x - &self.z).square();
+        let t3 = t1 - &t2;
+        let four_t2 = t2.double().double();
+        let x = t1 * &four_t2;
+        let z = t3 * &(four_t2 + &((P::COEFF_A + &P::BaseField::one().double()) * &t3));
+        Self::new(x, z)
+    }
+
+    /// Computes `self + other`, given `difference = self - other`.
+    ///
+    /// The result is undefined if `difference` is the identity or the point
+    /// `(0, 0)` of order two.
+    pub fn differential_add(&self, other: &Self, difference: &Self) -> Self {
+        let u = (self.x - &self.z) * &(other.x + &other.z);
+        let v = (self.x + &self.z) * &(other.x - &other.z);
+        let x = difference.z * &(u + &v).square();
+        let z = difference.x * &(u - &v).square();
+        Self::new(x, z)
+    }
+
+    /// Runs the Montgomery ladder on the big-endian `bits` of a scalar `k`,
+    /// returning `(k * self, (k + 1) * self)`. `cswap` must swap its
+    /// arguments if and only if its last argument is set.
+    fn ladder(
+        &self,
+        bits: impl Iterator<Item = bool>,
+        cswap: impl Fn(&mut Self, &mut Self, bool),
+    ) -> (Self, Self) {
+        let mut r0 = Self::zero();
+        let mut r1 = *self;
+        for bit in bits {
+            cswap(&mut r0, &mut r1, bit);
+            r1 = r0.differential_add(&r1, self);
+            r0 = r0.double();
+            cswap(&mut r0, &mut r1, bit);
+        }
+        (r0, r1)
+    }
+
+    /// Multiplies `self` by `by` with the Montgomery ladder.
+    pub fn mul<S: Into<<P::ScalarField as PrimeField>::BigInt>>(&self, by: S) -> Self {
+        let by = by.into();
+        if self.x.is_zero() || self.z.is_zero() {
+            // `self` is either the identity or the point `(0, 0)` of order
+            // two, on which the differential addition is undefined.
+            return if by.as_ref()[0] & 1 == 1 {
+                *self
+            } else {
+                Self::zero()
+            };
+        }
+        self.ladder(BitIteratorBE::without_leading_zeros(by), |a, b, bit| {
+            if bit {
+                ark_std::mem::swap(a, b)
+            }
+        })
+        .0
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: Parameters> XZProjective<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    /// Multiplies `self` by `by` in constant time, for use with secret
+    /// scalars, such as in key exchange.
+    ///
+    /// Unlike `mul`, this runs the ladder over every bit of `by`, leading
+    /// zeros included, and exchanges the two ladder points with
+    /// constant-time conditional swaps. Only checking for the identity and
+    /// the point of order two branches on `self`.
+    pub fn ct_mul<S: Into<<P::ScalarField as PrimeField>::BigInt>>(&self, by: S) -> Self {
+        let by = by.into();
+        if self.x.is_zero() || self.z.is_zero() {
+            let odd = Choice::from((by.as_ref()[0] & 1) as u8);
+            return Self::conditional_select(&Self::zero(), self, odd);
+        }
+        self.ladder(BitIteratorBE::new(by), |a, b, bit| {
+            Self::conditional_swap(a, b, Choice::from(bit as u8))
+        })
+        .0
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: Parameters> ConditionallySelectable for XZProjective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
+impl<P: Parameters> Zeroize for XZProjective<P> {
+    // The phantom data does not contain element-specific data
+    // and thus does not need to be zeroized.
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.z.zeroize();
+    }
+}
+
+impl<P: Parameters> Default for XZProjective<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: Parameters> From<GroupAffine<P>> for XZProjective<P> {
+    #[inline]
+    fn from(p: GroupAffine<P>) -> Self {
+        if p.infinity {
+            Self::zero()
+        } else {
+            Self::new(p.x, P::BaseField::one())
+        }
+    }
+}
@@ -30,6 +30,12 @@ use ark_std::rand::{
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+#[cfg(feature = "ct")]
+use ark_ff::fields::{
+    constant_time::{Choice, ConditionallySelectable, ConstantTimeEq},
+    ConstantTimeField,
+};
+
 /// Affine coordinates for a point on an elliptic curve in short Weierstrass form,
 /// over the base field `P::BaseField`.
 #[derive(Derivative)]
@@ -145,8 +151,7 @@ impl<P: Parameters> GroupAffine<P> {
     /// Checks if `self` is in the subgroup having order that equaling that of
     /// `P::ScalarField`.
     pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
-        self.mul_bits(BitIteratorBE::new(P::ScalarField::characteristic()))
-            .is_zero()
+        P::is_in_correct_subgroup_assuming_on_curve(self)
     }
 }
 
@@ -236,6 +241,38 @@ impl<P: Parameters> AffineCurve for GroupAffine<P> {
     fn mul_by_cofactor_inv(&self) -> Self {
         self.mul(P::COFACTOR_INV).into()
     }
+
+    fn clear_cofactor(&self) -> Self {
+        P::clear_cofactor(self)
+    }
+
+    #[inline]
+    fn glv_split(&self, scalar: &P::ScalarField) -> Option<[(Self, P::ScalarField); 2]> {
+        P::glv_split_affine(self, scalar)
+    }
+
+    /// Accumulates the buckets in extended Jacobian coordinates, which make
+    /// mixed additions cheaper than in Jacobian coordinates.
+    fn msm_window_sum<'a>(
+        bases_and_digits: impl Iterator<Item = (&'a Self, i64)>,
+        num_buckets: usize,
+    ) -> GroupProjective<P> {
+        let points = bases_and_digits
+            .filter(|(base, _)| !base.infinity)
+            .map(|(base, digit)| (base.x, base.y, digit));
+        let sum = crate::msm::xyzz::window_sum::<P>(points, num_buckets);
+        if sum.zz.is_zero() {
+            return GroupProjective::zero();
+        }
+        // (X/ZZ, Y/ZZZ) = (X'/Z'^2, Y'/Z'^3) for Z' = ZZ*ZZZ.
+        let zz_zzz = sum.zz * &sum.zzz;
+        let zzz2 = sum.zzz.square();
+        GroupProjective::new(
+            sum.x * &sum.zz * &zzz2,
+            sum.y * &sum.zz.square() * &sum.zz * &zzz2,
+            zz_zzz,
+        )
+    }
 }
 
 impl<P: Parameters> Neg for GroupAffine<P> {
@@ -356,7 +393,7 @@ impl<P: Parameters> Distribution<GroupProjective<P>> for Standard {
             let greatest = rng.gen();
 
             if let Some(p) = GroupAffine::get_point_from_x(x, greatest) {
-                return p.scale_by_cofactor().into();
+                return P::clear_cofactor(&p).into();
             }
         }
     }
@@ -397,6 +434,155 @@ impl<P: Parameters> GroupProjective<P> {
             _params: PhantomData,
         }
     }
+
+    /// Doubles `self` without checking whether it is the point at infinity.
+    /// The formulae map the point at infinity (`Z = 0`) to a point with
+    /// `Z = 0`, so the result is still correct in that case.
+    fn double_in_place_unchecked(&mut self) -> &mut Self {
+        if P::COEFF_A.is_zero() {
+            // A = X1^2
+            let mut a = self.x.square();
+
+            // B = Y1^2
+            let b = self.y.square();
+
+            // C = B^2
+            let mut c = b.square();
+
+            // D = 2*((X1+B)2-A-C)
+            let d = ((self.x + &b).square() - &a - &c).double();
+
+            // E = 3*A
+            let e = a + &*a.double_in_place();
+
+            // F = E^2
+            let f = e.square();
+
+            // Z3 = 2*Y1*Z1
+            self.z *= &self.y;
+            self.z.double_in_place();
+
+            // X3 = F-2*D
+            self.x = f - &d - &d;
+
+            // Y3 = E*(D-X3)-8*C
+            self.y = (d - &self.x) * &e - &*c.double_in_place().double_in_place().double_in_place();
+            self
+        } else {
+            // http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#doubling-dbl-2009-l
+            // XX = X1^2
+            let xx = self.x.square();
+
+            // YY = Y1^2
+            let yy = self.y.square();
+
+            // YYYY = YY^2
+            let mut yyyy = yy.square();
+
+            // ZZ = Z1^2
+            let zz = self.z.square();
+
+            // S = 2*((X1+YY)^2-XX-YYYY)
+            let s = ((self.x + &yy).square() - &xx - &yyyy).double();
+
+            // M = 3*XX+a*ZZ^2
+            let m = xx + &xx + &xx + &P::mul_by_a(&zz.square());
+
+            // T = M^2-2*S
+            let t = m.square() - &s.double();
+
+            // X3 = T
+            self.x = t;
+            // Y3 = M*(S-T)-8*YYYY
+            let old_y = self.y;
+            self.y = m * &(s - &t) - &*yyyy.double_in_place().double_in_place().double_in_place();
+            // Z3 = (Y1+Z1)^2-YY-ZZ
+            self.z = (old_y + &self.z).square() - &yy - &zz;
+            self
+        }
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: Parameters> GroupAffine<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    /// Multiplies `self` by `by` in constant time. See
+    /// [`GroupProjective::ct_mul`].
+    pub fn ct_mul<S: Into<<P::ScalarField as PrimeField>::BigInt>>(
+        &self,
+        by: S,
+    ) -> GroupProjective<P> {
+        self.into_projective().ct_mul(by)
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: Parameters> GroupProjective<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    /// Multiplies `self` by `by` in constant time, for use with secret
+    /// scalars, such as in signing or key exchange.
+    ///
+    /// Unlike `mul`, this processes every bit of `by` with a fixed window and
+    /// constant-time table lookups, and uses addition formulae that do not
+    /// branch on the points. Note that converting the result into affine
+    /// coordinates uses the variable-time `Field::inverse`.
+    pub fn ct_mul<S: Into<<P::ScalarField as PrimeField>::BigInt>>(&self, by: S) -> Self {
+        crate::constant_time::fixed_window_mul(
+            self,
+            Self::zero(),
+            by.into().as_ref(),
+            Self::ct_add,
+            |p| {
+                let mut p = *p;
+                *p.double_in_place_unchecked()
+            },
+        )
+    }
+
+    /// Computes `self + other` without branching on the points, by computing
+    /// both the sum and the doubling and selecting the right result.
+    fn ct_add(&self, other: &Self) -> Self {
+        // http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#addition-add-2007-bl
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        let u1 = self.x * &z2z2;
+        let u2 = other.x * &z1z1;
+        let s1 = self.y * &other.z * &z2z2;
+        let s2 = other.y * &self.z * &z1z1;
+        let h = u2 - &u1;
+        let i = (h.double()).square();
+        let j = h * &i;
+        let r = (s2 - &s1).double();
+        let v = u1 * &i;
+        let x = r.square() - &j - &(v.double());
+        let y = r * &(v - &x) - &*(s1 * &j).double_in_place();
+        let z = ((self.z + &other.z).square() - &z1z1 - &z2z2) * &h;
+        let sum = Self::new(x, y, z);
+
+        let mut doubled = *self;
+        doubled.double_in_place_unchecked();
+        let res = Self::conditional_select(&sum, &doubled, u1.ct_eq(&u2) & s1.ct_eq(&s2));
+        let res = Self::conditional_select(&res, other, self.z.ct_is_zero());
+        Self::conditional_select(&res, self, other.z.ct_is_zero())
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: Parameters> ConditionallySelectable for GroupProjective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
 }
 
 impl<P: Parameters> Zeroize for GroupProjective<P> {
@@ -477,68 +663,7 @@ impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
         if self.is_zero() {
             return self;
         }
-
-        if P::COEFF_A.is_zero() {
-            // A = X1^2
-            let mut a = self.x.square();
-
-            // B = Y1^2
-            let b = self.y.square();
-
-            // C = B^2
-            let mut c = b.square();
-
-            // D = 2*((X1+B)2-A-C)
-            let d = ((self.x + &b).square() - &a - &c).double();
-
-            // E = 3*A
-            let e = a + &*a.double_in_place();
-
-            // F = E^2
-            let f = e.square();
-
-            // Z3 = 2*Y1*Z1
-            self.z *= &self.y;
-            self.z.double_in_place();
-
-            // X3 = F-2*D
-            self.x = f - &d - &d;
-
-            // Y3 = E*(D-X3)-8*C
-            self.y = (d - &self.x) * &e - &*c.double_in_place().double_in_place().double_in_place();
-            self
-        } else {
-            // http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#doubling-dbl-2009-l
-            // XX = X1^2
-            let xx = self.x.square();
-
-            // YY = Y1^2
-            let yy = self.y.square();
-
-            // YYYY = YY^2
-            let mut yyyy = yy.square();
-
-            // ZZ = Z1^2
-            let zz = self.z.square();
-
-            // S = 2*((X1+YY)^2-XX-YYYY)
-            let s = ((self.x + &yy).square() - &xx - &yyyy).double();
-
-            // M = 3*XX+a*ZZ^2
-            let m = xx + &xx + &xx + &P::mul_by_a(&zz.square());
-
-            // T = M^2-2*S
-            let t = m.square() - &s.double();
-
-            // X3 = T
-            self.x = t;
-            // Y3 = M*(S-T)-8*YYYY
-            let old_y = self.y;
-            self.y = m * &(s - &t) - &*yyyy.double_in_place().double_in_place().double_in_place();
-            // Z3 = (Y1+Z1)^2-YY-ZZ
-            self.z = (old_y + &self.z).square() - &yy - &zz;
-            self
-        }
+        self.double_in_place_unchecked()
     }
 
     /// When `other.is_normalized()` (i.e., `other.z == 1`), we can use a more efficient
@@ -610,6 +735,11 @@ impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
             self.z -= &hh;
         }
     }
+
+    #[inline]
+    fn glv_split(&self, scalar: &P::ScalarField) -> Option<[(Self, P::ScalarField); 2]> {
+        P::glv_split_projective(self, scalar)
+    }
 }
 
 impl<P: Parameters> Neg for GroupProjective<P> {
@@ -0,0 +1,886 @@
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    io::{Read, Result as IoResult, Write},
+    marker::PhantomData,
+    ops::{Add, AddAssign, MulAssign, Neg, Sub, SubAssign},
+    vec::Vec,
+};
+
+use ark_ff::{
+    bytes::{FromBytes, ToBytes},
+    fields::{BitIteratorBE, Field, PrimeField},
+    ToConstraintField, UniformRand,
+};
+
+use crate::{
+    models::{short_weierstrass_jacobian, SWModelParameters as Parameters},
+    AffineCurve, ProjectiveCurve,
+};
+
+use num_traits::{One, Zero};
+use zeroize::Zeroize;
+
+use ark_std::rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[cfg(feature = "ct")]
+use ark_ff::fields::{
+    constant_time::{Choice, ConditionallySelectable},
+    ConstantTimeField,
+};
+
+/// Affine coordinates for a point on an elliptic curve in short Weierstrass form,
+/// over the base field `P::BaseField`.
+///
+/// This is the affine counterpart of the homogeneous projective
+/// `GroupProjective` below. It has the same representation and encoding as
+/// `short_weierstrass_jacobian::GroupAffine`, and converts to and from it
+/// with `From`.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "P: Parameters"),
+    Clone(bound = "P: Parameters"),
+    PartialEq(bound = "P: Parameters"),
+    Eq(bound = "P: Parameters"),
+    Debug(bound = "P: Parameters"),
+    Hash(bound = "P: Parameters")
+)]
+#[must_use]
+pub struct GroupAffine<P: Parameters> {
+    pub x: P::BaseField,
+    pub y: P::BaseField,
+    pub infinity: bool,
+    #[derivative(Debug = "ignore")]
+    _params: PhantomData<P>,
+}
+
+impl<P: Parameters> PartialEq<GroupProjective<P>> for GroupAffine<P> {
+    fn eq(&self, other: &GroupProjective<P>) -> bool {
+        self.into_projective() == *other
+    }
+}
+
+impl<P: Parameters> PartialEq<GroupAffine<P>> for GroupProjective<P> {
+    fn eq(&self, other: &GroupAffine<P>) -> bool {
+        *self == other.into_projective()
+    }
+}
+
+impl<P: Parameters> Display for GroupAffine<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.infinity {
+            write!(f, "GroupAffine(Infinity)")
+        } else {
+            write!(f, "GroupAffine(x={}, y={})", self.x, self.y)
+        }
+    }
+}
+
+impl<P: Parameters> GroupAffine<P> {
+    pub fn new(x: P::BaseField, y: P::BaseField, infinity: bool) -> Self {
+        Self {
+            x,
+            y,
+            infinity,
+            _params: PhantomData,
+        }
+    }
+
+    /// Multiply `self` by the cofactor of the curve, `P::COFACTOR`.
+    pub fn scale_by_cofactor(&self) -> GroupProjective<P> {
+        let cofactor = BitIteratorBE::new(P::COFACTOR);
+        self.mul_bits(cofactor)
+    }
+
+    /// Multiplies `self` by the scalar represented by `bits`. `bits` must be a big-endian
+    /// bit-wise decomposition of the scalar.
+    pub(crate) fn mul_bits(&self, bits: impl Iterator<Item = bool>) -> GroupProjective<P> {
+        let mut res = GroupProjective::zero();
+        // Skip leading zeros.
+        for i in bits.skip_while(|b| !b) {
+            res.double_in_place();
+            if i {
+                res.add_assign_mixed(self)
+            }
+        }
+        res
+    }
+
+    /// Attempts to construct an affine point given an x-coordinate. The
+    /// point is not guaranteed to be in the prime order subgroup.
+    ///
+    /// If and only if `greatest` is set will the lexicographically
+    /// largest y-coordinate be selected.
+    pub fn get_point_from_x(x: P::BaseField, greatest: bool) -> Option<Self> {
+        short_weierstrass_jacobian::GroupAffine::get_point_from_x(x, greatest).map(Self::from)
+    }
+
+    /// Checks if `self` is a valid point on the curve.
+    pub fn is_on_curve(&self) -> bool {
+        short_weierstrass_jacobian::GroupAffine::from(*self).is_on_curve()
+    }
+
+    /// Checks if `self` is in the subgroup having order that equaling that of
+    /// `P::ScalarField`.
+    pub fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
+        P::is_in_correct_subgroup_assuming_on_curve(&(*self).into())
+    }
+}
+
+impl<P: Parameters> Zeroize for GroupAffine<P> {
+    // The phantom data does not contain element-specific data
+    // and thus does not need to be zeroized.
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+        self.infinity.zeroize();
+    }
+}
+
+impl<P: Parameters> Zero for GroupAffine<P> {
+    /// Returns the point at infinity. Note that in affine coordinates,
+    /// the point at infinity does not lie on the curve, and this is indicated
+    /// by setting the `infinity` flag to true.
+    #[inline]
+    fn zero() -> Self {
+        Self::new(P::BaseField::zero(), P::BaseField::one(), true)
+    }
+
+    /// Checks if `self` is the point at infinity.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.infinity
+    }
+}
+
+impl<P: Parameters> Add<Self> for GroupAffine<P> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        let mut copy = self;
+        copy += &other;
+        copy
+    }
+}
+
+impl<'a, P: Parameters> AddAssign<&'a Self> for GroupAffine<P> {
+    fn add_assign(&mut self, other: &'a Self) {
+        let mut s_proj = GroupProjective::from(*self);
+        s_proj.add_assign_mixed(other);
+        *self = s_proj.into();
+    }
+}
+
+impl<P: Parameters> AffineCurve for GroupAffine<P> {
+    const COFACTOR: &'static [u64] = P::COFACTOR;
+    type BaseField = P::BaseField;
+    type ScalarField = P::ScalarField;
+    type Projective = GroupProjective<P>;
+
+    #[inline]
+    fn prime_subgroup_generator() -> Self {
+        Self::new(
+            P::AFFINE_GENERATOR_COEFFS.0,
+            P::AFFINE_GENERATOR_COEFFS.1,
+            false,
+        )
+    }
+
+    fn from_random_bytes(bytes: &[u8]) -> Option<Self> {
+        short_weierstrass_jacobian::GroupAffine::from_random_bytes(bytes).map(Self::from)
+    }
+
+    #[inline]
+    fn mul<S: Into<<Self::ScalarField as PrimeField>::BigInt>>(&self, by: S) -> GroupProjective<P> {
+        self.mul_bits(BitIteratorBE::new(by.into()))
+    }
+
+    #[inline]
+    fn mul_by_cofactor_to_projective(&self) -> Self::Projective {
+        self.scale_by_cofactor()
+    }
+
+    fn mul_by_cofactor_inv(&self) -> Self {
+        self.mul(P::COFACTOR_INV).into()
+    }
+
+    fn clear_cofactor(&self) -> Self {
+        P::clear_cofactor(&(*self).into()).into()
+    }
+
+    /// Accumulates the buckets in extended Jacobian coordinates, which make
+    /// mixed additions cheaper than in homogeneous coordinates.
+    fn msm_window_sum<'a>(
+        bases_and_digits: impl Iterator<Item = (&'a Self, i64)>,
+        num_buckets: usize,
+    ) -> GroupProjective<P> {
+        let points = bases_and_digits
+            .filter(|(base, _)| !base.infinity)
+            .map(|(base, digit)| (base.x, base.y, digit));
+        let sum = crate::msm::xyzz::window_sum::<P>(points, num_buckets);
+        if sum.zz.is_zero() {
+            return GroupProjective::zero();
+        }
+        // (X/ZZ, Y/ZZZ) = (X'/Z', Y'/Z') for Z' = ZZ*ZZZ.
+        GroupProjective::new(sum.x * &sum.zzz, sum.y * &sum.zz, sum.zz * &sum.zzz)
+    }
+}
+
+impl<P: Parameters> Neg for GroupAffine<P> {
+    type Output = Self;
+
+    /// If `self.is_zero()`, returns `self` (`== Self::zero()`).
+    /// Else, returns `(x, -y)`, where `self = (x, y)`.
+    #[inline]
+    fn neg(self) -> Self {
+        if !self.is_zero() {
+            Self::new(self.x, -self.y, false)
+        } else {
+            self
+        }
+    }
+}
+
+impl<P: Parameters> ToBytes for GroupAffine<P> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.x.write(&mut writer)?;
+        self.y.write(&mut writer)?;
+        self.infinity.write(&mut writer)
+    }
+}
+
+impl<P: Parameters> FromBytes for GroupAffine<P> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let x = P::BaseField::read(&mut reader)?;
+        let y = P::BaseField::read(&mut reader)?;
+        let infinity = bool::read(reader)?;
+        Ok(Self::new(x, y, infinity))
+    }
+}
+
+impl<P: Parameters> Default for GroupAffine<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: Parameters> core::iter::Sum<Self> for GroupAffine<P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(GroupProjective::<P>::zero(), |sum, x| sum.add_mixed(&x))
+            .into()
+    }
+}
+
+impl<'a, P: Parameters> core::iter::Sum<&'a Self> for GroupAffine<P> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(GroupProjective::<P>::zero(), |sum, x| sum.add_mixed(x))
+            .into()
+    }
+}
+
+impl<P: Parameters> From<short_weierstrass_jacobian::GroupAffine<P>> for GroupAffine<P> {
+    #[inline]
+    fn from(p: short_weierstrass_jacobian::GroupAffine<P>) -> Self {
+        Self::new(p.x, p.y, p.infinity)
+    }
+}
+
+impl<P: Parameters> From<GroupAffine<P>> for short_weierstrass_jacobian::GroupAffine<P> {
+    #[inline]
+    fn from(p: GroupAffine<P>) -> Self {
+        Self::new(p.x, p.y, p.infinity)
+    }
+}
+
+/// Homogeneous projective coordinates for a point on an elliptic curve in
+/// short Weierstrass form, over the base field `P::BaseField`. The point
+/// `(X : Y : Z)` represents the affine point `(X / Z, Y / Z)`, and the point
+/// at infinity is `(0 : 1 : 0)`.
+///
+/// This struct implements arithmetic via the complete formulae of
+/// [[RCB15]](https://eprint.iacr.org/2015/1060), which have no special cases
+/// for doubling or for the point at infinity, and hence do not branch on the
+/// points. They are correct for all inputs on curves without points of order
+/// two, and in particular for all points of the prime order subgroup. This
+/// makes this model suitable for side-channel-sensitive code, at the cost of
+/// slower arithmetic than `short_weierstrass_jacobian::GroupProjective`.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "P: Parameters"),
+    Clone(bound = "P: Parameters"),
+    Debug(bound = "P: Parameters")
+)]
+#[must_use]
+pub struct GroupProjective<P: Parameters> {
+    pub x: P::BaseField,
+    pub y: P::BaseField,
+    pub z: P::BaseField,
+    #[derivative(Debug = "ignore")]
+    _params: PhantomData<P>,
+}
+
+impl<P: Parameters> Display for GroupProjective<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", GroupAffine::from(*self))
+    }
+}
+
+impl<P: Parameters> Eq for GroupProjective<P> {}
+impl<P: Parameters> PartialEq for GroupProjective<P> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_zero() {
+            return other.is_zero();
+        }
+
+        if other.is_zero() {
+            return false;
+        }
+
+        // The points (X, Y, Z) and (X', Y', Z')
+        // are equal when (X * Z') = (X' * Z)
+        // and (Y * Z') = (Y' * Z).
+        self.x * &other.z == other.x * &self.z && self.y * &other.z == other.y * &self.z
+    }
+}
+
+impl<P: Parameters> Hash for GroupProjective<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.into_affine().hash(state)
+    }
+}
+
+impl<P: Parameters> Distribution<GroupProjective<P>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GroupProjective<P> {
+        loop {
+            let x = P::BaseField::rand(rng);
+            let greatest = rng.gen();
+
+            if let Some(p) = short_weierstrass_jacobian::GroupAffine::get_point_from_x(x, greatest)
+            {
+                return GroupAffine::from(P::clear_cofactor(&p)).into();
+            }
+        }
+    }
+}
+
+impl<P: Parameters> ToBytes for GroupProjective<P> {
+    #[inline]
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.x.write(&mut writer)?;
+        self.y.write(&mut writer)?;
+        self.z.write(writer)
+    }
+}
+
+impl<P: Parameters> FromBytes for GroupProjective<P> {
+    #[inline]
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let x = P::BaseField::read(&mut reader)?;
+        let y = P::BaseField::read(&mut reader)?;
+        let z = P::BaseField::read(reader)?;
+        Ok(Self::new(x, y, z))
+    }
+}
+
+impl<P: Parameters> Default for GroupProjective<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<P: Parameters> GroupProjective<P> {
+    pub fn new(x: P::BaseField, y: P::BaseField, z: P::BaseField) -> Self {
+        Self {
+            x,
+            y,
+            z,
+            _params: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    fn mul_by_3b(elem: &P::BaseField) -> P::BaseField {
+        let b3 = P::COEFF_B.double() + &P::COEFF_B;
+        b3 * elem
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: Parameters> GroupAffine<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    /// Multiplies `self` by `by` in constant time. See
+    /// [`GroupProjective::ct_mul`].
+    pub fn ct_mul<S: Into<<P::ScalarField as PrimeField>::BigInt>>(
+        &self,
+        by: S,
+    ) -> GroupProjective<P> {
+        self.into_projective().ct_mul(by)
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: Parameters> GroupProjective<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    /// Multiplies `self` by `by` in constant time, for use with secret
+    /// scalars, such as in signing or key exchange.
+    ///
+    /// Unlike `mul`, this processes every bit of `by` with a fixed window and
+    /// constant-time table lookups. The complete addition formulae do not
+    /// branch on the points. Note that converting the result into affine
+    /// coordinates uses the variable-time `Field::inverse`.
+    pub fn ct_mul<S: Into<<P::ScalarField as PrimeField>::BigInt>>(&self, by: S) -> Self {
+        crate::constant_time::fixed_window_mul(
+            self,
+            Self::zero(),
+            by.into().as_ref(),
+            |a, b| *a + b,
+            |p| p.double(),
+        )
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: Parameters> ConditionallySelectable for GroupProjective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
+impl<P: Parameters> Zeroize for GroupProjective<P> {
+    fn zeroize(&mut self) {
+        // `PhantomData` does not contain any data and thus does not need to be zeroized.
+        self.x.zeroize();
+        self.y.zeroize();
+        self.z.zeroize();
+    }
+}
+
+impl<P: Parameters> Zero for GroupProjective<P> {
+    /// Returns the point at infinity `(0 : 1 : 0)`.
+    #[inline]
+    fn zero() -> Self {
+        Self::new(
+            P::BaseField::zero(),
+            P::BaseField::one(),
+            P::BaseField::zero(),
+        )
+    }
+
+    /// Checks whether `self.z.is_zero()`.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.z.is_zero()
+    }
+}
+
+impl<P: Parameters> ProjectiveCurve for GroupProjective<P> {
+    const COFACTOR: &'static [u64] = P::COFACTOR;
+    type BaseField = P::BaseField;
+    type ScalarField = P::ScalarField;
+    type Affine = GroupAffine<P>;
+
+    #[inline]
+    fn prime_subgroup_generator() -> Self {
+        GroupAffine::prime_subgroup_generator().into()
+    }
+
+    #[inline]
+    fn is_normalized(&self) -> bool {
+        self.is_zero() || self.z.is_one()
+    }
+
+    /// Normalizes a slice of projective elements so that
+    /// conversion to affine is cheap.
+    ///
+    /// In more detail, this method converts a curve point in homogeneous
+    /// projective coordinates (x, y, z) into an equivalent representation
+    /// (x/z, y/z, 1).
+    ///
+    /// For `N = v.len()`, this costs 1 inversion + 5N field multiplications.
+    ///
+    /// (Where batch inversion comprises 3N field multiplications + 1 inversion of these operations)
+    #[inline]
+    fn batch_normalization(v: &mut [Self]) {
+        let mut z_s = v.iter().map(|g| g.z).collect::<Vec<_>>();
+        ark_ff::batch_inversion(&mut z_s);
+
+        // Perform affine transformations
+        ark_std::cfg_iter_mut!(v)
+            .zip(z_s)
+            .filter(|(g, _)| !g.is_normalized())
+            .for_each(|(g, z)| {
+                g.x *= &z; // x/z
+                g.y *= &z; // y/z
+                g.z = P::BaseField::one(); // z = 1
+            });
+    }
+
+    /// Sets `self = 2 * self`, using the doubling formulae of
+    /// [[RCB15]](https://eprint.iacr.org/2015/1060): Algorithm 9 if `P::A` is
+    /// zero, and Algorithm 3 otherwise. Unlike in Jacobian coordinates, these
+    /// also map the point at infinity to itself.
+    fn double_in_place(&mut self) -> &mut Self {
+        if P::COEFF_A.is_zero() {
+            // t0 = Y^2
+            let t0 = self.y.square();
+            // Z3 = 8 * t0
+            let mut z3 = t0.double().double().double();
+            // t1 = Y * Z
+            let t1 = self.y * &self.z;
+            // t2 = 3b * Z^2
+            let t2 = Self::mul_by_3b(&self.z.square());
+            // X3 = t2 * Z3
+            let mut x3 = t2 * &z3;
+            // Y3 = t0 + t2
+            let mut y3 = t0 + &t2;
+            // Z3 = t1 * Z3
+            z3 *= &t1;
+            // t0 = t0 - 3 * t2
+            let t0 = t0 - &(t2.double() + &t2);
+            // Y3 = X3 + t0 * Y3
+            y3 *= &t0;
+            y3 += &x3;
+            // X3 = 2 * t0 * X * Y
+            x3 = (t0 * &(self.x * &self.y)).double();
+
+            self.x = x3;
+            self.y = y3;
+            self.z = z3;
+            self
+        } else {
+            let t0 = self.x.square();
+            let t1 = self.y.square();
+            let mut t2 = self.z.square();
+            let mut t3 = (self.x * &self.y).double();
+            let mut z3 = (self.x * &self.z).double();
+            let mut x3 = P::mul_by_a(&z3);
+            let mut y3 = Self::mul_by_3b(&t2);
+            y3 += &x3;
+            x3 = t1 - &y3;
+            y3 += &t1;
+            y3 *= &x3;
+            x3 *= &t3;
+            z3 = Self::mul_by_3b(&z3);
+            t2 = P::mul_by_a(&t2);
+            t3 = P::mul_by_a(&(t0 - &t2));
+            t3 += &z3;
+            let t0 = (t0.double() + &t0 + &t2) * &t3;
+            y3 += &t0;
+            let t2 = (self.y * &self.z).double();
+            x3 -= &(t2 * &t3);
+            z3 = (t2 * &t1).double().double();
+
+            self.x = x3;
+            self.y = y3;
+            self.z = z3;
+            self
+        }
+    }
+
+    /// Sets `self = self + other`, using the mixed addition formulae of
+    /// [[RCB15]](https://eprint.iacr.org/2015/1060): Algorithm 8 if `P::A` is
+    /// zero, and Algorithm 2 otherwise. These only branch on whether `other`
+    /// is the point at infinity, which has no representation with `Z = 1`.
+    fn add_assign_mixed(&mut self, other: &GroupAffine<P>) {
+        if other.is_zero() {
+            return;
+        }
+
+        if P::COEFF_A.is_zero() {
+            let t0 = self.x * &other.x;
+            let t1 = self.y * &other.y;
+            // t3 = (X2 + Y2) * (X1 + Y1) - t0 - t1
+            let t3 = (other.x + &other.y) * &(self.x + &self.y) - &(t0 + &t1);
+            // t4 = Y2 * Z1 + Y1
+            let t4 = other.y * &self.z + &self.y;
+            // Y3 = X2 * Z1 + X1
+            let mut y3 = other.x * &self.z + &self.x;
+            // t0 = 3 * t0
+            let t0 = t0.double() + &t0;
+            let t2 = Self::mul_by_3b(&self.z);
+            let mut z3 = t1 + &t2;
+            let t1 = t1 - &t2;
+            y3 = Self::mul_by_3b(&y3);
+            // X3 = t3 * t1 - t4 * Y3
+            let x3 = t3 * &t1 - &(t4 * &y3);
+            // Y3 = t1 * Z3 + Y3 * t0
+            let y3 = t1 * &z3 + &(y3 * &t0);
+            // Z3 = Z3 * t4 + t0 * t3
+            z3 = z3 * &t4 + &(t0 * &t3);
+
+            self.x = x3;
+            self.y = y3;
+            self.z = z3;
+        } else {
+            let t0 = self.x * &other.x;
+            let t1 = self.y * &other.y;
+            let t3 = (other.x + &other.y) * &(self.x + &self.y) - &(t0 + &t1);
+            let t4 = other.x * &self.z + &self.x;
+            let t5 = other.y * &self.z + &self.y;
+            let mut z3 = P::mul_by_a(&t4);
+            let mut x3 = Self::mul_by_3b(&self.z);
+            z3 += &x3;
+            x3 = t1 - &z3;
+            z3 += &t1;
+            let mut y3 = x3 * &z3;
+            let mut t1 = t0.double() + &t0;
+            let mut t2 = P::mul_by_a(&self.z);
+            let mut t4 = Self::mul_by_3b(&t4);
+            t1 += &t2;
+            t2 = P::mul_by_a(&(t0 - &t2));
+            t4 += &t2;
+            y3 += &(t1 * &t4);
+            x3 = t3 * &x3 - &(t5 * &t4);
+            z3 = t5 * &z3 + &(t3 * &t1);
+
+            self.x = x3;
+            self.y = y3;
+            self.z = z3;
+        }
+    }
+}
+
+impl<P: Parameters> Neg for GroupProjective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(self.x, -self.y, self.z)
+    }
+}
+
+ark_ff::impl_additive_ops_from_ref!(GroupProjective, Parameters);
+
+impl<'a, P: Parameters> Add<&'a Self> for GroupProjective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &'a Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<'a, P: Parameters> AddAssign<&'a Self> for GroupProjective<P> {
+    /// Uses the complete addition formulae of
+    /// [[RCB15]](https://eprint.iacr.org/2015/1060): Algorithm 7 if `P::A` is
+    /// zero, and Algorithm 1 otherwise.
+    fn add_assign(&mut self, other: &'a Self) {
+        if P::COEFF_A.is_zero() {
+            let t0 = self.x * &other.x;
+            let t1 = self.y * &other.y;
+            let t2 = self.z * &other.z;
+            // t3 = (X1 + Y1) * (X2 + Y2) - t0 - t1
+            let t3 = (self.x + &self.y) * &(other.x + &other.y) - &(t0 + &t1);
+            // t4 = (Y1 + Z1) * (Y2 + Z2) - t1 - t2
+            let t4 = (self.y + &self.z) * &(other.y + &other.z) - &(t1 + &t2);
+            // Y3 = (X1 + Z1) * (X2 + Z2) - t0 - t2
+            let y3 = (self.x + &self.z) * &(other.x + &other.z) - &(t0 + &t2);
+            // t0 = 3 * t0
+            let t0 = t0.double() + &t0;
+            let t2 = Self::mul_by_3b(&t2);
+            let z3 = t1 + &t2;
+            let t1 = t1 - &t2;
+            let y3 = Self::mul_by_3b(&y3);
+
+            // X3 = t3 * t1 - t4 * Y3
+            self.x = t3 * &t1 - &(t4 * &y3);
+            // Y3 = t1 * Z3 + Y3 * t0
+            self.y = t1 * &z3 + &(y3 * &t0);
+            // Z3 = Z3 * t4 + t0 * t3
+            self.z = z3 * &t4 + &(t0 * &t3);
+        } else {
+            let t0 = self.x * &other.x;
+            let t1 = self.y * &other.y;
+            let t2 = self.z * &other.z;
+            let t3 = (self.x + &self.y) * &(other.x + &other.y) - &(t0 + &t1);
+            let t4 = (self.x + &self.z) * &(other.x + &other.z) - &(t0 + &t2);
+            let t5 = (self.y + &self.z) * &(other.y + &other.z) - &(t1 + &t2);
+            let mut z3 = P::mul_by_a(&t4);
+            let mut x3 = Self::mul_by_3b(&t2);
+            z3 += &x3;
+            x3 = t1 - &z3;
+            z3 += &t1;
+            let mut y3 = x3 * &z3;
+            let mut t1 = t0.double() + &t0;
+            let mut t2 = P::mul_by_a(&t2);
+            let mut t4 = Self::mul_by_3b(&t4);
+            t1 += &t2;
+            t2 = P::mul_by_a(&(t0 - &t2));
+            t4 += &t2;
+            y3 += &(t1 * &t4);
+
+            self.x = t3 * &x3 - &(t5 * &t4);
+            self.y = y3;
+            self.z = t5 * &z3 + &(t3 * &t1);
+        }
+    }
+}
+
+impl<'a, P: Parameters> Sub<&'a Self> for GroupProjective<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: &'a Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<'a, P: Parameters> SubAssign<&'a Self> for GroupProjective<P> {
+    fn sub_assign(&mut self, other: &'a Self) {
+        *self += &(-(*other));
+    }
+}
+
+impl<P: Parameters> MulAssign<P::ScalarField> for GroupProjective<P> {
+    fn mul_assign(&mut self, other: P::ScalarField) {
+        *self = self.mul(other.into_repr())
+    }
+}
+
+// The affine point X, Y is represented in the homogeneous projective
+// coordinates with Z = 1.
+impl<P: Parameters> From<GroupAffine<P>> for GroupProjective<P> {
+    #[inline]
+    fn from(p: GroupAffine<P>) -> GroupProjective<P> {
+        if p.is_zero() {
+            Self::zero()
+        } else {
+            Self::new(p.x, p.y, P::BaseField::one())
+        }
+    }
+}
+
+// The projective point X, Y, Z is represented in the affine
+// coordinates as X/Z, Y/Z.
+impl<P: Parameters> From<GroupProjective<P>> for GroupAffine<P> {
+    #[inline]
+    fn from(p: GroupProjective<P>) -> GroupAffine<P> {
+        if p.is_zero() {
+            GroupAffine::zero()
+        } else if p.z.is_one() {
+            // If Z is one, the point is already normalized.
+            GroupAffine::new(p.x, p.y, false)
+        } else {
+            // Z is nonzero, so it must have an inverse in a field.
+            let zinv = p.z.inverse().unwrap();
+            GroupAffine::new(p.x * &zinv, p.y * &zinv, false)
+        }
+    }
+}
+
+/// The encoding is that of `short_weierstrass_jacobian::GroupAffine`.
+impl<P: Parameters> CanonicalSerialize for GroupAffine<P> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        short_weierstrass_jacobian::GroupAffine::from(*self).serialize(writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        short_weierstrass_jacobian::GroupAffine::from(*self).serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        short_weierstrass_jacobian::GroupAffine::from(*self).serialize_uncompressed(writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        short_weierstrass_jacobian::GroupAffine::from(*self).uncompressed_size()
+    }
+}
+
+impl<P: Parameters> CanonicalSerialize for GroupProjective<P> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        GroupAffine::from(*self).serialize(writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        GroupAffine::from(*self).serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        GroupAffine::from(*self).serialize_uncompressed(writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        GroupAffine::from(*self).uncompressed_size()
+    }
+}
+
+impl<P: Parameters> CanonicalDeserialize for GroupAffine<P> {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        short_weierstrass_jacobian::GroupAffine::deserialize(reader).map(Self::from)
+    }
+
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        short_weierstrass_jacobian::GroupAffine::deserialize_uncompressed(reader).map(Self::from)
+    }
+
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        short_weierstrass_jacobian::GroupAffine::deserialize_unchecked(reader).map(Self::from)
+    }
+}
+
+impl<P: Parameters> CanonicalDeserialize for GroupProjective<P> {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        GroupAffine::deserialize(reader).map(Self::from)
+    }
+
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        GroupAffine::deserialize_uncompressed(reader).map(Self::from)
+    }
+
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        GroupAffine::deserialize_unchecked(reader).map(Self::from)
+    }
+}
+
+impl<M: Parameters, ConstraintF: Field> ToConstraintField<ConstraintF> for GroupAffine<M>
+where
+    M::BaseField: ToConstraintField<ConstraintF>,
+{
+    #[inline]
+    fn to_field_elements(&self) -> Option<Vec<ConstraintF>> {
+        short_weierstrass_jacobian::GroupAffine::from(*self).to_field_elements()
+    }
+}
+
+impl<M: Parameters, ConstraintF: Field> ToConstraintField<ConstraintF> for GroupProjective<M>
+where
+    M::BaseField: ToConstraintField<ConstraintF>,
+{
+    #[inline]
+    fn to_field_elements(&self) -> Option<Vec<ConstraintF>> {
+        GroupAffine::from(*self).to_field_elements()
+    }
+}
@@ -30,6 +30,12 @@ use ark_ff::{
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+#[cfg(feature = "ct")]
+use ark_ff::fields::{
+    constant_time::{Choice, ConditionallySelectable},
+    ConstantTimeField,
+};
+
 #[derive(Derivative)]
 #[derivative(
     Copy(bound = "P: Parameters"),
@@ -162,6 +168,10 @@ impl<P: Parameters> AffineCurve for GroupAffine<P> {
     fn mul_by_cofactor_inv(&self) -> Self {
         self.mul(P::COFACTOR_INV).into()
     }
+
+    fn clear_cofactor(&self) -> Self {
+        P::clear_cofactor(self)
+    }
 }
 
 impl<P: Parameters> Zeroize for GroupAffine<P> {
@@ -407,6 +417,59 @@ impl<P: Parameters> GroupProjective<P> {
         }
     }
 }
+#[cfg(feature = "ct")]
+impl<P: Parameters> GroupAffine<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    /// Multiplies `self` by `by` in constant time. See
+    /// [`GroupProjective::ct_mul`].
+    pub fn ct_mul<S: Into<<P::ScalarField as PrimeField>::BigInt>>(
+        &self,
+        by: S,
+    ) -> GroupProjective<P> {
+        self.into_projective().ct_mul(by)
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: Parameters> GroupProjective<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    /// Multiplies `self` by `by` in constant time, for use with secret
+    /// scalars, such as in signing or key exchange.
+    ///
+    /// Unlike `mul`, this processes every bit of `by` with a fixed window and
+    /// constant-time table lookups. The unified addition formulae do not
+    /// branch on the points. Note that converting the result into affine
+    /// coordinates uses the variable-time `Field::inverse`.
+    pub fn ct_mul<S: Into<<P::ScalarField as PrimeField>::BigInt>>(&self, by: S) -> Self {
+        crate::constant_time::fixed_window_mul(
+            self,
+            Self::zero(),
+            by.into().as_ref(),
+            |a, b| *a + b,
+            |p| p.double(),
+        )
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: Parameters> ConditionallySelectable for GroupProjective<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.x, &b.x, choice),
+            P::BaseField::conditional_select(&a.y, &b.y, choice),
+            P::BaseField::conditional_select(&a.t, &b.t, choice),
+            P::BaseField::conditional_select(&a.z, &b.z, choice),
+        )
+    }
+}
+
 impl<P: Parameters> Zeroize for GroupProjective<P> {
     // The phantom data does not contain element-specific data
     // and thus does not need to be zeroized.
@@ -683,6 +746,11 @@ where
     }
 }
 
+/// A point on a Montgomery curve, in affine coordinates.
+///
+/// This type only stores the coordinates; see `crate::models::montgomery`
+/// for the full curve model, including the point at infinity and group
+/// arithmetic.
 #[derive(Derivative)]
 #[derivative(
     Copy(bound = "P: MontgomeryParameters"),
@@ -715,6 +783,14 @@ impl<P: MontgomeryParameters> MontgomeryGroupAffine<P> {
     }
 }
 
+impl<P: MontgomeryParameters> From<MontgomeryGroupAffine<P>>
+    for crate::models::montgomery::GroupAffine<P>
+{
+    fn from(p: MontgomeryGroupAffine<P>) -> Self {
+        Self::new(p.x, p.y, false)
+    }
+}
+
 impl<P: Parameters> CanonicalSerialize for GroupAffine<P> {
     #[allow(unused_qualifications)]
     #[inline]
@@ -1,6 +1,9 @@
 mod fixed_base;
+mod precomputed_bases;
 mod variable_base;
+pub(crate) mod xyzz;
 pub use fixed_base::*;
+pub use precomputed_bases::*;
 pub use variable_base::*;
 
 /// The result of this function is only approximately `ln(a)`
@@ -0,0 +1,201 @@
+use ark_ff::{BigInteger, FpParameters, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    cfg_chunks, cfg_iter,
+    io::{Read, Write},
+    vec::Vec,
+};
+
+use crate::{AffineCurve, ProjectiveCurve};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use super::variable_base::signed_digit;
+
+/// Multi-scalar multiplication over a fixed set of bases, with a table of
+/// precomputed multiples of each base.
+///
+/// For a window size `c`, the table stores `2^(w * c) * base` for every base
+/// and every window `w`. A multi-scalar multiplication then needs no doublings
+/// at all: every window of every scalar is recoded into a signed digit in
+/// `[-2^(c - 1), 2^(c - 1)]`, and all the resulting terms are summed with a
+/// single pass of Pippenger's bucket method. This trades `num_windows` times
+/// the memory of the bases for a much lower cost per call, which pays off when
+/// the same bases, e.g. a structured reference string, are used many times.
+///
+/// The table can be serialized with `CanonicalSerialize`, so that it only has
+/// to be computed once.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrecomputedBasesMSM<G: AffineCurve> {
+    window: usize,
+    /// `table[i * num_windows + w]` is `2^(w * window) * bases[i]`.
+    table: Vec<G>,
+}
+
+impl<G: AffineCurve> PrecomputedBasesMSM<G> {
+    /// The largest supported window size. `Self::multi_scalar_mul` allocates
+    /// `2^(window - 1)` buckets, so larger windows, e.g. from a corrupted
+    /// table, would exhaust the memory.
+    pub const MAX_WINDOW: usize = 24;
+
+    /// Returns a window size suited to `num_bases` bases.
+    pub fn get_window_size(num_bases: usize) -> usize {
+        if num_bases < 32 {
+            3
+        } else {
+            ark_std::cmp::min(super::ln_without_floats(num_bases) + 2, Self::MAX_WINDOW)
+        }
+    }
+
+    /// Precomputes the table for `bases` with windows of `window` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is not in `1..=Self::MAX_WINDOW`.
+    pub fn new(bases: &[G], window: usize) -> Self {
+        assert!(
+            window > 0 && window <= Self::MAX_WINDOW,
+            "invalid window size {}",
+            window
+        );
+        let num_windows = Self::num_windows(window);
+        let table = cfg_iter!(bases)
+            .flat_map(|base| {
+                let mut shifts = Vec::with_capacity(num_windows);
+                let mut shift = base.into_projective();
+                for _ in 0..num_windows {
+                    shifts.push(shift);
+                    for _ in 0..window {
+                        shift.double_in_place();
+                    }
+                }
+                G::Projective::batch_normalization_into_affine(&shifts)
+            })
+            .collect();
+        Self { window, table }
+    }
+
+    /// The window size of the table.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// The number of bases in the table.
+    pub fn num_bases(&self) -> usize {
+        self.table.len() / Self::num_windows(self.window)
+    }
+
+    /// Computes `sum_i scalars[i] * bases[i]`, where `bases` are the bases the
+    /// table was built from. Extra bases or scalars are ignored.
+    ///
+    /// Scalars are expected to have at most `MODULUS_BITS` bits, which holds
+    /// for all canonical representations of elements of `G::ScalarField`.
+    pub fn multi_scalar_mul(
+        &self,
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+    ) -> G::Projective {
+        let num_windows = Self::num_windows(self.window);
+        let size = ark_std::cmp::min(self.num_bases(), scalars.len());
+        let table = &self.table[..size * num_windows];
+        let scalars = &scalars[..size];
+        let num_buckets = 1 << (self.window - 1);
+
+        #[cfg(feature = "parallel")]
+        let chunk_size = size / rayon::current_num_threads() + 1;
+        #[cfg(not(feature = "parallel"))]
+        let chunk_size = ark_std::cmp::max(1, size);
+
+        let window = self.window;
+        cfg_chunks!(table, chunk_size * num_windows)
+            .zip(cfg_chunks!(scalars, chunk_size))
+            .map(|(shifts, scalars)| {
+                let bases_and_digits = shifts
+                    .chunks(num_windows)
+                    .zip(scalars)
+                    .filter(|(_, scalar)| !scalar.is_zero())
+                    .flat_map(|(shifts, scalar)| {
+                        shifts.iter().enumerate().map(move |(w, shift)| {
+                            (shift, signed_digit(scalar.as_ref(), w * window, window))
+                        })
+                    })
+                    .filter(|(_, digit)| *digit != 0);
+                G::msm_window_sum(bases_and_digits, num_buckets)
+            })
+            .sum()
+    }
+
+    /// The number of windows of `window` bits needed for scalars of
+    /// `MODULUS_BITS` bits. As in `VariableBaseMSM`, the topmost window only
+    /// absorbs the carry of the signed-digit recoding when `window` divides
+    /// `MODULUS_BITS`.
+    fn num_windows(window: usize) -> usize {
+        <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize / window + 1
+    }
+
+    /// Checks that a deserialized table has a valid window size and a whole
+    /// number of bases.
+    fn check(window: u64, table: Vec<G>) -> Result<Self, SerializationError> {
+        if window == 0 || window > Self::MAX_WINDOW as u64 {
+            return Err(SerializationError::InvalidData);
+        }
+        let window = window as usize;
+        if table.len() % Self::num_windows(window) != 0 {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(Self { window, table })
+    }
+}
+
+impl<G: AffineCurve> CanonicalSerialize for PrecomputedBasesMSM<G> {
+    #[inline]
+    fn serialize<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        (self.window as u64).serialize(&mut writer)?;
+        self.table.serialize(&mut writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        (self.window as u64).serialized_size() + self.table.serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        (self.window as u64).serialize_uncompressed(&mut writer)?;
+        self.table.serialize_uncompressed(&mut writer)
+    }
+
+    #[inline]
+    fn serialize_unchecked<W: Write>(&self, mut writer: W) -> Result<(), SerializationError> {
+        (self.window as u64).serialize_unchecked(&mut writer)?;
+        self.table.serialize_unchecked(&mut writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        (self.window as u64).uncompressed_size() + self.table.uncompressed_size()
+    }
+}
+
+impl<G: AffineCurve> CanonicalDeserialize for PrecomputedBasesMSM<G> {
+    #[inline]
+    fn deserialize<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let window = u64::deserialize(&mut reader)?;
+        let table = Vec::<G>::deserialize(&mut reader)?;
+        Self::check(window, table)
+    }
+
+    #[inline]
+    fn deserialize_uncompressed<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let window = u64::deserialize_uncompressed(&mut reader)?;
+        let table = Vec::<G>::deserialize_uncompressed(&mut reader)?;
+        Self::check(window, table)
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(mut reader: R) -> Result<Self, SerializationError> {
+        let window = u64::deserialize_unchecked(&mut reader)?;
+        let table = Vec::<G>::deserialize_unchecked(&mut reader)?;
+        Self::check(window, table)
+    }
+}
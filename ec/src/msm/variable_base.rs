@@ -1,7 +1,8 @@
 use ark_ff::prelude::*;
-use ark_std::vec::Vec;
+use ark_serialize::SerializationError;
+use ark_std::{borrow::Borrow, io::Read, vec::Vec};
 
-use crate::{AffineCurve, ProjectiveCurve};
+use crate::{wnaf::WnafContext, AffineCurve, ProjectiveCurve};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -9,87 +10,283 @@ use rayon::prelude::*;
 pub struct VariableBaseMSM;
 
 impl VariableBaseMSM {
+    /// The largest number of terms for which `Self::multi_scalar_mul` uses
+    /// interleaved wNAFs instead of Pippenger's algorithm. Beyond this, the
+    /// bucket sums of the latter are amortized over enough terms to win. In
+    /// the `msm` benchmark of `ark-poly-benches` on BLS12-381 G1, wNAFs are
+    /// faster up to 16 terms, and the two are within noise up to 32.
+    pub const WNAF_THRESHOLD: usize = 16;
+
+    /// The window size of the interleaved wNAFs used for small inputs.
+    const WNAF_WINDOW_SIZE: usize = 4;
+
+    /// Computes `sum_i scalars[i] * bases[i]` with Pippenger's bucket method,
+    /// or with interleaved wNAFs (see `WnafContext::multi_scalar_mul`) for at
+    /// most `Self::WNAF_THRESHOLD` terms.
     pub fn multi_scalar_mul<G: AffineCurve>(
         bases: &[G],
         scalars: &[<G::ScalarField as PrimeField>::BigInt],
+    ) -> G::Projective {
+        Self::multi_scalar_mul_inner(bases, scalars, false)
+    }
+
+    /// Computes `sum_i scalars[i] * bases[i]` like `Self::multi_scalar_mul`,
+    /// but on curves with a GLV endomorphism (see `AffineCurve::glv_split`),
+    /// each term is first split into two terms with half-length scalars, which
+    /// halves the number of windows.
+    ///
+    /// All the bases must be in the prime order subgroup.
+    pub fn glv_multi_scalar_mul<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+    ) -> G::Projective {
+        Self::multi_scalar_mul_inner(bases, scalars, true)
+    }
+
+    /// Computes `sum_i scalars[i] * bases[i]` with Pippenger's bucket method
+    /// regardless of the number of terms.
+    pub fn multi_scalar_mul_pippenger<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+    ) -> G::Projective {
+        let size = ark_std::cmp::min(bases.len(), scalars.len());
+        let num_bits = <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+        Self::msm_with_num_bits(&bases[..size], &scalars[..size], num_bits)
+    }
+
+    fn multi_scalar_mul_inner<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+        glv: bool,
     ) -> G::Projective {
         let size = ark_std::cmp::min(bases.len(), scalars.len());
         let scalars = &scalars[..size];
         let bases = &bases[..size];
-        let scalars_and_bases_iter = scalars.iter().zip(bases).filter(|(s, _)| !s.is_zero());
+
+        if size <= Self::WNAF_THRESHOLD {
+            // Scalars that are not reduced fall through to Pippenger's algorithm.
+            let field_scalars = scalars
+                .iter()
+                .map(|s| G::ScalarField::from_repr(*s))
+                .collect::<Option<Vec<_>>>();
+            if let Some(field_scalars) = field_scalars {
+                let bases = bases.iter().map(G::into_projective).collect::<Vec<_>>();
+                let context = WnafContext::new(Self::WNAF_WINDOW_SIZE);
+                return if glv {
+                    context.glv_multi_scalar_mul(&bases, &field_scalars)
+                } else {
+                    context.multi_scalar_mul(&bases, &field_scalars)
+                };
+            }
+        }
+
+        if glv {
+            let split = bases
+                .iter()
+                .zip(scalars)
+                .map(|(base, scalar)| {
+                    G::ScalarField::from_repr(*scalar).and_then(|scalar| base.glv_split(&scalar))
+                })
+                .collect::<Option<Vec<_>>>();
+            if let Some(split) = split {
+                let (bases, scalars): (Vec<_>, Vec<_>) = split
+                    .into_iter()
+                    .flatten()
+                    .map(|(base, scalar)| (base, scalar.into_repr()))
+                    .unzip();
+                let num_bits = scalars.iter().map(|s| s.num_bits()).max().unwrap_or(0);
+                return Self::msm_with_num_bits(
+                    &bases,
+                    &scalars,
+                    ark_std::cmp::max(num_bits, 1) as usize,
+                );
+            }
+        }
+
+        let num_bits = <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
+        Self::msm_with_num_bits(bases, scalars, num_bits)
+    }
+
+    /// Computes `sum_i scalars[i] * bases[i]` for bases and scalars given as
+    /// iterators, which are consumed in chunks of `chunk_size` terms so that
+    /// at most one chunk is held in memory at a time. Extra bases or scalars
+    /// are ignored.
+    ///
+    /// Larger chunks amortize the bucket sums of Pippenger's algorithm over
+    /// more terms, so `chunk_size` should be as large as memory allows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn multi_scalar_mul_chunked<G, B, S>(
+        bases: B,
+        scalars: S,
+        chunk_size: usize,
+    ) -> G::Projective
+    where
+        G: AffineCurve,
+        B: IntoIterator,
+        B::Item: Borrow<G>,
+        S: IntoIterator,
+        S::Item: Borrow<<G::ScalarField as PrimeField>::BigInt>,
+    {
+        let mut bases = bases.into_iter();
+        Self::chunked::<G, _, _>(
+            || bases.next().map(|b| Ok(*b.borrow())),
+            scalars,
+            chunk_size,
+        )
+        .unwrap()
+    }
+
+    /// Computes `sum_i scalars[i] * bases[i]`, where the bases are read from
+    /// `reader` one by one with `CanonicalDeserialize::deserialize_uncompressed`,
+    /// so that they never have to be in memory all at once. One base is read
+    /// per scalar, and the terms are processed in chunks of `chunk_size` as in
+    /// `Self::multi_scalar_mul_chunked`.
+    ///
+    /// Returns an error if `reader` holds fewer bases than there are scalars,
+    /// or if a base is invalid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    pub fn multi_scalar_mul_from_reader<G, R, S>(
+        mut reader: R,
+        scalars: S,
+        chunk_size: usize,
+    ) -> Result<G::Projective, SerializationError>
+    where
+        G: AffineCurve,
+        R: Read,
+        S: IntoIterator,
+        S::Item: Borrow<<G::ScalarField as PrimeField>::BigInt>,
+    {
+        Self::chunked::<G, _, _>(
+            || Some(G::deserialize_uncompressed(&mut reader)),
+            scalars,
+            chunk_size,
+        )
+    }
+
+    /// Like `Self::multi_scalar_mul_from_reader`, but reads the bases with
+    /// `CanonicalDeserialize::deserialize_unchecked`, skipping the curve and
+    /// subgroup checks. The bases must come from a trusted source.
+    pub fn multi_scalar_mul_from_reader_unchecked<G, R, S>(
+        mut reader: R,
+        scalars: S,
+        chunk_size: usize,
+    ) -> Result<G::Projective, SerializationError>
+    where
+        G: AffineCurve,
+        R: Read,
+        S: IntoIterator,
+        S::Item: Borrow<<G::ScalarField as PrimeField>::BigInt>,
+    {
+        Self::chunked::<G, _, _>(
+            || Some(G::deserialize_unchecked(&mut reader)),
+            scalars,
+            chunk_size,
+        )
+    }
+
+    /// Sums the chunked multi-scalar multiplications of the bases returned by
+    /// `next_base`, until either it or `scalars` runs out.
+    fn chunked<G, F, S>(
+        mut next_base: F,
+        scalars: S,
+        chunk_size: usize,
+    ) -> Result<G::Projective, SerializationError>
+    where
+        G: AffineCurve,
+        F: FnMut() -> Option<Result<G, SerializationError>>,
+        S: IntoIterator,
+        S::Item: Borrow<<G::ScalarField as PrimeField>::BigInt>,
+    {
+        assert!(chunk_size > 0, "chunk size must be positive");
+        let mut scalars = scalars.into_iter();
+        let mut bases_chunk = Vec::with_capacity(chunk_size);
+        let mut scalars_chunk = Vec::with_capacity(chunk_size);
+        let mut result = G::Projective::zero();
+        loop {
+            bases_chunk.clear();
+            scalars_chunk.clear();
+            while scalars_chunk.len() < chunk_size {
+                let scalar = match scalars.next() {
+                    Some(scalar) => *scalar.borrow(),
+                    None => break,
+                };
+                match next_base() {
+                    Some(base) => bases_chunk.push(base?),
+                    None => break,
+                }
+                scalars_chunk.push(scalar);
+            }
+            if scalars_chunk.is_empty() {
+                return Ok(result);
+            }
+            result += &Self::multi_scalar_mul(&bases_chunk, &scalars_chunk);
+            if scalars_chunk.len() < chunk_size {
+                return Ok(result);
+            }
+        }
+    }
+
+    /// Pippenger's algorithm for scalars of at most `num_bits` bits.
+    ///
+    /// Each window of `c` bits is recoded into a signed digit in
+    /// `[-2^(c - 1), 2^(c - 1)]`, so that a window only needs `2^(c - 1)`
+    /// buckets: a negative digit adds the negated base to the bucket of its
+    /// absolute value.
+    fn msm_with_num_bits<G: AffineCurve>(
+        bases: &[G],
+        scalars: &[<G::ScalarField as PrimeField>::BigInt],
+        num_bits: usize,
+    ) -> G::Projective {
+        let size = bases.len();
+        let fr_one = G::ScalarField::one().into_repr();
+
+        // Bases with unit scalars are added once, instead of being placed in
+        // a bucket of the lowest window and passed over by all the others.
+        let mut unit_sum = G::Projective::zero();
+        for (_, base) in scalars.iter().zip(bases).filter(|(s, _)| **s == fr_one) {
+            unit_sum.add_assign_mixed(base);
+        }
+        let scalars_and_bases_iter = scalars
+            .iter()
+            .zip(bases)
+            .filter(|(s, _)| !s.is_zero() && **s != fr_one);
 
         let c = if size < 32 {
             3
         } else {
             super::ln_without_floats(size) + 2
         };
-
-        let num_bits = <G::ScalarField as PrimeField>::Params::MODULUS_BITS as usize;
-        let fr_one = G::ScalarField::one().into_repr();
+        let num_buckets = 1 << (c - 1);
 
         let zero = G::Projective::zero();
-        let window_starts: Vec<_> = (0..num_bits).step_by(c).collect();
+        // The digit of the topmost window absorbs the carry out of the window
+        // below it, so we need one more window than the unsigned method
+        // whenever `c` divides `num_bits`.
+        let window_starts: Vec<_> = (0..=num_bits / c).map(|w| w * c).collect();
 
         // Each window is of size `c`.
         // We divide up the bits 0..num_bits into windows of size `c`, and
         // in parallel process each such window.
         let window_sums: Vec<_> = ark_std::cfg_into_iter!(window_starts)
             .map(|w_start| {
-                let mut res = zero;
-                // We don't need the "zero" bucket, so we only have 2^c - 1 buckets.
-                let mut buckets = vec![zero; (1 << c) - 1];
                 // This clone is cheap, because the iterator contains just a
                 // pointer and an index into the original vectors.
-                scalars_and_bases_iter.clone().for_each(|(&scalar, base)| {
-                    if scalar == fr_one {
-                        // We only process unit scalars once in the first window.
-                        if w_start == 0 {
-                            res.add_assign_mixed(base);
-                        }
-                    } else {
-                        let mut scalar = scalar;
-
-                        // We right-shift by w_start, thus getting rid of the
-                        // lower bits.
-                        scalar.divn(w_start as u32);
-
-                        // We mod the remaining bits by 2^{window size}, thus taking `c` bits.
-                        let scalar = scalar.as_ref()[0] % (1 << c);
-
-                        // If the scalar is non-zero, we update the corresponding
-                        // bucket.
-                        // (Recall that `buckets` doesn't have a zero bucket.)
-                        if scalar != 0 {
-                            buckets[(scalar - 1) as usize].add_assign_mixed(base);
-                        }
-                    }
-                });
-
-                // Compute sum_{i in 0..num_buckets} (sum_{j in i..num_buckets} bucket[j])
-                // This is computed below for b buckets, using 2b curve additions.
-                //
-                // We could first normalize `buckets` and then use mixed-addition
-                // here, but that's slower for the kinds of groups we care about
-                // (Short Weierstrass curves and Twisted Edwards curves).
-                // In the case of Short Weierstrass curves,
-                // mixed addition saves ~4 field multiplications per addition.
-                // However normalization (with the inversion batched) takes ~6
-                // field multiplications per element,
-                // hence batch normalization is a slowdown.
-
-                // `running_sum` = sum_{j in i..num_buckets} bucket[j],
-                // where we iterate backward from i = num_buckets to 0.
-                let mut running_sum = G::Projective::zero();
-                buckets.into_iter().rev().for_each(|b| {
-                    running_sum += &b;
-                    res += &running_sum;
-                });
-                res
+                let bases_and_digits = scalars_and_bases_iter
+                    .clone()
+                    .map(|(scalar, base)| (base, signed_digit(scalar.as_ref(), w_start, c)))
+                    .filter(|(_, digit)| *digit != 0);
+                G::msm_window_sum(bases_and_digits, num_buckets)
             })
             .collect();
 
         // We store the sum for the lowest window.
-        let lowest = *window_sums.first().unwrap();
+        let lowest = *window_sums.first().unwrap() + &unit_sum;
 
         // We're traversing windows from high to low.
         lowest
@@ -105,3 +302,33 @@ impl VariableBaseMSM {
                 })
     }
 }
+
+/// Returns the `c`-bit window of `limbs` starting at bit `w_start`, recoded
+/// as a signed digit: the window is read as a negative number when its top
+/// bit is set, and the top bit of the window below is added back as a carry.
+/// Summing `digit * 2^w_start` over all windows recovers the scalar as long
+/// as the topmost window has its top bit unset.
+pub(super) fn signed_digit(limbs: &[u64], w_start: usize, c: usize) -> i64 {
+    let window = get_bits(limbs, w_start, c) as i64;
+    let top = window >> (c - 1);
+    let carry = if w_start == 0 {
+        0
+    } else {
+        get_bits(limbs, w_start - 1, 1) as i64
+    };
+    window - (top << c) + carry
+}
+
+/// Returns the `len < 64` bits of `limbs` starting at bit `start`, where bits
+/// past the end of `limbs` read as zero.
+fn get_bits(limbs: &[u64], start: usize, len: usize) -> u64 {
+    let (limb, offset) = (start / 64, start % 64);
+    if limb >= limbs.len() {
+        return 0;
+    }
+    let mut bits = limbs[limb] >> offset;
+    if offset + len > 64 && limb + 1 < limbs.len() {
+        bits |= limbs[limb + 1] << (64 - offset);
+    }
+    bits & ((1 << len) - 1)
+}
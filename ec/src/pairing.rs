@@ -0,0 +1,216 @@
+use crate::{group::Group, PairingEngine};
+use ark_ff::{
+    bytes::{FromBytes, ToBytes},
+    fields::{CyclotomicMultSubgroup, Field, PrimeField},
+    UniformRand,
+};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use ark_std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{Read, Result as IoResult, Write},
+    ops::{AddAssign, MulAssign, Neg, SubAssign},
+    rand::{
+        distributions::{Distribution, Standard},
+        Rng,
+    },
+};
+use num_traits::{One, Zero};
+
+/// An element of the target group of the pairing `E`, which is the subgroup
+/// of order `E::Fr` of the multiplicative group of `E::Fqk`.
+///
+/// The group law is written additively, to match `ProjectiveCurve`: adding
+/// two elements multiplies them in `E::Fqk`, and multiplying by a scalar
+/// exponentiates, using the cyclotomic arithmetic of
+/// `CyclotomicMultSubgroup`.
+///
+/// Elements are serialized in compressed form as a single element of the
+/// field over which `E::Fqk` is quadratic, using the algebraic torus `T2`,
+/// and in uncompressed form as an element of `E::Fqk`. Deserialization
+/// checks that the result lies in the target group, except with
+/// `CanonicalDeserialize::deserialize_unchecked`.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "E: PairingEngine"),
+    Clone(bound = "E: PairingEngine"),
+    Debug(bound = "E: PairingEngine"),
+    PartialEq(bound = "E: PairingEngine"),
+    Eq(bound = "E: PairingEngine"),
+    Hash(bound = "E: PairingEngine")
+)]
+#[must_use]
+pub struct PairingOutput<E: PairingEngine>(pub E::Fqk);
+
+impl<E: PairingEngine> PairingOutput<E> {
+    /// Checks that `self` lies in the target group, by raising it to the
+    /// power of the group order.
+    pub fn is_in_correct_subgroup(&self) -> bool {
+        self.0.pow(E::Fr::characteristic()).is_one()
+    }
+}
+
+impl<E: PairingEngine> Display for PairingOutput<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "PairingOutput({})", self.0)
+    }
+}
+
+impl<E: PairingEngine> Default for PairingOutput<E> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<E: PairingEngine> Zero for PairingOutput<E> {
+    /// The identity of the target group, which is one in `E::Fqk`.
+    #[inline]
+    fn zero() -> Self {
+        Self(E::Fqk::one())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_one()
+    }
+}
+
+impl<E: PairingEngine> Group for PairingOutput<E> {
+    type ScalarField = E::Fr;
+
+    #[inline]
+    fn double(&self) -> Self {
+        Self(self.0.cyclotomic_square())
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        self.0 = self.0.cyclotomic_square();
+        self
+    }
+}
+
+impl<E: PairingEngine> Neg for PairingOutput<E> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(self.0.cyclotomic_inverse())
+    }
+}
+
+impl<'a, E: PairingEngine> core::ops::Add<&'a Self> for PairingOutput<E> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &'a Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<'a, E: PairingEngine> AddAssign<&'a Self> for PairingOutput<E> {
+    #[inline]
+    fn add_assign(&mut self, other: &'a Self) {
+        self.0 *= &other.0;
+    }
+}
+
+impl<'a, E: PairingEngine> core::ops::Sub<&'a Self> for PairingOutput<E> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: &'a Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<'a, E: PairingEngine> SubAssign<&'a Self> for PairingOutput<E> {
+    #[inline]
+    fn sub_assign(&mut self, other: &'a Self) {
+        self.0 *= &other.0.cyclotomic_inverse();
+    }
+}
+
+ark_ff::impl_additive_ops_from_ref!(PairingOutput, PairingEngine);
+
+impl<E: PairingEngine> MulAssign<E::Fr> for PairingOutput<E> {
+    #[inline]
+    fn mul_assign(&mut self, other: E::Fr) {
+        self.0 = self.0.cyclotomic_exp(other.into_repr());
+    }
+}
+
+impl<E: PairingEngine> Distribution<PairingOutput<E>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PairingOutput<E> {
+        // The final exponentiation maps the multiplicative group of `E::Fqk`
+        // onto the target group.
+        loop {
+            if let Some(f) = E::final_exponentiation(&E::Fqk::rand(rng)) {
+                return PairingOutput(f);
+            }
+        }
+    }
+}
+
+impl<E: PairingEngine> ToBytes for PairingOutput<E> {
+    #[inline]
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write(writer)
+    }
+}
+
+impl<E: PairingEngine> FromBytes for PairingOutput<E> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        E::Fqk::read(reader).map(Self)
+    }
+}
+
+impl<E: PairingEngine> CanonicalSerialize for PairingOutput<E> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.0.torus_compress().serialize(writer)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        <E::Fqk as CyclotomicMultSubgroup>::TorusField::zero().serialized_size()
+    }
+
+    #[inline]
+    fn serialize_uncompressed<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.0.serialize(writer)
+    }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.0.serialized_size()
+    }
+}
+
+impl<E: PairingEngine> CanonicalDeserialize for PairingOutput<E> {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let m = CanonicalDeserialize::deserialize(reader)?;
+        let res = Self(E::Fqk::torus_decompress(&m));
+        if !res.is_in_correct_subgroup() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(res)
+    }
+
+    fn deserialize_uncompressed<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let res = Self::deserialize_unchecked(reader)?;
+        if !res.is_in_correct_subgroup() {
+            return Err(SerializationError::InvalidData);
+        }
+        Ok(res)
+    }
+
+    #[inline]
+    fn deserialize_unchecked<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        E::Fqk::deserialize(reader).map(Self)
+    }
+}
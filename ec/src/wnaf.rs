@@ -37,6 +37,105 @@ impl WnafContext {
         self.mul_with_table(&table, scalar).unwrap()
     }
 
+    /// Computes `scalar * g` like `Self::mul`, but on curves with a GLV
+    /// endomorphism (see `ProjectiveCurve::glv_split`) the scalar is first
+    /// split into two half-length scalars, whose wNAFs are then processed
+    /// jointly.
+    ///
+    /// `g` must be in the prime order subgroup.
+    pub fn glv_mul<G: ProjectiveCurve>(&self, g: G, scalar: &G::ScalarField) -> G {
+        self.glv_multi_scalar_mul(&[g], &[*scalar])
+    }
+
+    /// Computes `sum_i scalars[i] * bases[i]` with interleaved wNAFs: the
+    /// wNAFs of all the scalars are processed from the most significant digit
+    /// down, so that the doublings are shared between all the terms. Extra
+    /// bases or scalars are ignored.
+    ///
+    /// This beats Pippenger's algorithm for a small number of terms, where
+    /// the bucket sums of the latter dominate.
+    pub fn multi_scalar_mul<G: ProjectiveCurve>(
+        &self,
+        bases: &[G],
+        scalars: &[G::ScalarField],
+    ) -> G {
+        self.multi_scalar_mul_inner(bases, scalars, false)
+    }
+
+    /// Computes `sum_i scalars[i] * bases[i]` like `Self::multi_scalar_mul`,
+    /// but splits each term in two as in `Self::glv_mul`.
+    ///
+    /// All the bases must be in the prime order subgroup.
+    pub fn glv_multi_scalar_mul<G: ProjectiveCurve>(
+        &self,
+        bases: &[G],
+        scalars: &[G::ScalarField],
+    ) -> G {
+        self.multi_scalar_mul_inner(bases, scalars, true)
+    }
+
+    fn multi_scalar_mul_inner<G: ProjectiveCurve>(
+        &self,
+        bases: &[G],
+        scalars: &[G::ScalarField],
+        glv: bool,
+    ) -> G {
+        let mut tables = Vec::with_capacity(2 * bases.len());
+        let mut wnafs = Vec::with_capacity(2 * bases.len());
+        for (base, scalar) in bases.iter().zip(scalars) {
+            let split = if glv { base.glv_split(scalar) } else { None };
+            match split {
+                Some(split) => {
+                    for (base, scalar) in split.iter() {
+                        tables.push(self.table(*base));
+                        wnafs.push(scalar.into_repr().find_wnaf(self.window_size).unwrap());
+                    }
+                },
+                None => {
+                    tables.push(self.table(*base));
+                    wnafs.push(scalar.into_repr().find_wnaf(self.window_size).unwrap());
+                },
+            }
+        }
+        Self::mul_interleaved(&tables, &wnafs)
+    }
+
+    /// Computes `sum_i wnafs[i] * tables[i][0]`, sharing the doublings
+    /// between all the terms.
+    ///
+    /// The tables are normalized together first, which costs a single
+    /// inversion and turns every addition below into a mixed addition.
+    fn mul_interleaved<G: ProjectiveCurve>(tables: &[Vec<G>], wnafs: &[Vec<i64>]) -> G {
+        let table_len = tables.first().map_or(0, Vec::len);
+        let flat_tables = G::batch_normalization_into_affine(&tables.concat());
+        let tables = flat_tables.chunks(ark_std::cmp::max(table_len, 1));
+
+        let len = wnafs.iter().map(Vec::len).max().unwrap_or(0);
+        let mut result = G::zero();
+        let mut found_non_zero = false;
+
+        for i in (0..len).rev() {
+            if found_non_zero {
+                result.double_in_place();
+            }
+
+            for (table, wnaf) in tables.clone().zip(wnafs) {
+                let n = wnaf.get(i).copied().unwrap_or(0);
+                if n != 0 {
+                    found_non_zero = true;
+
+                    if n > 0 {
+                        result.add_assign_mixed(&table[(n / 2) as usize]);
+                    } else {
+                        result.add_assign_mixed(&-table[((-n) / 2) as usize]);
+                    }
+                }
+            }
+        }
+
+        result
+    }
+
     /// Computes scalar multiplication of a group element by `scalar`.
     /// `base_table` holds precomputed multiples of the group element; it can be generated using `Self::table`.
     /// `scalar` is an element of `G::ScalarField`.
@@ -75,3 +174,70 @@ impl WnafContext {
         Some(result)
     }
 }
+
+/// Computes `a * p + b * q` with Shamir's trick on the joint sparse form of
+/// `a` and `b`, as in Algorithm 3.50 of the Guide to Elliptic Curve
+/// Cryptography. On average only half of the joint digits are nonzero, so
+/// this costs about `n` doublings and `n / 2` additions for `n`-bit scalars,
+/// with just `p + q` and `p - q` precomputed.
+pub fn mul_add<G: ProjectiveCurve>(a: &G::ScalarField, p: G, b: &G::ScalarField, q: G) -> G {
+    let jsf = joint_sparse_form(a.into_repr(), b.into_repr());
+    let sum = p + &q;
+    let diff = p - &q;
+
+    let mut result = G::zero();
+    for &(u0, u1) in jsf.iter().rev() {
+        result.double_in_place();
+        match (u0, u1) {
+            (0, 0) => {},
+            (1, 0) => result += &p,
+            (-1, 0) => result -= &p,
+            (0, 1) => result += &q,
+            (0, -1) => result -= &q,
+            (1, 1) => result += &sum,
+            (-1, -1) => result -= &sum,
+            (1, -1) => result += &diff,
+            (-1, 1) => result -= &diff,
+            _ => unreachable!(),
+        }
+    }
+    result
+}
+
+/// Returns the joint sparse form of `k0` and `k1`, least significant digits
+/// first: pairs `(u_0[j], u_1[j])` of digits in `{-1, 0, 1}` such that
+/// `k_i = sum_j u_i[j] 2^j`, where of any three consecutive pairs at least one
+/// is `(0, 0)`, and no nonzero digit is next to one of the opposite sign.
+fn joint_sparse_form<B: BigInteger>(mut k0: B, mut k1: B) -> Vec<(i8, i8)> {
+    let mut jsf = Vec::with_capacity(B::NUM_LIMBS * 64 + 1);
+    // The carries `d_0` and `d_1`, so that the digits left to produce are
+    // those of `k_i + d_i`.
+    let (mut d0, mut d1) = (0u64, 0u64);
+    while !k0.is_zero() || d0 != 0 || !k1.is_zero() || d1 != 0 {
+        // `l_i = (d_i + k_i) mod 8`.
+        let l0 = (d0 + (k0.as_ref()[0] & 7)) & 7;
+        let l1 = (d1 + (k1.as_ref()[0] & 7)) & 7;
+        let u = |l: u64, l_other: u64| -> i8 {
+            if l & 1 == 0 {
+                return 0;
+            }
+            let u = if l & 3 == 1 { 1 } else { -1 };
+            if (l == 3 || l == 5) && l_other & 3 == 2 {
+                -u
+            } else {
+                u
+            }
+        };
+        let (u0, u1) = (u(l0, l1), u(l1, l0));
+        jsf.push((u0, u1));
+        if 2 * d0 as i8 == 1 + u0 {
+            d0 = 1 - d0;
+        }
+        if 2 * d1 as i8 == 1 + u1 {
+            d1 = 1 - d1;
+        }
+        k0.div2();
+        k1.div2();
+    }
+    jsf
+}
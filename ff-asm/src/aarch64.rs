@@ -0,0 +1,145 @@
+//! Montgomery multiplication for aarch64, emitted as stable `asm!` blocks.
+//!
+//! The generated code follows the CIOS loop with the no-carry optimization of
+//! `MulAssign for Fp`: for every limb `b[i]` it accumulates `a * b[i]` into the
+//! running result and folds in one step of Montgomery reduction. Each
+//! multiply-accumulate computes the two halves of the 128-bit product with
+//! `mul` and `umulh`, and propagates carries with `adds`/`adc` chains. As with
+//! the x86-64 backend, the result is only reduced to `[0, 2 * MODULUS)`, and the
+//! caller has to finish the reduction.
+
+use std::fmt::Write;
+
+/// Returns a block that sets `a` to the Montgomery product of `a` and `b`, or
+/// of `a` with itself if `b` is `None`.
+pub(crate) fn generate_impl(num_limbs: usize, a: &str, b: Option<&str>) -> String {
+    let mut asm = Asm::default();
+    for i in 0..num_limbs {
+        asm.mul_add_reduce_row(i, num_limbs);
+    }
+    for j in 0..num_limbs {
+        asm.push(format!("str {{r{}}}, [{{a}}, #{}]", j, 8 * j));
+    }
+
+    let b_operand = if b.is_some() {
+        "b = in(reg) b.as_ptr(),"
+    } else {
+        "b = in(reg) a.as_ptr(),"
+    };
+    let result_operands = (0..num_limbs)
+        .map(|j| format!("r{} = out(reg) _,", j))
+        .collect::<String>();
+    format!(
+        "{{
+            let a = &mut {a};
+            {b_binding}
+            unsafe {{
+                core::arch::asm!(
+                    {template}
+                    a = in(reg) a.as_mut_ptr(),
+                    {b_operand}
+                    m = in(reg) P::MODULUS.0.as_ptr(),
+                    inv = in(reg) P::INV,
+                    {result_operands}
+                    bi = out(reg) _,
+                    k = out(reg) _,
+                    t = out(reg) _,
+                    lo = out(reg) _,
+                    hi = out(reg) _,
+                    c1 = out(reg) _,
+                    c2 = out(reg) _,
+                    options(nostack),
+                );
+            }}
+        }}",
+        a = a,
+        b_binding = b.map_or(String::new(), |b| format!("let b = &{};", b)),
+        template = asm.template(),
+        b_operand = b_operand,
+        result_operands = result_operands,
+    )
+}
+
+/// The instructions of an `asm!` template, one per line.
+#[derive(Default)]
+struct Asm {
+    lines: Vec<String>,
+}
+
+impl Asm {
+    fn push(&mut self, line: String) {
+        self.lines.push(line);
+    }
+
+    /// Computes `r += a * b[i]`, and then adds `k * MODULUS` for the `k` that
+    /// makes the lowest limb vanish and shifts it out, as in one iteration of
+    /// the outer loop of `MulAssign for Fp`.
+    fn mul_add_reduce_row(&mut self, i: usize, num_limbs: usize) {
+        let first = i == 0;
+        self.push(format!("ldr {{bi}}, [{{b}}, #{}]", 8 * i));
+
+        // (c1, r0) = r0 + a[0] * b[i]
+        self.push("ldr {t}, [{a}]".to_string());
+        self.mul("{t}", "{bi}");
+        if first {
+            self.push("mov {r0}, {lo}".to_string());
+            self.push("mov {c1}, {hi}".to_string());
+        } else {
+            self.push("adds {r0}, {r0}, {lo}".to_string());
+            self.push("adc {c1}, {hi}, xzr".to_string());
+        }
+
+        // k = r0 * INV, and c2 = (r0 + k * MODULUS[0]) >> 64, whose lowest
+        // limb is zero.
+        self.push("mul {k}, {r0}, {inv}".to_string());
+        self.push("ldr {t}, [{m}]".to_string());
+        self.mul("{k}", "{t}");
+        self.push("cmn {r0}, {lo}".to_string());
+        self.push("adc {c2}, {hi}, xzr".to_string());
+
+        for j in 1..num_limbs {
+            // (c1, r[j]) = r[j] + a[j] * b[i] + c1
+            self.push(format!("ldr {{t}}, [{{a}}, #{}]", 8 * j));
+            self.mul("{t}", "{bi}");
+            if first {
+                self.push(format!("adds {{r{}}}, {{lo}}, {{c1}}", j));
+                self.push("adc {c1}, {hi}, xzr".to_string());
+            } else {
+                self.mac(&format!("{{r{}}}", j), &format!("{{r{}}}", j), "{c1}");
+            }
+
+            // (c2, r[j - 1]) = r[j] + k * MODULUS[j] + c2
+            self.push(format!("ldr {{t}}, [{{m}}, #{}]", 8 * j));
+            self.mul("{k}", "{t}");
+            self.mac(&format!("{{r{}}}", j - 1), &format!("{{r{}}}", j), "{c2}");
+        }
+
+        // r[N - 1] = c1 + c2, which cannot overflow by the no-carry
+        // optimization.
+        self.push(format!("add {{r{}}}, {{c1}}, {{c2}}", num_limbs - 1));
+    }
+
+    /// Sets `(hi, lo)` to the 128-bit product of `x` and `y`.
+    fn mul(&mut self, x: &str, y: &str) {
+        self.push(format!("mul {{lo}}, {}, {}", x, y));
+        self.push(format!("umulh {{hi}}, {}, {}", x, y));
+    }
+
+    /// Sets `(carry, dest)` to `acc + (hi, lo) + carry`. This cannot overflow,
+    /// since `hi` is at most `2^64 - 2`.
+    fn mac(&mut self, dest: &str, acc: &str, carry: &str) {
+        self.push(format!("adds {}, {}, {{lo}}", dest, acc));
+        self.push("adc {hi}, {hi}, xzr".to_string());
+        self.push(format!("adds {}, {}, {}", dest, dest, carry));
+        self.push(format!("adc {}, {{hi}}, xzr", carry));
+    }
+
+    /// The template strings of the `asm!` invocation.
+    fn template(&self) -> String {
+        let mut template = String::new();
+        for line in &self.lines {
+            writeln!(template, "\"{}\",", line).unwrap();
+        }
+        template
+    }
+}
@@ -12,9 +12,8 @@ pub struct Context {
 
 #[derive(Clone)]
 struct Declare {
-    ty: String,
+    reg: String,
     var: String,
-    pos: usize,
     token: String,
 }
 
@@ -47,22 +46,18 @@ impl Context {
             .unwrap_or_else(|| self.get(fallback_id))
     }
 
-    pub fn add_declaration(&mut self, id: &str, ty: &str, var: &str) {
-        self.declarations.insert(
-            id.to_string(),
-            Declare {
-                ty: ty.to_string(),
-                var: var.to_string(),
-                pos: self.declarations.len(),
-                token: format!("${}", self.declarations.len()),
-            },
-        );
-        self.declaration_vec.push(Declare {
-            ty: ty.to_string(),
+    /// Declares an input of the `asm!` block with value `var`, passed in the
+    /// register `reg`. Inputs are given explicit registers so that none of
+    /// them can end up in `rbx`, which the generated code saves and restores
+    /// by hand.
+    pub fn add_declaration(&mut self, id: &str, reg: &str, var: &str) {
+        let declare = Declare {
+            reg: reg.to_string(),
             var: var.to_string(),
-            pos: self.declaration_vec.len(),
-            token: format!("${}", self.declaration_vec.len()),
-        });
+            token: format!("%{}", reg),
+        };
+        self.declarations.insert(id.to_string(), declare.clone());
+        self.declaration_vec.push(declare);
     }
 
     pub fn add_buffer(&mut self, extra_reg: usize) {
@@ -73,49 +68,50 @@ impl Context {
         ));
     }
 
-    pub fn add_llvm_asm(&mut self, ctx_string: String) {
+    pub fn add_asm(&mut self, ctx_string: String) {
         self.append(&format!(
             "
                     unsafe {{
-                        llvm_asm!({}
-                            :
-                            :",
+                        core::arch::asm!({},",
             ctx_string
         ));
     }
 
     pub fn add_clobber_from_vec(&mut self, clobbers: Vec<&str>) {
         for clobber in clobbers {
-            self.clobbers.push(format!(" \"{}\"", clobber));
+            self.add_clobber(clobber);
         }
     }
 
+    /// Marks the register `clobber` as overwritten by the `asm!` block.
+    /// Flags and memory are always assumed to be clobbered.
     pub fn add_clobber(&mut self, clobber: &str) {
-        self.clobbers.push(format!(" \"{}\"", clobber));
+        self.clobbers.push(format!("out(\"{}\") _", clobber));
     }
 
     pub fn build(&mut self) {
-        for i in 0..self.declarations.len() {
-            let dec = &self.declaration_vec[i];
-            let last = i == self.declarations.len() - 1;
+        for dec in &self.declaration_vec {
             let dec = &format!(
                 "
-                            \"{}\"({}){}      // {}",
-                dec.ty,
-                dec.var,
-                if last { "" } else { "," },
-                dec.pos
+                            in(\"{}\") {},",
+                dec.reg, dec.var,
             );
-            self.append(dec);
+            self.ctx_string += dec;
         }
-        let clobbers = self.clobbers.join(",");
-        self.append(&format!(
+        for clobber in &self.clobbers {
+            let clobber = &format!(
+                "
+                            {},",
+                clobber
+            );
+            self.ctx_string += clobber;
+        }
+        self.append(
             "
-                            : {}
+                            options(att_syntax),
                         );
-                    }}
+                    }
                 ",
-            clobbers
-        ));
+        );
     }
 }
@@ -17,10 +17,17 @@ use context::*;
 
 mod unroll;
 
+mod aarch64;
+
 use std::cell::RefCell;
 
 const MAX_REGS: usize = 6;
 
+/// The smallest number of limbs for which squaring has its own assembly. For
+/// fewer limbs, the cross products it saves do not pay for its extra memory
+/// traffic, and squaring uses the multiplication code instead.
+const SQUARE_MIN_LIMBS: usize = 5;
+
 /// Attribute used to unroll for loops found inside a function block.
 #[proc_macro_attribute]
 pub fn unroll_for_loops(_meta: TokenStream, input: TokenStream) -> TokenStream {
@@ -86,11 +93,11 @@ pub fn x86_64_asm_mul(input: TokenStream) -> TokenStream {
         let impl_block = generate_impl(num_limbs, true);
 
         let inner_ts: Expr = syn::parse_str(&impl_block).unwrap();
-        let ts = quote::quote! {
+        let ts = quote::quote! {{
             let a = &mut #a;
             let b = &#b;
             #inner_ts
-        };
+        }};
         ts.into()
     } else {
         TokenStream::new()
@@ -137,83 +144,100 @@ pub fn x86_64_asm_square(input: TokenStream) -> TokenStream {
         let impl_block = generate_impl(num_limbs, false);
 
         let inner_ts: Expr = syn::parse_str(&impl_block).unwrap();
-        let ts = quote::quote! {
+        let ts = quote::quote! {{
             let a = &mut #a;
             #inner_ts
-        };
+        }};
         ts.into()
     } else {
         TokenStream::new()
     }
 }
 
-fn generate_llvm_asm_mul_string(
+/// Like `x86_64_asm_mul!`, but emits a stable `asm!` block for aarch64, which
+/// multiplies limbs with `mul` and `umulh`.
+#[proc_macro]
+pub fn aarch64_asm_mul(input: TokenStream) -> TokenStream {
+    let AsmMulInput { num_limbs, a, b } = syn::parse_macro_input!(input);
+    let num_limbs = parse_num_limbs(&num_limbs);
+    if num_limbs <= 6 {
+        let (a, b) = (quote::quote!(#a), quote::quote!(#b));
+        let impl_block = aarch64::generate_impl(num_limbs, &a.to_string(), Some(&b.to_string()));
+        let ts: Expr = syn::parse_str(&impl_block).unwrap();
+        quote::quote!( #ts ).into()
+    } else {
+        TokenStream::new()
+    }
+}
+
+/// Like `x86_64_asm_square!`, but emits a stable `asm!` block for aarch64.
+#[proc_macro]
+pub fn aarch64_asm_square(input: TokenStream) -> TokenStream {
+    let AsmSquareInput { num_limbs, a } = syn::parse_macro_input!(input);
+    let num_limbs = parse_num_limbs(&num_limbs);
+    if num_limbs <= 6 {
+        let a = quote::quote!(#a);
+        let impl_block = aarch64::generate_impl(num_limbs, &a.to_string(), None);
+        let ts: Expr = syn::parse_str(&impl_block).unwrap();
+        quote::quote!( #ts ).into()
+    } else {
+        TokenStream::new()
+    }
+}
+
+fn parse_num_limbs(num_limbs: &Expr) -> usize {
+    if let Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(ref lit_int),
+        ..
+    }) = num_limbs
+    {
+        lit_int.base10_parse::<usize>().unwrap()
+    } else {
+        panic!("The number of limbs must be a literal");
+    }
+}
+
+fn generate_asm_mul_string(
     a: &str,
     b: &str,
     modulus: &str,
-    zero: &str,
     mod_prime: &str,
     limbs: usize,
 ) -> String {
-    let llvm_asm_string = RefCell::new(String::new());
+    let asm_string = RefCell::new(String::new());
 
-    let begin = || llvm_asm_string.borrow_mut().push_str("\"");
+    let begin = || asm_string.borrow_mut().push('"');
 
     let end = || {
-        llvm_asm_string.borrow_mut().push_str(
+        asm_string.borrow_mut().push_str(
             "
                                 \"",
         )
     };
 
     let _comment = |comment: &str| {
-        llvm_asm_string
+        asm_string
             .borrow_mut()
             .push_str(&format!("         // {}", comment));
     };
 
-    let mulxq = |a: &str, b: &str, c: &str| {
-        llvm_asm_string.borrow_mut().push_str(&format!(
-            "
-                                mulxq {}, {}, {}",
-            a, b, c
-        ));
-    };
-
-    let adcxq = |a: &str, b: &str| {
-        llvm_asm_string.borrow_mut().push_str(&format!(
-            "
-                                adcxq {}, {}",
-            a, b
-        ));
-    };
-
-    let adoxq = |a: &str, b: &str| {
-        llvm_asm_string.borrow_mut().push_str(&format!(
+    let instr = |op: &str, args: &[&str]| {
+        asm_string.borrow_mut().push_str(&format!(
             "
-                                adoxq {}, {}",
-            a, b
+                                {} {}",
+            op,
+            args.join(", ")
         ));
     };
 
-    let movq = |a: &str, b: &str| {
-        llvm_asm_string.borrow_mut().push_str(&format!(
-            "
-                                movq {}, {}",
-            a, b
-        ));
-    };
-
-    let xorq = |a: &str, b: &str| {
-        llvm_asm_string.borrow_mut().push_str(&format!(
-            "
-                                xorq {}, {}",
-            a, b
-        ));
-    };
+    let mulxq = |a: &str, b: &str, c: &str| instr("mulxq", &[a, b, c]);
+    let adcxq = |a: &str, b: &str| instr("adcxq", &[a, b]);
+    let adoxq = |a: &str, b: &str| instr("adoxq", &[a, b]);
+    let movq = |a: &str, b: &str| instr("movq", &[a, b]);
+    let xorq = |a: &str, b: &str| instr("xorq", &[a, b]);
 
     macro_rules! mul_1 {
-        ($a:expr, $b:ident, $zero:ident, $limbs:expr) => {
+        ($a:expr, $b:ident, $limbs:expr) => {
             movq($a, RDX);
             mulxq($b[0], R[0], R[1]);
             for j in 1..$limbs - 1 {
@@ -221,14 +245,14 @@ fn generate_llvm_asm_mul_string(
                 adcxq(RAX, R[j]);
             }
             mulxq($b[$limbs - 1], RAX, RCX);
-            movq($zero, RBX);
+            movq(ZERO, RBX);
             adcxq(RAX, R[$limbs - 1]);
             adcxq(RBX, RCX);
         };
     }
 
     macro_rules! mul_add_1 {
-        ($a:ident, $b:ident, $zero:ident, $i:ident, $limbs:expr) => {
+        ($a:ident, $b:ident, $i:ident, $limbs:expr) => {
             movq($a[$i], RDX);
             for j in 0..$limbs - 1 {
                 mulxq($b[j], RAX, RBX);
@@ -236,7 +260,7 @@ fn generate_llvm_asm_mul_string(
                 adoxq(RBX, R[(j + $i + 1) % $limbs]);
             }
             mulxq($b[$limbs - 1], RAX, RCX);
-            movq($zero, RBX);
+            movq(ZERO, RBX);
             adcxq(RAX, R[($i + $limbs - 1) % $limbs]);
             adoxq(RBX, RCX);
             adcxq(RBX, RCX);
@@ -244,7 +268,7 @@ fn generate_llvm_asm_mul_string(
     }
 
     macro_rules! mul_add_shift_1 {
-        ($a:ident, $mod_prime:ident, $zero:ident, $i:ident, $limbs:expr) => {
+        ($a:ident, $mod_prime:ident, $i:ident, $limbs:expr) => {
             movq($mod_prime, RDX);
             mulxq(R[$i], RDX, RAX);
             mulxq($a[0], RAX, RBX);
@@ -256,7 +280,7 @@ fn generate_llvm_asm_mul_string(
                 adoxq(RBX, R[(j + $i + 1) % $limbs]);
             }
             mulxq($a[$limbs - 1], RAX, R[$i % $limbs]);
-            movq($zero, RBX);
+            movq(ZERO, RBX);
             adcxq(RAX, R[($i + $limbs - 1) % $limbs]);
             adoxq(RCX, R[$i % $limbs]);
             adcxq(RBX, R[$i % $limbs]);
@@ -268,54 +292,192 @@ fn generate_llvm_asm_mul_string(
         reg!(b0, b1, b, limbs);
         reg!(m, m1, modulus, limbs);
 
+        // Older compilers reject `rbx` as an operand of `asm!`, so it is saved
+        // by hand.
+        instr("pushq", &[RBX]);
         xorq(RCX, RCX);
         for i in 0..limbs {
             if i == 0 {
-                mul_1!(a1[0], b1, zero, limbs);
+                mul_1!(a1[0], b1, limbs);
             } else {
-                mul_add_1!(a1, b1, zero, i, limbs);
+                mul_add_1!(a1, b1, i, limbs);
             }
-            mul_add_shift_1!(m1, mod_prime, zero, i, limbs);
+            mul_add_shift_1!(m1, mod_prime, i, limbs);
         }
 
         for i in 0..limbs {
             movq(R[i], a1[i]);
         }
+        instr("popq", &[RBX]);
     }
     end();
-    llvm_asm_string.into_inner()
+    asm_string.into_inner()
+}
+
+/// Squaring computes each cross product `a[i] * a[j]` with `i < j` only
+/// once. The double-width square is accumulated in `buf`:
+///
+/// 1. the cross products are summed row by row, with the running sum in a
+///    rotating window of `limbs` registers, as in `generate_asm_mul_string`;
+/// 2. their sum is doubled, and the squares `a[i]^2` are added in, on two
+///    independent carry chains;
+/// 3. the low half is Montgomery-reduced in the same rotating window, and the
+///    high half is added to the result. As the result of the reduction is at
+///    most `MODULUS`, and the high half is below it, the sum needs no more than
+///    `limbs` limbs.
+fn generate_asm_square_string(
+    a: &str,
+    modulus: &str,
+    mod_prime: &str,
+    buf: &str,
+    limbs: usize,
+) -> String {
+    let asm_string = RefCell::new(String::new());
+
+    let instr = |op: &str, args: &[&str]| {
+        asm_string.borrow_mut().push_str(&format!(
+            "
+                                {} {}",
+            op,
+            args.join(", ")
+        ));
+    };
+
+    let mulxq = |a: &str, b: &str, c: &str| instr("mulxq", &[a, b, c]);
+    let adcxq = |a: &str, b: &str| instr("adcxq", &[a, b]);
+    let adoxq = |a: &str, b: &str| instr("adoxq", &[a, b]);
+    let movq = |a: &str, b: &str| instr("movq", &[a, b]);
+    let xorq = |a: &str, b: &str| instr("xorq", &[a, b]);
+
+    asm_string.borrow_mut().push('"');
+    {
+        reg!(a0, a1, a, limbs);
+        reg!(m0, m1, modulus, limbs);
+        reg!(buf0, buf1, buf, 2 * limbs);
+
+        instr("pushq", &[RBX]);
+
+        // The cross products. `rcx` stays zero throughout, and after row `i`
+        // the window holds the limbs `i + 2..=i + limbs` of the sum, with limb
+        // `p` in `R[p % limbs]`. The first row fills the whole window.
+        xorq(RCX, RCX);
+        movq(a1[0], RDX);
+        mulxq(a1[1], R[1], R[2 % limbs]);
+        for j in 2..limbs {
+            mulxq(a1[j], RAX, R[(j + 1) % limbs]);
+            adcxq(RAX, R[j]);
+        }
+        adcxq(RCX, R[0]);
+        movq(R[1], buf1[1]);
+        for i in 1..limbs - 1 {
+            movq(RCX, R[i]);
+            movq(a1[i], RDX);
+            for j in i + 1..limbs {
+                mulxq(a1[j], RAX, RBX);
+                adcxq(RAX, R[(i + j) % limbs]);
+                adoxq(RBX, R[(i + j + 1) % limbs]);
+            }
+            adcxq(RCX, R[i]);
+            // Limb `i + 1` is final, since later rows start at limb `2i + 3`.
+            movq(R[i + 1], buf1[i + 1]);
+        }
+        movq(RCX, R[limbs - 1]);
+
+        // Double the cross products and add the squares. Limb 0 of the cross
+        // products is zero.
+        for (i, a_i) in a1.iter().enumerate() {
+            movq(a_i, RDX);
+            mulxq(RDX, RAX, RBX);
+            for (p, square) in [(2 * i, RAX), (2 * i + 1, RBX)].iter().copied() {
+                if p == 0 {
+                    movq(square, buf1[0]);
+                } else if p < limbs {
+                    movq(buf1[p], RCX);
+                    adcxq(RCX, RCX);
+                    adoxq(square, RCX);
+                    movq(RCX, buf1[p]);
+                } else {
+                    adcxq(R[p - limbs], R[p - limbs]);
+                    adoxq(square, R[p - limbs]);
+                }
+            }
+        }
+        for i in 0..limbs {
+            movq(R[i], buf1[limbs + i]);
+            movq(buf1[i], R[i]);
+        }
+
+        // Reduce the low half.
+        for i in 0..limbs {
+            movq(mod_prime, RDX);
+            instr("imulq", &[R[i], RDX]);
+            xorq(RCX, RCX);
+            for j in 0..limbs - 1 {
+                mulxq(m1[j], RAX, RBX);
+                adcxq(RAX, R[(i + j) % limbs]);
+                adoxq(RBX, R[(i + j + 1) % limbs]);
+            }
+            mulxq(m1[limbs - 1], RAX, R[i]);
+            adcxq(RAX, R[(i + limbs - 1) % limbs]);
+            adoxq(RCX, R[i]);
+            adcxq(RCX, R[i]);
+        }
+
+        // Add the high half.
+        for i in 0..limbs {
+            let op = if i == 0 { "addq" } else { "adcq" };
+            instr(op, &[buf1[limbs + i], R[i]]);
+        }
+        for i in 0..limbs {
+            movq(R[i], a1[i]);
+        }
+
+        instr("popq", &[RBX]);
+    }
+    asm_string.borrow_mut().push_str(
+        "
+                                \"",
+    );
+    asm_string.into_inner()
 }
 
 fn generate_impl(num_limbs: usize, is_mul: bool) -> String {
+    let use_square = !is_mul && num_limbs >= SQUARE_MIN_LIMBS;
     let mut ctx = Context::new();
-    ctx.add_declaration("a", "r", "a");
+    ctx.add_declaration("a", "rsi", "a.as_mut_ptr()");
     if is_mul {
-        ctx.add_declaration("b", "r", "b");
+        ctx.add_declaration("b", "rdi", "b.as_ptr()");
     }
-    ctx.add_declaration("modulus", "r", "&P::MODULUS.0");
-    ctx.add_declaration("0", "i", "0u64");
-    ctx.add_declaration("mod_prime", "i", "P::INV");
-
-    if num_limbs > MAX_REGS {
+    if use_square {
         ctx.add_buffer(2 * num_limbs);
-        ctx.add_declaration("buf", "r", "&mut spill_buffer");
+        ctx.add_declaration("buf", "rdi", "spill_buffer.as_mut_ptr()");
     }
+    ctx.add_declaration("modulus", "r14", "P::MODULUS.0.as_ptr()");
+    ctx.add_declaration("mod_prime", "r15", "P::INV");
+
+    let asm_string = if use_square {
+        generate_asm_square_string(
+            &ctx.clone().get("a"),
+            &ctx.clone().get("modulus"),
+            &ctx.clone().get("mod_prime"),
+            &ctx.clone().get("buf"),
+            num_limbs,
+        )
+    } else {
+        generate_asm_mul_string(
+            &ctx.clone().get("a"),
+            &ctx.clone().try_get("b", "a"),
+            &ctx.clone().get("modulus"),
+            &ctx.clone().get("mod_prime"),
+            num_limbs,
+        )
+    };
 
-    let llvm_asm_string = generate_llvm_asm_mul_string(
-        &ctx.clone().get("a"),
-        &ctx.clone().try_get("b", "a"),
-        &ctx.clone().get("modulus"),
-        &ctx.clone().get("0"),
-        &ctx.clone().get("mod_prime"),
-        num_limbs,
-    );
-
-    ctx.add_llvm_asm(llvm_asm_string);
-    ctx.add_clobber_from_vec(vec!["rcx", "rbx", "rdx", "rax"]);
+    ctx.add_asm(asm_string);
+    ctx.add_clobber_from_vec(vec!["rax", "rcx", "rdx"]);
     for clobber in REG_CLOBBER.iter().take(std::cmp::min(num_limbs, 8)) {
         ctx.add_clobber(clobber);
     }
-    ctx.add_clobber_from_vec(vec!["cc", "memory"]);
     ctx.build();
     format!("{{ {} }}", ctx.get_string())
 }
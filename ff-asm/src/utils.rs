@@ -2,6 +2,7 @@ pub const RAX: &str = "%rax";
 pub const RBX: &str = "%rbx";
 pub const RCX: &str = "%rcx";
 pub const RDX: &str = "%rdx";
+pub const ZERO: &str = "$0";
 // pub const RDI: &'static str = "%rdi";
 // pub const RSI: &'static str = "%rsi";
 pub const R: [&str; 8] = ["%r8", "%r9", "%r10", "%r11", "%r12", "%r13", "%r14", "%r15"];
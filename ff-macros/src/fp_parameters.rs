@@ -0,0 +1,351 @@
+//! Computation of the constants required by `FftParameters` and `FpParameters`
+//! from a modulus and a multiplicative generator.
+
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use std::str::FromStr;
+
+/// The limb counts for which `ark-ff` provides a `Fp*Parameters` marker trait.
+pub(crate) const SUPPORTED_LIMBS: &[(usize, &str)] = &[
+    (1, "Fp64Parameters"),
+    (4, "Fp256Parameters"),
+    (5, "Fp320Parameters"),
+    (6, "Fp384Parameters"),
+    (7, "Fp448Parameters"),
+    (12, "Fp768Parameters"),
+    (13, "Fp832Parameters"),
+];
+
+/// All constants of a prime field, as little-endian `u64` limbs.
+/// Field elements (`R`, `GENERATOR`, roots of unity) are in Montgomery form.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct FpConstants {
+    pub limbs: usize,
+    pub modulus: Vec<u64>,
+    pub modulus_bits: u32,
+    pub repr_shave_bits: u32,
+    pub r: Vec<u64>,
+    pub r2: Vec<u64>,
+    pub inv: u64,
+    pub generator: Vec<u64>,
+    pub two_adicity: u32,
+    pub two_adic_root_of_unity: Vec<u64>,
+    pub t: Vec<u64>,
+    pub t_minus_one_div_two: Vec<u64>,
+    pub modulus_minus_one_div_two: Vec<u64>,
+    pub small_subgroup: Option<SmallSubgroup>,
+}
+
+/// The mixed-radix constants of a prime field.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct SmallSubgroup {
+    pub base: u32,
+    pub adicity: u32,
+    pub large_subgroup_root_of_unity: Vec<u64>,
+}
+
+/// Parses a non-negative integer given either in decimal, or in hexadecimal
+/// with a `0x` prefix.
+pub(crate) fn parse_biguint(s: &str) -> Result<BigUint, String> {
+    let s = s.trim().replace('_', "");
+    let parsed = if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        BigUint::parse_bytes(hex.as_bytes(), 16)
+    } else {
+        BigUint::from_str(&s).ok()
+    };
+    parsed.ok_or_else(|| format!("could not parse `{}` as a decimal or hex integer", s))
+}
+
+/// Returns the `limbs` least-significant `u64` limbs of `n`.
+pub(crate) fn to_limbs(n: &BigUint, limbs: usize) -> Vec<u64> {
+    let mut digits = n.to_u64_digits();
+    assert!(
+        digits.len() <= limbs,
+        "integer does not fit into {} limbs",
+        limbs
+    );
+    digits.resize(limbs, 0);
+    digits
+}
+
+/// Returns the smallest number of limbs that can hold `modulus`.
+pub(crate) fn default_limbs(modulus: &BigUint) -> usize {
+    modulus.bits().div_ceil(64) as usize
+}
+
+/// Computes `-modulus^{-1} mod 2^64` via Newton iteration.
+fn montgomery_inv(modulus_low_limb: u64) -> u64 {
+    let mut inv = 1u64;
+    for _ in 0..63 {
+        inv = inv.wrapping_mul(inv);
+        inv = inv.wrapping_mul(modulus_low_limb);
+    }
+    inv.wrapping_neg()
+}
+
+/// Returns the primes below `2^16` that divide `n`, followed by the remaining
+/// cofactor of `n` if it is not `1`.
+///
+/// The cofactor is not guaranteed to be prime: fully factoring `MODULUS - 1`
+/// is out of reach for cryptographically sized fields.
+fn prime_factors(n: &BigUint) -> Vec<BigUint> {
+    const BOUND: usize = 1 << 16;
+    let mut sieve = vec![true; BOUND];
+    let mut factors = Vec::new();
+    let mut cofactor = n.clone();
+    for p in 2..BOUND {
+        if !sieve[p] {
+            continue;
+        }
+        for multiple in (p * p..BOUND).step_by(p) {
+            sieve[multiple] = false;
+        }
+        let p = BigUint::from(p);
+        if (&cofactor % &p).is_zero() {
+            while (&cofactor % &p).is_zero() {
+                cofactor /= &p;
+            }
+            factors.push(p);
+        }
+    }
+    if !cofactor.is_one() {
+        factors.push(cofactor);
+    }
+    factors
+}
+
+/// Computes all field constants for the prime `modulus` with the
+/// multiplicative generator `generator`, using `limbs` 64-bit limbs.
+///
+/// Returns an error if the modulus is even, does not leave the top bit of
+/// `limbs` limbs free, or if `generator` does not have order `modulus - 1`.
+/// The order is checked against every prime factor of `modulus - 1` below
+/// `2^16` and against the remaining cofactor, which is a complete check
+/// whenever that cofactor is prime.
+pub(crate) fn compute_constants(
+    modulus: &BigUint,
+    generator: &BigUint,
+    small_subgroup_base: Option<u32>,
+    limbs: usize,
+) -> Result<FpConstants, String> {
+    let one = BigUint::one();
+    let two = &one + &one;
+
+    if modulus <= &two {
+        return Err("the modulus must be an odd prime greater than 2".into());
+    }
+    if !modulus.bit(0) {
+        return Err(format!("the modulus {} is even", modulus));
+    }
+    let modulus_bits = modulus.bits() as u32;
+    // Addition without carry needs `2 * MODULUS < 2^(64 * limbs)`.
+    if modulus_bits as usize >= 64 * limbs {
+        return Err(format!(
+            "the modulus has {} bits, which does not leave the top bit of {} limbs ({} bits) free",
+            modulus_bits,
+            limbs,
+            64 * limbs
+        ));
+    }
+    if generator.is_zero() || generator >= modulus {
+        return Err("the generator must be a non-zero element of the field".into());
+    }
+
+    let modulus_minus_one = modulus - &one;
+    let modulus_minus_one_div_two = &modulus_minus_one >> 1;
+    // Both the two-adic root of unity and the square root algorithm rely on the
+    // generator being a quadratic non-residue.
+    if generator.modpow(&modulus_minus_one_div_two, modulus) != modulus_minus_one {
+        return Err(format!(
+            "the generator {} is a quadratic residue, so it cannot generate the multiplicative group",
+            generator
+        ));
+    }
+    if let Some(q) = prime_factors(&modulus_minus_one).into_iter().find(|q| {
+        generator
+            .modpow(&(&modulus_minus_one / q), modulus)
+            .is_one()
+    }) {
+        return Err(format!(
+            "the generator {} has an order that is not divisible by {}, so it cannot generate the multiplicative group",
+            generator, q
+        ));
+    }
+
+    let r = (&one << (64 * limbs)) % modulus;
+    let r2 = (&r * &r) % modulus;
+    let to_mont = |x: &BigUint| (x * &r) % modulus;
+
+    let two_adicity = modulus_minus_one.trailing_zeros().unwrap() as u32;
+    let t = &modulus_minus_one >> two_adicity;
+    let t_minus_one_div_two = (&t - &one) >> 1;
+    let two_adic_root_of_unity = generator.modpow(&t, modulus);
+
+    let small_subgroup = match small_subgroup_base {
+        Some(base) => {
+            if base < 3 {
+                return Err("the small subgroup base must be an integer greater than 2".into());
+            }
+            let base_big = BigUint::from(base);
+            let mut adicity = 0u32;
+            let mut remainder = t.clone();
+            while (&remainder % &base_big).is_zero() {
+                remainder /= &base_big;
+                adicity += 1;
+            }
+            if adicity == 0 {
+                return Err(format!(
+                    "the multiplicative group has no subgroup of order {}",
+                    base
+                ));
+            }
+            // `remainder` is now `(MODULUS - 1) / (2^s * base^adicity)`.
+            let root = generator.modpow(&remainder, modulus);
+            Some(SmallSubgroup {
+                base,
+                adicity,
+                large_subgroup_root_of_unity: to_limbs(&to_mont(&root), limbs),
+            })
+        },
+        None => None,
+    };
+
+    Ok(FpConstants {
+        limbs,
+        modulus: to_limbs(modulus, limbs),
+        modulus_bits,
+        repr_shave_bits: 64 * limbs as u32 - modulus_bits,
+        r: to_limbs(&r, limbs),
+        r2: to_limbs(&r2, limbs),
+        inv: montgomery_inv(modulus.to_u64_digits()[0]),
+        generator: to_limbs(&to_mont(generator), limbs),
+        two_adicity,
+        two_adic_root_of_unity: to_limbs(&to_mont(&two_adic_root_of_unity), limbs),
+        t: to_limbs(&t, limbs),
+        t_minus_one_div_two: to_limbs(&t_minus_one_div_two, limbs),
+        modulus_minus_one_div_two: to_limbs(&modulus_minus_one_div_two, limbs),
+        small_subgroup,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bls12_381_fr_constants() {
+        let modulus = parse_biguint(
+            "52435875175126190479447740508185965837690552500527637822603658699938581184513",
+        )
+        .unwrap();
+        let c = compute_constants(&modulus, &BigUint::from(7u64), None, 4).unwrap();
+        assert_eq!(
+            c.modulus,
+            [
+                0xffffffff00000001,
+                0x53bda402fffe5bfe,
+                0x3339d80809a1d805,
+                0x73eda753299d7d48
+            ]
+        );
+        assert_eq!(c.modulus_bits, 255);
+        assert_eq!(c.repr_shave_bits, 1);
+        assert_eq!(
+            c.r,
+            [
+                0x1fffffffe,
+                0x5884b7fa00034802,
+                0x998c4fefecbc4ff5,
+                0x1824b159acc5056f
+            ]
+        );
+        assert_eq!(
+            c.r2,
+            [
+                0xc999e990f3f29c6d,
+                0x2b6cedcb87925c23,
+                0x5d314967254398f,
+                0x748d9d99f59ff11
+            ]
+        );
+        assert_eq!(c.inv, 0xfffffffeffffffff);
+        assert_eq!(
+            c.generator,
+            [
+                0xefffffff1,
+                0x17e363d300189c0f,
+                0xff9c57876f8457b0,
+                0x351332208fc5a8c4
+            ]
+        );
+        assert_eq!(c.two_adicity, 32);
+        assert_eq!(
+            c.two_adic_root_of_unity,
+            [
+                0xb9b58d8c5f0e466a,
+                0x5b1b4c801819d7ec,
+                0xaf53ae352a31e64,
+                0x5bf3adda19e9b27b
+            ]
+        );
+        assert_eq!(
+            c.t,
+            [
+                0xfffe5bfeffffffff,
+                0x9a1d80553bda402,
+                0x299d7d483339d808,
+                0x73eda753
+            ]
+        );
+        assert_eq!(
+            c.t_minus_one_div_two,
+            [
+                0x7fff2dff7fffffff,
+                0x4d0ec02a9ded201,
+                0x94cebea4199cec04,
+                0x39f6d3a9
+            ]
+        );
+        assert_eq!(c.small_subgroup, None);
+    }
+
+    #[test]
+    fn test_hex_modulus() {
+        let dec = parse_biguint("9223371938070528001").unwrap();
+        let hex = parse_biguint("0x7fff_ffe9_0000_0001").unwrap();
+        assert_eq!(dec, hex);
+
+        let c = compute_constants(&hex, &BigUint::from(19u64), Some(3), 1).unwrap();
+        assert_eq!(c.modulus_bits, 63);
+        assert_eq!(c.repr_shave_bits, 1);
+        assert_eq!(c.two_adicity, 32);
+        // 2^63 - 23 * 2^32 = 2^32 * 3 * 5^3 * 7 * 199 * 4111
+        let small_subgroup = c.small_subgroup.unwrap();
+        assert_eq!(small_subgroup.base, 3);
+        assert_eq!(small_subgroup.adicity, 1);
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let modulus = parse_biguint(
+            "52435875175126190479447740508185965837690552500527637822603658699938581184513",
+        )
+        .unwrap();
+        // Even modulus.
+        assert!(compute_constants(&(&modulus + 1u64), &BigUint::from(7u64), None, 4).is_err());
+        // Modulus does not fit into a single limb.
+        assert!(compute_constants(&modulus, &BigUint::from(7u64), None, 1).is_err());
+        // A 64-bit modulus leaves no spare bit in a single limb.
+        let goldilocks = parse_biguint("0xffff_ffff_0000_0001").unwrap();
+        assert!(compute_constants(&goldilocks, &BigUint::from(7u64), None, 1).is_err());
+        // 4 is a square, and hence cannot be a generator.
+        assert!(compute_constants(&modulus, &BigUint::from(4u64), None, 4).is_err());
+        // 5 is a non-residue, but its order divides `(MODULUS - 1) / 3`.
+        assert!(compute_constants(&modulus, &BigUint::from(5u64), None, 4).is_err());
+        // There is no subgroup of order 7.
+        assert!(compute_constants(&modulus, &BigUint::from(7u64), Some(7), 4).is_err());
+
+        assert_eq!(default_limbs(&modulus), 4);
+        assert_eq!(default_limbs(&BigUint::from(101u64)), 1);
+    }
+}
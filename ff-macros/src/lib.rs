@@ -1,10 +1,14 @@
 #![warn(unused, future_incompatible, nonstandard_style, rust_2018_idioms)]
 #![forbid(unsafe_code)]
 
-use num_bigint::{BigInt, Sign};
+use num_bigint::{BigInt, BigUint, Sign};
 use proc_macro::TokenStream;
+use quote::{format_ident, quote};
 use std::str::FromStr;
-use syn::{Expr, Lit};
+use syn::{Expr, Lit, Meta};
+
+mod fp_parameters;
+use fp_parameters::*;
 
 fn parse_string(input: TokenStream) -> Option<String> {
     let input: Expr = syn::parse(input).unwrap();
@@ -52,6 +56,173 @@ pub fn to_sign_and_limbs(input: TokenStream) -> TokenStream {
     quote::quote!(#tuple).into()
 }
 
+/// Derives `FftParameters`, `FpParameters` and, if one exists, the matching
+/// `Fp*Parameters` marker trait for a struct, computing every constant from the modulus and
+/// a multiplicative generator.
+///
+/// The following attributes are supported:
+/// * `#[modulus = "..."]` (required): the prime modulus, in decimal or in
+///   hexadecimal with a `0x` prefix.
+/// * `#[generator = "..."]` (required): a generator of the multiplicative group.
+///   Its order is checked against the prime factors of `MODULUS - 1`.
+/// * `#[small_subgroup_base = "..."]` (optional): a small odd prime `q` dividing
+///   `MODULUS - 1`, for which the mixed-radix constants are emitted.
+/// * `#[limbs = ...]` (optional): the number of 64-bit limbs of the
+///   representation. Defaults to the smallest number of limbs that fits the modulus.
+///
+/// It is a compile-time error for the modulus to be even or not to leave the
+/// top bit of the chosen number of limbs free.
+///
+/// # Example
+/// ```ignore
+/// use ark_ff::fields::{Fp256, FpParameters};
+///
+/// #[derive(FpParameters)]
+/// #[modulus = "52435875175126190479447740508185965837690552500527637822603658699938581184513"]
+/// #[generator = "7"]
+/// pub struct FrParameters;
+/// pub type Fr = Fp256<FrParameters>;
+/// ```
+#[proc_macro_derive(
+    FpParameters,
+    attributes(modulus, generator, small_subgroup_base, limbs)
+)]
+pub fn fp_parameters(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    match impl_fp_parameters(&ast) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn impl_fp_parameters(ast: &syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let mut modulus = None;
+    let mut generator = None;
+    let mut small_subgroup_base = None;
+    let mut limbs = None;
+
+    for attr in &ast.attrs {
+        let name = match attr.path.get_ident() {
+            Some(name) => name.to_string(),
+            None => continue,
+        };
+        if !["modulus", "generator", "small_subgroup_base", "limbs"].contains(&name.as_str()) {
+            continue;
+        }
+        let lit = match attr.parse_meta()? {
+            Meta::NameValue(nv) => nv.lit,
+            meta => {
+                return Err(syn::Error::new_spanned(
+                    meta,
+                    format!("expected `#[{} = \"...\"]`", name),
+                ))
+            },
+        };
+        let value = match &lit {
+            Lit::Str(s) => s.value(),
+            Lit::Int(i) => i.base10_digits().to_string(),
+            _ => return Err(syn::Error::new_spanned(lit, "expected a string or integer")),
+        };
+        let error = |msg: String| syn::Error::new_spanned(&lit, msg);
+        match name.as_str() {
+            "modulus" => modulus = Some(parse_biguint(&value).map_err(error)?),
+            "generator" => generator = Some(parse_biguint(&value).map_err(error)?),
+            "small_subgroup_base" => {
+                small_subgroup_base = Some(value.parse::<u32>().map_err(|e| error(e.to_string()))?)
+            },
+            _ => limbs = Some(value.parse::<usize>().map_err(|e| error(e.to_string()))?),
+        }
+    }
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let missing = |attr: &str| {
+        syn::Error::new_spanned(name, format!("missing `#[{} = \"...\"]` attribute", attr))
+    };
+    let modulus: BigUint = modulus.ok_or_else(|| missing("modulus"))?;
+    let generator: BigUint = generator.ok_or_else(|| missing("generator"))?;
+    let limbs = match limbs {
+        Some(limbs) => limbs,
+        None => default_limbs(&modulus),
+    };
+    let params_trait = SUPPORTED_LIMBS
+        .iter()
+        .find(|(l, _)| *l == limbs)
+        .map(|(_, params_trait)| {
+            let params_trait = format_ident!("{}", params_trait);
+            quote!(impl #impl_generics ark_ff::fields::#params_trait for #name #ty_generics #where_clause {})
+        });
+
+    let c = compute_constants(&modulus, &generator, small_subgroup_base, limbs)
+        .map_err(|e| syn::Error::new_spanned(name, e))?;
+    let limbs_count = proc_macro2::Literal::usize_unsuffixed(limbs);
+
+    let bigint_tokens =
+        |limbs: &[u64]| quote!(ark_ff::biginteger::BigInt::<#limbs_count>::new([#(#limbs),*]));
+    let modulus_limbs = bigint_tokens(&c.modulus);
+    let r = bigint_tokens(&c.r);
+    let r2 = bigint_tokens(&c.r2);
+    let generator = bigint_tokens(&c.generator);
+    let two_adic_root_of_unity = bigint_tokens(&c.two_adic_root_of_unity);
+    let t = bigint_tokens(&c.t);
+    let t_minus_one_div_two = bigint_tokens(&c.t_minus_one_div_two);
+    let modulus_minus_one_div_two = bigint_tokens(&c.modulus_minus_one_div_two);
+    let FpConstants {
+        modulus_bits,
+        repr_shave_bits,
+        inv,
+        two_adicity,
+        ..
+    } = c;
+    let small_subgroup = c.small_subgroup.map(|s| {
+        let SmallSubgroup { base, adicity, .. } = s;
+        let root = bigint_tokens(&s.large_subgroup_root_of_unity);
+        quote! {
+            const SMALL_SUBGROUP_BASE: Option<u32> = Some(#base);
+            const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some(#adicity);
+            const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<Self::BigInt> = Some(#root);
+        }
+    });
+
+    Ok(quote! {
+        #params_trait
+
+        impl #impl_generics ark_ff::fields::FftParameters for #name #ty_generics #where_clause {
+            type BigInt = ark_ff::biginteger::BigInt<#limbs_count>;
+
+            const TWO_ADICITY: u32 = #two_adicity;
+
+            const TWO_ADIC_ROOT_OF_UNITY: Self::BigInt = #two_adic_root_of_unity;
+
+            #small_subgroup
+        }
+
+        impl #impl_generics ark_ff::fields::FpParameters for #name #ty_generics #where_clause {
+            const MODULUS: Self::BigInt = #modulus_limbs;
+
+            const MODULUS_BITS: u32 = #modulus_bits;
+
+            const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+            const REPR_SHAVE_BITS: u32 = #repr_shave_bits;
+
+            const R: Self::BigInt = #r;
+
+            const R2: Self::BigInt = #r2;
+
+            const INV: u64 = #inv;
+
+            const GENERATOR: Self::BigInt = #generator;
+
+            const MODULUS_MINUS_ONE_DIV_TWO: Self::BigInt = #modulus_minus_one_div_two;
+
+            const T: Self::BigInt = #t;
+
+            const T_MINUS_ONE_DIV_TWO: Self::BigInt = #t_minus_one_div_two;
+        }
+    })
+}
+
 #[test]
 fn test_str_to_limbs() {
     let (is_positive, limbs) = str_to_limbs("-5");
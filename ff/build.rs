@@ -1,21 +1,46 @@
 extern crate rustc_version;
-use rustc_version::{version, version_meta, Channel, Version};
+use rustc_version::{version, Version};
 
 fn main() {
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(use_asm)");
+    println!("cargo:rustc-check-cfg=cfg(use_aarch64_asm)");
+    println!("cargo:rustc-check-cfg=cfg(use_u32_limbs)");
 
-    let is_nightly = version_meta().expect("nightly check failed").channel == Channel::Nightly;
+    // `cfg!` refers to the host here, so the target is read from the
+    // environment. Both backends only need the stable `asm!` macro.
+    let target_arch = std::env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+    let target_features = std::env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+    let has_target_feature = |feature| target_features.split(',').any(|f| f == feature);
+    let has_stable_asm = version().expect("Installed rustc version unparseable!")
+        >= Version::parse("1.59.0").unwrap();
+    let asm_enabled = std::env::var("CARGO_FEATURE_ASM").is_ok() && has_stable_asm;
 
-    let should_use_asm = cfg!(all(
-        feature = "asm",
-        target_feature = "bmi2",
-        target_feature = "adx",
-        target_arch = "x86_64"
-    )) && is_nightly;
+    let should_use_asm = asm_enabled
+        && target_arch == "x86_64"
+        && has_target_feature("bmi2")
+        && has_target_feature("adx");
     if should_use_asm {
         println!("cargo:rustc-cfg=use_asm");
     }
 
+    let should_use_aarch64_asm = asm_enabled && target_arch == "aarch64";
+    if should_use_aarch64_asm {
+        println!("cargo:rustc-cfg=use_aarch64_asm");
+    }
+
+    // 32-bit targets multiply `u64`s in software, so they use 32-bit words
+    // instead. The `u32-limbs` feature selects them on other targets, for
+    // testing, unless `asm` is enabled too; in particular `--all-features`
+    // keeps the `u64` backend.
+    let pointer_width = std::env::var("CARGO_CFG_TARGET_POINTER_WIDTH").unwrap_or_default();
+    let should_use_u32_limbs = pointer_width == "32"
+        || (std::env::var("CARGO_FEATURE_U32_LIMBS").is_ok()
+            && std::env::var("CARGO_FEATURE_ASM").is_err());
+    if should_use_u32_limbs {
+        println!("cargo:rustc-cfg=use_u32_limbs");
+    }
+
     // TODO: remove this once RFC 2495 ships
     if version().expect("Installed rustc version unparseable!") < Version::parse("1.51.0").unwrap()
     {
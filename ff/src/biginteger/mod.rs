@@ -19,8 +19,6 @@ use zeroize::Zeroize;
 
 #[macro_use]
 pub mod arithmetic;
-#[macro_use]
-mod macros;
 
 pub fn signed_mod_reduction(n: u64, modulus: u64) -> i64 {
     let t = (n % modulus) as i64;
@@ -31,14 +29,892 @@ pub fn signed_mod_reduction(n: u64, modulus: u64) -> i64 {
     }
 }
 
-bigint_impl!(BigInteger64, 1);
-bigint_impl!(BigInteger128, 2);
-bigint_impl!(BigInteger256, 4);
-bigint_impl!(BigInteger320, 5);
-bigint_impl!(BigInteger384, 6);
-bigint_impl!(BigInteger448, 7);
-bigint_impl!(BigInteger768, 12);
-bigint_impl!(BigInteger832, 13);
+/// A big integer with `N` 64-bit limbs, stored least-significant limb first.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Zeroize)]
+pub struct BigInt<const N: usize>(pub [u64; N]);
+
+impl<const N: usize> BigInt<N> {
+    pub const fn new(value: [u64; N]) -> Self {
+        Self(value)
+    }
+
+    /// Returns `self + other` and whether the addition overflowed.
+    pub const fn overflowing_add(&self, other: &Self) -> (Self, bool) {
+        let mut res = [0u64; N];
+        let mut carry = 0;
+        let mut i = 0;
+        while i < N {
+            res[i] = adc!(self.0[i], other.0[i], &mut carry);
+            i += 1;
+        }
+        (Self(res), carry != 0)
+    }
+
+    /// Returns `self - other` and whether the subtraction underflowed.
+    pub const fn overflowing_sub(&self, other: &Self) -> (Self, bool) {
+        let mut res = [0u64; N];
+        let mut borrow = 0;
+        let mut i = 0;
+        while i < N {
+            res[i] = sbb!(self.0[i], other.0[i], &mut borrow);
+            i += 1;
+        }
+        (Self(res), borrow != 0)
+    }
+
+    /// Returns `self + other`, or `None` on overflow.
+    pub const fn checked_add(&self, other: &Self) -> Option<Self> {
+        match self.overflowing_add(other) {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Returns `self - other`, or `None` on underflow.
+    pub const fn checked_sub(&self, other: &Self) -> Option<Self> {
+        match self.overflowing_sub(other) {
+            (res, false) => Some(res),
+            (_, true) => None,
+        }
+    }
+
+    /// Returns `self * other`, or `None` on overflow.
+    pub const fn checked_mul(&self, other: &Self) -> Option<Self> {
+        let (lo, hi) = self.mul_wide(other);
+        if hi.is_zero_const() {
+            Some(lo)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self + other` modulo `2^(64 * N)`.
+    pub const fn wrapping_add(&self, other: &Self) -> Self {
+        self.overflowing_add(other).0
+    }
+
+    /// Returns `self - other` modulo `2^(64 * N)`.
+    pub const fn wrapping_sub(&self, other: &Self) -> Self {
+        self.overflowing_sub(other).0
+    }
+
+    /// Returns `self * other` modulo `2^(64 * N)`.
+    pub const fn wrapping_mul(&self, other: &Self) -> Self {
+        self.mul_wide(other).0
+    }
+
+    /// Returns the full product `self * other` as `(low, high)`, where `low`
+    /// holds the least significant `N` limbs and `high` the most significant.
+    pub const fn mul_wide(&self, other: &Self) -> (Self, Self) {
+        let mut lo = [0u64; N];
+        let mut hi = [0u64; N];
+        let mut i = 0;
+        while i < N {
+            let mut carry = 0;
+            let mut j = 0;
+            while j < N {
+                let k = i + j;
+                if k < N {
+                    lo[k] = mac_with_carry!(lo[k], self.0[i], other.0[j], &mut carry);
+                } else {
+                    hi[k - N] = mac_with_carry!(hi[k - N], self.0[i], other.0[j], &mut carry);
+                }
+                j += 1;
+            }
+            // No earlier row reaches limb `i + N`.
+            hi[i] = carry;
+            i += 1;
+        }
+        (Self(lo), Self(hi))
+    }
+
+    /// Returns the quotient and remainder of `self` divided by `divisor`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    pub const fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero_const(), "division by zero");
+        let mut quotient = [0u64; N];
+        let mut rem = Self([0u64; N]);
+        let mut i = self.num_bits_const();
+        while i > 0 {
+            i -= 1;
+            let reduced = rem.shl1_reduce(self.bit_const(i), divisor);
+            rem = reduced.0;
+            if reduced.1 {
+                quotient[i / 64] |= 1 << (i % 64);
+            }
+        }
+        (Self(quotient), rem)
+    }
+
+    /// Returns `self^exp mod modulus`.
+    ///
+    /// Odd moduli, such as those of prime fields, use Montgomery
+    /// multiplication. Even moduli reduce every product bit by bit, which
+    /// takes time quadratic in the bit length of `modulus` per
+    /// multiplication.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub const fn mod_pow(&self, exp: &Self, modulus: &Self) -> Self {
+        assert!(!modulus.is_zero_const(), "zero modulus");
+        let base = self.div_rem(modulus).1;
+        let mut one = [0u64; N];
+        one[0] = 1;
+        let one = Self(one);
+        if modulus.0[0] & 1 == 0 {
+            let mut res = one.div_rem(modulus).1;
+            let mut i = exp.num_bits_const();
+            while i > 0 {
+                i -= 1;
+                res = res.mul_mod(&res, modulus);
+                if exp.bit_const(i) {
+                    res = res.mul_mod(&base, modulus);
+                }
+            }
+            return res;
+        }
+
+        // `r = 2^(64 * N) mod modulus` and `r2 = r^2 mod modulus` are the
+        // Montgomery forms of one and of `2^(64 * N)`.
+        let mut r = one.div_rem(modulus).1;
+        let mut i = 0;
+        while i < 64 * N {
+            r = r.shl1_reduce(false, modulus).0;
+            i += 1;
+        }
+        let mut r2 = r;
+        let mut i = 0;
+        while i < 64 * N {
+            r2 = r2.shl1_reduce(false, modulus).0;
+            i += 1;
+        }
+        // `inv = -modulus^-1 mod 2^64`, by Newton iteration.
+        let mut inv = 1u64;
+        let mut i = 0;
+        while i < 6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(modulus.0[0].wrapping_mul(inv)));
+            i += 1;
+        }
+        let inv = inv.wrapping_neg();
+
+        let base = base.mont_mul(&r2, modulus, inv);
+        let mut res = r;
+        let mut i = exp.num_bits_const();
+        while i > 0 {
+            i -= 1;
+            res = res.mont_mul(&res, modulus, inv);
+            if exp.bit_const(i) {
+                res = res.mont_mul(&base, modulus, inv);
+            }
+        }
+        res.mont_mul(&one, modulus, inv)
+    }
+
+    /// Returns the greatest common divisor of `self` and `other`, with
+    /// `gcd(0, 0) = 0`.
+    pub const fn gcd(&self, other: &Self) -> Self {
+        if self.is_zero_const() {
+            return *other;
+        }
+        if other.is_zero_const() {
+            return *self;
+        }
+        // Stein's binary algorithm: factor out the common powers of two, and
+        // then repeatedly subtract the smaller odd number from the larger.
+        let a_twos = self.trailing_zeros_const();
+        let b_twos = other.trailing_zeros_const();
+        let twos = if a_twos < b_twos { a_twos } else { b_twos };
+        let mut a = self.shr_const(a_twos);
+        let mut b = *other;
+        loop {
+            b = b.shr_const(b.trailing_zeros_const());
+            if b.lt_const(&a) {
+                let t = a;
+                a = b;
+                b = t;
+            }
+            b = b.wrapping_sub(&a);
+            if b.is_zero_const() {
+                return a.shl_const(twos);
+            }
+        }
+    }
+
+    /// Returns the inverse of `self` modulo `modulus`, or `None` if `self` and
+    /// `modulus` are not coprime or `modulus` is zero.
+    pub const fn mod_inverse(&self, modulus: &Self) -> Option<Self> {
+        if modulus.is_zero_const() {
+            return None;
+        }
+        let mut one = [0u64; N];
+        one[0] = 1;
+        let one = Self(one);
+        if modulus.eq_const(&one) {
+            return Some(Self([0u64; N]));
+        }
+        // The extended Euclidean algorithm, keeping track of the Bézout
+        // coefficients of `self` modulo `modulus`: `t_i * self = r_i`.
+        let mut r0 = *modulus;
+        let mut r1 = self.div_rem(modulus).1;
+        let mut t0 = Self([0u64; N]);
+        let mut t1 = one;
+        while !r1.is_zero_const() {
+            let (q, r) = r0.div_rem(&r1);
+            r0 = r1;
+            r1 = r;
+            let t = t0.sub_mod(&q.mul_mod(&t1, modulus), modulus);
+            t0 = t1;
+            t1 = t;
+        }
+        if r0.eq_const(&one) {
+            Some(t0)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self * other mod modulus`.
+    const fn mul_mod(&self, other: &Self, modulus: &Self) -> Self {
+        let (lo, hi) = self.mul_wide(other);
+        let mut rem = Self([0u64; N]);
+        let mut i = 64 * N + hi.num_bits_const();
+        while i > 0 {
+            i -= 1;
+            let bit = if i >= 64 * N {
+                hi.bit_const(i - 64 * N)
+            } else {
+                lo.bit_const(i)
+            };
+            rem = rem.shl1_reduce(bit, modulus).0;
+        }
+        rem
+    }
+
+    /// Returns `self * other / 2^(64 * N) mod modulus` for `self, other <
+    /// modulus`, an odd `modulus` and `inv = -modulus^-1 mod 2^64`.
+    const fn mont_mul(&self, other: &Self, modulus: &Self, inv: u64) -> Self {
+        let (mut lo, mut hi) = self.mul_wide(other);
+        // Clear the low limbs one at a time by adding multiples of
+        // `modulus`, with `carry2` collecting the carries out of `hi`.
+        let mut carry2 = 0;
+        let mut i = 0;
+        while i < N {
+            let k = lo.0[i].wrapping_mul(inv);
+            let mut carry = 0;
+            let mut j = 0;
+            while j < N {
+                if i + j < N {
+                    lo.0[i + j] = mac_with_carry!(lo.0[i + j], k, modulus.0[j], &mut carry);
+                } else {
+                    hi.0[i + j - N] = mac_with_carry!(hi.0[i + j - N], k, modulus.0[j], &mut carry);
+                }
+                j += 1;
+            }
+            hi.0[i] = adc!(hi.0[i], carry2, &mut carry);
+            carry2 = carry;
+            i += 1;
+        }
+        // The result is less than `2 * modulus`.
+        if carry2 != 0 || !hi.lt_const(modulus) {
+            hi.wrapping_sub(modulus)
+        } else {
+            hi
+        }
+    }
+
+    /// Returns `self - other mod modulus` for `self, other < modulus`.
+    const fn sub_mod(&self, other: &Self, modulus: &Self) -> Self {
+        match self.overflowing_sub(other) {
+            (res, false) => res,
+            (res, true) => res.wrapping_add(modulus),
+        }
+    }
+
+    /// For `self < modulus`, returns `2 * self + bit`, reduced by `modulus`
+    /// once if it is not smaller, and whether it was reduced.
+    const fn shl1_reduce(&self, bit: bool, modulus: &Self) -> (Self, bool) {
+        let overflow = self.0[N - 1] >> 63 == 1;
+        let mut res = self.shl_const(1);
+        res.0[0] |= bit as u64;
+        // If `2 * self + bit` overflows `N` limbs, it is at least `modulus`,
+        // and the wrapping subtraction still yields the right result.
+        if overflow || !res.lt_const(modulus) {
+            (res.wrapping_sub(modulus), true)
+        } else {
+            (res, false)
+        }
+    }
+
+    const fn is_zero_const(&self) -> bool {
+        let mut i = 0;
+        while i < N {
+            if self.0[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const fn eq_const(&self, other: &Self) -> bool {
+        let mut i = 0;
+        while i < N {
+            if self.0[i] != other.0[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const fn lt_const(&self, other: &Self) -> bool {
+        let mut i = N;
+        while i > 0 {
+            i -= 1;
+            if self.0[i] != other.0[i] {
+                return self.0[i] < other.0[i];
+            }
+        }
+        false
+    }
+
+    const fn bit_const(&self, i: usize) -> bool {
+        (self.0[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    const fn num_bits_const(&self) -> usize {
+        let mut i = N;
+        while i > 0 {
+            i -= 1;
+            if self.0[i] != 0 {
+                return 64 * i + 64 - self.0[i].leading_zeros() as usize;
+            }
+        }
+        0
+    }
+
+    const fn trailing_zeros_const(&self) -> usize {
+        let mut i = 0;
+        while i < N {
+            if self.0[i] != 0 {
+                return 64 * i + self.0[i].trailing_zeros() as usize;
+            }
+            i += 1;
+        }
+        64 * N
+    }
+
+    /// Returns `self << n` for `n < 64 * N`, dropping the overflowing bits.
+    const fn shl_const(&self, n: usize) -> Self {
+        let (limbs, bits) = (n / 64, n % 64);
+        let mut res = [0u64; N];
+        let mut i = N;
+        while i > limbs {
+            i -= 1;
+            res[i] = self.0[i - limbs] << bits;
+            if bits > 0 && i > limbs {
+                res[i] |= self.0[i - limbs - 1] >> (64 - bits);
+            }
+        }
+        Self(res)
+    }
+
+    /// Returns `self >> n` for `n < 64 * N`.
+    const fn shr_const(&self, n: usize) -> Self {
+        let (limbs, bits) = (n / 64, n % 64);
+        let mut res = [0u64; N];
+        let mut i = 0;
+        while i + limbs < N {
+            res[i] = self.0[i + limbs] >> bits;
+            if bits > 0 && i + limbs + 1 < N {
+                res[i] |= self.0[i + limbs + 1] << (64 - bits);
+            }
+            i += 1;
+        }
+        Self(res)
+    }
+}
+
+impl<const N: usize> Default for BigInt<N> {
+    #[inline]
+    fn default() -> Self {
+        Self([0u64; N])
+    }
+}
+
+/// Defines `$name` as an alias for `BigInt<$num_limbs>`, together with a
+/// constructor function of the same name so that `$name([...])` keeps
+/// working as it did when `$name` was a tuple struct.
+macro_rules! bigint_alias {
+    ($name:ident, $num_limbs:expr) => {
+        pub type $name = BigInt<$num_limbs>;
+
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        #[inline]
+        pub const fn $name(value: [u64; $num_limbs]) -> $name {
+            BigInt::<$num_limbs>(value)
+        }
+    };
+}
+
+bigint_alias!(BigInteger64, 1);
+bigint_alias!(BigInteger128, 2);
+bigint_alias!(BigInteger256, 4);
+bigint_alias!(BigInteger320, 5);
+bigint_alias!(BigInteger384, 6);
+bigint_alias!(BigInteger448, 7);
+bigint_alias!(BigInteger768, 12);
+bigint_alias!(BigInteger832, 13);
+
+impl<const N: usize> BigInteger for BigInt<N> {
+    const NUM_LIMBS: usize = N;
+
+    #[inline]
+    fn add_nocarry(&mut self, other: &Self) -> bool {
+        let mut carry = 0;
+
+        for i in 0..N {
+            #[cfg(all(target_arch = "x86_64", feature = "asm"))]
+            #[allow(unsafe_code)]
+            unsafe {
+                use core::arch::x86_64::_addcarry_u64;
+                carry = _addcarry_u64(carry, self.0[i], other.0[i], &mut self.0[i])
+            };
+
+            #[cfg(not(all(target_arch = "x86_64", feature = "asm")))]
+            {
+                self.0[i] = adc!(self.0[i], other.0[i], &mut carry);
+            }
+        }
+
+        carry != 0
+    }
+
+    #[inline]
+    fn sub_noborrow(&mut self, other: &Self) -> bool {
+        let mut borrow = 0;
+
+        for i in 0..N {
+            #[cfg(all(target_arch = "x86_64", feature = "asm"))]
+            #[allow(unsafe_code)]
+            unsafe {
+                use core::arch::x86_64::_subborrow_u64;
+                borrow = _subborrow_u64(borrow, self.0[i], other.0[i], &mut self.0[i])
+            };
+
+            #[cfg(not(all(target_arch = "x86_64", feature = "asm")))]
+            {
+                self.0[i] = sbb!(self.0[i], other.0[i], &mut borrow);
+            }
+        }
+
+        borrow != 0
+    }
+
+    #[inline]
+    #[allow(unused)]
+    fn mul2(&mut self) {
+        #[cfg(all(target_arch = "x86_64", feature = "asm"))]
+        #[allow(unsafe_code)]
+        {
+            let mut carry = 0;
+
+            for i in 0..N {
+                unsafe {
+                    use core::arch::x86_64::_addcarry_u64;
+                    carry = _addcarry_u64(carry, self.0[i], self.0[i], &mut self.0[i])
+                };
+            }
+        }
+
+        #[cfg(not(all(target_arch = "x86_64", feature = "asm")))]
+        {
+            let mut last = 0;
+            for i in 0..N {
+                let a = &mut self.0[i];
+                let tmp = *a >> 63;
+                *a <<= 1;
+                *a |= last;
+                last = tmp;
+            }
+        }
+    }
+
+    #[inline]
+    fn muln(&mut self, mut n: u32) {
+        if n >= (64 * N) as u32 {
+            *self = Self::from(0);
+            return;
+        }
+
+        while n >= 64 {
+            let mut t = 0;
+            for i in 0..N {
+                core::mem::swap(&mut t, &mut self.0[i]);
+            }
+            n -= 64;
+        }
+
+        if n > 0 {
+            let mut t = 0;
+            #[allow(unused)]
+            for i in 0..N {
+                let a = &mut self.0[i];
+                let t2 = *a >> (64 - n);
+                *a <<= n;
+                *a |= t;
+                t = t2;
+            }
+        }
+    }
+
+    #[inline]
+    #[allow(unused)]
+    fn div2(&mut self) {
+        let mut t = 0;
+        for i in 0..N {
+            let a = &mut self.0[N - i - 1];
+            let t2 = *a << 63;
+            *a >>= 1;
+            *a |= t;
+            t = t2;
+        }
+    }
+
+    #[inline]
+    fn divn(&mut self, mut n: u32) {
+        if n >= (64 * N) as u32 {
+            *self = Self::from(0);
+            return;
+        }
+
+        while n >= 64 {
+            let mut t = 0;
+            for i in 0..N {
+                core::mem::swap(&mut t, &mut self.0[N - i - 1]);
+            }
+            n -= 64;
+        }
+
+        if n > 0 {
+            let mut t = 0;
+            #[allow(unused)]
+            for i in 0..N {
+                let a = &mut self.0[N - i - 1];
+                let t2 = *a << (64 - n);
+                *a >>= n;
+                *a |= t;
+                t = t2;
+            }
+        }
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.0[0] & 1 == 1
+    }
+
+    #[inline]
+    fn is_even(&self) -> bool {
+        !self.is_odd()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(|&limb| limb == 0)
+    }
+
+    #[inline]
+    fn num_bits(&self) -> u32 {
+        let mut ret = N as u32 * 64;
+        for i in self.0.iter().rev() {
+            let leading = i.leading_zeros();
+            ret -= leading;
+            if leading != 64 {
+                break;
+            }
+        }
+
+        ret
+    }
+
+    #[inline]
+    fn get_bit(&self, i: usize) -> bool {
+        if i >= 64 * N {
+            false
+        } else {
+            let limb = i / 64;
+            let bit = i - (64 * limb);
+            (self.0[limb] & (1 << bit)) != 0
+        }
+    }
+
+    #[inline]
+    fn from_bits_be(bits: &[bool]) -> Self {
+        let mut res = Self::default();
+        let mut acc: u64 = 0;
+
+        let mut bits = bits.to_vec();
+        bits.reverse();
+        for (i, bits64) in bits.chunks(64).enumerate() {
+            for bit in bits64.iter().rev() {
+                acc <<= 1;
+                acc += *bit as u64;
+            }
+            res.0[i] = acc;
+            acc = 0;
+        }
+        res
+    }
+
+    fn from_bits_le(bits: &[bool]) -> Self {
+        let mut res = Self::default();
+        let mut acc: u64 = 0;
+
+        let bits = bits.to_vec();
+        for (i, bits64) in bits.chunks(64).enumerate() {
+            for bit in bits64.iter().rev() {
+                acc <<= 1;
+                acc += *bit as u64;
+            }
+            res.0[i] = acc;
+            acc = 0;
+        }
+        res
+    }
+
+    #[inline]
+    fn to_bytes_be(&self) -> Vec<u8> {
+        let mut le_bytes = self.to_bytes_le();
+        le_bytes.reverse();
+        le_bytes
+    }
+
+    #[inline]
+    fn to_bytes_le(&self) -> Vec<u8> {
+        let array_map = self.0.iter().map(|limb| limb.to_le_bytes());
+        let mut res = Vec::<u8>::with_capacity(N * 8);
+        for limb in array_map {
+            res.extend_from_slice(&limb);
+        }
+        res
+    }
+
+    #[inline]
+    fn mul_wide(&self, other: &Self) -> (Self, Self) {
+        Self::mul_wide(self, other)
+    }
+
+    #[inline]
+    fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        Self::div_rem(self, divisor)
+    }
+
+    #[inline]
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Self::checked_add(self, other)
+    }
+
+    #[inline]
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Self::checked_sub(self, other)
+    }
+
+    #[inline]
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Self::checked_mul(self, other)
+    }
+
+    #[inline]
+    fn wrapping_add(&self, other: &Self) -> Self {
+        Self::wrapping_add(self, other)
+    }
+
+    #[inline]
+    fn wrapping_sub(&self, other: &Self) -> Self {
+        Self::wrapping_sub(self, other)
+    }
+
+    #[inline]
+    fn wrapping_mul(&self, other: &Self) -> Self {
+        Self::wrapping_mul(self, other)
+    }
+
+    #[inline]
+    fn mod_pow(&self, exp: &Self, modulus: &Self) -> Self {
+        Self::mod_pow(self, exp, modulus)
+    }
+
+    #[inline]
+    fn gcd(&self, other: &Self) -> Self {
+        Self::gcd(self, other)
+    }
+
+    #[inline]
+    fn mod_inverse(&self, modulus: &Self) -> Option<Self> {
+        Self::mod_inverse(self, modulus)
+    }
+}
+
+impl<const N: usize> CanonicalSerialize for BigInt<N> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.write(writer)?;
+        Ok(())
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        Self::NUM_LIMBS * 8
+    }
+}
+
+impl<const N: usize> CanonicalDeserialize for BigInt<N> {
+    #[inline]
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        let value = Self::read(reader)?;
+        Ok(value)
+    }
+}
+
+impl<const N: usize> ToBytes for BigInt<N> {
+    #[inline]
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write(writer)
+    }
+}
+
+impl<const N: usize> FromBytes for BigInt<N> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        <[u64; N]>::read(reader).map(Self::new)
+    }
+}
+
+impl<const N: usize> Display for BigInt<N> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        for i in self.0.iter().rev() {
+            write!(f, "{:016X}", *i)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> Ord for BigInt<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+        use core::cmp::Ordering;
+        for i in 0..N {
+            let a = &self.0[N - i - 1];
+            let b = &other.0[N - i - 1];
+            if a < b {
+                return Ordering::Less;
+            } else if a > b {
+                return Ordering::Greater;
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl<const N: usize> PartialOrd for BigInt<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Distribution<BigInt<N>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BigInt<N> {
+        let mut res = [0u64; N];
+        for limb in res.iter_mut() {
+            *limb = rng.gen();
+        }
+        BigInt(res)
+    }
+}
+
+impl<const N: usize> AsMut<[u64]> for BigInt<N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u64] {
+        &mut self.0
+    }
+}
+
+impl<const N: usize> AsRef<[u64]> for BigInt<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u64] {
+        &self.0
+    }
+}
+
+impl<const N: usize> From<u64> for BigInt<N> {
+    #[inline]
+    fn from(val: u64) -> BigInt<N> {
+        let mut repr = Self::default();
+        repr.0[0] = val;
+        repr
+    }
+}
+
+impl<const N: usize> TryFrom<BigUint> for BigInt<N> {
+    type Error = ark_std::string::String;
+
+    #[inline]
+    fn try_from(val: num_bigint::BigUint) -> Result<BigInt<N>, Self::Error> {
+        let bytes = val.to_bytes_le();
+
+        if bytes.len() > N * 8 {
+            Err(format!(
+                "A BigUint of {} bytes cannot fit into a BigInt<{}>.",
+                bytes.len(),
+                N
+            ))
+        } else {
+            let mut limbs = [0u64; N];
+
+            bytes.chunks(8).enumerate().for_each(|(i, chunk)| {
+                let mut chunk_padded = [0u8; 8];
+                chunk_padded[..chunk.len()].copy_from_slice(chunk);
+                limbs[i] = u64::from_le_bytes(chunk_padded)
+            });
+
+            Ok(Self(limbs))
+        }
+    }
+}
+
+impl<const N: usize> From<BigInt<N>> for BigUint {
+    #[inline]
+    fn from(other: BigInt<N>) -> Self {
+        BigUint::from_bytes_le(&other.to_bytes_le())
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<const N: usize> subtle::ConstantTimeEq for BigInt<N> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0[..].ct_eq(&other.0[..])
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<const N: usize> subtle::ConditionallySelectable for BigInt<N> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: subtle::Choice) -> Self {
+        let mut res = *a;
+        for (r, b) in res.0.iter_mut().zip(&b.0) {
+            r.conditional_assign(b, choice);
+        }
+        res
+    }
+}
 
 #[cfg(test)]
 mod tests;
@@ -136,11 +1012,125 @@ pub trait BigInteger:
     /// with trailing zeros.
     fn to_bytes_le(&self) -> Vec<u8>;
 
+    /// Returns the full product `self * other` as `(low, high)`, where `low`
+    /// holds the least significant limbs and `high` the most significant.
+    fn mul_wide(&self, other: &Self) -> (Self, Self) {
+        let mut low = Self::default();
+        let mut high = Self::default();
+        let (a, b) = (self.as_ref(), other.as_ref());
+        for (i, a_i) in a.iter().enumerate() {
+            let mut carry = 0;
+            for (j, b_j) in b.iter().enumerate() {
+                let k = i + j;
+                if k < Self::NUM_LIMBS {
+                    let limb = &mut low.as_mut()[k];
+                    *limb = mac_with_carry!(*limb, *a_i, *b_j, &mut carry);
+                } else {
+                    let limb = &mut high.as_mut()[k - Self::NUM_LIMBS];
+                    *limb = mac_with_carry!(*limb, *a_i, *b_j, &mut carry);
+                }
+            }
+            high.as_mut()[i] = carry;
+        }
+        (low, high)
+    }
+
+    /// Returns the quotient and remainder of `self` divided by `divisor`.
+    /// Panics if `divisor` is zero.
+    fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let (self_big, divisor_big): (BigUint, BigUint) = ((*self).into(), (*divisor).into());
+        assert!(divisor_big != BigUint::from(0u64), "division by zero");
+        let to_self = |x: BigUint| Self::try_from(x).ok().unwrap();
+        (
+            to_self(&self_big / &divisor_big),
+            to_self(self_big % divisor_big),
+        )
+    }
+
+    /// Returns `self + other`, or `None` on overflow.
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        let mut res = *self;
+        if res.add_nocarry(other) {
+            None
+        } else {
+            Some(res)
+        }
+    }
+
+    /// Returns `self - other`, or `None` on underflow.
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        let mut res = *self;
+        if res.sub_noborrow(other) {
+            None
+        } else {
+            Some(res)
+        }
+    }
+
+    /// Returns `self * other`, or `None` on overflow.
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        let (low, high) = self.mul_wide(other);
+        if high.is_zero() {
+            Some(low)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self + other`, ignoring overflow.
+    fn wrapping_add(&self, other: &Self) -> Self {
+        let mut res = *self;
+        res.add_nocarry(other);
+        res
+    }
+
+    /// Returns `self - other`, ignoring underflow.
+    fn wrapping_sub(&self, other: &Self) -> Self {
+        let mut res = *self;
+        res.sub_noborrow(other);
+        res
+    }
+
+    /// Returns `self * other`, ignoring overflow.
+    fn wrapping_mul(&self, other: &Self) -> Self {
+        self.mul_wide(other).0
+    }
+
+    /// Returns `self^exp mod modulus`. Panics if `modulus` is zero.
+    fn mod_pow(&self, exp: &Self, modulus: &Self) -> Self {
+        let modulus: BigUint = (*modulus).into();
+        assert!(modulus != BigUint::from(0u64), "zero modulus");
+        let base: BigUint = (*self).into();
+        let res = base.modpow(&(*exp).into(), &modulus);
+        Self::try_from(res).ok().unwrap()
+    }
+
+    /// Returns the greatest common divisor of `self` and `other`.
+    fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (*self, *other);
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1;
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    /// Returns the inverse of `self` modulo `modulus`, if it exists.
+    fn mod_inverse(&self, modulus: &Self) -> Option<Self> {
+        if modulus.is_zero() {
+            return None;
+        }
+        let this: BigUint = (*self).into();
+        let inverse = this.modinv(&(*modulus).into())?;
+        Self::try_from(inverse).ok()
+    }
+
     /// Returns the windowed non-adjacent form of `self`, for a window of size `w`.
     fn find_wnaf(&self, w: usize) -> Option<Vec<i64>> {
         // w > 2 due to definition of wNAF, and w < 64 to make sure that `i64`
         // can fit each signed digit
-        if w >= 2 && w < 64 {
+        if (2..64).contains(&w) {
             let mut res = vec![];
             let mut e = *self;
 
@@ -1,4 +1,7 @@
-use crate::{biginteger::BigInteger, UniformRand};
+use crate::{
+    biginteger::{BigInteger, BigInteger256},
+    UniformRand,
+};
 use num_bigint::BigUint;
 
 fn biginteger_arithmetic_test<B: BigInteger>(a: B, b: B, zero: B) {
@@ -67,6 +70,143 @@ fn biginteger_conversion_test<B: BigInteger>() {
     assert_eq!(x, x_recovered);
 }
 
+fn biginteger_multiprecision_test<B: BigInteger>() {
+    use num_traits::{One, Zero};
+
+    let mut rng = ark_std::test_rng();
+    let bits = 64 * B::NUM_LIMBS;
+    let to_big = |x: B| -> BigUint { x.into() };
+    let from_big = |x: BigUint| B::try_from(x).ok().unwrap();
+    let wrap = BigUint::one() << bits;
+
+    for i in 0..20 {
+        let a: B = UniformRand::rand(&mut rng);
+        let mut b: B = UniformRand::rand(&mut rng);
+        // Divisors and moduli of every size, down to a single limb.
+        b.divn((i * 13 % bits) as u32);
+        if b.is_zero() {
+            b = B::from(3u64);
+        }
+        let (a_big, b_big) = (to_big(a), to_big(b));
+
+        let (lo, hi) = a.mul_wide(&b);
+        assert_eq!(to_big(lo) + (to_big(hi) << bits), &a_big * &b_big);
+        assert_eq!(to_big(a.wrapping_mul(&b)), &a_big * &b_big % &wrap);
+        assert_eq!(
+            a.checked_mul(&b).map(to_big),
+            Some(&a_big * &b_big).filter(|p| p < &wrap)
+        );
+
+        assert_eq!(to_big(a.wrapping_add(&b)), (&a_big + &b_big) % &wrap);
+        assert_eq!(
+            a.checked_add(&b).map(to_big),
+            Some(&a_big + &b_big).filter(|s| s < &wrap)
+        );
+        assert_eq!(
+            to_big(a.wrapping_sub(&b)),
+            (&a_big + &wrap - &b_big) % &wrap
+        );
+        assert_eq!(a.checked_sub(&b).is_none(), a < b);
+        assert_eq!(b.checked_sub(&b), Some(B::from(0u64)));
+
+        let (q, r) = a.div_rem(&b);
+        assert_eq!(to_big(q), &a_big / &b_big);
+        assert_eq!(to_big(r), &a_big % &b_big);
+
+        let e: B = UniformRand::rand(&mut rng);
+        assert_eq!(to_big(a.mod_pow(&e, &b)), a_big.modpow(&to_big(e), &b_big));
+
+        let c: B = UniformRand::rand(&mut rng);
+        let g = a.gcd(&b);
+        assert!((&a_big % to_big(g)).is_zero() && (&b_big % to_big(g)).is_zero());
+        // gcd(a * c, b * c) = gcd(a, b) * c for small enough factors.
+        let small_c = from_big(to_big(c) >> (bits - 8));
+        let (mut a_small, mut b_small) = (a, b);
+        a_small.divn(8);
+        b_small.divn(8);
+        assert_eq!(
+            a_small
+                .wrapping_mul(&small_c)
+                .gcd(&b_small.wrapping_mul(&small_c)),
+            a_small.gcd(&b_small).wrapping_mul(&small_c)
+        );
+
+        match a.mod_inverse(&b) {
+            Some(inv) => {
+                assert!(inv < b);
+                assert_eq!((&a_big * to_big(inv)) % &b_big, BigUint::one() % &b_big);
+            },
+            None => assert!(!to_big(g).is_one()),
+        }
+    }
+
+    let zero = B::from(0u64);
+    let one = B::from(1u64);
+    assert_eq!(zero.gcd(&zero), zero);
+    assert_eq!(zero.gcd(&one), one);
+    assert_eq!(B::from(12u64).gcd(&B::from(18u64)), B::from(6u64));
+    assert_eq!(
+        B::from(3u64).mod_inverse(&B::from(7u64)),
+        Some(B::from(5u64))
+    );
+    assert_eq!(B::from(4u64).mod_inverse(&B::from(6u64)), None);
+    assert_eq!(one.mod_inverse(&zero), None);
+    assert_eq!(B::from(5u64).mod_pow(&zero, &B::from(7u64)), one);
+    assert_eq!(B::from(5u64).mod_pow(&zero, &one), zero);
+    let max = from_big(&wrap - BigUint::one());
+    assert_eq!(max.checked_add(&one), None);
+    assert_eq!(max.wrapping_add(&one), zero);
+    assert_eq!(zero.checked_sub(&one), None);
+    assert_eq!(zero.wrapping_sub(&one), max);
+    assert_eq!(
+        max.mul_wide(&max),
+        (one, from_big(&wrap - BigUint::from(2u64)))
+    );
+}
+
+#[test]
+fn test_biginteger_const_fn() {
+    use crate::biginteger::BigInteger256 as B;
+    const A: B = B::new([u64::MAX, u64::MAX, 0, 0]);
+    const B_: B = B::new([3, 0, 0, 0]);
+    const PRODUCT: (B, B) = A.mul_wide(&B_);
+    const QUOTIENT: (B, B) = A.div_rem(&B_);
+    const INVERSE: Option<B> = B_.mod_inverse(&A);
+    assert_eq!(PRODUCT.0, B::new([u64::MAX - 2, u64::MAX, 2, 0]));
+    assert_eq!(
+        QUOTIENT,
+        (
+            B::new([0x5555555555555555, 0x5555555555555555, 0, 0]),
+            B::from(0u64)
+        )
+    );
+    assert_eq!(INVERSE, None);
+}
+
+#[test]
+fn test_biginteger_mod_pow() {
+    use crate::{
+        fields::FpParameters,
+        test_field::{Fr, FrParameters},
+    };
+    let p = FrParameters::MODULUS;
+    let p_minus_one = p.wrapping_sub(&1u64.into());
+    let mut rng = ark_std::test_rng();
+    for _ in 0..10 {
+        // Fermat's little theorem, for the odd modulus of a prime field.
+        let a: BigInteger256 = Fr::rand(&mut rng).into();
+        let expected = if a.is_zero() { 0u64 } else { 1u64 };
+        assert_eq!(a.mod_pow(&p_minus_one, &p), expected.into());
+
+        // An even modulus.
+        let m = p.wrapping_add(&p);
+        let e: BigInteger256 = Fr::rand(&mut rng).into();
+        let (a_big, e_big, m_big): (BigUint, BigUint, BigUint) = (a.into(), e.into(), m.into());
+        let res: BigUint = a.mod_pow(&e, &m).into();
+        assert_eq!(res, a_big.modpow(&e_big, &m_big));
+    }
+}
+
 fn test_biginteger<B: BigInteger>(zero: B) {
     let mut rng = ark_std::test_rng();
     let a: B = UniformRand::rand(&mut rng);
@@ -75,6 +215,7 @@ fn test_biginteger<B: BigInteger>(zero: B) {
     biginteger_bytes_test::<B>();
     biginteger_bits_test::<B>();
     biginteger_conversion_test::<B>();
+    biginteger_multiprecision_test::<B>();
 }
 
 #[test]
@@ -118,3 +259,15 @@ fn test_biginteger832() {
     use crate::biginteger::BigInteger832 as B;
     test_biginteger(B::new([0u64; 13]));
 }
+
+#[test]
+fn test_biginteger512() {
+    use crate::biginteger::BigInt;
+    test_biginteger(BigInt::<8>::new([0u64; 8]));
+}
+
+#[test]
+fn test_biginteger1024() {
+    use crate::biginteger::BigInt;
+    test_biginteger(BigInt::<16>::new([0u64; 16]));
+}
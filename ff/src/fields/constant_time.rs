@@ -0,0 +1,172 @@
+//! Constant-time field operations, available with the `ct` feature.
+//!
+//! The methods in this module take time independent of the *values* of
+//! their inputs (but not of the field parameters), which makes them suitable
+//! for handling secret data such as private keys.
+
+use crate::fields::{Field, SquareRootField};
+pub use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// The interface for a field supporting constant-time equality checks,
+/// conditional selection and inversion.
+pub trait ConstantTimeField: Field + ConstantTimeEq + ConditionallySelectable {
+    /// Returns the multiplicative inverse of `self`, or none if `self` is zero.
+    fn ct_inverse(&self) -> CtOption<Self>;
+
+    /// Returns whether `self` is zero.
+    fn ct_is_zero(&self) -> Choice {
+        self.ct_eq(&Self::zero())
+    }
+}
+
+/// The interface for a field supporting constant-time square roots.
+///
+/// Among cubic extensions, this is only implemented for `Fp3`: like
+/// `SquareRootField`, it is not implemented for a generic `CubicExtField`.
+pub trait ConstantTimeSqrt: ConstantTimeField + SquareRootField {
+    /// Returns a square root of `self`, or none if `self` is not a square.
+    fn ct_sqrt(&self) -> CtOption<Self>;
+}
+
+/// Constant-time variant of the Tonelli-Shanks algorithm, for a field whose
+/// multiplicative group has order `2^two_adicity * t`, with `t` odd.
+/// `qnr_to_t` must be a quadratic non-residue raised to the power `t`.
+///
+/// The sequence of field operations performed only depends on `two_adicity`,
+/// as every data-dependent branch is replaced by a conditional selection.
+pub(crate) fn ct_tonelli_shanks<F: ConstantTimeField>(
+    elem: &F,
+    two_adicity: u32,
+    t_minus_one_div_two: impl AsRef<[u64]>,
+    qnr_to_t: F,
+) -> CtOption<F> {
+    // w = elem^((t - 1) / 2)
+    let w = elem.pow(t_minus_one_div_two);
+
+    let mut v = two_adicity;
+    let mut x = *elem * &w;
+    let mut b = x * &w;
+    let mut z = qnr_to_t;
+
+    for max_v in (1..=two_adicity).rev() {
+        let mut k = 1;
+        let mut tmp = b.square();
+        let mut j_less_than_v = Choice::from(1);
+
+        for j in 2..max_v {
+            let tmp_is_one = tmp.ct_eq(&F::one());
+            let squared = F::conditional_select(&tmp, &z, tmp_is_one).square();
+            tmp = F::conditional_select(&squared, &tmp, tmp_is_one);
+            let new_z = F::conditional_select(&z, &squared, tmp_is_one);
+            j_less_than_v &= !j.ct_eq(&v);
+            k = u32::conditional_select(&j, &k, tmp_is_one);
+            z = F::conditional_select(&z, &new_z, j_less_than_v);
+        }
+
+        let result = x * &z;
+        x = F::conditional_select(&result, &x, b.ct_eq(&F::one()));
+        z.square_in_place();
+        b *= &z;
+        v = k;
+    }
+
+    CtOption::new(x, x.square().ct_eq(elem))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fields::{BabyBear, Goldilocks, Mersenne31},
+        test_field::{Fq, Fq2, Fq3, Fq6, Fr},
+        UniformRand,
+    };
+    use ark_std::test_rng;
+    use num_traits::Zero;
+
+    fn ct_inverse_test<F: ConstantTimeField>() {
+        let mut rng = test_rng();
+        assert!(bool::from(F::zero().ct_inverse().is_none()));
+        for _ in 0..100 {
+            let a = F::rand(&mut rng);
+            let inv = a.ct_inverse();
+            assert_eq!(Option::<F>::from(inv), a.inverse());
+        }
+    }
+
+    fn ct_sqrt_test<F: ConstantTimeSqrt>() {
+        let mut rng = test_rng();
+        assert_eq!(Option::<F>::from(F::zero().ct_sqrt()), Some(F::zero()));
+        for _ in 0..100 {
+            let a = F::rand(&mut rng);
+            let sqrt = a.square().ct_sqrt().unwrap();
+            assert!(sqrt == a || sqrt == -a);
+
+            let sqrt = Option::<F>::from(a.ct_sqrt());
+            assert_eq!(sqrt.is_some(), a.sqrt().is_some());
+            if let Some(sqrt) = sqrt {
+                assert_eq!(sqrt.square(), a);
+            }
+        }
+    }
+
+    fn ct_select_test<F: ConstantTimeField>() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let a = F::rand(&mut rng);
+            let b = F::rand(&mut rng);
+            assert!(bool::from(a.ct_eq(&a)));
+            assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+            assert_eq!(F::conditional_select(&a, &b, Choice::from(0)), a);
+            assert_eq!(F::conditional_select(&a, &b, Choice::from(1)), b);
+
+            let (mut x, mut y) = (a, b);
+            F::conditional_swap(&mut x, &mut y, Choice::from(1));
+            assert_eq!((x, y), (b, a));
+            assert_eq!(bool::from(a.ct_is_zero()), a.is_zero());
+        }
+    }
+
+    #[test]
+    fn test_ct_fp() {
+        ct_select_test::<Fr>();
+        ct_inverse_test::<Fr>();
+        ct_sqrt_test::<Fr>();
+        ct_select_test::<Fq>();
+        ct_inverse_test::<Fq>();
+        ct_sqrt_test::<Fq>();
+    }
+
+    #[test]
+    fn test_ct_small_fp() {
+        ct_select_test::<Goldilocks>();
+        ct_inverse_test::<Goldilocks>();
+        ct_sqrt_test::<Goldilocks>();
+        ct_sqrt_test::<Mersenne31>();
+        ct_sqrt_test::<BabyBear>();
+    }
+
+    #[test]
+    fn test_ct_quadratic_extension() {
+        ct_select_test::<Fq2>();
+        ct_inverse_test::<Fq2>();
+        ct_sqrt_test::<Fq2>();
+
+        // Elements with `c1 == 0` take a separate path.
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let a = Fq2::new(Fq::rand(&mut rng), Fq::zero());
+            let sqrt = Option::<Fq2>::from(a.ct_sqrt()).unwrap();
+            assert_eq!(sqrt.square(), a);
+        }
+    }
+
+    #[test]
+    fn test_ct_cubic_extension() {
+        ct_select_test::<Fq3>();
+        ct_inverse_test::<Fq3>();
+        ct_sqrt_test::<Fq3>();
+        ct_select_test::<Fq6>();
+        ct_inverse_test::<Fq6>();
+    }
+}
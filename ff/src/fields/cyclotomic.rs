@@ -0,0 +1,85 @@
+use crate::fields::{Field, QuadExtField, QuadExtParameters};
+use num_traits::{One, Zero};
+
+/// Fields with a subgroup of their multiplicative group in which arithmetic is
+/// cheaper than in the whole field, such as the fields hosting the target
+/// groups of pairings.
+///
+/// For a quadratic extension `Self = F[w] / (w^2 - γ)`, the elements of norm
+/// one over `F` form such a subgroup: their inverse is their conjugate, and
+/// they can be compressed to a single element of `F` using the algebraic
+/// torus `T2`. Curve-specific fields may implement faster squaring on a
+/// smaller cyclotomic subgroup.
+///
+/// All of the methods below may only be used on elements of odd order in the
+/// norm one subgroup.
+pub trait CyclotomicMultSubgroup: Field {
+    /// The field `F` over which `Self` is a quadratic extension, which holds
+    /// compressed elements.
+    type TorusField: Field;
+
+    /// Returns `self * self`.
+    #[must_use]
+    fn cyclotomic_square(&self) -> Self;
+
+    /// Returns the inverse of `self`.
+    #[must_use]
+    fn cyclotomic_inverse(&self) -> Self;
+
+    /// Returns `self^exponent`.
+    #[must_use]
+    fn cyclotomic_exp(&self, exponent: impl AsRef<[u64]>) -> Self;
+
+    /// Compresses `self` to the element `m` of `Self::TorusField` such that
+    /// `self = (m + w) / (m - w)`. The identity is mapped to zero.
+    fn torus_compress(&self) -> Self::TorusField;
+
+    /// Recovers an element from its compression `m`, as computed by
+    /// `Self::torus_compress`.
+    fn torus_decompress(m: &Self::TorusField) -> Self;
+}
+
+impl<P: QuadExtParameters> CyclotomicMultSubgroup for QuadExtField<P> {
+    type TorusField = P::BaseField;
+
+    #[inline]
+    fn cyclotomic_square(&self) -> Self {
+        P::cyclotomic_square(self)
+    }
+
+    #[inline]
+    fn cyclotomic_inverse(&self) -> Self {
+        let mut res = *self;
+        res.conjugate();
+        res
+    }
+
+    #[inline]
+    fn cyclotomic_exp(&self, exponent: impl AsRef<[u64]>) -> Self {
+        P::cyclotomic_exp(self, exponent)
+    }
+
+    fn torus_compress(&self) -> P::BaseField {
+        // Elements of odd order with `c1 = 0` satisfy `c0^2 = 1`, and so are
+        // the identity.
+        if self.c1.is_zero() {
+            return P::BaseField::zero();
+        }
+        (P::BaseField::one() + &self.c0) * &self.c1.inverse().unwrap()
+    }
+
+    fn torus_decompress(m: &P::BaseField) -> Self {
+        if m.is_zero() {
+            return Self::one();
+        }
+        // (m + w) / (m - w) = (m^2 + γ + 2m * w) / (m^2 - γ), where `m^2 - γ`
+        // is nonzero since `γ` is a non-residue.
+        let m_squared = m.square();
+        let gamma = P::NONRESIDUE;
+        let denominator = (m_squared - &gamma).inverse().unwrap();
+        Self::new(
+            (m_squared + &gamma) * &denominator,
+            m.double() * &denominator,
+        )
+    }
+}
@@ -0,0 +1,151 @@
+//! The `expand_message` functions of
+//! [RFC 9380, Section 5.3](https://www.rfc-editor.org/rfc/rfc9380.html#section-5.3),
+//! which produce uniformly random byte strings from a message and a domain
+//! separation tag.
+
+use ark_std::{marker::PhantomData, vec, vec::Vec};
+use digest::{
+    generic_array::typenum::Unsigned, BlockInput, Digest, ExtendableOutput, Update, XofReader,
+};
+
+use super::HashToFieldError;
+
+/// The prefix used to hash domain separation tags longer than 255 bytes.
+const LONG_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// The maximum number of bytes `expand_message` may output.
+const MAX_LEN_IN_BYTES: usize = 65535;
+
+/// Expands a message into a uniformly random byte string.
+pub trait Expander: Sized {
+    /// Creates an expander with the domain separation tag `dst`, for
+    /// `security_param` bits of security. Tags longer than 255 bytes are hashed
+    /// as described in RFC 9380, Section 5.3.3.
+    fn new(dst: &[u8], security_param: usize) -> Self;
+
+    /// Expands `msg` into `len_in_bytes` uniformly random bytes.
+    fn expand(&self, msg: &[u8], len_in_bytes: usize) -> Result<Vec<u8>, HashToFieldError>;
+}
+
+/// Returns `dst || I2OSP(len(dst), 1)`.
+fn dst_prime(dst: &[u8]) -> Vec<u8> {
+    let mut dst_prime = dst.to_vec();
+    dst_prime.push(dst.len() as u8);
+    dst_prime
+}
+
+/// `expand_message_xmd`, for a hash function `H` with a fixed output size.
+pub struct ExpanderXmd<H: Digest + BlockInput> {
+    dst: Vec<u8>,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: Digest + BlockInput> Expander for ExpanderXmd<H> {
+    fn new(dst: &[u8], _security_param: usize) -> Self {
+        let dst = if dst.len() > 255 {
+            H::new()
+                .chain(LONG_DST_PREFIX)
+                .chain(dst)
+                .finalize()
+                .to_vec()
+        } else {
+            dst.to_vec()
+        };
+        Self {
+            dst,
+            _hasher: PhantomData,
+        }
+    }
+
+    fn expand(&self, msg: &[u8], len_in_bytes: usize) -> Result<Vec<u8>, HashToFieldError> {
+        let b_in_bytes = H::output_size();
+        let ell = len_in_bytes.div_ceil(b_in_bytes);
+        if ell > 255 || len_in_bytes > MAX_LEN_IN_BYTES {
+            return Err(HashToFieldError::OutputTooLong);
+        }
+
+        let dst_prime = dst_prime(&self.dst);
+        let z_pad = vec![0u8; H::BlockSize::to_usize()];
+        let l_i_b_str = (len_in_bytes as u16).to_be_bytes();
+
+        // b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)
+        let b_0 = H::new()
+            .chain(&z_pad)
+            .chain(msg)
+            .chain(l_i_b_str)
+            .chain([0u8])
+            .chain(&dst_prime)
+            .finalize();
+
+        // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
+        let mut b_i = H::new()
+            .chain(&b_0)
+            .chain([1u8])
+            .chain(&dst_prime)
+            .finalize();
+
+        let mut uniform_bytes = Vec::with_capacity(ell * b_in_bytes);
+        uniform_bytes.extend_from_slice(&b_i);
+        for i in 2..=ell {
+            // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+            let xored = b_0
+                .iter()
+                .zip(b_i.iter())
+                .map(|(a, b)| a ^ b)
+                .collect::<Vec<_>>();
+            b_i = H::new()
+                .chain(xored)
+                .chain([i as u8])
+                .chain(&dst_prime)
+                .finalize();
+            uniform_bytes.extend_from_slice(&b_i);
+        }
+        uniform_bytes.truncate(len_in_bytes);
+        Ok(uniform_bytes)
+    }
+}
+
+/// `expand_message_xof`, for an extendable-output function `H`.
+pub struct ExpanderXof<H: ExtendableOutput + Update + Default> {
+    dst: Vec<u8>,
+    _hasher: PhantomData<H>,
+}
+
+impl<H: ExtendableOutput + Update + Default> Expander for ExpanderXof<H> {
+    fn new(dst: &[u8], security_param: usize) -> Self {
+        let dst = if dst.len() > 255 {
+            let mut hashed_dst = vec![0u8; (2 * security_param).div_ceil(8)];
+            H::default()
+                .chain(LONG_DST_PREFIX)
+                .chain(dst)
+                .finalize_xof()
+                .read(&mut hashed_dst);
+            hashed_dst
+        } else {
+            dst.to_vec()
+        };
+        Self {
+            dst,
+            _hasher: PhantomData,
+        }
+    }
+
+    fn expand(&self, msg: &[u8], len_in_bytes: usize) -> Result<Vec<u8>, HashToFieldError> {
+        if len_in_bytes > MAX_LEN_IN_BYTES {
+            return Err(HashToFieldError::OutputTooLong);
+        }
+
+        // uniform_bytes = H(msg || I2OSP(len_in_bytes, 2) || DST_prime, len_in_bytes)
+        let mut uniform_bytes = vec![0u8; len_in_bytes];
+        H::default()
+            .chain(msg)
+            .chain((len_in_bytes as u16).to_be_bytes())
+            .chain(dst_prime(&self.dst))
+            .finalize_xof()
+            .read(&mut uniform_bytes);
+        Ok(uniform_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests;
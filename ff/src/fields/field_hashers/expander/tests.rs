@@ -0,0 +1,176 @@
+//! Test vectors from RFC 9380, Appendix K.
+
+use super::{Expander, ExpanderXmd, ExpanderXof};
+use ark_std::{iter, string::String, vec::Vec};
+use sha2::{Sha256, Sha512};
+use sha3::{Shake128, Shake256};
+
+/// The messages of the test vectors. Each vector set covers all of them, with
+/// 0x20 and then 0x80 bytes of output.
+fn messages() -> Vec<Vec<u8>> {
+    let q128 = iter::repeat_n(b'q', 128);
+    let a512 = iter::repeat_n(b'a', 512);
+    vec![
+        b"".to_vec(),
+        b"abc".to_vec(),
+        b"abcdef0123456789".to_vec(),
+        b"q128_".iter().copied().chain(q128).collect(),
+        b"a512_".iter().copied().chain(a512).collect(),
+    ]
+}
+
+fn long_dst(prefix: &str) -> String {
+    let mut dst = String::from(prefix);
+    dst.extend(iter::repeat_n('1', 221));
+    dst
+}
+
+fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn expander_test<E: Expander>(dst: &[u8], security_param: usize, expected: &[&str; 10]) {
+    let expander = E::new(dst, security_param);
+    let msgs = messages();
+    let cases = [0x20, 0x80]
+        .iter()
+        .flat_map(|len| msgs.iter().map(move |msg| (msg, *len)));
+    for ((msg, len), expected) in cases.zip(expected.iter()) {
+        assert_eq!(expander.expand(msg, len).unwrap(), decode_hex(expected));
+    }
+}
+
+#[test]
+fn test_expand_message_xmd() {
+    expander_test::<ExpanderXmd<Sha256>>(
+        b"QUUX-V01-CS02-with-expander-SHA256-128",
+        128,
+        &XMD_SHA256,
+    );
+    expander_test::<ExpanderXmd<Sha256>>(
+        long_dst("QUUX-V01-CS02-with-expander-SHA256-128-long-DST-").as_bytes(),
+        128,
+        &XMD_SHA256_LONG_DST,
+    );
+    expander_test::<ExpanderXmd<Sha512>>(
+        b"QUUX-V01-CS02-with-expander-SHA512-256",
+        256,
+        &XMD_SHA512,
+    );
+}
+
+#[test]
+fn test_expand_message_xof() {
+    expander_test::<ExpanderXof<Shake128>>(
+        b"QUUX-V01-CS02-with-expander-SHAKE128",
+        128,
+        &XOF_SHAKE128,
+    );
+    expander_test::<ExpanderXof<Shake128>>(
+        long_dst("QUUX-V01-CS02-with-expander-SHAKE128-long-DST-").as_bytes(),
+        128,
+        &XOF_SHAKE128_LONG_DST,
+    );
+    expander_test::<ExpanderXof<Shake256>>(
+        b"QUUX-V01-CS02-with-expander-SHAKE256",
+        256,
+        &XOF_SHAKE256,
+    );
+}
+
+#[test]
+fn test_output_too_long() {
+    let xmd = ExpanderXmd::<Sha256>::new(b"dst", 128);
+    assert!(xmd.expand(b"msg", 255 * 32).is_ok());
+    assert!(xmd.expand(b"msg", 255 * 32 + 1).is_err());
+    let xof = ExpanderXof::<Shake128>::new(b"dst", 128);
+    assert!(xof.expand(b"msg", 65535).is_ok());
+    assert!(xof.expand(b"msg", 65536).is_err());
+}
+
+#[rustfmt::skip]
+const XMD_SHA256: [&str; 10] = [
+    "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+    "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+    "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+    "b23a1d2b4d97b2ef7785562a7e8bac7eed54ed6e97e29aa51bfe3f12ddad1ff9",
+    "4623227bcc01293b8c130bf771da8c298dede7383243dc0993d2d94823958c4c",
+    "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+    "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40",
+    "ef904a29bffc4cf9ee82832451c946ac3c8f8058ae97d8d629831a74c6572bd9ebd0df635cd1f208e2038e760c4994984ce73f0d55ea9f22af83ba4734569d4bc95e18350f740c07eef653cbb9f87910d833751825f0ebefa1abe5420bb52be14cf489b37fe1a72f7de2d10be453b2c9d9eb20c7e3f6edc5a60629178d9478df",
+    "80be107d0884f0d881bb460322f0443d38bd222db8bd0b0a5312a6fedb49c1bbd88fd75d8b9a09486c60123dfa1d73c1cc3169761b17476d3c6b7cbbd727acd0e2c942f4dd96ae3da5de368d26b32286e32de7e5a8cb2949f866a0b80c58116b29fa7fabb3ea7d520ee603e0c25bcaf0b9a5e92ec6a1fe4e0391d1cdbce8c68a",
+    "546aff5444b5b79aa6148bd81728704c32decb73a3ba76e9e75885cad9def1d06d6792f8a7d12794e90efed817d96920d728896a4510864370c207f99bd4a608ea121700ef01ed879745ee3e4ceef777eda6d9e5e38b90c86ea6fb0b36504ba4a45d22e86f6db5dd43d98a294bebb9125d5b794e9d2a81181066eb954966a487",
+];
+
+#[rustfmt::skip]
+const XMD_SHA256_LONG_DST: [&str; 10] = [
+    "f3cc2e0db48536caaf29143f49adbb8085838e9b4750bac7b44a88a4b884d2ee",
+    "48e38db5fca30ab2c9282b4d039af6e4062b01b85a3c86a9b1cd86a1e14457a7",
+    "23a8d7f92d212829cdf3848cfaf2cae0ff638b7a85c695d8df9e51a6c460467f",
+    "fa35097e6421ab21230270b03c83afe8a8886a70f60f7b9db02dcc77bb0d3d69",
+    "aeaebd690ce34d01b12aa57bb3005c1b460de67c8e521d082fa65e64966c8c6a",
+    "b2637df0866a341a515b0ebe47f418af310b2579eefec94961a37d0bcacbd5fd41ef616cc0f7e5b461923d6333adc5b47d514bf743efa18f12e3142bf917a6bd08ec91633f5a0f0115603302cc5a72ee706cbf4e55426825413fa57c2eca7386f92013e01b10c390758165adf66a4f820a21f302de5e26bcdb66a3ef6c8dfa72",
+    "4d410443807be5ecb318c4011a6f614991c1ab6fcbb9547c915e65f9d92178cd174ad2ccb6bd104ed9c16af1e78077e9f98d7441a71ade207387f82bbd2bf46cafa91a7d083f7e5aef26e688dbcdf40b0491f06d315514fda01ea88cd6b23c4622323612da454572af337b847e8d54573468d3873a553261d7214fe5341c0aa7",
+    "3786880a56c6fd8e36f647598bdc9197daab0c3b835853c2531608f1c1be2af803a86b51ec08a4211a41fdbea3c76cfcbf105cac1343cdc2ca057e8c8995f4e76278d7d612a9312ebe9991344c08a78541066f9eb1300ed322d1070aef5e8301246b2afd2ca961b58b5a684424e24e9f57e319c6fdb0c7bd2ec783ff274426b4",
+    "6fbec46c5906cfc613fc25735d9c2383bb605ee59c659c569fb59e965d9b35da36619ba0741080bfafa3eaa9c3be12f499cf5292b0aad5109241eb4987c5404222021626908899f769b1e1810a26aec74cddc547ce790eb22be23c3531ed9b069b7eb25415174950e5d4f176b542b4d032ac403c24b414eee13638f6cbe3e615",
+    "f8cff3ed255af200c80a994bcb4d1d7fff8e2f3de3d47251cf0688cc089e77f2e1c48373619f2d96b029f794374bee5648c8c62c96104172031ce5a0a3c4cc9e933dc85cb8213ee93efea29150730d1bc65f9449c14ee1b793f1524ca4d8ae6b0ba967b47f50d0e6b1de6df48a9d9ac0575deb706b0fe955adb23e60c44b1653",
+];
+
+#[rustfmt::skip]
+const XMD_SHA512: [&str; 10] = [
+    "6b9a7312411d92f921c6f68ca0b6380730a1a4d982c507211a90964c394179ba",
+    "0da749f12fbe5483eb066a5f595055679b976e93abe9be6f0f6318bce7aca8dc",
+    "087e45a86e2939ee8b91100af1583c4938e0f5fc6c9db4b107b83346bc967f58",
+    "7336234ee9983902440f6bc35b348352013becd88938d2afec44311caf8356b3",
+    "57b5f7e766d5be68a6bfe1768e3c2b7f1228b3e4b3134956dd73a59b954c66f4",
+    "41b037d1734a5f8df225dd8c7de38f851efdb45c372887be655212d07251b921b052b62eaed99b46f72f2ef4cc96bfaf254ebbbec091e1a3b9e4fb5e5b619d2e0c5414800a1d882b62bb5cd1778f098b8eb6cb399d5d9d18f5d5842cf5d13d7eb00a7cff859b605da678b318bd0e65ebff70bec88c753b159a805d2c89c55961",
+    "7f1dddd13c08b543f2e2037b14cefb255b44c83cc397c1786d975653e36a6b11bdd7732d8b38adb4a0edc26a0cef4bb45217135456e58fbca1703cd6032cb1347ee720b87972d63fbf232587043ed2901bce7f22610c0419751c065922b488431851041310ad659e4b23520e1772ab29dcdeb2002222a363f0c2b1c972b3efe1",
+    "3f721f208e6199fe903545abc26c837ce59ac6fa45733f1baaf0222f8b7acb0424814fcb5eecf6c1d38f06e9d0a6ccfbf85ae612ab8735dfdf9ce84c372a77c8f9e1c1e952c3a61b7567dd0693016af51d2745822663d0c2367e3f4f0bed827feecc2aaf98c949b5ed0d35c3f1023d64ad1407924288d366ea159f46287e61ac",
+    "b799b045a58c8d2b4334cf54b78260b45eec544f9f2fb5bd12fb603eaee70db7317bf807c406e26373922b7b8920fa29142703dd52bdf280084fb7ef69da78afdf80b3586395b433dc66cde048a258e476a561e9deba7060af40adf30c64249ca7ddea79806ee5beb9a1422949471d267b21bc88e688e4014087a0b592b695ed",
+    "05b0bfef265dcee87654372777b7c44177e2ae4c13a27f103340d9cd11c86cb2426ffcad5bd964080c2aee97f03be1ca18e30a1f14e27bc11ebbd650f305269cc9fb1db08bf90bfc79b42a952b46daf810359e7bc36452684784a64952c343c52e5124cd1f71d474d5197fefc571a92929c9084ffe1112cf5eea5192ebff330b",
+];
+
+#[rustfmt::skip]
+const XOF_SHAKE128: [&str; 10] = [
+    "86518c9cd86581486e9485aa74ab35ba150d1c75c88e26b7043e44e2acd735a2",
+    "8696af52a4d862417c0763556073f47bc9b9ba43c99b505305cb1ec04a9ab468",
+    "912c58deac4821c3509dbefa094df54b34b8f5d01a191d1d3108a2c89077acca",
+    "1adbcc448aef2a0cebc71dac9f756b22e51839d348e031e63b33ebb50faeaf3f",
+    "df3447cc5f3e9a77da10f819218ddf31342c310778e0e4ef72bbaecee786a4fe",
+    "7314ff1a155a2fb99a0171dc71b89ab6e3b2b7d59e38e64419b8b6294d03ffee42491f11370261f436220ef787f8f76f5b26bdcd850071920ce023f3ac46847744f4612b8714db8f5db83205b2e625d95afd7d7b4d3094d3bdde815f52850bb41ead9822e08f22cf41d615a303b0d9dde73263c049a7b9898208003a739a2e57",
+    "c952f0c8e529ca8824acc6a4cab0e782fc3648c563ddb00da7399f2ae35654f4860ec671db2356ba7baa55a34a9d7f79197b60ddae6e64768a37d699a78323496db3878c8d64d909d0f8a7de4927dcab0d3dbbc26cb20a49eceb0530b431cdf47bc8c0fa3e0d88f53b318b6739fbed7d7634974f1b5c386d6230c76260d5337a",
+    "19b65ee7afec6ac06a144f2d6134f08eeec185f1a890fe34e68f0e377b7d0312883c048d9b8a1d6ecc3b541cb4987c26f45e0c82691ea299b5e6889bbfe589153016d8131717ba26f07c3c14ffbef1f3eff9752e5b6183f43871a78219a75e7000fbac6a7072e2b83c790a3a5aecd9d14be79f9fd4fb180960a3772e08680495",
+    "ca1b56861482b16eae0f4a26212112362fcc2d76dcc80c93c4182ed66c5113fe41733ed68be2942a3487394317f3379856f4822a611735e50528a60e7ade8ec8c71670fec6661e2c59a09ed36386513221688b35dc47e3c3111ee8c67ff49579089d661caa29db1ef10eb6eace575bf3dc9806e7c4016bd50f3c0e2a6481ee6d",
+    "9d763a5ce58f65c91531b4100c7266d479a5d9777ba761693d052acd37d149e7ac91c796a10b919cd74a591a1e38719fb91b7203e2af31eac3bff7ead2c195af7d88b8bc0a8adf3d1e90ab9bed6ddc2b7f655dd86c730bdeaea884e73741097142c92f0e3fc1811b699ba593c7fbd81da288a29d423df831652e3a01a9374999",
+];
+
+#[rustfmt::skip]
+const XOF_SHAKE128_LONG_DST: [&str; 10] = [
+    "35ecdc8a4cbab200e8f805d26a44c7c951b580f3f08b8be68a9ff8d975778943",
+    "048ef2491f0bd9f9ba49732ba4fb13d721a543c31886777b9c04310762875409",
+    "305e4f6eb11d0f58aa7685686ebc9dfce7e3e4d3264c321a938f5a58888a78df",
+    "22d8a519a3bd926b12da3228b95697823a90da1fff2cc921d1f9577b81875b68",
+    "c1e46b7947508341c8f5ad3cd6036a55cc34daf439f3a436afc9de5f3962b88d",
+    "2050634f3721eea716e912ea3c8b9214f1a56a59e2f49209672e11b94c27dd18d8fd5deb82fa6ca20427eb153a7ac606cb755319fbcf6f8e0712f54ea79d4a2258b785a9a1ee372660ce25b783f446e46e77a52ba3ef11d7de23a94548043977d935274b7fa3ced0d09e4166191caf683748f6ad1a855fd0ee3decfe28d74e20",
+    "b1618e2199ebdd030a1fb3e3c793d61fb249ed3a9ef41ef3844c841a8858fb875e7ecb94086b5dbc23a3c4fbad4883421ac2cbf374c057320d5d50246f22fe5c9705a08567a15d1e64c5bcf9c36c852aede7054debcb83058f391c24d7f5a2d34f3abe8e0dfd7856f54de416e454845be8f33f1b1894538168d1bb86b7e98f2c",
+    "d7784c2c8e8b6a9f1573c57cea0d8d47583a02409021b5f39908e8eb41d5d79ac8de623747058ea37c32e984e8c53a6cd6c63142100ca13cab8a1beb9188b2ca9f0fd03c9ae7ffe4dd37864f045d0c4f0e9dcd6efe18c24c1e6a9d0f0eb57da0122dc926ab0613382e0adb89c2ab95d50406c36dc24b1f881b4bc7576b5b8f40",
+    "98da1d64b30d6f3ea71b9101ec50f434f7df88a53d221327b086968277f0accb6543c9483d4e398d318b32ec3a0322a7511806afa8f5e933a42fa43600ad34ef83d311667a5f17d4873ce3a0eb245f6775dd57162e603fa90467233011b3312eb78e938999e140d62e93e8a4a31c734c4192f81daf2dbe7069f3da96ea39214b",
+    "de0b274c103d463b6ce3773c1311ece03bcaf462e1eaa8466e276e394b375390ac0650a15707ff96d4169d86f6fd4a1ccdff8bf0a37d4441fa1f4b549ffe7de7c23c1ecbd2bd99bd0109770b94d2c066876c53c27b34db12bcb5525b94e84b49d18734d263e6e996dc0c25d5d3e9938f51cbe9e40e4dde3c4138926bdc693c8c",
+];
+
+#[rustfmt::skip]
+const XOF_SHAKE256: [&str; 10] = [
+    "2ffc05c48ed32b95d72e807f6eab9f7530dd1c2f013914c8fed38c5ccc15ad76",
+    "b39e493867e2767216792abce1f2676c197c0692aed061560ead251821808e07",
+    "245389cf44a13f0e70af8665fe5337ec2dcd138890bb7901c4ad9cfceb054b65",
+    "719b3911821e6428a5ed9b8e600f2866bcf23c8f0515e52d6c6c019a03f16f0e",
+    "9181ead5220b1963f1b5951f35547a5ea86a820562287d6ca4723633d17ccbbc",
+    "7a1361d2d7d82d79e035b8880c5a3c86c5afa719478c007d96e6c88737a3f631dd74a2c88df79a4cb5e5d9f7504957c70d669ec6bfedc31e01e2bacc4ff3fdf9b6a00b17cc18d9d72ace7d6b81c2e481b4f73f34f9a7505dccbe8f5485f3d20c5409b0310093d5d6492dea4e18aa6979c23c8ea5de01582e9689612afbb353df",
+    "a54303e6b172909783353ab05ef08dd435a558c3197db0c132134649708e0b9b4e34fb99b92a9e9e28fc1f1d8860d85897a8e021e6382f3eea10577f968ff6df6c45fe624ce65ca25932f679a42a404bc3681efe03fcd45ef73bb3a8f79ba784f80f55ea8a3c367408f30381299617f50c8cf8fbb21d0f1e1d70b0131a7b6fbe",
+    "e42e4d9538a189316e3154b821c1bafb390f78b2f010ea404e6ac063deb8c0852fcd412e098e231e43427bd2be1330bb47b4039ad57b30ae1fc94e34993b162ff4d695e42d59d9777ea18d3848d9d336c25d2acb93adcad009bcfb9cde12286df267ada283063de0bb1505565b2eb6c90e31c48798ecdc71a71756a9110ff373",
+    "4ac054dda0a38a65d0ecf7afd3c2812300027c8789655e47aecf1ecc1a2426b17444c7482c99e5907afd9c25b991990490bb9c686f43e79b4471a23a703d4b02f23c669737a886a7ec28bddb92c3a98de63ebf878aa363a501a60055c048bea11840c4717beae7eee28c3cfa42857b3d130188571943a7bd747de831bd6444e0",
+    "09afc76d51c2cccbc129c2315df66c2be7295a231203b8ab2dd7f95c2772c68e500bc72e20c602abc9964663b7a03a389be128c56971ce81001a0b875e7fd17822db9d69792ddf6a23a151bf470079c518279aef3e75611f8f828994a9988f4a8a256ddb8bae161e658d5a2a09bcfe839c6396dc06ee5c8ff3c22d3b1f9deb7e",
+];
@@ -0,0 +1,88 @@
+//! Hashing to finite fields, following
+//! [RFC 9380, Section 5](https://www.rfc-editor.org/rfc/rfc9380.html#section-5).
+
+mod expander;
+pub use expander::{Expander, ExpanderXmd, ExpanderXof};
+
+use crate::fields::{Field, PrimeField};
+use ark_std::{fmt, marker::PhantomData, vec::Vec};
+
+/// This is an error that could occur while hashing to a field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HashToFieldError {
+    /// The number of requested bytes exceeds what `expand_message` can
+    /// produce.
+    OutputTooLong,
+}
+
+impl ark_std::error::Error for HashToFieldError {}
+
+impl fmt::Display for HashToFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        match self {
+            HashToFieldError::OutputTooLong => {
+                write!(f, "the requested output is too long for expand_message")
+            },
+        }
+    }
+}
+
+/// Hashes arbitrary messages to elements of the field `F`.
+pub trait HashToField<F: Field>: Sized {
+    /// Creates a hasher with the domain separation tag `dst`.
+    fn new(dst: &[u8]) -> Self;
+
+    /// Hashes `msg` to `count` elements of `F`.
+    fn hash_to_field(&self, msg: &[u8], count: usize) -> Result<Vec<F>, HashToFieldError>;
+}
+
+/// The `hash_to_field` function of RFC 9380, built on top of the expander `E`
+/// (one of [`ExpanderXmd`] and [`ExpanderXof`]) and targeting `SEC_PARAM` bits
+/// of security.
+///
+/// Elements of extension fields are built from `F::extension_degree()`
+/// consecutive elements of the base prime field, in the order expected by
+/// `Field::from_base_prime_field_elems`.
+pub struct DefaultFieldHasher<E: Expander, F: Field, const SEC_PARAM: usize = 128> {
+    expander: E,
+    len_per_base_elem: usize,
+    _field: PhantomData<F>,
+}
+
+impl<E: Expander, F: Field, const SEC_PARAM: usize> HashToField<F>
+    for DefaultFieldHasher<E, F, SEC_PARAM>
+{
+    fn new(dst: &[u8]) -> Self {
+        // L = ceil((ceil(log2(p)) + k) / 8)
+        let base_field_size_in_bits = F::BasePrimeField::size_in_bits();
+        let len_per_base_elem = (base_field_size_in_bits + SEC_PARAM).div_ceil(8);
+        Self {
+            expander: E::new(dst, SEC_PARAM),
+            len_per_base_elem,
+            _field: PhantomData,
+        }
+    }
+
+    fn hash_to_field(&self, msg: &[u8], count: usize) -> Result<Vec<F>, HashToFieldError> {
+        let m = F::extension_degree() as usize;
+        let len_per_elem = self.len_per_base_elem * m;
+        let len_in_bytes = count
+            .checked_mul(len_per_elem)
+            .ok_or(HashToFieldError::OutputTooLong)?;
+        let uniform_bytes = self.expander.expand(msg, len_in_bytes)?;
+
+        Ok(uniform_bytes
+            .chunks(len_per_elem)
+            .map(|elem_bytes| {
+                let base_elems = elem_bytes
+                    .chunks(self.len_per_base_elem)
+                    .map(F::BasePrimeField::from_be_bytes_mod_order)
+                    .collect::<Vec<_>>();
+                F::from_base_prime_field_elems(&base_elems).unwrap()
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,58 @@
+use super::{DefaultFieldHasher, ExpanderXmd, ExpanderXof, HashToField};
+use crate::{
+    fields::Field,
+    test_field::{Fq, Fq2, Fq3, Fq6, Fr},
+};
+use ark_std::vec::Vec;
+use sha2::Sha256;
+use sha3::Shake128;
+
+const DST: &[u8] = b"ARKWORKS-HASH-TO-FIELD-TEST";
+
+/// Checks that each element of `E` is made of consecutive elements obtained
+/// by hashing to its base prime field.
+fn extension_test<E: Field, H1: HashToField<E>, H2: HashToField<E::BasePrimeField>>() {
+    let degree = E::extension_degree() as usize;
+    let ext_hasher = H1::new(DST);
+    let base_hasher = H2::new(DST);
+    for msg in [&b""[..], b"abc", b"abcdef0123456789"].iter() {
+        for count in 0..4 {
+            let elems = ext_hasher.hash_to_field(msg, count).unwrap();
+            let base_elems = base_hasher.hash_to_field(msg, count * degree).unwrap();
+            assert_eq!(elems.len(), count);
+            let expected = base_elems
+                .chunks(degree)
+                .map(|c| E::from_base_prime_field_elems(c).unwrap())
+                .collect::<Vec<_>>();
+            assert_eq!(elems, expected);
+        }
+    }
+}
+
+#[test]
+fn test_hash_to_extension_field_xmd() {
+    type H<F> = DefaultFieldHasher<ExpanderXmd<Sha256>, F>;
+    extension_test::<Fq2, H<Fq2>, H<Fq>>();
+    extension_test::<Fq6, H<Fq6>, H<Fq>>();
+    extension_test::<Fq3, H<Fq3>, H<Fr>>();
+}
+
+#[test]
+fn test_hash_to_extension_field_xof() {
+    type H<F> = DefaultFieldHasher<ExpanderXof<Shake128>, F>;
+    extension_test::<Fq2, H<Fq2>, H<Fq>>();
+    extension_test::<Fq6, H<Fq6>, H<Fq>>();
+    extension_test::<Fq3, H<Fq3>, H<Fr>>();
+}
+
+#[test]
+fn test_hash_to_field_domain_separation() {
+    let hasher = <DefaultFieldHasher<ExpanderXmd<Sha256>, Fq> as HashToField<Fq>>::new(DST);
+    let other = <DefaultFieldHasher<ExpanderXmd<Sha256>, Fq> as HashToField<Fq>>::new(b"OTHER");
+    let elems = hasher.hash_to_field(b"abc", 2).unwrap();
+    assert_ne!(elems[0], elems[1]);
+    assert_ne!(elems, other.hash_to_field(b"abc", 2).unwrap());
+    assert!(hasher.hash_to_field(b"abc", 1 << 16).is_err());
+    // `count * L` does not fit into a `usize`.
+    assert!(hasher.hash_to_field(b"abc", usize::MAX).is_err());
+}
@@ -18,11 +18,10 @@ use ark_std::{
 };
 
 pub use ark_ff_macros;
+pub use ark_ff_macros::FpParameters;
 use num_traits::{One, Zero};
 use zeroize::Zeroize;
 
-#[macro_use]
-pub mod macros;
 pub mod utils;
 
 #[macro_use]
@@ -31,6 +30,16 @@ pub mod arithmetic;
 pub mod models;
 pub use self::models::*;
 
+pub mod field_hashers;
+
+pub mod cyclotomic;
+pub use self::cyclotomic::*;
+
+#[cfg(feature = "ct")]
+pub mod constant_time;
+#[cfg(feature = "ct")]
+pub use self::constant_time::*;
+
 #[cfg(feature = "parallel")]
 use ark_std::cmp::max;
 #[cfg(feature = "parallel")]
@@ -135,6 +144,11 @@ pub trait Field:
     /// If the slice length != Self::extension_degree(), must return None.
     fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self>;
 
+    /// Convert a field element into its `Self::extension_degree()` coefficients
+    /// over `Self::BasePrimeField`. This is the inverse of
+    /// `from_base_prime_field_elems`.
+    fn to_base_prime_field_elems(&self) -> Vec<Self::BasePrimeField>;
+
     /// Returns `self + self`.
     #[must_use]
     fn double(&self) -> Self;
@@ -547,20 +561,8 @@ impl<Slice: AsRef<[u64]>> Iterator for BitIteratorLE<Slice> {
     }
 }
 
-use crate::biginteger::{
-    BigInteger256, BigInteger320, BigInteger384, BigInteger448, BigInteger64, BigInteger768,
-    BigInteger832,
-};
 use num_bigint::BigUint;
 
-impl_field_bigint_conv!(Fp64, BigInteger64, Fp64Parameters);
-impl_field_bigint_conv!(Fp256, BigInteger256, Fp256Parameters);
-impl_field_bigint_conv!(Fp320, BigInteger320, Fp320Parameters);
-impl_field_bigint_conv!(Fp384, BigInteger384, Fp384Parameters);
-impl_field_bigint_conv!(Fp448, BigInteger448, Fp448Parameters);
-impl_field_bigint_conv!(Fp768, BigInteger768, Fp768Parameters);
-impl_field_bigint_conv!(Fp832, BigInteger832, Fp832Parameters);
-
 // Given a vector of field elements {v_i}, compute the vector {v_i^(-1)}
 pub fn batch_inversion<F: Field>(v: &mut [F]) {
     batch_inversion_and_mul(v, &F::one());
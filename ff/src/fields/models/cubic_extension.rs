@@ -25,6 +25,11 @@ use crate::{
     ToConstraintField, UniformRand,
 };
 
+#[cfg(feature = "ct")]
+use crate::fields::constant_time::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeField, CtOption,
+};
+
 pub trait CubicExtParameters: 'static + Send + Sync {
     /// The prime field that this cubic extension is eventually an extension of.
     type BasePrimeField: PrimeField;
@@ -60,6 +65,88 @@ pub trait CubicExtParameters: 'static + Send + Sync {
     );
 }
 
+/// Squares `c0 + c1 * y` in place, where `y^2` is the generator of
+/// `CubicExtField<P>` over `P::BaseField`, so that `y^6 = P::NONRESIDUE` and
+/// this is a sixth degree extension of `P::BaseField`. The element must lie
+/// in the cyclotomic subgroup of that extension, of order
+/// `q^2 - q + 1` for `q = |P::BaseField|`.
+///
+/// This is the squaring of "Faster Squaring in the Cyclotomic Subgroup of
+/// Sixth Degree Extensions" by Robert Granger and Michael Scott, which
+/// `Fp12`, `Fp18` and `Fp24` share.
+pub(crate) fn cyclotomic_square_in_place<P: CubicExtParameters>(
+    c0: &mut CubicExtField<P>,
+    c1: &mut CubicExtField<P>,
+) {
+    let nr = P::mul_base_field_by_nonresidue;
+
+    let r0 = &c0.c0;
+    let r4 = &c0.c1;
+    let r3 = &c0.c2;
+    let r2 = &c1.c0;
+    let r1 = &c1.c1;
+    let r5 = &c1.c2;
+
+    // t0 + t1*y = (z0 + z1*y)^2 = a^2
+    let mut tmp = *r0 * r1;
+    let t0 = (*r0 + r1) * &(nr(&r1) + r0) - &tmp - &nr(&tmp);
+    let t1 = tmp.double();
+
+    // t2 + t3*y = (z2 + z3*y)^2 = b^2
+    tmp = *r2 * r3;
+    let t2 = (*r2 + r3) * &(nr(&r3) + r2) - &tmp - &nr(&tmp);
+    let t3 = tmp.double();
+
+    // t4 + t5*y = (z4 + z5*y)^2 = c^2
+    tmp = *r4 * r5;
+    let t4 = (*r4 + r5) * &(nr(&r5) + r4) - &tmp - &nr(&tmp);
+    let t5 = tmp.double();
+
+    let z0 = &mut c0.c0;
+    let z4 = &mut c0.c1;
+    let z3 = &mut c0.c2;
+    let z2 = &mut c1.c0;
+    let z1 = &mut c1.c1;
+    let z5 = &mut c1.c2;
+
+    // for A
+
+    // z0 = 3 * t0 - 2 * z0
+    *z0 = t0 - &*z0;
+    z0.double_in_place();
+    *z0 += &t0;
+
+    // z1 = 3 * t1 + 2 * z1
+    *z1 = t1 + &*z1;
+    z1.double_in_place();
+    *z1 += &t1;
+
+    // for B
+
+    // z2 = 3 * (NONRESIDUE * t5) + 2 * z2
+    tmp = nr(&t5);
+    *z2 += tmp;
+    z2.double_in_place();
+    *z2 += &tmp;
+
+    // z3 = 3 * t4 - 2 * z3
+    *z3 = t4 - &*z3;
+    z3.double_in_place();
+    *z3 += &t4;
+
+    // for C
+
+    // z4 = 3 * t2 - 2 * z4
+    *z4 = t2 - &*z4;
+    z4.double_in_place();
+    *z4 += &t2;
+
+    // z5 = 3 * t3 + 2 * z5
+    *z5 += t3;
+    z5.double_in_place();
+    *z5 += &t3;
+}
+
 #[derive(Derivative)]
 #[derivative(
     Default(bound = "P: CubicExtParameters"),
@@ -157,6 +244,13 @@ impl<P: CubicExtParameters> Field for CubicExtField<P> {
         ))
     }
 
+    fn to_base_prime_field_elems(&self) -> Vec<Self::BasePrimeField> {
+        let mut elems = self.c0.to_base_prime_field_elems();
+        elems.extend(self.c1.to_base_prime_field_elems());
+        elems.extend(self.c2.to_base_prime_field_elems());
+        elems
+    }
+
     fn double(&self) -> Self {
         let mut result = *self;
         result.double_in_place();
@@ -269,6 +363,61 @@ impl<P: CubicExtParameters> Field for CubicExtField<P> {
     }
 }
 
+#[cfg(feature = "ct")]
+impl<P: CubicExtParameters> ConstantTimeEq for CubicExtField<P>
+where
+    P::BaseField: ConstantTimeEq,
+{
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1) & self.c2.ct_eq(&other.c2)
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: CubicExtParameters> ConditionallySelectable for CubicExtField<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.c0, &b.c0, choice),
+            P::BaseField::conditional_select(&a.c1, &b.c1, choice),
+            P::BaseField::conditional_select(&a.c2, &b.c2, choice),
+        )
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: CubicExtParameters> ConstantTimeField for CubicExtField<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    fn ct_inverse(&self) -> CtOption<Self> {
+        // Same as `Field::inverse`, with the inversion in the base field
+        // performed in constant time.
+        let t0 = self.c0.square();
+        let t1 = self.c1.square();
+        let t2 = self.c2.square();
+        let t3 = self.c0 * &self.c1;
+        let t4 = self.c0 * &self.c2;
+        let t5 = self.c1 * &self.c2;
+        let n5 = P::mul_base_field_by_nonresidue(&t5);
+
+        let s0 = t0 - &n5;
+        let s1 = P::mul_base_field_by_nonresidue(&t2) - &t3;
+        let s2 = t1 - &t4;
+
+        let a1 = self.c2 * &s1;
+        let a2 = self.c1 * &s2;
+        let a3 = P::mul_base_field_by_nonresidue(&(a1 + &a2));
+        (self.c0 * &s0 + &a3)
+            .ct_inverse()
+            .map(|t6| Self::new(t6 * &s0, t6 * &s1, t6 * &s2))
+    }
+}
+
 /// `CubicExtField` elements are ordered lexicographically.
 impl<P: CubicExtParameters> Ord for CubicExtField<P> {
     #[inline(always)]
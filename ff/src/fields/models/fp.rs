@@ -0,0 +1,1415 @@
+use ark_serialize::{
+    buffer_byte_size, CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, EmptyFlags, Flags, SerializationError,
+};
+use ark_std::{
+    cmp::{min, Ord, Ordering, PartialOrd},
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{Read, Result as IoResult, Write},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+    vec::Vec,
+};
+use num_traits::{One, Zero};
+
+use crate::{
+    biginteger::{
+        arithmetic as fa, BigInt, BigInteger as _BigInteger, BigInteger256, BigInteger320,
+        BigInteger384, BigInteger448, BigInteger64, BigInteger768, BigInteger832,
+    },
+    bytes::{FromBytes, ToBytes},
+    fields::{FftField, Field, FpParameters, LegendreSymbol, PrimeField, SquareRootField},
+};
+
+use super::montgomery_u32;
+
+#[cfg(feature = "ct")]
+use crate::fields::constant_time::{
+    ct_tonelli_shanks, Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeField,
+    ConstantTimeSqrt, CtOption,
+};
+
+/// Represents an element of the prime field F_p, where `p == P::MODULUS`.
+/// This type can represent elements in any field of size at most `64 * N`
+/// bits.
+#[derive(Derivative)]
+#[derivative(
+    Default(bound = ""),
+    Hash(bound = ""),
+    Clone(bound = ""),
+    Copy(bound = ""),
+    Debug(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = "")
+)]
+pub struct Fp<P, const N: usize>(
+    pub BigInt<N>,
+    #[derivative(Debug = "ignore")]
+    #[doc(hidden)]
+    pub PhantomData<P>,
+);
+
+pub trait Fp64Parameters: FpParameters<BigInt = BigInteger64> {}
+pub trait Fp256Parameters: FpParameters<BigInt = BigInteger256> {}
+pub trait Fp320Parameters: FpParameters<BigInt = BigInteger320> {}
+pub trait Fp384Parameters: FpParameters<BigInt = BigInteger384> {}
+pub trait Fp448Parameters: FpParameters<BigInt = BigInteger448> {}
+pub trait Fp768Parameters: FpParameters<BigInt = BigInteger768> {}
+pub trait Fp832Parameters: FpParameters<BigInt = BigInteger832> {}
+
+/// A prime field of size at most 64 bits.
+pub type Fp64<P> = Fp<P, 1>;
+/// A prime field of size at most 256 bits.
+pub type Fp256<P> = Fp<P, 4>;
+/// A prime field of size at most 320 bits.
+pub type Fp320<P> = Fp<P, 5>;
+/// A prime field of size at most 384 bits.
+pub type Fp384<P> = Fp<P, 6>;
+/// A prime field of size at most 448 bits.
+pub type Fp448<P> = Fp<P, 7>;
+/// A prime field of size at most 768 bits.
+pub type Fp768<P> = Fp<P, 12>;
+/// A prime field of size at most 832 bits.
+pub type Fp832<P> = Fp<P, 13>;
+
+/// Holds the `2 * N` limbs of a product before Montgomery reduction.
+struct MulBuffer<const N: usize> {
+    b0: [u64; N],
+    b1: [u64; N],
+}
+
+impl<const N: usize> MulBuffer<N> {
+    #[inline(always)]
+    const fn zeroed() -> Self {
+        Self {
+            b0: [0u64; N],
+            b1: [0u64; N],
+        }
+    }
+}
+
+impl<const N: usize> Index<usize> for MulBuffer<N> {
+    type Output = u64;
+
+    #[inline(always)]
+    fn index(&self, index: usize) -> &u64 {
+        if index < N {
+            &self.b0[index]
+        } else {
+            &self.b1[index - N]
+        }
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for MulBuffer<N> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: usize) -> &mut u64 {
+        if index < N {
+            &mut self.b0[index]
+        } else {
+            &mut self.b1[index - N]
+        }
+    }
+}
+
+impl<P, const N: usize> Fp<P, N> {
+    #[inline]
+    pub const fn new(element: BigInt<N>) -> Self {
+        Self(element, PhantomData)
+    }
+
+    const fn const_is_zero(&self) -> bool {
+        let mut is_zero = true;
+        let mut i = 0;
+        while i < N {
+            is_zero &= (self.0).0[i] == 0;
+            i += 1;
+        }
+        is_zero
+    }
+
+    const fn const_neg(self, modulus: BigInt<N>) -> Self {
+        if !self.const_is_zero() {
+            Self::new(Self::sub_noborrow(&modulus, &self.0))
+        } else {
+            self
+        }
+    }
+
+    /// Interpret a string of decimal numbers as a prime field element.
+    /// Does not accept unnecessary leading zeroes or a blank string.
+    /// For *internal* use only; please use the `field_new` macro instead
+    /// of this method
+    #[doc(hidden)]
+    pub const fn const_from_str(
+        limbs: &[u64],
+        is_positive: bool,
+        r2: BigInt<N>,
+        modulus: BigInt<N>,
+        inv: u64,
+    ) -> Self {
+        let mut repr = BigInt::<N>([0; N]);
+        let mut i = 0;
+        while i < limbs.len() {
+            repr.0[i] = limbs[i];
+            i += 1;
+        }
+        let res = Self::const_from_repr(repr, r2, modulus, inv);
+        if is_positive {
+            res
+        } else {
+            res.const_neg(modulus)
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn const_from_repr(
+        repr: BigInt<N>,
+        r2: BigInt<N>,
+        modulus: BigInt<N>,
+        inv: u64,
+    ) -> Self {
+        let mut r = Self::new(repr);
+        if r.const_is_zero() {
+            r
+        } else {
+            r = r.const_mul(&Fp(r2, PhantomData), modulus, inv);
+            r
+        }
+    }
+
+    /// Montgomery multiplication using the CIOS method, which works for any
+    /// modulus. The accumulator has `N + 2` limbs: the `N` limbs of `r`,
+    /// followed by `hi` and `top`.
+    const fn const_mul(self, other: &Self, modulus: BigInt<N>, inv: u64) -> Self {
+        let mut r = [0u64; N];
+        let mut hi = 0u64;
+
+        let mut i = 0;
+        while i < N {
+            // r += self * other[i]
+            let mut carry = 0;
+            let mut j = 0;
+            while j < N {
+                r[j] = mac_with_carry!(r[j], (self.0).0[j], (other.0).0[i], &mut carry);
+                j += 1;
+            }
+            hi = adc!(hi, 0, &mut carry);
+            let top = carry;
+
+            // r = (r + k * modulus) / 2^64
+            let k = r[0].wrapping_mul(inv);
+            let mut carry = 0;
+            mac_with_carry!(r[0], k, modulus.0[0], &mut carry);
+            let mut j = 1;
+            while j < N {
+                r[j - 1] = mac_with_carry!(r[j], k, modulus.0[j], &mut carry);
+                j += 1;
+            }
+            r[N - 1] = adc!(hi, 0, &mut carry);
+            hi = top + carry;
+            i += 1;
+        }
+
+        // The result is less than `2 * modulus`, but may not fit in `N` limbs.
+        // The modulus is subtracted if `hi != 0` or if the subtraction does not
+        // borrow; the choice is made with a mask so as not to branch on the result.
+        let mut reduced = r;
+        let mut borrow = 0;
+        let mut i = 0;
+        while i < N {
+            reduced[i] = sbb!(reduced[i], modulus.0[i], &mut borrow);
+            i += 1;
+        }
+        let mask = 0u64.wrapping_sub(hi | (borrow ^ 1));
+        let mut i = 0;
+        while i < N {
+            r[i] = (reduced[i] & mask) | (r[i] & !mask);
+            i += 1;
+        }
+        Self::new(BigInt::<N>(r))
+    }
+
+    // need unused assignment because the last iteration of the loop produces an assignment
+    // to `borrow` that is unused.
+    #[allow(unused_assignments)]
+    const fn sub_noborrow(a: &BigInt<N>, b: &BigInt<N>) -> BigInt<N> {
+        let mut a = *a;
+        let mut borrow = 0;
+        let mut i = 0;
+        while i < N {
+            a.0[i] = sbb!(a.0[i], b.0[i], &mut borrow);
+            i += 1;
+        }
+        a
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> Fp<P, N> {
+    #[inline(always)]
+    pub(crate) fn is_valid(&self) -> bool {
+        self.0 < P::MODULUS
+    }
+
+    #[inline]
+    #[cfg(not(feature = "ct"))]
+    fn reduce(&mut self) {
+        if !self.is_valid() {
+            self.0.sub_noborrow(&P::MODULUS);
+        }
+    }
+
+    #[inline]
+    #[cfg(feature = "ct")]
+    fn reduce(&mut self) {
+        let mut reduced = self.0;
+        let borrow = reduced.sub_noborrow(&P::MODULUS);
+        self.0
+            .conditional_assign(&reduced, Choice::from(!borrow as u8));
+    }
+
+    /// Returns true if `P::MODULUS` has a zero most significant bit and at
+    /// least one zero bit in the remaining bits, in which case the
+    /// "no-carry" optimization applies to Montgomery multiplication.
+    #[inline(always)]
+    fn can_use_no_carry_optimization() -> bool {
+        let first_bit_set = P::MODULUS.0[N - 1] >> 63 != 0;
+        // N can be 1, hence we can run into a case with an unused mut.
+        #[allow(unused_mut)]
+        let mut all_bits_set = P::MODULUS.0[N - 1] == !0 - (1 << 63);
+        for i in 1..N {
+            all_bits_set &= P::MODULUS.0[N - i - 1] == !0u64;
+        }
+        !(first_bit_set || all_bits_set)
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> Zero for Fp<P, N> {
+    #[inline]
+    fn zero() -> Self {
+        Fp::<P, N>(BigInt::from(0), PhantomData)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> One for Fp<P, N> {
+    #[inline]
+    fn one() -> Self {
+        Fp::<P, N>(P::R, PhantomData)
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.0 == P::R
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> Field for Fp<P, N> {
+    type BasePrimeField = Self;
+
+    fn extension_degree() -> u64 {
+        1
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        if elems.len() != (Self::extension_degree() as usize) {
+            return None;
+        }
+        Some(elems[0])
+    }
+
+    fn to_base_prime_field_elems(&self) -> Vec<Self::BasePrimeField> {
+        vec![*self]
+    }
+
+    #[inline]
+    fn double(&self) -> Self {
+        let mut temp = *self;
+        temp.double_in_place();
+        temp
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        // This cannot exceed the backing capacity.
+        self.0.mul2();
+        // However, it may need to be reduced.
+        self.reduce();
+        self
+    }
+
+    #[inline]
+    fn characteristic() -> &'static [u64] {
+        P::MODULUS.as_ref()
+    }
+
+    #[inline]
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+        if F::BIT_SIZE > 8 {
+            None
+        } else {
+            // Copy the input into a temporary buffer of `N` limbs.
+            let mut limbs = [0u64; N];
+            for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+                let mut chunk_padded = [0u8; 8];
+                chunk_padded[..chunk.len()].copy_from_slice(chunk);
+                *limb = u64::from_le_bytes(chunk_padded);
+            }
+            // The bytes of the last limb, followed by the byte after it.
+            let mut last_bytes = [0u8; 9];
+            last_bytes[..8].copy_from_slice(&limbs[N - 1].to_le_bytes());
+            last_bytes[8] = bytes.get(8 * N).copied().unwrap_or(0);
+
+            // This mask retains everything in the last limb
+            // that is below `P::MODULUS_BITS`.
+            let last_limb_mask = (u64::MAX >> P::REPR_SHAVE_BITS).to_le_bytes();
+            let mut last_bytes_mask = [0u8; 9];
+            last_bytes_mask[..8].copy_from_slice(&last_limb_mask);
+
+            // Length of the buffer containing the field element and the flag.
+            let output_byte_size = buffer_byte_size(P::MODULUS_BITS as usize + F::BIT_SIZE);
+            // Location of the flag is the last byte of the serialized
+            // form of the field element.
+            let flag_location = output_byte_size - 1;
+
+            // At which byte is the flag located in the last limb?
+            let flag_location_in_last_limb = flag_location - (8 * (N - 1));
+
+            // The mask only has the last `F::BIT_SIZE` bits set
+            let flags_mask = u8::MAX.checked_shl(8 - (F::BIT_SIZE as u32)).unwrap_or(0);
+
+            // Mask away the remaining bytes, and try to reconstruct the
+            // flag
+            let mut flags: u8 = 0;
+            for (i, (b, m)) in last_bytes.iter_mut().zip(&last_bytes_mask).enumerate() {
+                if i == flag_location_in_last_limb {
+                    flags = *b & flags_mask
+                }
+                *b &= m;
+            }
+            let mut last_limb = [0u8; 8];
+            last_limb.copy_from_slice(&last_bytes[..8]);
+            limbs[N - 1] = u64::from_le_bytes(last_limb);
+
+            Self::from_repr(BigInt(limbs)).and_then(|f| F::from_u8(flags).map(|flag| (f, flag)))
+        }
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        let mut temp = *self;
+        temp.square_in_place();
+        temp
+    }
+
+    #[inline]
+    #[allow(unused_braces, clippy::absurd_extreme_comparisons)]
+    fn square_in_place(&mut self) -> &mut Self {
+        if N == 1 {
+            // We default to multiplying with `self` using the `Mul` impl
+            // for the 1 limb case
+            *self = *self * *self;
+            return self;
+        }
+        if cfg!(use_u32_limbs) {
+            (self.0).0 = montgomery_u32::square(&(self.0).0, &P::MODULUS.0, P::INV);
+            return self;
+        }
+        #[cfg(use_asm)]
+        #[allow(unsafe_code, unused_mut)]
+        {
+            if N <= 6 && Self::can_use_no_carry_optimization() {
+                match N {
+                    2 => ark_ff_asm::x86_64_asm_square!(2, (self.0).0),
+                    3 => ark_ff_asm::x86_64_asm_square!(3, (self.0).0),
+                    4 => ark_ff_asm::x86_64_asm_square!(4, (self.0).0),
+                    5 => ark_ff_asm::x86_64_asm_square!(5, (self.0).0),
+                    6 => ark_ff_asm::x86_64_asm_square!(6, (self.0).0),
+                    _ => unreachable!(),
+                };
+                self.reduce();
+                return self;
+            }
+        }
+        #[cfg(use_aarch64_asm)]
+        #[allow(unsafe_code)]
+        {
+            if N <= 6 && Self::can_use_no_carry_optimization() {
+                match N {
+                    2 => ark_ff_asm::aarch64_asm_square!(2, (self.0).0),
+                    3 => ark_ff_asm::aarch64_asm_square!(3, (self.0).0),
+                    4 => ark_ff_asm::aarch64_asm_square!(4, (self.0).0),
+                    5 => ark_ff_asm::aarch64_asm_square!(5, (self.0).0),
+                    6 => ark_ff_asm::aarch64_asm_square!(6, (self.0).0),
+                    _ => unreachable!(),
+                };
+                self.reduce();
+                return self;
+            }
+        }
+        let mut r = MulBuffer::<N>::zeroed();
+
+        let mut carry = 0;
+        for i in 0..N {
+            if i < N - 1 {
+                for j in 0..N {
+                    if j > i {
+                        r[i + j] =
+                            mac_with_carry!(r[i + j], (self.0).0[i], (self.0).0[j], &mut carry);
+                    }
+                }
+                r[N + i] = carry;
+                carry = 0;
+            }
+        }
+        r[N * 2 - 1] = r[N * 2 - 2] >> 63;
+        for i in 0..N {
+            // This computes `r[2 * (N - 1) - (i + 1)]`, but additionally
+            // handles the case where the index underflows.
+            // Note that we should never hit this case because it only occurs
+            // when `N == 1`, but we handle that separately above.
+            let subtractor = (2 * (N - 1usize))
+                .checked_sub(i + 1)
+                .map(|index| r[index])
+                .unwrap_or(0);
+            r[2 * (N - 1) - i] = (r[2 * (N - 1) - i] << 1) | (subtractor >> 63);
+        }
+        for i in 3..N {
+            r[N + 1 - i] = (r[N + 1 - i] << 1) | (r[N - i] >> 63);
+        }
+        // When `N == 2`, the first loop above has already doubled `r[1]`.
+        if N > 2 {
+            r[1] <<= 1;
+        }
+
+        for i in 0..N {
+            r[2 * i] = mac_with_carry!(r[2 * i], (self.0).0[i], (self.0).0[i], &mut carry);
+            // need unused assignment because the last iteration of the loop produces an
+            // assignment to `carry` that is unused.
+            #[allow(unused_assignments)]
+            {
+                r[2 * i + 1] = adc!(r[2 * i + 1], 0, &mut carry);
+            }
+        }
+        // Montgomery reduction
+        let mut _carry2 = 0;
+        for i in 0..N {
+            let k = r[i].wrapping_mul(P::INV);
+            let mut carry = 0;
+            mac_with_carry!(r[i], k, P::MODULUS.0[0], &mut carry);
+            for j in 1..N {
+                r[j + i] = mac_with_carry!(r[j + i], k, P::MODULUS.0[j], &mut carry);
+            }
+            r[N + i] = adc!(r[N + i], _carry2, &mut carry);
+            _carry2 = carry;
+        }
+        (self.0).0 = r.b1;
+        self.reduce();
+        self
+    }
+
+    #[inline]
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // Guajardo Kumar Paar Pelzl
+            // Efficient Software-Implementation of Finite Fields with Applications to
+            // Cryptography
+            // Algorithm 16 (BEA for Inversion in Fp)
+
+            let one = BigInt::from(1);
+
+            let mut u = self.0;
+            let mut v = P::MODULUS;
+            let mut b = Fp::<P, N>(P::R2, PhantomData); // Avoids unnecessary reduction step.
+            let mut c = Self::zero();
+
+            while u != one && v != one {
+                while u.is_even() {
+                    u.div2();
+
+                    if b.0.is_even() {
+                        b.0.div2();
+                    } else {
+                        b.0.add_nocarry(&P::MODULUS);
+                        b.0.div2();
+                    }
+                }
+
+                while v.is_even() {
+                    v.div2();
+
+                    if c.0.is_even() {
+                        c.0.div2();
+                    } else {
+                        c.0.add_nocarry(&P::MODULUS);
+                        c.0.div2();
+                    }
+                }
+
+                if v < u {
+                    u.sub_noborrow(&v);
+                    b.sub_assign(&c);
+                } else {
+                    v.sub_noborrow(&u);
+                    c.sub_assign(&b);
+                }
+            }
+
+            if u == one {
+                Some(b)
+            } else {
+                Some(c)
+            }
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        if let Some(inverse) = self.inverse() {
+            *self = inverse;
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    /// The Frobenius map has no effect in a prime field.
+    #[inline]
+    fn frobenius_map(&mut self, _: usize) {}
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> PrimeField for Fp<P, N> {
+    type Params = P;
+    type BigInt = BigInt<N>;
+
+    #[inline]
+    fn from_repr(r: BigInt<N>) -> Option<Self> {
+        let mut r = Fp(r, PhantomData);
+        if r.is_zero() {
+            Some(r)
+        } else if r.is_valid() {
+            r *= &Fp(P::R2, PhantomData);
+            Some(r)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    #[allow(clippy::modulo_one)]
+    fn into_repr(&self) -> BigInt<N> {
+        if cfg!(use_u32_limbs) {
+            return BigInt::new(montgomery_u32::from_montgomery(
+                &(self.0).0,
+                &P::MODULUS.0,
+                P::INV,
+            ));
+        }
+        let mut tmp = self.0;
+        let mut r = tmp.0;
+        // Montgomery Reduction
+        for i in 0..N {
+            let k = r[i].wrapping_mul(P::INV);
+            let mut carry = 0;
+
+            mac_with_carry!(r[i], k, P::MODULUS.0[0], &mut carry);
+            for j in 1..N {
+                r[(j + i) % N] = mac_with_carry!(r[(j + i) % N], k, P::MODULUS.0[j], &mut carry);
+            }
+            r[i % N] = carry;
+        }
+        tmp.0 = r;
+        tmp
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> FftField for Fp<P, N> {
+    type FftParams = P;
+
+    #[inline]
+    fn two_adic_root_of_unity() -> Self {
+        Fp::<P, N>(P::TWO_ADIC_ROOT_OF_UNITY, PhantomData)
+    }
+
+    #[inline]
+    fn large_subgroup_root_of_unity() -> Option<Self> {
+        Some(Fp::<P, N>(P::LARGE_SUBGROUP_ROOT_OF_UNITY?, PhantomData))
+    }
+
+    #[inline]
+    fn multiplicative_generator() -> Self {
+        Fp::<P, N>(P::GENERATOR, PhantomData)
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> SquareRootField for Fp<P, N> {
+    #[inline]
+    fn legendre(&self) -> LegendreSymbol {
+        use crate::fields::LegendreSymbol::*;
+
+        // s = self^((MODULUS - 1) // 2)
+        let s = self.pow(P::MODULUS_MINUS_ONE_DIV_TWO);
+        if s.is_zero() {
+            Zero
+        } else if s.is_one() {
+            QuadraticResidue
+        } else {
+            QuadraticNonResidue
+        }
+    }
+
+    #[inline]
+    fn sqrt(&self) -> Option<Self> {
+        sqrt_impl!(Self, P, self)
+    }
+
+    fn sqrt_in_place(&mut self) -> Option<&mut Self> {
+        (*self).sqrt().map(|sqrt| {
+            *self = sqrt;
+            self
+        })
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> ConstantTimeEq for Fp<P, N> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> ConditionallySelectable for Fp<P, N> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Fp::<P, N>(BigInt::conditional_select(&a.0, &b.0, choice), PhantomData)
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> ConstantTimeField for Fp<P, N> {
+    /// Computes the inverse as `self^(MODULUS - 2)`, by Fermat's little
+    /// theorem. The exponent is public, so `Field::pow` runs in constant time.
+    fn ct_inverse(&self) -> CtOption<Self> {
+        let mut exp = P::MODULUS;
+        exp.sub_noborrow(&BigInt::from(2u64));
+        CtOption::new(self.pow(exp), !self.ct_is_zero())
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> ConstantTimeSqrt for Fp<P, N> {
+    #[inline]
+    fn ct_sqrt(&self) -> CtOption<Self> {
+        ct_tonelli_shanks(
+            self,
+            P::TWO_ADICITY,
+            P::T_MINUS_ONE_DIV_TWO,
+            Self::qnr_to_t(),
+        )
+    }
+}
+
+/// Note that this implementation of `Ord` compares field elements viewing
+/// them as integers in the range 0, 1, ..., P::MODULUS - 1. However, other
+/// implementations of `PrimeField` might choose a different ordering, and
+/// as such, users should use this `Ord` for applications where
+/// any ordering suffices (like in a BTreeMap), and not in applications
+/// where a particular ordering is required.
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> Ord for Fp<P, N> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.into_repr().cmp(&other.into_repr())
+    }
+}
+
+/// Note that this implementation of `PartialOrd` compares field elements viewing
+/// them as integers in the range 0, 1, ..., `P::MODULUS` - 1. However, other
+/// implementations of `PrimeField` might choose a different ordering, and
+/// as such, users should use this `PartialOrd` for applications where
+/// any ordering suffices (like in a BTreeMap), and not in applications
+/// where a particular ordering is required.
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> PartialOrd for Fp<P, N> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> From<u128> for Fp<P, N> {
+    fn from(other: u128) -> Self {
+        let mut default_int = BigInt::<N>::default();
+        if N == 1 {
+            default_int.0[0] = (other % u128::from(P::MODULUS.0[0])) as u64;
+        } else {
+            let upper = (other >> 64) as u64;
+            let lower = ((other << 64) >> 64) as u64;
+            // This is equivalent to the following, but satisfying the compiler:
+            // default_int.0[0] = lower;
+            // default_int.0[1] = upper;
+            let limbs = [lower, upper];
+            for (cur, other) in default_int.0.iter_mut().zip(&limbs) {
+                *cur = *other;
+            }
+        }
+        Self::from_repr(default_int).unwrap()
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> From<i128> for Fp<P, N> {
+    fn from(other: i128) -> Self {
+        let abs = Self::from(other.unsigned_abs());
+        if other.is_positive() {
+            abs
+        } else {
+            -abs
+        }
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> From<bool> for Fp<P, N> {
+    fn from(other: bool) -> Self {
+        if N == 1 {
+            Self::from_repr(BigInt::from(u64::from(other) % P::MODULUS.0[0])).unwrap()
+        } else {
+            Self::from_repr(BigInt::from(u64::from(other))).unwrap()
+        }
+    }
+}
+
+macro_rules! impl_prime_field_from_int {
+    ($int: expr) => {
+        paste::paste! {
+            impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> From<[<u $int>]> for Fp<P, N> {
+                fn from(other: [<u $int>]) -> Self {
+                    if N == 1 {
+                        Self::from_repr(BigInt::from(u64::from(other) % P::MODULUS.0[0])).unwrap()
+                    } else {
+                        Self::from_repr(BigInt::from(u64::from(other))).unwrap()
+                    }
+                }
+            }
+
+            impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> From<[<i $int>]> for Fp<P, N> {
+                fn from(other: [<i $int>]) -> Self {
+                    let abs = Self::from(other.unsigned_abs());
+                    if other.is_positive() {
+                        abs
+                    } else {
+                        -abs
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_prime_field_from_int!(64);
+impl_prime_field_from_int!(32);
+impl_prime_field_from_int!(16);
+impl_prime_field_from_int!(8);
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize>
+    ark_std::rand::distributions::Distribution<Fp<P, N>>
+    for ark_std::rand::distributions::Standard
+{
+    #[inline]
+    fn sample<R: ark_std::rand::Rng + ?Sized>(&self, rng: &mut R) -> Fp<P, N> {
+        loop {
+            let mut tmp = Fp(
+                rng.sample(ark_std::rand::distributions::Standard),
+                PhantomData,
+            );
+
+            // Mask away the unused bits at the beginning.
+            assert!(P::REPR_SHAVE_BITS <= 64);
+            let mask = if P::REPR_SHAVE_BITS == 64 {
+                0
+            } else {
+                u64::MAX >> P::REPR_SHAVE_BITS
+            };
+            if let Some(val) = tmp.0.as_mut().last_mut() {
+                *val &= mask;
+            }
+
+            if tmp.is_valid() {
+                return tmp;
+            }
+        }
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> CanonicalSerializeWithFlags for Fp<P, N> {
+    fn serialize_with_flags<W: Write, F: Flags>(
+        &self,
+        mut writer: W,
+        flags: F,
+    ) -> Result<(), SerializationError> {
+        // All reasonable `Flags` should be less than 8 bits in size
+        // (256 values are enough for anyone!)
+        if F::BIT_SIZE > 8 {
+            return Err(SerializationError::NotEnoughSpace);
+        }
+
+        // Calculate the number of bytes required to represent a field element
+        // serialized with `flags`. If `F::BIT_SIZE < 8`,
+        // this is at most `8 * N + 1`
+        let output_byte_size = buffer_byte_size(P::MODULUS_BITS as usize + F::BIT_SIZE);
+        // The flags are placed in the last byte of the output.
+        let flag_location = output_byte_size - 1;
+
+        // Write out `self` limb by limb, setting the flag bits along the way.
+        let repr = self.into_repr();
+        for (i, limb) in repr.0.iter().enumerate() {
+            let start = 8 * i;
+            if start >= output_byte_size {
+                break;
+            }
+            let mut bytes = limb.to_le_bytes();
+            if flag_location < start + 8 {
+                bytes[flag_location - start] |= flags.u8_bitmask();
+            }
+            writer.write_all(&bytes[..min(8, output_byte_size - start)])?;
+        }
+        // The flags did not fit into the unused bits of the last limb.
+        if output_byte_size > 8 * N {
+            writer.write_all(&[flags.u8_bitmask()])?;
+        }
+        Ok(())
+    }
+
+    // Let `m = 8 * n` for some `n` be the smallest multiple of 8 greater
+    // than `P::MODULUS_BITS`.
+    // If `(m - P::MODULUS_BITS) >= F::BIT_SIZE` , then this method returns `n`;
+    // otherwise, it returns `n + 1`.
+    fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+        buffer_byte_size(P::MODULUS_BITS as usize + F::BIT_SIZE)
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> CanonicalSerialize for Fp<P, N> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.serialized_size_with_flags::<EmptyFlags>()
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> CanonicalDeserializeWithFlags
+    for Fp<P, N>
+{
+    fn deserialize_with_flags<R: Read, F: Flags>(
+        mut reader: R,
+    ) -> Result<(Self, F), SerializationError> {
+        // All reasonable `Flags` should be less than 8 bits in size
+        // (256 values are enough for anyone!)
+        if F::BIT_SIZE > 8 {
+            return Err(SerializationError::NotEnoughSpace);
+        }
+        // Calculate the number of bytes required to represent a field element
+        // serialized with `flags`. If `F::BIT_SIZE < 8`,
+        // this is at most `8 * N + 1`
+        let output_byte_size = buffer_byte_size(P::MODULUS_BITS as usize + F::BIT_SIZE);
+        // The flags are placed in the last byte of the input.
+        let flag_location = output_byte_size - 1;
+
+        // Read `self` limb by limb, removing the flag bits along the way.
+        let mut repr = BigInt::<N>::default();
+        let mut flags = None;
+        for (i, limb) in repr.0.iter_mut().enumerate() {
+            let start = 8 * i;
+            if start >= output_byte_size {
+                break;
+            }
+            let mut bytes = [0u8; 8];
+            reader.read_exact(&mut bytes[..min(8, output_byte_size - start)])?;
+            if flag_location < start + 8 {
+                flags = F::from_u8_remove_flags(&mut bytes[flag_location - start]);
+            }
+            *limb = u64::from_le_bytes(bytes);
+        }
+        // The flags did not fit into the unused bits of the last limb.
+        if output_byte_size > 8 * N {
+            let mut byte = [0u8; 1];
+            reader.read_exact(&mut byte)?;
+            flags = F::from_u8_remove_flags(&mut byte[0]);
+        }
+        let flags = flags.ok_or(SerializationError::UnexpectedFlags)?;
+
+        let element =
+            Self::from_repr(repr).ok_or_else(|| crate::error("FromBytes::read failed"))?;
+        Ok((element, flags))
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> CanonicalDeserialize for Fp<P, N> {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize_with_flags::<R, EmptyFlags>(reader).map(|(r, _)| r)
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> ToBytes for Fp<P, N> {
+    #[inline]
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.into_repr().write(writer)
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> FromBytes for Fp<P, N> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        BigInt::read(reader).and_then(|b| match Fp::from_repr(b) {
+            Some(f) => Ok(f),
+            None => Err(crate::error("FromBytes::read failed")),
+        })
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> FromStr for Fp<P, N> {
+    type Err = ();
+
+    /// Interpret a string of numbers as a (congruent) prime field element.
+    /// Does not accept unnecessary leading zeroes or a blank string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(());
+        }
+
+        if s == "0" {
+            return Ok(Self::zero());
+        }
+
+        let mut res = Self::zero();
+
+        let ten = Self::from(<Self as PrimeField>::BigInt::from(10));
+
+        let mut first_digit = true;
+
+        for c in s.chars() {
+            match c.to_digit(10) {
+                Some(c) => {
+                    if first_digit {
+                        if c == 0 {
+                            return Err(());
+                        }
+
+                        first_digit = false;
+                    }
+
+                    res.mul_assign(&ten);
+                    let digit = Self::from(u64::from(c));
+                    res.add_assign(&digit);
+                },
+                None => {
+                    return Err(());
+                },
+            }
+        }
+        if !res.is_valid() {
+            Err(())
+        } else {
+            Ok(res)
+        }
+    }
+}
+
+/// Outputs a string containing the value of `self`, chunked up into
+/// 64-bit limbs.
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> Display for Fp<P, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Fp{} \"({})\"", 64 * N, self.into_repr())
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> Neg for Fp<P, N> {
+    type Output = Self;
+    #[inline]
+    #[cfg(not(feature = "ct"))]
+    fn neg(self) -> Self {
+        if !self.is_zero() {
+            let mut tmp = P::MODULUS;
+            tmp.sub_noborrow(&self.0);
+            Fp::<P, N>(tmp, PhantomData)
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    #[cfg(feature = "ct")]
+    fn neg(self) -> Self {
+        let mut tmp = P::MODULUS;
+        tmp.sub_noborrow(&self.0);
+        Self::conditional_select(&Fp::<P, N>(tmp, PhantomData), &self, self.ct_is_zero())
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> Add<&Fp<P, N>> for Fp<P, N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &Self) -> Self {
+        self.add_assign(other);
+        self
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> Sub<&Fp<P, N>> for Fp<P, N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: &Self) -> Self {
+        self.sub_assign(other);
+        self
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> Mul<&Fp<P, N>> for Fp<P, N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: &Self) -> Self {
+        self.mul_assign(other);
+        self
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> Div<&Fp<P, N>> for Fp<P, N> {
+    type Output = Self;
+
+    /// Returns `self * other.inverse()` if `other.inverse()` is `Some`, and
+    /// panics otherwise.
+    #[inline]
+    fn div(mut self, other: &Self) -> Self {
+        self.mul_assign(&other.inverse().unwrap());
+        self
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> AddAssign<&Self> for Fp<P, N> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        // This cannot exceed the backing capacity.
+        self.0.add_nocarry(&other.0);
+        // However, it may need to be reduced
+        self.reduce();
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> SubAssign<&Self> for Fp<P, N> {
+    #[inline]
+    #[cfg(not(feature = "ct"))]
+    fn sub_assign(&mut self, other: &Self) {
+        // If `other` is larger than `self`, add the modulus to self first.
+        if other.0 > self.0 {
+            self.0.add_nocarry(&P::MODULUS);
+        }
+        self.0.sub_noborrow(&other.0);
+    }
+
+    #[inline]
+    #[cfg(feature = "ct")]
+    fn sub_assign(&mut self, other: &Self) {
+        // If the subtraction borrows, add the modulus back.
+        let borrow = self.0.sub_noborrow(&other.0);
+        let mut corrected = self.0;
+        corrected.add_nocarry(&P::MODULUS);
+        self.0
+            .conditional_assign(&corrected, Choice::from(borrow as u8));
+    }
+}
+
+/// This modular multiplication algorithm uses Montgomery
+/// reduction for efficient implementation. It also additionally
+/// uses the "no-carry optimization" outlined
+/// [here](https://hackmd.io/@zkteam/modular_multiplication) if
+/// `P::MODULUS` has (a) a non-zero MSB, and (b) at least one
+/// zero bit in the rest of the modulus. On 32-bit targets, and with the
+/// `u32-limbs` feature, it works on 32-bit words instead.
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> MulAssign<&Self> for Fp<P, N> {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        if cfg!(use_u32_limbs) {
+            (self.0).0 = montgomery_u32::mul(&(self.0).0, &(other.0).0, &P::MODULUS.0, P::INV);
+            return;
+        }
+        // No-carry optimisation applied to CIOS
+        if Self::can_use_no_carry_optimization() {
+            #[cfg(use_asm)]
+            #[allow(unsafe_code, unused_mut)]
+            {
+                // Tentatively avoid using assembly for `N == 1`.
+                if N <= 6 && N > 1 {
+                    match N {
+                        2 => ark_ff_asm::x86_64_asm_mul!(2, (self.0).0, (other.0).0),
+                        3 => ark_ff_asm::x86_64_asm_mul!(3, (self.0).0, (other.0).0),
+                        4 => ark_ff_asm::x86_64_asm_mul!(4, (self.0).0, (other.0).0),
+                        5 => ark_ff_asm::x86_64_asm_mul!(5, (self.0).0, (other.0).0),
+                        6 => ark_ff_asm::x86_64_asm_mul!(6, (self.0).0, (other.0).0),
+                        _ => unreachable!(),
+                    };
+                    self.reduce();
+                    return;
+                }
+            }
+            #[cfg(use_aarch64_asm)]
+            #[allow(unsafe_code)]
+            {
+                if N <= 6 && N > 1 {
+                    match N {
+                        2 => ark_ff_asm::aarch64_asm_mul!(2, (self.0).0, (other.0).0),
+                        3 => ark_ff_asm::aarch64_asm_mul!(3, (self.0).0, (other.0).0),
+                        4 => ark_ff_asm::aarch64_asm_mul!(4, (self.0).0, (other.0).0),
+                        5 => ark_ff_asm::aarch64_asm_mul!(5, (self.0).0, (other.0).0),
+                        6 => ark_ff_asm::aarch64_asm_mul!(6, (self.0).0, (other.0).0),
+                        _ => unreachable!(),
+                    };
+                    self.reduce();
+                    return;
+                }
+            }
+            let mut r = [0u64; N];
+            let mut carry1 = 0u64;
+            let mut carry2 = 0u64;
+
+            for i in 0..N {
+                r[0] = fa::mac(r[0], (self.0).0[0], (other.0).0[i], &mut carry1);
+                let k = r[0].wrapping_mul(P::INV);
+                fa::mac_discard(r[0], k, P::MODULUS.0[0], &mut carry2);
+                for j in 1..N {
+                    r[j] = mac_with_carry!(r[j], (self.0).0[j], (other.0).0[i], &mut carry1);
+                    r[j - 1] = mac_with_carry!(r[j], k, P::MODULUS.0[j], &mut carry2);
+                }
+                r[N - 1] = carry1 + carry2;
+            }
+            (self.0).0 = r;
+            self.reduce();
+        // Alternative implementation
+        } else {
+            *self = self.const_mul(other, P::MODULUS, P::INV);
+        }
+    }
+}
+
+/// Computes `self *= other.inverse()` if `other.inverse()` is `Some`, and
+/// panics otherwise.
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> DivAssign<&Self> for Fp<P, N> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().unwrap());
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> Add<Self> for Fp<P, N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: Self) -> Self {
+        self.add_assign(&other);
+        self
+    }
+}
+
+impl<'a, P: FpParameters<BigInt = BigInt<N>>, const N: usize> Add<&'a mut Self> for Fp<P, N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &'a mut Self) -> Self {
+        self.add_assign(&*other);
+        self
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> Sub<Self> for Fp<P, N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: Self) -> Self {
+        self.sub_assign(&other);
+        self
+    }
+}
+
+impl<'a, P: FpParameters<BigInt = BigInt<N>>, const N: usize> Sub<&'a mut Self> for Fp<P, N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: &'a mut Self) -> Self {
+        self.sub_assign(&*other);
+        self
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> core::iter::Sum<Self> for Fp<P, N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<'a, P: FpParameters<BigInt = BigInt<N>>, const N: usize> core::iter::Sum<&'a Self>
+    for Fp<P, N>
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> AddAssign<Self> for Fp<P, N> {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        self.add_assign(&other)
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> SubAssign<Self> for Fp<P, N> {
+    #[inline]
+    fn sub_assign(&mut self, other: Self) {
+        self.sub_assign(&other)
+    }
+}
+
+impl<'a, P: FpParameters<BigInt = BigInt<N>>, const N: usize> AddAssign<&'a mut Self> for Fp<P, N> {
+    #[inline]
+    fn add_assign(&mut self, other: &'a mut Self) {
+        self.add_assign(&*other)
+    }
+}
+
+impl<'a, P: FpParameters<BigInt = BigInt<N>>, const N: usize> SubAssign<&'a mut Self> for Fp<P, N> {
+    #[inline]
+    fn sub_assign(&mut self, other: &'a mut Self) {
+        self.sub_assign(&*other)
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> Mul<Self> for Fp<P, N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: Self) -> Self {
+        self.mul_assign(&other);
+        self
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> Div<Self> for Fp<P, N> {
+    type Output = Self;
+
+    #[inline]
+    fn div(mut self, other: Self) -> Self {
+        self.div_assign(&other);
+        self
+    }
+}
+
+impl<'a, P: FpParameters<BigInt = BigInt<N>>, const N: usize> Mul<&'a mut Self> for Fp<P, N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: &'a mut Self) -> Self {
+        self.mul_assign(&*other);
+        self
+    }
+}
+
+impl<'a, P: FpParameters<BigInt = BigInt<N>>, const N: usize> Div<&'a mut Self> for Fp<P, N> {
+    type Output = Self;
+
+    #[inline]
+    fn div(mut self, other: &'a mut Self) -> Self {
+        self.div_assign(&*other);
+        self
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> core::iter::Product<Self> for Fp<P, N> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), Mul::mul)
+    }
+}
+
+impl<'a, P: FpParameters<BigInt = BigInt<N>>, const N: usize> core::iter::Product<&'a Self>
+    for Fp<P, N>
+{
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), Mul::mul)
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> MulAssign<Self> for Fp<P, N> {
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        self.mul_assign(&other)
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> DivAssign<Self> for Fp<P, N> {
+    #[inline]
+    fn div_assign(&mut self, other: Self) {
+        self.div_assign(&other)
+    }
+}
+
+impl<'a, P: FpParameters<BigInt = BigInt<N>>, const N: usize> MulAssign<&'a mut Self> for Fp<P, N> {
+    #[inline]
+    fn mul_assign(&mut self, other: &'a mut Self) {
+        self.mul_assign(&*other)
+    }
+}
+
+impl<'a, P: FpParameters<BigInt = BigInt<N>>, const N: usize> DivAssign<&'a mut Self> for Fp<P, N> {
+    #[inline]
+    fn div_assign(&mut self, other: &'a mut Self) {
+        self.div_assign(&*other)
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> zeroize::Zeroize for Fp<P, N> {
+    // The phantom data does not contain element-specific data
+    // and thus does not need to be zeroized.
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> From<num_bigint::BigUint> for Fp<P, N> {
+    #[inline]
+    fn from(val: num_bigint::BigUint) -> Fp<P, N> {
+        Fp::<P, N>::from_le_bytes_mod_order(&val.to_bytes_le())
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> From<Fp<P, N>> for num_bigint::BigUint {
+    #[inline]
+    fn from(other: Fp<P, N>) -> Self {
+        other.into_repr().into()
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> From<Fp<P, N>> for BigInt<N> {
+    fn from(other: Fp<P, N>) -> Self {
+        other.into_repr()
+    }
+}
+
+impl<P: FpParameters<BigInt = BigInt<N>>, const N: usize> From<BigInt<N>> for Fp<P, N> {
+    /// Converts `Self::BigInteger` into `Self`
+    ///
+    /// # Panics
+    /// This method panics if `int` is larger than `P::MODULUS`.
+    fn from(int: BigInt<N>) -> Self {
+        Self::from_repr(int).unwrap()
+    }
+}
@@ -0,0 +1,154 @@
+use super::cubic_extension::*;
+use crate::fields::{Fp2, Fp2Parameters, Fp4, Fp4Parameters};
+use core::marker::PhantomData;
+use core::ops::{AddAssign, MulAssign, SubAssign};
+
+type Fp2Params<P> = <<P as Fp12Parameters>::Fp4Params as Fp4Parameters>::Fp2Params;
+type Fp<P> = <Fp2Params<P> as Fp2Parameters>::Fp;
+
+pub trait Fp12Parameters: 'static + Send + Sync + Copy {
+    type Fp4Params: Fp4Parameters;
+
+    /// This *must* equal (0, 1), so that `w^3` is the element `v` that
+    /// generates `Fp4` over `Fp2`.
+    const NONRESIDUE: Fp4<Self::Fp4Params>;
+
+    /// Coefficients for the Frobenius automorphism.
+    /// non_residue^((modulus^i-1)/3) for i=0,...,11, which lie in the base
+    /// prime field when `modulus = 1 mod 12`.
+    const FROBENIUS_COEFF_FP12_C1: &'static [Fp<Self>];
+    /// non_residue^((2*modulus^i-2)/3) for i=0,...,11.
+    const FROBENIUS_COEFF_FP12_C2: &'static [Fp<Self>];
+
+    /// Multiply by cubic nonresidue v.
+    #[inline(always)]
+    fn mul_fp4_by_nonresidue(fe: &Fp4<Self::Fp4Params>) -> Fp4<Self::Fp4Params> {
+        // (c0 + c1 * v) * v = c1 * u + c0 * v, where v^2 = u.
+        Fp4::new(
+            <Self::Fp4Params as Fp4Parameters>::mul_fp2_by_nonresidue(&fe.c1),
+            fe.c0,
+        )
+    }
+}
+
+pub struct Fp12ParamsWrapper<P: Fp12Parameters>(PhantomData<P>);
+
+impl<P: Fp12Parameters> CubicExtParameters for Fp12ParamsWrapper<P> {
+    type BasePrimeField = Fp<P>;
+    type BaseField = Fp4<P::Fp4Params>;
+    type FrobCoeff = Fp<P>;
+
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize = 12;
+
+    const NONRESIDUE: Self::BaseField = P::NONRESIDUE;
+
+    const FROBENIUS_COEFF_C1: &'static [Self::FrobCoeff] = P::FROBENIUS_COEFF_FP12_C1;
+    const FROBENIUS_COEFF_C2: &'static [Self::FrobCoeff] = P::FROBENIUS_COEFF_FP12_C2;
+
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue(fe: &Self::BaseField) -> Self::BaseField {
+        P::mul_fp4_by_nonresidue(fe)
+    }
+
+    fn mul_base_field_by_frob_coeff(
+        c1: &mut Self::BaseField,
+        c2: &mut Self::BaseField,
+        power: usize,
+    ) {
+        c1.mul_by_fp(&Self::FROBENIUS_COEFF_C1[power % Self::DEGREE_OVER_BASE_PRIME_FIELD]);
+        c2.mul_by_fp(&Self::FROBENIUS_COEFF_C2[power % Self::DEGREE_OVER_BASE_PRIME_FIELD]);
+    }
+}
+
+pub type Fp12<P> = CubicExtField<Fp12ParamsWrapper<P>>;
+
+impl<P: Fp12Parameters> Fp12<P> {
+    pub fn mul_by_fp(&mut self, element: &Fp<P>) {
+        self.c0.mul_by_fp(element);
+        self.c1.mul_by_fp(element);
+        self.c2.mul_by_fp(element);
+    }
+
+    pub fn mul_by_fp2(&mut self, element: &Fp2<Fp2Params<P>>) {
+        self.c0.mul_by_fp2(element);
+        self.c1.mul_by_fp2(element);
+        self.c2.mul_by_fp2(element);
+    }
+
+    pub fn mul_by_fp4(&mut self, element: &Fp4<P::Fp4Params>) {
+        self.c0.mul_assign(element);
+        self.c1.mul_assign(element);
+        self.c2.mul_assign(element);
+    }
+
+    pub fn mul_by_1(&mut self, c1: &Fp4<P::Fp4Params>) {
+        let mut b_b = self.c1;
+        b_b.mul_assign(c1);
+
+        let mut t1 = *c1;
+        {
+            let mut tmp = self.c1;
+            tmp.add_assign(&self.c2);
+
+            t1.mul_assign(&tmp);
+            t1.sub_assign(&b_b);
+            t1 = P::mul_fp4_by_nonresidue(&t1);
+        }
+
+        let mut t2 = *c1;
+        {
+            let mut tmp = self.c0;
+            tmp.add_assign(&self.c1);
+
+            t2.mul_assign(&tmp);
+            t2.sub_assign(&b_b);
+        }
+
+        self.c0 = t1;
+        self.c1 = t2;
+        self.c2 = b_b;
+    }
+
+    pub fn mul_by_01(&mut self, c0: &Fp4<P::Fp4Params>, c1: &Fp4<P::Fp4Params>) {
+        let mut a_a = self.c0;
+        let mut b_b = self.c1;
+        a_a.mul_assign(c0);
+        b_b.mul_assign(c1);
+
+        let mut t1 = *c1;
+        {
+            let mut tmp = self.c1;
+            tmp.add_assign(&self.c2);
+
+            t1.mul_assign(&tmp);
+            t1.sub_assign(&b_b);
+            t1 = P::mul_fp4_by_nonresidue(&t1);
+            t1.add_assign(&a_a);
+        }
+
+        let mut t3 = *c0;
+        {
+            let mut tmp = self.c0;
+            tmp.add_assign(&self.c2);
+
+            t3.mul_assign(&tmp);
+            t3.sub_assign(&a_a);
+            t3.add_assign(&b_b);
+        }
+
+        let mut t2 = *c0;
+        t2.add_assign(c1);
+        {
+            let mut tmp = self.c0;
+            tmp.add_assign(&self.c1);
+
+            t2.mul_assign(&tmp);
+            t2.sub_assign(&a_a);
+            t2.sub_assign(&b_b);
+        }
+
+        self.c0 = t1;
+        self.c1 = t2;
+        self.c2 = t3;
+    }
+}
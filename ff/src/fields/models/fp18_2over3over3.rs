@@ -0,0 +1,119 @@
+use super::cubic_extension::cyclotomic_square_in_place;
+use super::quadratic_extension::*;
+use crate::fields::{fp9_3over3::*, Fp3, Fp3Parameters};
+use core::marker::PhantomData;
+use core::ops::{AddAssign, SubAssign};
+
+type Fp3Params<P> = <<P as Fp18Parameters>::Fp9Params as Fp9Parameters>::Fp3Params;
+
+pub trait Fp18Parameters: 'static + Send + Sync + Copy {
+    type Fp9Params: Fp9Parameters;
+
+    /// This *must* equal (0, 1, 0), so that `z^2` is the element `v` that
+    /// generates `Fp9` over `Fp3`.
+    const NONRESIDUE: Fp9<Self::Fp9Params>;
+
+    /// Coefficients for the Frobenius automorphism.
+    /// non_residue^((modulus^i-1)/2) for i=0,...,17, which lie in `Fp3` when
+    /// `modulus = 1 mod 6`.
+    const FROBENIUS_COEFF_FP18_C1: &'static [Fp3<Fp3Params<Self>>];
+
+    /// Multiply by quadratic nonresidue v.
+    #[inline(always)]
+    fn mul_fp9_by_nonresidue(fe: &Fp9<Self::Fp9Params>) -> Fp9<Self::Fp9Params> {
+        // (c0 + c1 * v + c2 * v^2) * v = c2 * u + c0 * v + c1 * v^2, where
+        // v^3 = u.
+        let new_c0 = Self::Fp9Params::mul_fp3_by_nonresidue(&fe.c2);
+        let new_c1 = fe.c0;
+        let new_c2 = fe.c1;
+        Fp9::new(new_c0, new_c1, new_c2)
+    }
+}
+
+pub struct Fp18ParamsWrapper<P: Fp18Parameters>(PhantomData<P>);
+
+impl<P: Fp18Parameters> QuadExtParameters for Fp18ParamsWrapper<P> {
+    type BasePrimeField = <Fp3Params<P> as Fp3Parameters>::Fp;
+    type BaseField = Fp9<P::Fp9Params>;
+    type FrobCoeff = Fp3<Fp3Params<P>>;
+
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize = 18;
+
+    const NONRESIDUE: Self::BaseField = P::NONRESIDUE;
+
+    const FROBENIUS_COEFF_C1: &'static [Self::FrobCoeff] = P::FROBENIUS_COEFF_FP18_C1;
+
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue(fe: &Self::BaseField) -> Self::BaseField {
+        P::mul_fp9_by_nonresidue(fe)
+    }
+
+    fn mul_base_field_by_frob_coeff(fe: &mut Self::BaseField, power: usize) {
+        fe.mul_by_fp3(&Self::FROBENIUS_COEFF_C1[power % Self::DEGREE_OVER_BASE_PRIME_FIELD]);
+    }
+
+    fn cyclotomic_square(fe: &Fp18<P>) -> Fp18<P> {
+        fe.cyclotomic_square()
+    }
+}
+
+pub type Fp18<P> = QuadExtField<Fp18ParamsWrapper<P>>;
+
+impl<P: Fp18Parameters> Fp18<P> {
+    pub fn mul_by_fp(&mut self, element: &<Fp3Params<P> as Fp3Parameters>::Fp) {
+        self.c0.mul_by_fp(element);
+        self.c1.mul_by_fp(element);
+    }
+
+    pub fn mul_by_034(
+        &mut self,
+        c0: &Fp3<Fp3Params<P>>,
+        c3: &Fp3<Fp3Params<P>>,
+        c4: &Fp3<Fp3Params<P>>,
+    ) {
+        let mut a = self.c0;
+        a.mul_by_fp3(c0);
+        let mut b = self.c1;
+        b.mul_by_01(&c3, &c4);
+
+        let c0 = *c0 + c3;
+        let c1 = c4;
+        let mut e = self.c0 + &self.c1;
+        e.mul_by_01(&c0, &c1);
+        self.c1 = e - &(a + &b);
+        self.c0 = a + &P::mul_fp9_by_nonresidue(&b);
+    }
+
+    pub fn mul_by_014(
+        &mut self,
+        c0: &Fp3<Fp3Params<P>>,
+        c1: &Fp3<Fp3Params<P>>,
+        c4: &Fp3<Fp3Params<P>>,
+    ) {
+        let mut aa = self.c0;
+        aa.mul_by_01(c0, c1);
+        let mut bb = self.c1;
+        bb.mul_by_1(c4);
+        let mut o = *c1;
+        o.add_assign(c4);
+        self.c1.add_assign(&self.c0);
+        self.c1.mul_by_01(c0, &o);
+        self.c1.sub_assign(&aa);
+        self.c1.sub_assign(&bb);
+        self.c0 = bb;
+        self.c0 = P::mul_fp9_by_nonresidue(&self.c0);
+        self.c0.add_assign(&aa);
+    }
+
+    /// `Fp18` is a sixth degree extension of `Fp3`, and the cyclotomic
+    /// subgroup of order `p^6 - p^3 + 1` is the one of that extension.
+    pub fn cyclotomic_square_in_place(&mut self) {
+        cyclotomic_square_in_place(&mut self.c0, &mut self.c1);
+    }
+
+    pub fn cyclotomic_square(&self) -> Self {
+        let mut result = *self;
+        result.cyclotomic_square_in_place();
+        result
+    }
+}
@@ -0,0 +1,123 @@
+use super::cubic_extension::cyclotomic_square_in_place;
+use super::quadratic_extension::*;
+use crate::fields::{
+    fp12_3over2over2::{Fp12, Fp12Parameters},
+    Fp2, Fp2Parameters, Fp4, Fp4Parameters,
+};
+use core::marker::PhantomData;
+use core::ops::{AddAssign, SubAssign};
+
+type Fp4Params<P> = <<P as Fp24Parameters>::Fp12Params as Fp12Parameters>::Fp4Params;
+type Fp2Params<P> = <Fp4Params<P> as Fp4Parameters>::Fp2Params;
+
+pub trait Fp24Parameters: 'static + Send + Sync + Copy {
+    type Fp12Params: Fp12Parameters;
+
+    /// This *must* equal (0, 1, 0), so that `z^2` is the element `w` that
+    /// generates `Fp12` over `Fp4`.
+    const NONRESIDUE: Fp12<Self::Fp12Params>;
+
+    /// Coefficients for the Frobenius automorphism.
+    /// non_residue^((modulus^i-1)/2) for i=0,...,23, which lie in `Fp2` when
+    /// `modulus = 1 mod 12`.
+    const FROBENIUS_COEFF_FP24_C1: &'static [Fp2<Fp2Params<Self>>];
+
+    /// Multiply by quadratic nonresidue w.
+    #[inline(always)]
+    fn mul_fp12_by_nonresidue(fe: &Fp12<Self::Fp12Params>) -> Fp12<Self::Fp12Params> {
+        // (c0 + c1 * w + c2 * w^2) * w = c2 * v + c0 * w + c1 * w^2, where
+        // w^3 = v.
+        let new_c0 = Self::Fp12Params::mul_fp4_by_nonresidue(&fe.c2);
+        let new_c1 = fe.c0;
+        let new_c2 = fe.c1;
+        Fp12::new(new_c0, new_c1, new_c2)
+    }
+}
+
+pub struct Fp24ParamsWrapper<P: Fp24Parameters>(PhantomData<P>);
+
+impl<P: Fp24Parameters> QuadExtParameters for Fp24ParamsWrapper<P> {
+    type BasePrimeField = <Fp2Params<P> as Fp2Parameters>::Fp;
+    type BaseField = Fp12<P::Fp12Params>;
+    type FrobCoeff = Fp2<Fp2Params<P>>;
+
+    const DEGREE_OVER_BASE_PRIME_FIELD: usize = 24;
+
+    const NONRESIDUE: Self::BaseField = P::NONRESIDUE;
+
+    const FROBENIUS_COEFF_C1: &'static [Self::FrobCoeff] = P::FROBENIUS_COEFF_FP24_C1;
+
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue(fe: &Self::BaseField) -> Self::BaseField {
+        P::mul_fp12_by_nonresidue(fe)
+    }
+
+    fn mul_base_field_by_frob_coeff(fe: &mut Self::BaseField, power: usize) {
+        fe.mul_by_fp2(&Self::FROBENIUS_COEFF_C1[power % Self::DEGREE_OVER_BASE_PRIME_FIELD]);
+    }
+
+    fn cyclotomic_square(fe: &Fp24<P>) -> Fp24<P> {
+        fe.cyclotomic_square()
+    }
+}
+
+pub type Fp24<P> = QuadExtField<Fp24ParamsWrapper<P>>;
+
+impl<P: Fp24Parameters> Fp24<P> {
+    pub fn mul_by_fp(&mut self, element: &<Fp2Params<P> as Fp2Parameters>::Fp) {
+        self.c0.mul_by_fp(element);
+        self.c1.mul_by_fp(element);
+    }
+
+    pub fn mul_by_034(
+        &mut self,
+        c0: &Fp4<Fp4Params<P>>,
+        c3: &Fp4<Fp4Params<P>>,
+        c4: &Fp4<Fp4Params<P>>,
+    ) {
+        let mut a = self.c0;
+        a.mul_by_fp4(c0);
+        let mut b = self.c1;
+        b.mul_by_01(&c3, &c4);
+
+        let c0 = *c0 + c3;
+        let c1 = c4;
+        let mut e = self.c0 + &self.c1;
+        e.mul_by_01(&c0, &c1);
+        self.c1 = e - &(a + &b);
+        self.c0 = a + &P::mul_fp12_by_nonresidue(&b);
+    }
+
+    pub fn mul_by_014(
+        &mut self,
+        c0: &Fp4<Fp4Params<P>>,
+        c1: &Fp4<Fp4Params<P>>,
+        c4: &Fp4<Fp4Params<P>>,
+    ) {
+        let mut aa = self.c0;
+        aa.mul_by_01(c0, c1);
+        let mut bb = self.c1;
+        bb.mul_by_1(c4);
+        let mut o = *c1;
+        o.add_assign(c4);
+        self.c1.add_assign(&self.c0);
+        self.c1.mul_by_01(c0, &o);
+        self.c1.sub_assign(&aa);
+        self.c1.sub_assign(&bb);
+        self.c0 = bb;
+        self.c0 = P::mul_fp12_by_nonresidue(&self.c0);
+        self.c0.add_assign(&aa);
+    }
+
+    /// `Fp24` is a sixth degree extension of `Fp4`, and the cyclotomic
+    /// subgroup of order `p^8 - p^4 + 1` is the one of that extension.
+    pub fn cyclotomic_square_in_place(&mut self) {
+        cyclotomic_square_in_place(&mut self.c0, &mut self.c1);
+    }
+
+    pub fn cyclotomic_square(&self) -> Self {
+        let mut result = *self;
+        result.cyclotomic_square_in_place();
+        result
+    }
+}
@@ -90,3 +90,19 @@ impl<P: Fp3Parameters> SquareRootField for Fp3<P> {
         })
     }
 }
+
+#[cfg(feature = "ct")]
+impl<P: Fp3Parameters> ConstantTimeSqrt for Fp3<P>
+where
+    P::Fp: ConstantTimeField,
+{
+    #[inline]
+    fn ct_sqrt(&self) -> CtOption<Self> {
+        ct_tonelli_shanks(
+            self,
+            P::TWO_ADICITY,
+            P::T_MINUS_ONE_DIV_TWO,
+            Self::qnr_to_t(),
+        )
+    }
+}
@@ -1,72 +1,10 @@
-use ark_std::{
-    cmp::{Ord, Ordering, PartialOrd},
-    fmt::{Display, Formatter, Result as FmtResult},
-    io::{Read, Result as IoResult, Write},
-    marker::PhantomData,
-    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
-    str::FromStr,
-};
-use num_traits::{One, Zero};
+pub mod fp;
+pub use self::fp::*;
 
-use crate::{
-    biginteger::{
-        arithmetic as fa, BigInteger as _BigInteger, BigInteger256, BigInteger320, BigInteger384,
-        BigInteger448, BigInteger64, BigInteger768, BigInteger832,
-    },
-    bytes::{FromBytes, ToBytes},
-    fields::{FftField, Field, FpParameters, LegendreSymbol, PrimeField, SquareRootField},
-};
-use ark_serialize::*;
+mod montgomery_u32;
 
-impl_Fp!(Fp64, Fp64Parameters, BigInteger64, BigInteger64, 1, "64");
-impl_Fp!(
-    Fp256,
-    Fp256Parameters,
-    BigInteger256,
-    BigInteger256,
-    4,
-    "256"
-);
-impl_Fp!(
-    Fp320,
-    Fp320Parameters,
-    BigInteger320,
-    BigInteger320,
-    5,
-    "320"
-);
-impl_Fp!(
-    Fp384,
-    Fp384Parameters,
-    BigInteger384,
-    BigInteger384,
-    6,
-    "384"
-);
-impl_Fp!(
-    Fp448,
-    Fp448Parameters,
-    BigInteger448,
-    BigInteger448,
-    7,
-    "448"
-);
-impl_Fp!(
-    Fp768,
-    Fp768Parameters,
-    BigInteger768,
-    BigInteger768,
-    12,
-    "768"
-);
-impl_Fp!(
-    Fp832,
-    Fp832Parameters,
-    BigInteger832,
-    BigInteger832,
-    13,
-    "832"
-);
+pub mod small_fp;
+pub use self::small_fp::*;
 
 pub mod fp2;
 pub use self::fp2::*;
@@ -82,9 +20,20 @@ pub mod fp6_2over3;
 pub mod fp6_3over2;
 pub use self::fp6_3over2::*;
 
+pub mod fp9_3over3;
+pub use self::fp9_3over3::*;
+
 pub mod fp12_2over3over2;
 pub use self::fp12_2over3over2::*;
 
+pub mod fp12_3over2over2;
+
+pub mod fp18_2over3over3;
+pub use self::fp18_2over3over3::*;
+
+pub mod fp24_2over3over2over2;
+pub use self::fp24_2over3over2over2::*;
+
 pub mod quadratic_extension;
 pub use quadratic_extension::*;
 
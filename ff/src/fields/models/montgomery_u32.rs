@@ -0,0 +1,282 @@
+//! Montgomery arithmetic on 32-bit words, for targets without a fast
+//! `u64 * u64 -> u128` multiplication such as wasm32 and 32-bit
+//! microcontrollers.
+//!
+//! Field elements keep their `[u64; N]` representation; each limb is split
+//! into two words on entry and joined back on exit. Since `R = 2^(64 * N)`
+//! is also `2^(32 * 2N)`, and `P::INV` truncated to 32 bits is `-MODULUS^-1`
+//! modulo `2^32`, the results are exactly those of the `u64` code.
+
+use ark_std::ops::{Index, IndexMut};
+
+/// Calculates `a + b + carry`, returning the sum and setting `carry` to the
+/// carry-out.
+#[inline(always)]
+fn adc(a: u32, b: u32, carry: &mut u32) -> u32 {
+    let tmp = u64::from(a) + u64::from(b) + u64::from(*carry);
+    *carry = (tmp >> 32) as u32;
+    tmp as u32
+}
+
+/// Calculates `a + b * c + carry`, returning the least significant word and
+/// setting `carry` to the most significant one.
+#[inline(always)]
+fn mac_with_carry(a: u32, b: u32, c: u32, carry: &mut u32) -> u32 {
+    let tmp = u64::from(a) + u64::from(b) * u64::from(c) + u64::from(*carry);
+    *carry = (tmp >> 32) as u32;
+    tmp as u32
+}
+
+/// `K * N` little-endian 32-bit words, laid out as `K` arrays of `N` words
+/// since `[u32; 2 * N]` cannot be written with const generics.
+#[derive(Copy, Clone)]
+struct Words<const N: usize, const K: usize>([[u32; N]; K]);
+
+impl<const N: usize, const K: usize> Words<N, K> {
+    #[inline(always)]
+    const fn zeroed() -> Self {
+        Self([[0u32; N]; K])
+    }
+}
+
+impl<const N: usize> Words<N, 2> {
+    #[inline(always)]
+    fn from_limbs(limbs: &[u64; N]) -> Self {
+        let mut words = Self::zeroed();
+        for (i, limb) in limbs.iter().enumerate() {
+            words[2 * i] = *limb as u32;
+            words[2 * i + 1] = (*limb >> 32) as u32;
+        }
+        words
+    }
+
+    #[inline(always)]
+    fn into_limbs(self) -> [u64; N] {
+        let mut limbs = [0u64; N];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            *limb = u64::from(self[2 * i]) | (u64::from(self[2 * i + 1]) << 32);
+        }
+        limbs
+    }
+}
+
+impl<const N: usize, const K: usize> Index<usize> for Words<N, K> {
+    type Output = u32;
+
+    #[inline(always)]
+    fn index(&self, index: usize) -> &u32 {
+        &self.0[index / N][index % N]
+    }
+}
+
+impl<const N: usize, const K: usize> IndexMut<usize> for Words<N, K> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: usize) -> &mut u32 {
+        &mut self.0[index / N][index % N]
+    }
+}
+
+/// Subtracts `modulus` from `r + hi * 2^(64 * N)` if the result is not
+/// negative, given that `r + hi * 2^(64 * N) < 2 * modulus`. The choice is
+/// made with a mask, as in `Fp::const_mul`.
+#[inline(always)]
+fn sub_modulus_if_needed<const N: usize>(r: Words<N, 2>, hi: u32, modulus: &[u64; N]) -> [u64; N] {
+    let mut r = r.into_limbs();
+    let mut reduced = r;
+    let mut borrow = false;
+    for (limb, m) in reduced.iter_mut().zip(modulus) {
+        let (tmp, borrow1) = limb.overflowing_sub(*m);
+        let (tmp, borrow2) = tmp.overflowing_sub(borrow as u64);
+        *limb = tmp;
+        borrow = borrow1 | borrow2;
+    }
+    let mask = 0u64.wrapping_sub(u64::from(hi != 0) | u64::from(!borrow));
+    for (limb, reduced) in r.iter_mut().zip(&reduced) {
+        *limb = (reduced & mask) | (*limb & !mask);
+    }
+    r
+}
+
+/// Returns `a * b / R mod modulus` for `a, b < modulus`, using the CIOS
+/// method on `2 * N` words like `Fp::const_mul`.
+#[inline]
+pub(crate) fn mul<const N: usize>(
+    a: &[u64; N],
+    b: &[u64; N],
+    modulus: &[u64; N],
+    inv: u64,
+) -> [u64; N] {
+    let a = Words::<N, 2>::from_limbs(a);
+    let b = Words::<N, 2>::from_limbs(b);
+    let m = Words::<N, 2>::from_limbs(modulus);
+    let inv = inv as u32;
+    let words = 2 * N;
+
+    let mut r = Words::<N, 2>::zeroed();
+    let mut hi = 0u32;
+    for i in 0..words {
+        // r += a * b[i]
+        let mut carry = 0;
+        for j in 0..words {
+            r[j] = mac_with_carry(r[j], a[j], b[i], &mut carry);
+        }
+        hi = adc(hi, 0, &mut carry);
+        let top = carry;
+
+        // r = (r + k * modulus) / 2^32
+        let k = r[0].wrapping_mul(inv);
+        let mut carry = 0;
+        mac_with_carry(r[0], k, m[0], &mut carry);
+        for j in 1..words {
+            r[j - 1] = mac_with_carry(r[j], k, m[j], &mut carry);
+        }
+        r[words - 1] = adc(hi, 0, &mut carry);
+        hi = top + carry;
+    }
+    sub_modulus_if_needed(r, hi, modulus)
+}
+
+/// Returns `a^2 / R mod modulus` for `a < modulus`. The cross products are
+/// computed once and doubled, and the `4 * N`-word square is then reduced
+/// one word at a time.
+#[inline]
+pub(crate) fn square<const N: usize>(a: &[u64; N], modulus: &[u64; N], inv: u64) -> [u64; N] {
+    let a = Words::<N, 2>::from_limbs(a);
+    let m = Words::<N, 2>::from_limbs(modulus);
+    let inv = inv as u32;
+    let words = 2 * N;
+
+    // Cross products a[i] * a[j] for i < j, which end below word 2 * words - 1.
+    let mut t = Words::<N, 4>::zeroed();
+    for i in 0..words - 1 {
+        let mut carry = 0;
+        for j in i + 1..words {
+            t[i + j] = mac_with_carry(t[i + j], a[i], a[j], &mut carry);
+        }
+        t[i + words] = carry;
+    }
+    // Double them, then add the squares a[i]^2.
+    t[2 * words - 1] = t[2 * words - 2] >> 31;
+    for i in (1..2 * words - 1).rev() {
+        t[i] = (t[i] << 1) | (t[i - 1] >> 31);
+    }
+    t[0] <<= 1;
+    let mut carry = 0;
+    for i in 0..words {
+        t[2 * i] = mac_with_carry(t[2 * i], a[i], a[i], &mut carry);
+        t[2 * i + 1] = adc(t[2 * i + 1], 0, &mut carry);
+    }
+
+    // Montgomery reduction, with `carry2` collecting the carries out of the
+    // upper half.
+    let mut carry2 = 0;
+    for i in 0..words {
+        let k = t[i].wrapping_mul(inv);
+        let mut carry = 0;
+        mac_with_carry(t[i], k, m[0], &mut carry);
+        for j in 1..words {
+            t[i + j] = mac_with_carry(t[i + j], k, m[j], &mut carry);
+        }
+        t[i + words] = adc(t[i + words], carry2, &mut carry);
+        carry2 = carry;
+    }
+    let mut r = Words::<N, 2>::zeroed();
+    for i in 0..words {
+        r[i] = t[i + words];
+    }
+    sub_modulus_if_needed(r, carry2, modulus)
+}
+
+/// Returns `a / R mod modulus`, the canonical representative of the field
+/// element with Montgomery form `a`, like `Fp::into_repr`.
+#[inline]
+pub(crate) fn from_montgomery<const N: usize>(
+    a: &[u64; N],
+    modulus: &[u64; N],
+    inv: u64,
+) -> [u64; N] {
+    let mut r = Words::<N, 2>::from_limbs(a);
+    let m = Words::<N, 2>::from_limbs(modulus);
+    let inv = inv as u32;
+    let words = 2 * N;
+
+    for i in 0..words {
+        let k = r[i].wrapping_mul(inv);
+        let mut carry = 0;
+        mac_with_carry(r[i], k, m[0], &mut carry);
+        for j in 1..words {
+            r[(i + j) % words] = mac_with_carry(r[(i + j) % words], k, m[j], &mut carry);
+        }
+        r[i] = carry;
+    }
+    r.into_limbs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        biginteger::BigInt,
+        fields::FpParameters,
+        test_field::{Fq, FqParameters, Fr, FrParameters},
+        UniformRand,
+    };
+    use ark_std::{test_rng, vec::Vec};
+
+    const ITERATIONS: usize = 1000;
+
+    /// Returns `a * b mod modulus` with `BigInt` arithmetic, where `M = 2 * N`.
+    fn mul_mod<const N: usize, const M: usize>(
+        a: &[u64; N],
+        b: &[u64; N],
+        modulus: &[u64; N],
+    ) -> [u64; N] {
+        let widen = |x: &[u64; N]| {
+            let mut wide = BigInt::<M>::new([0u64; M]);
+            wide.0[..N].copy_from_slice(x);
+            wide
+        };
+        let product = widen(a).wrapping_mul(&widen(b));
+        let remainder = product.div_rem(&widen(modulus)).1;
+        let mut result = [0u64; N];
+        result.copy_from_slice(&remainder.0[..N]);
+        result
+    }
+
+    /// Checks the functions against `BigInt` arithmetic modulo
+    /// `P::MODULUS`, which does not go through `Fp` and so also holds when
+    /// `Fp` uses this module.
+    fn montgomery_test<P, F, const N: usize, const M: usize>()
+    where
+        P: FpParameters<BigInt = BigInt<N>>,
+        F: UniformRand + Into<BigInt<N>>,
+    {
+        let modulus = &P::MODULUS.0;
+        let r_inv = P::R.mod_inverse(&P::MODULUS).unwrap().0;
+        let mut rng = test_rng();
+        let mut elements: Vec<[u64; N]> = (0..ITERATIONS)
+            .map(|_| F::rand(&mut rng).into().0)
+            .collect();
+        elements.push(P::MODULUS.wrapping_sub(&BigInt::from(1)).0);
+        elements.push([0u64; N]);
+        for (a, b) in elements.iter().zip(elements.iter().rev()) {
+            let mont_mul = |a, b| mul_mod::<N, M>(&mul_mod::<N, M>(a, b, modulus), &r_inv, modulus);
+            assert_eq!(mul(a, b, modulus, P::INV), mont_mul(a, b));
+            assert_eq!(square(a, modulus, P::INV), mont_mul(a, a));
+            assert_eq!(
+                from_montgomery(a, modulus, P::INV),
+                mul_mod::<N, M>(a, &r_inv, modulus)
+            );
+        }
+    }
+
+    #[test]
+    fn test_fr() {
+        montgomery_test::<FrParameters, Fr, 4, 8>();
+    }
+
+    #[test]
+    fn test_fq() {
+        montgomery_test::<FqParameters, Fq, 6, 12>();
+    }
+}
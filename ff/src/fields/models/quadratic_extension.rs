@@ -25,6 +25,11 @@ use crate::{
     ToConstraintField, UniformRand,
 };
 
+#[cfg(feature = "ct")]
+use crate::fields::constant_time::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeField, ConstantTimeSqrt, CtOption,
+};
+
 /// Defines a Quadratic extension field from a quadratic non-residue.
 pub trait QuadExtParameters: 'static + Send + Sync + Sized {
     /// The prime field that this quadratic extension is eventually an extension of.
@@ -90,8 +95,16 @@ pub trait QuadExtParameters: 'static + Send + Sync + Sized {
     /// the appropriate Frobenius coefficient.
     fn mul_base_field_by_frob_coeff(fe: &mut Self::BaseField, power: usize);
 
+    /// A specializable method for squaring that is to be used *only* when
+    /// `fe` is known to be in the cyclotomic subgroup.
+    fn cyclotomic_square(fe: &QuadExtField<Self>) -> QuadExtField<Self> {
+        fe.square()
+    }
+
     /// A specializable method for exponentiating that is to be used
-    /// *only* when `fe` is known to be in the cyclotommic subgroup.
+    /// *only* when `fe` is known to be in the cyclotommic subgroup. By
+    /// default this squares with `Self::cyclotomic_square`, so that towers
+    /// only need to specialize the squaring.
     fn cyclotomic_exp(fe: &QuadExtField<Self>, exponent: impl AsRef<[u64]>) -> QuadExtField<Self> {
         let mut res = QuadExtField::one();
         let mut self_inverse = fe.clone();
@@ -102,7 +115,7 @@ pub trait QuadExtParameters: 'static + Send + Sync + Sized {
 
         for &value in naf.iter().rev() {
             if found_nonzero {
-                res.square_in_place();
+                res = Self::cyclotomic_square(&res);
             }
 
             if value != 0 {
@@ -213,6 +226,12 @@ impl<P: QuadExtParameters> Field for QuadExtField<P> {
         ))
     }
 
+    fn to_base_prime_field_elems(&self) -> Vec<Self::BasePrimeField> {
+        let mut elems = self.c0.to_base_prime_field_elems();
+        elems.extend(self.c1.to_base_prime_field_elems());
+        elems
+    }
+
     fn double(&self) -> Self {
         let mut result = *self;
         result.double_in_place();
@@ -401,6 +420,93 @@ where
     }
 }
 
+#[cfg(feature = "ct")]
+impl<P: QuadExtParameters> ConstantTimeEq for QuadExtField<P>
+where
+    P::BaseField: ConstantTimeEq,
+{
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: QuadExtParameters> ConditionallySelectable for QuadExtField<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            P::BaseField::conditional_select(&a.c0, &b.c0, choice),
+            P::BaseField::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: QuadExtParameters> ConstantTimeField for QuadExtField<P>
+where
+    P::BaseField: ConstantTimeField,
+{
+    fn ct_inverse(&self) -> CtOption<Self> {
+        // Same as `Field::inverse`, with the inversion in the base field
+        // performed in constant time.
+        let v1 = self.c1.square();
+        let v0 = P::sub_and_mul_base_field_by_nonresidue(&self.c0.square(), &v1);
+        v0.ct_inverse().map(|v1| {
+            let c0 = self.c0 * &v1;
+            let c1 = -(self.c1 * &v1);
+            Self::new(c0, c1)
+        })
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: QuadExtParameters> ConstantTimeSqrt for QuadExtField<P>
+where
+    P::BaseField: ConstantTimeSqrt,
+{
+    fn ct_sqrt(&self) -> CtOption<Self> {
+        // The complex method used by `SquareRootField::sqrt`, where every
+        // candidate is computed and the right one is selected at the end.
+        let zero = P::BaseField::zero();
+        let two_inv = P::BaseField::one()
+            .double()
+            .inverse()
+            .expect("Two should always have an inverse");
+        let nonresidue_inv = P::NONRESIDUE
+            .inverse()
+            .expect("The nonresidue should always have an inverse");
+
+        // If `c1 == 0`, the root is either `sqrt(c0)` or `sqrt(c0 / NONRESIDUE) * u`.
+        let c0_sqrt = self.c0.ct_sqrt();
+        let c0_over_nonresidue_sqrt = (self.c0 * &nonresidue_inv).ct_sqrt();
+        let c1_zero_cand = Self::conditional_select(
+            &Self::new(zero, c0_over_nonresidue_sqrt.unwrap_or(zero)),
+            &Self::new(c0_sqrt.unwrap_or(zero), zero),
+            c0_sqrt.is_some(),
+        );
+
+        // Otherwise, the root is `c0 + c1 / (2 * c0) * u`, where `c0^2` is
+        // one of `(c0 +- sqrt(norm)) / 2`.
+        let alpha = self.norm().ct_sqrt().unwrap_or(zero);
+        let delta = (alpha + &self.c0) * &two_inv;
+        let delta_sqrt = delta.ct_sqrt();
+        let c0 = P::BaseField::conditional_select(
+            &(delta - &alpha).ct_sqrt().unwrap_or(zero),
+            &delta_sqrt.unwrap_or(zero),
+            delta_sqrt.is_some(),
+        );
+        let c0_inv = c0.ct_inverse().unwrap_or(zero);
+        let cand = Self::new(c0, self.c1 * &two_inv * &c0_inv);
+
+        let sqrt_cand = Self::conditional_select(&cand, &c1_zero_cand, self.c1.ct_is_zero());
+        CtOption::new(sqrt_cand, sqrt_cand.square().ct_eq(self))
+    }
+}
+
 /// `QuadExtField` elements are ordered lexicographically.
 impl<P: QuadExtParameters> Ord for QuadExtField<P> {
     #[inline(always)]
@@ -0,0 +1,54 @@
+use crate::{
+    biginteger::BigInteger64 as BigInteger,
+    fields::{FftParameters, FpParameters, SmallFp, SmallFpParameters},
+};
+
+/// The BabyBear field, of order `2^31 - 2^27 + 1`.
+pub type BabyBear = SmallFp<BabyBearParameters>;
+
+pub struct BabyBearParameters;
+
+impl SmallFpParameters for BabyBearParameters {
+    /// Products fit into 64 bits, and the division by the constant modulus
+    /// is compiled to a multiplication by its reciprocal.
+    #[inline(always)]
+    fn reduce(x: u128) -> u64 {
+        (x as u64) % Self::MODULUS.0[0]
+    }
+}
+
+impl FftParameters for BabyBearParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 27;
+
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T = 440564289
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger::new([0x2c4f8ad3]);
+}
+
+impl FpParameters for BabyBearParameters {
+    /// MODULUS = 2013265921
+    const MODULUS: BigInteger = BigInteger::new([0x78000001]);
+
+    const MODULUS_BITS: u32 = 31;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 33;
+
+    const R: BigInteger = BigInteger::new([0x45dddde3]);
+
+    const R2: BigInteger = BigInteger::new([0x27922ab6]);
+
+    const INV: u64 = 0xc7c0000077ffffff;
+
+    /// GENERATOR = 31
+    const GENERATOR: BigInteger = BigInteger::new([0x5ddde6b]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger::new([0x3c000000]);
+
+    /// T = (MODULUS - 1) / 2^27
+    const T: BigInteger = BigInteger::new([15]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger::new([7]);
+}
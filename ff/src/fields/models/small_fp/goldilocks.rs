@@ -0,0 +1,75 @@
+use crate::{
+    biginteger::BigInteger64 as BigInteger,
+    fields::{FftParameters, FpParameters, SmallFp, SmallFpParameters},
+};
+
+/// The Goldilocks field, of order `2^64 - 2^32 + 1`.
+pub type Goldilocks = SmallFp<GoldilocksParameters>;
+
+pub struct GoldilocksParameters;
+
+/// `2^64 - MODULUS = 2^32 - 1`.
+const EPSILON: u64 = 0xffffffff;
+
+impl SmallFpParameters for GoldilocksParameters {
+    /// Splits `x` into `x_lo + 2^64 * x_hi_lo + 2^96 * x_hi_hi`, and uses
+    /// `2^64 = 2^32 - 1` and `2^96 = -1` modulo `MODULUS`. This works for any
+    /// `x`.
+    #[inline(always)]
+    fn reduce(x: u128) -> u64 {
+        let x_lo = x as u64;
+        let x_hi = (x >> 64) as u64;
+        let x_hi_hi = x_hi >> 32;
+        let x_hi_lo = x_hi & EPSILON;
+
+        // t0 = x_lo - x_hi_hi, where a borrow of 2^64 is corrected by
+        // subtracting EPSILON. This cannot underflow again, since `x_hi_hi`
+        // is less than 2^32.
+        let (t0, borrow) = x_lo.overflowing_sub(x_hi_hi);
+        let t0 = t0.wrapping_sub(0u32.wrapping_sub(borrow as u32) as u64);
+        // t1 = x_hi_lo * (2^32 - 1), which fits into 64 bits.
+        let t1 = x_hi_lo * EPSILON;
+        // Likewise, a carry of 2^64 is corrected by adding EPSILON. This
+        // cannot overflow, since the sum wrapped around.
+        let (t2, carry) = t0.overflowing_add(t1);
+        let t2 = t2.wrapping_add(0u32.wrapping_sub(carry as u32) as u64);
+
+        Goldilocks::sub_modulus_if_needed(t2, false)
+    }
+}
+
+impl FftParameters for GoldilocksParameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 32;
+
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T = 1753635133440165772
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger::new([0xda58878b0d514e98]);
+}
+
+impl FpParameters for GoldilocksParameters {
+    /// MODULUS = 18446744069414584321
+    const MODULUS: BigInteger = BigInteger::new([0xffffffff00000001]);
+
+    const MODULUS_BITS: u32 = 64;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 0;
+
+    const R: BigInteger = BigInteger::new([0xffffffff]);
+
+    const R2: BigInteger = BigInteger::new([0xfffffffe00000001]);
+
+    const INV: u64 = 0xfffffffeffffffff;
+
+    /// GENERATOR = 7
+    const GENERATOR: BigInteger = BigInteger::new([0x6fffffff9]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger::new([0x7fffffff80000000]);
+
+    /// T = (MODULUS - 1) / 2^32
+    const T: BigInteger = BigInteger::new([0xffffffff]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger::new([0x7fffffff]);
+}
@@ -0,0 +1,63 @@
+use crate::{
+    biginteger::BigInteger64 as BigInteger,
+    fields::{FftParameters, FpParameters, SmallFp, SmallFpParameters},
+};
+
+/// The Mersenne-31 field, of order `2^31 - 1`.
+///
+/// Its multiplicative group has two-adicity 1, so it does not support
+/// radix-2 FFTs beyond size 2.
+pub type Mersenne31 = SmallFp<Mersenne31Parameters>;
+
+pub struct Mersenne31Parameters;
+
+impl SmallFpParameters for Mersenne31Parameters {
+    /// Folds the bits above the 31st onto the lower ones twice, using
+    /// `2^31 = 1` modulo `MODULUS`.
+    #[inline(always)]
+    fn reduce(x: u128) -> u64 {
+        const MODULUS: u64 = Mersenne31Parameters::MODULUS.0[0];
+        // `x < 2^62`, so this is less than 2^32, and the next fold is at
+        // most `MODULUS + 1`.
+        let x = x as u64;
+        let x = (x & MODULUS) + (x >> 31);
+        let x = (x & MODULUS) + (x >> 31);
+        Mersenne31::sub_modulus_if_needed(x, false)
+    }
+}
+
+impl FftParameters for Mersenne31Parameters {
+    type BigInt = BigInteger;
+
+    const TWO_ADICITY: u32 = 1;
+
+    /// TWO_ADIC_ROOT_OF_UNITY = GENERATOR^T = MODULUS - 1
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger::new([0x7ffffffb]);
+}
+
+impl FpParameters for Mersenne31Parameters {
+    /// MODULUS = 2147483647
+    const MODULUS: BigInteger = BigInteger::new([0x7fffffff]);
+
+    const MODULUS_BITS: u32 = 31;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 33;
+
+    const R: BigInteger = BigInteger::new([0x4]);
+
+    const R2: BigInteger = BigInteger::new([0x10]);
+
+    const INV: u64 = 0x4000000080000001;
+
+    /// GENERATOR = 7
+    const GENERATOR: BigInteger = BigInteger::new([0x1c]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger::new([0x3fffffff]);
+
+    /// T = (MODULUS - 1) / 2
+    const T: BigInteger = BigInteger::new([0x3fffffff]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger::new([0x1fffffff]);
+}
@@ -0,0 +1,731 @@
+//! Prime fields whose modulus fits into a single machine word and has a
+//! special form, such as the Goldilocks, Mersenne-31 and BabyBear fields.
+//!
+//! Unlike [`Fp64`](crate::fields::Fp64), elements of a [`SmallFp`] are kept in
+//! canonical form rather than in Montgomery form, and products are reduced by
+//! [`SmallFpParameters::reduce`], which exploits the shape of the modulus.
+//! The parameters are still [`FpParameters`], with the `GENERATOR`,
+//! `TWO_ADIC_ROOT_OF_UNITY` and `LARGE_SUBGROUP_ROOT_OF_UNITY` constants in
+//! Montgomery form as for every other field; they are converted to canonical
+//! form with `INV` when read.
+
+use ark_serialize::{
+    buffer_byte_size, CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, EmptyFlags, Flags, SerializationError,
+};
+use ark_std::{
+    cmp::{min, Ord, Ordering, PartialOrd},
+    fmt::{Display, Formatter, Result as FmtResult},
+    io::{Read, Result as IoResult, Write},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+    vec::Vec,
+};
+use num_traits::{One, Zero};
+
+use crate::{
+    biginteger::{BigInt, BigInteger64},
+    bytes::{FromBytes, ToBytes},
+    fields::{FftField, Field, FpParameters, LegendreSymbol, PrimeField, SquareRootField},
+};
+
+#[cfg(feature = "ct")]
+use crate::fields::constant_time::{
+    ct_tonelli_shanks, Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeField,
+    ConstantTimeSqrt, CtOption,
+};
+
+pub mod baby_bear;
+pub use self::baby_bear::*;
+
+pub mod goldilocks;
+pub use self::goldilocks::*;
+
+pub mod mersenne31;
+pub use self::mersenne31::*;
+
+#[cfg(test)]
+mod tests;
+
+/// Parameters of a prime field with a special-form modulus of at most 64 bits.
+pub trait SmallFpParameters: FpParameters<BigInt = BigInteger64> {
+    /// Returns `x % MODULUS`, for any `x < MODULUS^2`.
+    fn reduce(x: u128) -> u64;
+}
+
+/// Represents an element of the prime field F_p, where `p == P::MODULUS`, by
+/// its canonical representative in `[0, p)`.
+#[derive(Derivative)]
+#[derivative(
+    Default(bound = ""),
+    Hash(bound = ""),
+    Clone(bound = ""),
+    Copy(bound = ""),
+    Debug(bound = ""),
+    PartialEq(bound = ""),
+    Eq(bound = "")
+)]
+pub struct SmallFp<P>(
+    pub u64,
+    #[derivative(Debug = "ignore")]
+    #[doc(hidden)]
+    pub PhantomData<P>,
+);
+
+impl<P> SmallFp<P> {
+    /// Returns the element with canonical representative `value`, which must
+    /// be less than the modulus.
+    #[inline]
+    pub const fn new(value: u64) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Interpret a string of decimal numbers as a prime field element.
+    /// This has the same signature as `Fp::const_from_str`, so that `field_new`
+    /// works for both; the Montgomery constants are ignored.
+    /// For *internal* use only; please use the `field_new` macro instead
+    /// of this method
+    #[doc(hidden)]
+    pub const fn const_from_str(
+        limbs: &[u64],
+        is_positive: bool,
+        _r2: BigInteger64,
+        modulus: BigInteger64,
+        _inv: u64,
+    ) -> Self {
+        let modulus = modulus.0[0];
+        let value = if limbs.is_empty() {
+            0
+        } else {
+            limbs[0] % modulus
+        };
+        if is_positive || value == 0 {
+            Self::new(value)
+        } else {
+            Self::new(modulus - value)
+        }
+    }
+}
+
+impl<P: SmallFpParameters> SmallFp<P> {
+    const MODULUS: u64 = P::MODULUS.0[0];
+
+    /// Returns `x - MODULUS` if `carry` is set or `x >= MODULUS`, and `x`
+    /// otherwise. This reduces the sum of two canonical values, with `carry`
+    /// the carry out of the addition.
+    #[inline(always)]
+    #[cfg(not(feature = "ct"))]
+    fn sub_modulus_if_needed(x: u64, carry: bool) -> u64 {
+        let (reduced, borrow) = x.overflowing_sub(Self::MODULUS);
+        if carry || !borrow {
+            reduced
+        } else {
+            x
+        }
+    }
+
+    #[inline(always)]
+    #[cfg(feature = "ct")]
+    fn sub_modulus_if_needed(x: u64, carry: bool) -> u64 {
+        let (reduced, borrow) = x.overflowing_sub(Self::MODULUS);
+        u64::conditional_select(&x, &reduced, Choice::from((carry | !borrow) as u8))
+    }
+
+    /// Returns the canonical representative of the element with Montgomery
+    /// form `x`, that is `x * R^-1 mod MODULUS` for `R = 2^64`, like
+    /// `Fp::into_repr`. This is used for the constants of `P`.
+    #[inline]
+    fn from_montgomery(x: u64) -> u64 {
+        // `x + k * MODULUS` is divisible by 2^64, and the quotient is at most
+        // `MODULUS`.
+        let k = x.wrapping_mul(P::INV);
+        let t = (u128::from(x) + u128::from(k) * u128::from(Self::MODULUS)) >> 64;
+        Self::sub_modulus_if_needed(t as u64, false)
+    }
+}
+
+impl<P: SmallFpParameters> Zero for SmallFp<P> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(0)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<P: SmallFpParameters> One for SmallFp<P> {
+    #[inline]
+    fn one() -> Self {
+        Self::new(1)
+    }
+
+    #[inline]
+    fn is_one(&self) -> bool {
+        self.0 == 1
+    }
+}
+
+impl<P: SmallFpParameters> Field for SmallFp<P> {
+    type BasePrimeField = Self;
+
+    fn extension_degree() -> u64 {
+        1
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self::BasePrimeField]) -> Option<Self> {
+        if elems.len() != (Self::extension_degree() as usize) {
+            return None;
+        }
+        Some(elems[0])
+    }
+
+    fn to_base_prime_field_elems(&self) -> Vec<Self::BasePrimeField> {
+        vec![*self]
+    }
+
+    #[inline]
+    fn double(&self) -> Self {
+        *self + self
+    }
+
+    #[inline]
+    fn double_in_place(&mut self) -> &mut Self {
+        *self = self.double();
+        self
+    }
+
+    #[inline]
+    fn characteristic() -> &'static [u64] {
+        P::MODULUS.as_ref()
+    }
+
+    #[inline]
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+        if F::BIT_SIZE > 8 {
+            return None;
+        }
+        // The bytes of the element, followed by the byte after it.
+        let mut buffer = [0u8; 9];
+        let len = min(9, bytes.len());
+        buffer[..len].copy_from_slice(&bytes[..len]);
+
+        // The flags are in the last byte of the serialized form of the
+        // element, and only occupy its top `F::BIT_SIZE` bits.
+        let output_byte_size = buffer_byte_size(P::MODULUS_BITS as usize + F::BIT_SIZE);
+        let flags_mask = u8::MAX.checked_shl(8 - (F::BIT_SIZE as u32)).unwrap_or(0);
+        let flags = buffer[output_byte_size - 1] & flags_mask;
+
+        // Retain everything below `P::MODULUS_BITS`.
+        let mut limb = [0u8; 8];
+        limb.copy_from_slice(&buffer[..8]);
+        let value = u64::from_le_bytes(limb) & (u64::MAX >> P::REPR_SHAVE_BITS);
+
+        Self::from_repr(BigInt([value])).and_then(|f| F::from_u8(flags).map(|flag| (f, flag)))
+    }
+
+    #[inline]
+    fn square(&self) -> Self {
+        *self * self
+    }
+
+    #[inline]
+    fn square_in_place(&mut self) -> &mut Self {
+        *self = self.square();
+        self
+    }
+
+    /// Computes the inverse as `self^(MODULUS - 2)`, by Fermat's little
+    /// theorem.
+    #[inline]
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(self.pow([Self::MODULUS - 2]))
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        if let Some(inverse) = self.inverse() {
+            *self = inverse;
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    /// The Frobenius map has no effect in a prime field.
+    #[inline]
+    fn frobenius_map(&mut self, _: usize) {}
+}
+
+impl<P: SmallFpParameters> PrimeField for SmallFp<P> {
+    type Params = P;
+    type BigInt = BigInteger64;
+
+    #[inline]
+    fn from_repr(r: BigInteger64) -> Option<Self> {
+        if r.0[0] < Self::MODULUS {
+            Some(Self::new(r.0[0]))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn into_repr(&self) -> BigInteger64 {
+        BigInt::new([self.0])
+    }
+}
+
+impl<P: SmallFpParameters> FftField for SmallFp<P> {
+    type FftParams = P;
+
+    #[inline]
+    fn two_adic_root_of_unity() -> Self {
+        Self::new(Self::from_montgomery(P::TWO_ADIC_ROOT_OF_UNITY.0[0]))
+    }
+
+    #[inline]
+    fn large_subgroup_root_of_unity() -> Option<Self> {
+        Some(Self::new(Self::from_montgomery(
+            P::LARGE_SUBGROUP_ROOT_OF_UNITY?.0[0],
+        )))
+    }
+
+    #[inline]
+    fn multiplicative_generator() -> Self {
+        Self::new(Self::from_montgomery(P::GENERATOR.0[0]))
+    }
+}
+
+impl<P: SmallFpParameters> SquareRootField for SmallFp<P> {
+    #[inline]
+    fn legendre(&self) -> LegendreSymbol {
+        use crate::fields::LegendreSymbol::*;
+
+        // s = self^((MODULUS - 1) // 2)
+        let s = self.pow(P::MODULUS_MINUS_ONE_DIV_TWO);
+        if s.is_zero() {
+            Zero
+        } else if s.is_one() {
+            QuadraticResidue
+        } else {
+            QuadraticNonResidue
+        }
+    }
+
+    #[inline]
+    fn sqrt(&self) -> Option<Self> {
+        sqrt_impl!(Self, P, self)
+    }
+
+    fn sqrt_in_place(&mut self) -> Option<&mut Self> {
+        (*self).sqrt().map(|sqrt| {
+            *self = sqrt;
+            self
+        })
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: SmallFpParameters> ConstantTimeEq for SmallFp<P> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: SmallFpParameters> ConditionallySelectable for SmallFp<P> {
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(u64::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: SmallFpParameters> ConstantTimeField for SmallFp<P> {
+    fn ct_inverse(&self) -> CtOption<Self> {
+        CtOption::new(self.pow([Self::MODULUS - 2]), !self.ct_is_zero())
+    }
+}
+
+#[cfg(feature = "ct")]
+impl<P: SmallFpParameters> ConstantTimeSqrt for SmallFp<P> {
+    #[inline]
+    fn ct_sqrt(&self) -> CtOption<Self> {
+        ct_tonelli_shanks(
+            self,
+            P::TWO_ADICITY,
+            P::T_MINUS_ONE_DIV_TWO,
+            Self::qnr_to_t(),
+        )
+    }
+}
+
+/// Note that this implementation of `Ord` compares field elements viewing
+/// them as integers in the range 0, 1, ..., P::MODULUS - 1, like the one of
+/// `Fp`.
+impl<P: SmallFpParameters> Ord for SmallFp<P> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<P: SmallFpParameters> PartialOrd for SmallFp<P> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: SmallFpParameters> From<u128> for SmallFp<P> {
+    fn from(other: u128) -> Self {
+        Self::new((other % u128::from(Self::MODULUS)) as u64)
+    }
+}
+
+impl<P: SmallFpParameters> From<i128> for SmallFp<P> {
+    fn from(other: i128) -> Self {
+        let abs = Self::from(other.unsigned_abs());
+        if other.is_positive() {
+            abs
+        } else {
+            -abs
+        }
+    }
+}
+
+impl<P: SmallFpParameters> From<bool> for SmallFp<P> {
+    fn from(other: bool) -> Self {
+        Self::new(u64::from(other))
+    }
+}
+
+macro_rules! impl_small_prime_field_from_int {
+    ($int: expr) => {
+        paste::paste! {
+            impl<P: SmallFpParameters> From<[<u $int>]> for SmallFp<P> {
+                fn from(other: [<u $int>]) -> Self {
+                    Self::new(u64::from(other) % Self::MODULUS)
+                }
+            }
+
+            impl<P: SmallFpParameters> From<[<i $int>]> for SmallFp<P> {
+                fn from(other: [<i $int>]) -> Self {
+                    let abs = Self::from(other.unsigned_abs());
+                    if other.is_positive() {
+                        abs
+                    } else {
+                        -abs
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_small_prime_field_from_int!(64);
+impl_small_prime_field_from_int!(32);
+impl_small_prime_field_from_int!(16);
+impl_small_prime_field_from_int!(8);
+
+impl<P: SmallFpParameters> ark_std::rand::distributions::Distribution<SmallFp<P>>
+    for ark_std::rand::distributions::Standard
+{
+    #[inline]
+    fn sample<R: ark_std::rand::Rng + ?Sized>(&self, rng: &mut R) -> SmallFp<P> {
+        loop {
+            // Mask away the unused bits at the beginning.
+            let value = rng.gen::<u64>() & (u64::MAX >> P::REPR_SHAVE_BITS);
+            if value < SmallFp::<P>::MODULUS {
+                return SmallFp::new(value);
+            }
+        }
+    }
+}
+
+/// Uses the same encoding as `Fp64`: the canonical representative in
+/// little-endian order, truncated to the bytes needed for `P::MODULUS_BITS`
+/// and the flags.
+impl<P: SmallFpParameters> CanonicalSerializeWithFlags for SmallFp<P> {
+    fn serialize_with_flags<W: Write, F: Flags>(
+        &self,
+        mut writer: W,
+        flags: F,
+    ) -> Result<(), SerializationError> {
+        // All reasonable `Flags` should be less than 8 bits in size
+        // (256 values are enough for anyone!)
+        if F::BIT_SIZE > 8 {
+            return Err(SerializationError::NotEnoughSpace);
+        }
+        let output_byte_size = buffer_byte_size(P::MODULUS_BITS as usize + F::BIT_SIZE);
+
+        let mut bytes = [0u8; 9];
+        bytes[..8].copy_from_slice(&self.0.to_le_bytes());
+        bytes[output_byte_size - 1] |= flags.u8_bitmask();
+        writer.write_all(&bytes[..output_byte_size])?;
+        Ok(())
+    }
+
+    fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+        buffer_byte_size(P::MODULUS_BITS as usize + F::BIT_SIZE)
+    }
+}
+
+impl<P: SmallFpParameters> CanonicalSerialize for SmallFp<P> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    #[inline]
+    fn serialized_size(&self) -> usize {
+        self.serialized_size_with_flags::<EmptyFlags>()
+    }
+}
+
+impl<P: SmallFpParameters> CanonicalDeserializeWithFlags for SmallFp<P> {
+    fn deserialize_with_flags<R: Read, F: Flags>(
+        mut reader: R,
+    ) -> Result<(Self, F), SerializationError> {
+        // All reasonable `Flags` should be less than 8 bits in size
+        // (256 values are enough for anyone!)
+        if F::BIT_SIZE > 8 {
+            return Err(SerializationError::NotEnoughSpace);
+        }
+        let output_byte_size = buffer_byte_size(P::MODULUS_BITS as usize + F::BIT_SIZE);
+
+        let mut bytes = [0u8; 9];
+        reader.read_exact(&mut bytes[..output_byte_size])?;
+        // As in `from_random_bytes_with_flags`, only the top `F::BIT_SIZE`
+        // bits are passed to `F`, since a 64-bit modulus leaves no spare bit.
+        let flags_mask = u8::MAX.checked_shl(8 - (F::BIT_SIZE as u32)).unwrap_or(0);
+        let last_byte = &mut bytes[output_byte_size - 1];
+        let flags =
+            F::from_u8(*last_byte & flags_mask).ok_or(SerializationError::UnexpectedFlags)?;
+        *last_byte &= !flags_mask;
+        let mut limb = [0u8; 8];
+        limb.copy_from_slice(&bytes[..8]);
+
+        let element = Self::from_repr(BigInt([u64::from_le_bytes(limb)]))
+            .ok_or_else(|| crate::error("FromBytes::read failed"))?;
+        Ok((element, flags))
+    }
+}
+
+impl<P: SmallFpParameters> CanonicalDeserialize for SmallFp<P> {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize_with_flags::<R, EmptyFlags>(reader).map(|(r, _)| r)
+    }
+}
+
+impl<P: SmallFpParameters> ToBytes for SmallFp<P> {
+    #[inline]
+    fn write<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.into_repr().write(writer)
+    }
+}
+
+impl<P: SmallFpParameters> FromBytes for SmallFp<P> {
+    #[inline]
+    fn read<R: Read>(reader: R) -> IoResult<Self> {
+        BigInt::read(reader).and_then(|b| match Self::from_repr(b) {
+            Some(f) => Ok(f),
+            None => Err(crate::error("FromBytes::read failed")),
+        })
+    }
+}
+
+impl<P: SmallFpParameters> FromStr for SmallFp<P> {
+    type Err = ();
+
+    /// Interpret a string of numbers as a (congruent) prime field element.
+    /// Does not accept unnecessary leading zeroes or a blank string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(());
+        }
+
+        if s == "0" {
+            return Ok(Self::zero());
+        }
+
+        let mut res = Self::zero();
+        let ten = Self::from(10u64);
+        let mut first_digit = true;
+
+        for c in s.chars() {
+            match c.to_digit(10) {
+                Some(c) => {
+                    if first_digit {
+                        if c == 0 {
+                            return Err(());
+                        }
+
+                        first_digit = false;
+                    }
+
+                    res *= &ten;
+                    res += &Self::from(u64::from(c));
+                },
+                None => {
+                    return Err(());
+                },
+            }
+        }
+        Ok(res)
+    }
+}
+
+impl<P: SmallFpParameters> Display for SmallFp<P> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "SmallFp \"({})\"", self.into_repr())
+    }
+}
+
+impl<P: SmallFpParameters> Neg for SmallFp<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::zero() - self
+    }
+}
+
+impl<P: SmallFpParameters> Add<&SmallFp<P>> for SmallFp<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, other: &Self) -> Self {
+        self.add_assign(other);
+        self
+    }
+}
+
+impl<P: SmallFpParameters> Sub<&SmallFp<P>> for SmallFp<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, other: &Self) -> Self {
+        self.sub_assign(other);
+        self
+    }
+}
+
+impl<P: SmallFpParameters> Mul<&SmallFp<P>> for SmallFp<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, other: &Self) -> Self {
+        self.mul_assign(other);
+        self
+    }
+}
+
+impl<P: SmallFpParameters> Div<&SmallFp<P>> for SmallFp<P> {
+    type Output = Self;
+
+    /// Returns `self * other.inverse()` if `other.inverse()` is `Some`, and
+    /// panics otherwise.
+    #[inline]
+    fn div(mut self, other: &Self) -> Self {
+        self.mul_assign(&other.inverse().unwrap());
+        self
+    }
+}
+
+impl<P: SmallFpParameters> AddAssign<&Self> for SmallFp<P> {
+    #[inline]
+    fn add_assign(&mut self, other: &Self) {
+        let (sum, carry) = self.0.overflowing_add(other.0);
+        self.0 = Self::sub_modulus_if_needed(sum, carry);
+    }
+}
+
+impl<P: SmallFpParameters> SubAssign<&Self> for SmallFp<P> {
+    #[inline]
+    #[cfg(not(feature = "ct"))]
+    fn sub_assign(&mut self, other: &Self) {
+        // If the subtraction borrows, add the modulus back.
+        let (diff, borrow) = self.0.overflowing_sub(other.0);
+        self.0 = if borrow {
+            diff.wrapping_add(Self::MODULUS)
+        } else {
+            diff
+        };
+    }
+
+    #[inline]
+    #[cfg(feature = "ct")]
+    fn sub_assign(&mut self, other: &Self) {
+        // If the subtraction borrows, add the modulus back.
+        let (diff, borrow) = self.0.overflowing_sub(other.0);
+        let corrected = diff.wrapping_add(Self::MODULUS);
+        self.0 = u64::conditional_select(&diff, &corrected, Choice::from(borrow as u8));
+    }
+}
+
+impl<P: SmallFpParameters> MulAssign<&Self> for SmallFp<P> {
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        self.0 = P::reduce(u128::from(self.0) * u128::from(other.0));
+    }
+}
+
+/// Computes `self *= other.inverse()` if `other.inverse()` is `Some`, and
+/// panics otherwise.
+impl<P: SmallFpParameters> DivAssign<&Self> for SmallFp<P> {
+    #[inline]
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().unwrap());
+    }
+}
+
+impl_additive_ops_from_ref!(SmallFp, SmallFpParameters);
+impl_multiplicative_ops_from_ref!(SmallFp, SmallFpParameters);
+
+impl<P: SmallFpParameters> zeroize::Zeroize for SmallFp<P> {
+    // The phantom data does not contain element-specific data
+    // and thus does not need to be zeroized.
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<P: SmallFpParameters> From<num_bigint::BigUint> for SmallFp<P> {
+    #[inline]
+    fn from(val: num_bigint::BigUint) -> SmallFp<P> {
+        SmallFp::<P>::from_le_bytes_mod_order(&val.to_bytes_le())
+    }
+}
+
+impl<P: SmallFpParameters> From<SmallFp<P>> for num_bigint::BigUint {
+    #[inline]
+    fn from(val: SmallFp<P>) -> num_bigint::BigUint {
+        val.into_repr().into()
+    }
+}
+
+impl<P: SmallFpParameters> From<SmallFp<P>> for BigInteger64 {
+    fn from(val: SmallFp<P>) -> BigInteger64 {
+        val.into_repr()
+    }
+}
+
+impl<P: SmallFpParameters> From<BigInteger64> for SmallFp<P> {
+    /// Converts `Self::BigInteger` into `Self`
+    ///
+    /// # Panics
+    /// This method panics if `int` is larger than `P::MODULUS`.
+    fn from(int: BigInteger64) -> Self {
+        Self::from_repr(int).unwrap()
+    }
+}
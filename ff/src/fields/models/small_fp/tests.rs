@@ -0,0 +1,191 @@
+use super::*;
+use crate::{field_new, fields::Fp64, UniformRand};
+use ark_serialize::SWFlags;
+use ark_std::test_rng;
+
+const ITERATIONS: usize = 1000;
+
+/// Returns random elements, preceded by the ones next to the edges of the
+/// canonical range.
+fn test_elements<P: SmallFpParameters>() -> Vec<SmallFp<P>> {
+    let modulus = P::MODULUS.0[0];
+    let mut rng = test_rng();
+    let mut elements: Vec<_> = [
+        0,
+        1,
+        2,
+        modulus / 2,
+        modulus / 2 + 1,
+        modulus - 2,
+        modulus - 1,
+    ]
+    .iter()
+    .map(|&value| SmallFp::new(value))
+    .collect();
+    elements.extend((0..ITERATIONS).map(|_| SmallFp::rand(&mut rng)));
+    elements
+}
+
+/// Checks the arithmetic against `u128` arithmetic modulo `P::MODULUS`.
+fn arithmetic_test<P: SmallFpParameters>() {
+    let modulus = u128::from(P::MODULUS.0[0]);
+    let elements = test_elements::<P>();
+    for (a, b) in elements.iter().zip(elements.iter().rev()) {
+        let (x, y) = (u128::from(a.0), u128::from(b.0));
+        assert!(x < modulus);
+        assert_eq!(u128::from((*a + b).0), (x + y) % modulus);
+        assert_eq!(u128::from((*a - b).0), (x + modulus - y) % modulus);
+        assert_eq!(u128::from((-*a).0), (modulus - x) % modulus);
+        assert_eq!(u128::from(a.double().0), 2 * x % modulus);
+        assert_eq!(u128::from((*a * b).0), x * y % modulus);
+        assert_eq!(u128::from(a.square().0), x * x % modulus);
+        assert_eq!(SmallFp::<P>::from(x * y), *a * b);
+        if !a.is_zero() {
+            assert_eq!(*a * a.inverse().unwrap(), SmallFp::one());
+        }
+    }
+    assert!(SmallFp::<P>::zero().inverse().is_none());
+}
+
+/// Checks the Montgomery constants and the encoding against `Fp64`, which
+/// uses the same parameters.
+fn montgomery_consistency_test<P: SmallFpParameters>() {
+    let elements = test_elements::<P>();
+    for (a, b) in elements.iter().zip(elements.iter().rev()) {
+        let a_mont = Fp64::<P>::from_repr(a.into_repr()).unwrap();
+        let b_mont = Fp64::<P>::from_repr(b.into_repr()).unwrap();
+        assert_eq!((a_mont * b_mont).into_repr(), (*a * b).into_repr());
+
+        let mut bytes = Vec::new();
+        a.serialize_with_flags(&mut bytes, SWFlags::PositiveY)
+            .unwrap();
+        let mut bytes_mont = Vec::new();
+        a_mont
+            .serialize_with_flags(&mut bytes_mont, SWFlags::PositiveY)
+            .unwrap();
+        assert_eq!(bytes, bytes_mont);
+
+        // Without flags, the top bit of a 64-bit modulus is part of the value.
+        let mut bytes_mont = Vec::new();
+        a_mont.serialize(&mut bytes_mont).unwrap();
+        assert_eq!(Fp64::<P>::deserialize(&bytes_mont[..]).unwrap(), a_mont);
+    }
+
+    // The constants are in Montgomery form, as for `Fp64`.
+    assert_eq!(
+        SmallFp::<P>::multiplicative_generator().into_repr(),
+        Fp64::<P>::multiplicative_generator().into_repr()
+    );
+    assert_eq!(
+        SmallFp::<P>::two_adic_root_of_unity().into_repr(),
+        Fp64::<P>::two_adic_root_of_unity().into_repr()
+    );
+}
+
+fn fft_constants_test<P: SmallFpParameters>() {
+    let generator = SmallFp::<P>::multiplicative_generator();
+    assert_eq!(generator.legendre(), LegendreSymbol::QuadraticNonResidue);
+    let root = SmallFp::<P>::two_adic_root_of_unity();
+    assert_eq!(generator.pow(P::T), root);
+    assert_eq!(root.pow([1 << P::TWO_ADICITY]), SmallFp::one());
+    assert_eq!(root.pow([1 << (P::TWO_ADICITY - 1)]), -SmallFp::one());
+}
+
+fn sqrt_test<P: SmallFpParameters>() {
+    for a in test_elements::<P>() {
+        let sqrt = a.square().sqrt().unwrap();
+        assert!(sqrt == a || sqrt == -a);
+        match a.sqrt() {
+            Some(sqrt) => assert_eq!(sqrt.square(), a),
+            None => assert_eq!(a.legendre(), LegendreSymbol::QuadraticNonResidue),
+        }
+    }
+}
+
+fn serialization_test<P: SmallFpParameters>() {
+    for a in test_elements::<P>() {
+        let mut bytes = Vec::new();
+        a.serialize(&mut bytes).unwrap();
+        assert_eq!(bytes.len(), a.serialized_size());
+        assert_eq!(SmallFp::<P>::deserialize(&bytes[..]).unwrap(), a);
+        assert_eq!(
+            SmallFp::<P>::from_random_bytes(&bytes),
+            Some(a),
+            "{:?}",
+            bytes
+        );
+
+        let mut bytes = Vec::new();
+        a.serialize_with_flags(&mut bytes, SWFlags::NegativeY)
+            .unwrap();
+        assert_eq!(bytes.len(), a.serialized_size_with_flags::<SWFlags>());
+        let (b, flags) = SmallFp::<P>::deserialize_with_flags::<_, SWFlags>(&bytes[..]).unwrap();
+        assert!(b == a && flags == SWFlags::NegativeY);
+        let (b, flags) = SmallFp::<P>::from_random_bytes_with_flags::<SWFlags>(&bytes).unwrap();
+        assert!(b == a && flags == SWFlags::NegativeY);
+
+        assert_eq!(SmallFp::<P>::from_str(&ark_std::format!("{}", a.0)), Ok(a));
+    }
+    let modulus = P::MODULUS.0[0];
+    assert!(SmallFp::<P>::deserialize(&modulus.to_le_bytes()[..]).is_err());
+}
+
+fn small_fp_test<P: SmallFpParameters>() {
+    arithmetic_test::<P>();
+    montgomery_consistency_test::<P>();
+    fft_constants_test::<P>();
+    sqrt_test::<P>();
+    serialization_test::<P>();
+}
+
+#[test]
+fn test_goldilocks() {
+    small_fp_test::<GoldilocksParameters>();
+
+    // The reduction accepts any 128-bit input.
+    let modulus = u128::from(GoldilocksParameters::MODULUS.0[0]);
+    for x in [
+        u128::MAX,
+        u128::MAX - 1,
+        1 << 96,
+        (1 << 96) - 1,
+        modulus << 64,
+    ] {
+        assert_eq!(
+            u128::from(GoldilocksParameters::reduce(x)),
+            x % modulus,
+            "{:x}",
+            x
+        );
+    }
+}
+
+#[test]
+fn test_mersenne31() {
+    small_fp_test::<Mersenne31Parameters>();
+}
+
+#[test]
+fn test_baby_bear() {
+    small_fp_test::<BabyBearParameters>();
+}
+
+#[test]
+fn test_generators() {
+    assert_eq!(
+        Goldilocks::multiplicative_generator(),
+        Goldilocks::from(7u64)
+    );
+    assert_eq!(
+        Mersenne31::multiplicative_generator(),
+        Mersenne31::from(7u64)
+    );
+    assert_eq!(BabyBear::multiplicative_generator(), BabyBear::from(31u64));
+}
+
+#[test]
+fn test_field_new() {
+    assert_eq!(field_new!(Goldilocks, "7"), Goldilocks::from(7u64));
+    assert_eq!(field_new!(Mersenne31, "-1"), -Mersenne31::one());
+    assert_eq!(field_new!(BabyBear, "0"), BabyBear::zero());
+}
@@ -2,7 +2,6 @@
 #![warn(unused, future_incompatible, nonstandard_style, rust_2018_idioms)]
 #![allow(clippy::op_ref, clippy::suspicious_op_assign_impl)]
 #![cfg_attr(not(feature = "asm"), forbid(unsafe_code))]
-#![cfg_attr(use_asm, feature(llvm_asm))]
 #![cfg_attr(feature = "asm", deny(unsafe_code))]
 
 #[macro_use]
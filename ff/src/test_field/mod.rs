@@ -3,6 +3,8 @@ pub(crate) use fq::*;
 #[allow(unused)]
 pub(crate) use fq2::*;
 #[allow(unused)]
+pub(crate) use fq3::*;
+#[allow(unused)]
 pub(crate) use fq6::*;
 pub(crate) use fr::*;
 
@@ -260,7 +262,7 @@ pub(crate) mod fq {
 pub(crate) mod fq2 {
     // Copy of BLS12-377's Fq2
     use super::fq::*;
-    use crate::{field_new, fields::*};
+    use crate::fields::*;
 
     pub type Fq2 = Fp2<Fq2Parameters>;
 
@@ -301,10 +303,69 @@ pub(crate) mod fq2 {
     pub const FQ2_ONE: Fq2 = field_new!(Fq2, FQ_ONE, FQ_ZERO);
 }
 
+pub(crate) mod fq3 {
+    // Cubic extension of the copy of BLS12-381's Fr
+    use super::fr::*;
+    use crate::fields::*;
+
+    #[allow(dead_code)]
+    pub type Fq3 = Fp3<Fq3Parameters>;
+
+    pub struct Fq3Parameters;
+
+    impl Fp3Parameters for Fq3Parameters {
+        type Fp = Fr;
+
+        /// NONRESIDUE = 2
+        #[rustfmt::skip]
+        const NONRESIDUE: Fr = field_new!(Fr, "2");
+
+        #[rustfmt::skip]
+        const FROBENIUS_COEFF_FP3_C1: &'static [Fr] = &[
+            field_new!(Fr, "1"),
+            field_new!(Fr, "228988810152649578064853576960394133503"),
+            field_new!(Fr, "52435875175126190479447740508185965837461563690374988244538805122978187051009"),
+        ];
+
+        #[rustfmt::skip]
+        const FROBENIUS_COEFF_FP3_C2: &'static [Fr] = &[
+            field_new!(Fr, "1"),
+            field_new!(Fr, "52435875175126190479447740508185965837461563690374988244538805122978187051009"),
+            field_new!(Fr, "228988810152649578064853576960394133503"),
+        ];
+
+        const TWO_ADICITY: u32 = 32;
+
+        #[rustfmt::skip]
+        const T_MINUS_ONE_DIV_TWO: &'static [u64] = &[
+            0xfffd89fffffffffe,
+            0x1cd44dfefda16206,
+            0x331b5b4c57915fa,
+            0xaf32071e4e6fac0a,
+            0xe4aad2bed3581fa,
+            0x3e33af62bf71e8dd,
+            0xf17356f3b3a6c26e,
+            0xf56bea3f0a0b859e,
+            0x29ae810e61848c84,
+            0x13f0870fe8d8119e,
+            0x3f6f2f009b3d42b8,
+            0xbe2fa65,
+        ];
+
+        /// (7^t, 0, 0)
+        #[rustfmt::skip]
+        const QUADRATIC_NONRESIDUE_TO_T: (Fr, Fr, Fr) = (
+            field_new!(Fr, "6614261185917488098854187749076597481344859171379867242998434664205028915962"),
+            field_new!(Fr, "0"),
+            field_new!(Fr, "0"),
+        );
+    }
+}
+
 pub(crate) mod fq6 {
     // Copy of BLS12-377's Fq6
     use super::{fq::*, fq2::*};
-    use crate::{field_new, fields::*};
+    use crate::fields::*;
 
     #[allow(dead_code)]
     pub type Fq6 = Fp6<Fq6Parameters>;
@@ -1,6 +1,6 @@
 extern crate criterion;
 
-use ark_ff::FftField;
+use ark_ff::{BabyBear, FftField, Goldilocks};
 use ark_poly::{polynomial::univariate::DensePolynomial, polynomial::UVPolynomial};
 use ark_poly::{EvaluationDomain, MixedRadixEvaluationDomain, Radix2EvaluationDomain};
 use ark_poly_benches::size_range;
@@ -14,6 +14,7 @@ use criterion::{criterion_group, criterion_main, Bencher, Criterion};
 const BENCHMARK_MIN_DEGREE: usize = 1 << 15;
 const BENCHMARK_MAX_DEGREE_BLS12_381: usize = 1 << 22;
 const BENCHMARK_MAX_DEGREE_MNT6_753: usize = 1 << 17;
+const BENCHMARK_MAX_DEGREE_SMALL_FIELDS: usize = 1 << 22;
 const BENCHMARK_LOG_INTERVAL_DEGREE: usize = 1;
 
 const ENABLE_RADIX2_BENCHES: bool = true;
@@ -39,6 +40,14 @@ fn default_size_range_mnt6_753() -> Vec<usize> {
     )
 }
 
+fn default_size_range_small_fields() -> Vec<usize> {
+    size_range(
+        BENCHMARK_LOG_INTERVAL_DEGREE,
+        BENCHMARK_MIN_DEGREE,
+        BENCHMARK_MAX_DEGREE_SMALL_FIELDS,
+    )
+}
+
 fn setup_bench(
     c: &mut Criterion,
     name: &str,
@@ -134,5 +143,33 @@ fn bench_mnt6_753(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, bench_bls12_381, bench_mnt6_753);
+fn bench_goldilocks(c: &mut Criterion) {
+    let name = "goldilocks - radix2";
+    if ENABLE_RADIX2_BENCHES {
+        fft_benches::<Goldilocks, Radix2EvaluationDomain<Goldilocks>>(
+            c,
+            name,
+            &default_size_range_small_fields(),
+        );
+    }
+}
+
+fn bench_baby_bear(c: &mut Criterion) {
+    let name = "baby_bear - radix2";
+    if ENABLE_RADIX2_BENCHES {
+        fft_benches::<BabyBear, Radix2EvaluationDomain<BabyBear>>(
+            c,
+            name,
+            &default_size_range_small_fields(),
+        );
+    }
+}
+
+criterion_group!(
+    benches,
+    bench_bls12_381,
+    bench_mnt6_753,
+    bench_goldilocks,
+    bench_baby_bear
+);
 criterion_main!(benches);
@@ -0,0 +1,87 @@
+extern crate criterion;
+
+use ark_ff::{
+    fields::Fp64, BabyBear, BabyBearParameters, Field, Goldilocks, GoldilocksParameters, Mersenne31,
+};
+use ark_test_curves::bls12_381::Fr as bls12_381_fr;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// number of random elements each operation is applied to per iteration
+const SAMPLES: usize = 1000;
+
+const ENABLE_MONTGOMERY_COMPARISON_BENCHES: bool = true;
+
+fn field_benches<F: Field>(c: &mut Criterion, name: &'static str) {
+    let mut rng = ark_std::test_rng();
+    let a: Vec<F> = (0..SAMPLES).map(|_| F::rand(&mut rng)).collect();
+    let b: Vec<F> = (0..SAMPLES).map(|_| F::rand(&mut rng)).collect();
+
+    let mut group = c.benchmark_group(name);
+    group.bench_function("add", |bench| {
+        bench.iter(|| {
+            for (a, b) in a.iter().zip(&b) {
+                black_box(*a + b);
+            }
+        })
+    });
+    group.bench_function("sub", |bench| {
+        bench.iter(|| {
+            for (a, b) in a.iter().zip(&b) {
+                black_box(*a - b);
+            }
+        })
+    });
+    group.bench_function("mul", |bench| {
+        bench.iter(|| {
+            for (a, b) in a.iter().zip(&b) {
+                black_box(*a * b);
+            }
+        })
+    });
+    group.bench_function("square", |bench| {
+        bench.iter(|| {
+            for a in a.iter() {
+                black_box(a.square());
+            }
+        })
+    });
+    group.bench_function("inverse", |bench| {
+        bench.iter(|| {
+            for a in a.iter() {
+                black_box(a.inverse());
+            }
+        })
+    });
+    group.finish();
+}
+
+fn bench_goldilocks(c: &mut Criterion) {
+    field_benches::<Goldilocks>(c, "goldilocks");
+    if ENABLE_MONTGOMERY_COMPARISON_BENCHES {
+        field_benches::<Fp64<GoldilocksParameters>>(c, "goldilocks - montgomery");
+    }
+}
+
+fn bench_baby_bear(c: &mut Criterion) {
+    field_benches::<BabyBear>(c, "baby_bear");
+    if ENABLE_MONTGOMERY_COMPARISON_BENCHES {
+        field_benches::<Fp64<BabyBearParameters>>(c, "baby_bear - montgomery");
+    }
+}
+
+fn bench_mersenne31(c: &mut Criterion) {
+    field_benches::<Mersenne31>(c, "mersenne31");
+}
+
+fn bench_bls12_381(c: &mut Criterion) {
+    field_benches::<bls12_381_fr>(c, "bls12_381 fr");
+}
+
+criterion_group!(
+    benches,
+    bench_goldilocks,
+    bench_baby_bear,
+    bench_mersenne31,
+    bench_bls12_381
+);
+criterion_main!(benches);
@@ -0,0 +1,118 @@
+extern crate criterion;
+
+use ark_ec::{msm::VariableBaseMSM, wnaf::WnafContext, AffineCurve, ProjectiveCurve};
+use ark_ff::{BigInteger, FpParameters, PrimeField, UniformRand, Zero};
+use ark_test_curves::bls12_381::{Fr, G1Affine, G1Projective};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+// numbers of terms around `VariableBaseMSM::WNAF_THRESHOLD`
+const SMALL_SIZES: [usize; 8] = [2, 4, 8, 12, 16, 20, 24, 32];
+
+const LOG_LARGE_SIZES: [usize; 3] = [10, 12, 14];
+
+fn random_terms(size: usize) -> (Vec<G1Affine>, Vec<Fr>) {
+    let mut rng = ark_std::test_rng();
+    let bases = (0..size)
+        .map(|_| G1Projective::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let bases = G1Projective::batch_normalization_into_affine(&bases);
+    let scalars = (0..size).map(|_| Fr::rand(&mut rng)).collect();
+    (bases, scalars)
+}
+
+/// Compares interleaved wNAFs with Pippenger's algorithm for the small
+/// sizes at which `VariableBaseMSM::multi_scalar_mul` switches between them.
+fn bench_wnaf_threshold(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bls12_381 g1 small msm");
+    for &size in SMALL_SIZES.iter() {
+        let (bases, scalars) = random_terms(size);
+        let reprs = scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+        let context = WnafContext::new(4);
+        group.bench_with_input(BenchmarkId::new("wnaf", size), &size, |b, _| {
+            b.iter(|| {
+                let bases = bases
+                    .iter()
+                    .map(G1Affine::into_projective)
+                    .collect::<Vec<_>>();
+                black_box(context.multi_scalar_mul(&bases, &scalars))
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("pippenger", size), &size, |b, _| {
+            b.iter(|| black_box(VariableBaseMSM::multi_scalar_mul_pippenger(&bases, &reprs)))
+        });
+    }
+    group.finish();
+}
+
+/// Pippenger's algorithm with unsigned windows and Jacobian buckets, which
+/// `VariableBaseMSM` used before switching to signed digits and XYZZ buckets.
+fn unsigned_jacobian_msm(
+    bases: &[G1Affine],
+    scalars: &[<Fr as PrimeField>::BigInt],
+) -> G1Projective {
+    let num_bits = <Fr as PrimeField>::Params::MODULUS_BITS as usize;
+    let c = if bases.len() < 32 {
+        3
+    } else {
+        (ark_std::log2(bases.len()) * 69 / 100) as usize + 2
+    };
+
+    let window_sums: Vec<_> = (0..num_bits)
+        .step_by(c)
+        .map(|w_start| {
+            let mut buckets = vec![G1Projective::zero(); (1 << c) - 1];
+            for (scalar, base) in scalars.iter().zip(bases) {
+                let mut scalar = *scalar;
+                scalar.divn(w_start as u32);
+                let scalar = scalar.as_ref()[0] % (1 << c);
+                if scalar != 0 {
+                    buckets[(scalar - 1) as usize].add_assign_mixed(base);
+                }
+            }
+            let mut res = G1Projective::zero();
+            let mut running_sum = G1Projective::zero();
+            for bucket in buckets.into_iter().rev() {
+                running_sum += &bucket;
+                res += &running_sum;
+            }
+            res
+        })
+        .collect();
+
+    let lowest = window_sums[0];
+    lowest
+        + &window_sums[1..]
+            .iter()
+            .rev()
+            .fold(G1Projective::zero(), |mut total, sum_i| {
+                total += sum_i;
+                for _ in 0..c {
+                    total.double_in_place();
+                }
+                total
+            })
+}
+
+/// Compares Pippenger's algorithm with signed digits and XYZZ buckets against
+/// unsigned windows and Jacobian buckets.
+fn bench_pippenger(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bls12_381 g1 pippenger");
+    group.sample_size(10);
+    for &log_size in LOG_LARGE_SIZES.iter() {
+        let size = 1 << log_size;
+        let (bases, scalars) = random_terms(size);
+        let reprs = scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+        group.bench_with_input(BenchmarkId::new("signed xyzz", size), &size, |b, _| {
+            b.iter(|| black_box(VariableBaseMSM::multi_scalar_mul_pippenger(&bases, &reprs)))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("unsigned jacobian", size),
+            &size,
+            |b, _| b.iter(|| black_box(unsigned_jacobian_msm(&bases, &reprs))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_wnaf_threshold, bench_pippenger);
+criterion_main!(benches);
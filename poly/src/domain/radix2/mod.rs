@@ -213,7 +213,7 @@ mod tests {
     use crate::domain::Vec;
     use crate::polynomial::{univariate::*, Polynomial, UVPolynomial};
     use crate::{EvaluationDomain, Radix2EvaluationDomain};
-    use ark_ff::{FftField, Field, One, UniformRand, Zero};
+    use ark_ff::{BabyBear, FftField, Field, Goldilocks, One, UniformRand, Zero};
     use ark_std::rand::Rng;
     use ark_std::test_rng;
     use ark_test_curves::bls12_381::Fr;
@@ -316,6 +316,12 @@ mod tests {
 
     #[test]
     fn test_fft_correctness() {
+        fft_correctness_test::<Fr>();
+        fft_correctness_test::<Goldilocks>();
+        fft_correctness_test::<BabyBear>();
+    }
+
+    fn fft_correctness_test<F: FftField>() {
         // Tests that the ffts output the correct result.
         // This assumes a correct polynomial evaluation at point procedure.
         // It tests consistency of FFT/IFFT, and coset_fft/coset_ifft,
@@ -324,15 +330,15 @@ mod tests {
         // Runs in time O(degree^2)
         let log_degree = 5;
         let degree = 1 << log_degree;
-        let rand_poly = DensePolynomial::<Fr>::rand(degree - 1, &mut test_rng());
+        let rand_poly = DensePolynomial::<F>::rand(degree - 1, &mut test_rng());
 
         for log_domain_size in log_degree..(log_degree + 2) {
             let domain_size = 1 << log_domain_size;
-            let domain = Radix2EvaluationDomain::<Fr>::new(domain_size).unwrap();
+            let domain = Radix2EvaluationDomain::<F>::new(domain_size).unwrap();
             let poly_evals = domain.fft(&rand_poly.coeffs);
             let poly_coset_evals = domain.coset_fft(&rand_poly.coeffs);
             for (i, x) in domain.elements().enumerate() {
-                let coset_x = Fr::multiplicative_generator() * x;
+                let coset_x = F::multiplicative_generator() * x;
 
                 assert_eq!(poly_evals[i], rand_poly.evaluate(&x));
                 assert_eq!(poly_coset_evals[i], rand_poly.evaluate(&coset_x));
@@ -990,6 +990,17 @@ mod test {
         assert!(de.is_err());
     }
 
+    #[test]
+    fn test_empty_flags() {
+        // `EmptyFlags` has no bits, so every byte belongs to the data.
+        for value in [0u8, 1, 0x7f, 0x80, 0xc0, 0xff] {
+            assert!(EmptyFlags::from_u8(value) == Some(EmptyFlags));
+            let mut byte = value;
+            assert!(EmptyFlags::from_u8_remove_flags(&mut byte) == Some(EmptyFlags));
+            assert_eq!(byte, value);
+        }
+    }
+
     #[test]
     fn test_vec() {
         test_serialize(vec![1u64, 2, 3, 4, 5]);
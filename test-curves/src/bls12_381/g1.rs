@@ -1,7 +1,13 @@
 use crate::bls12_381::*;
 use ark_ec::{
+    glv::GLVParameters,
+    hashing::curve_maps::{
+        svdw::SvdWParameters,
+        wb::{IsogenyMap, WBParameters},
+    },
     models::{ModelParameters, SWModelParameters},
     short_weierstrass_jacobian::*,
+    AffineCurve, ProjectiveCurve,
 };
 use ark_ff::{field_new, Zero};
 
@@ -40,6 +46,63 @@ impl SWModelParameters for Parameters {
     fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    /// Multiplies by the effective cofactor `1 - x = 0xd201000000010001`
+    /// instead of `COFACTOR`, as in RFC 9380, Section 8.8.1.
+    fn clear_cofactor(p: &G1Affine) -> G1Affine {
+        p.into_projective().mul([0xd201000000010001]).into_affine()
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1Affine) -> bool {
+        ark_ec::bls12::g1::is_in_correct_subgroup_assuming_on_curve::<crate::bls12_381::Parameters>(
+            p,
+        )
+    }
+
+    #[inline]
+    fn glv_split_affine(p: &G1Affine, k: &Fr) -> Option<[(G1Affine, Fr); 2]> {
+        Some(Self::split_affine(p, k))
+    }
+
+    #[inline]
+    fn glv_split_projective(p: &G1Projective, k: &Fr) -> Option<[(G1Projective, Fr); 2]> {
+        Some(Self::split_projective(p, k))
+    }
+}
+
+impl GLVParameters for Parameters {
+    /// ENDO_COEFF = a primitive cube root of unity in Fq
+    #[rustfmt::skip]
+    const ENDO_COEFF: Fq = field_new!(Fq, "793479390729215512621379701633421447060886740281060493010456487427281649075476305620758731620350");
+
+    /// LAMBDA = -x^2, the eigenvalue of the endomorphism on G1
+    #[rustfmt::skip]
+    const LAMBDA: Fr = field_new!(Fr, "52435875175126190479447740508185965837461563690374988244538805122978187051009");
+
+    /// SCALAR_DECOMP_COEFFS = [1, -(x^2 - 1), x^2, 1]
+    const SCALAR_DECOMP_COEFFS: [(bool, &'static [u64]); 4] = [
+        (false, &[0x1]),
+        (true, &[0xffffffff, 0xac45a4010001a402]),
+        (false, &[0x100000000, 0xac45a4010001a402]),
+        (false, &[0x1]),
+    ];
+
+    /// SCALAR_DECOMP_ROUNDING = [round(2^256 / r), round((x^2 - 1) * 2^256 / r)]
+    const SCALAR_DECOMP_ROUNDING: [(bool, &'static [u64]); 2] = [
+        (false, &[0x2]),
+        (false, &[0x63f6e522f6cfee2e, 0x7c6becf1e01faadd, 0x1]),
+    ];
+}
+
+impl WBParameters for Parameters {
+    type IsogenousCurve = g1_swu_iso::SWUIsoParameters;
+
+    const ISOGENY_MAP: IsogenyMap<'static, Fq> = g1_swu_iso::ISOGENY_MAP_TO_G1;
+}
+
+impl SvdWParameters for Parameters {
+    /// ZETA = -3
+    const ZETA: Fq = field_new!(Fq, "-3");
 }
 
 /// G1_GENERATOR_X =
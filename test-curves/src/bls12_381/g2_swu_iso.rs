@@ -0,0 +1,97 @@
+use crate::bls12_381::*;
+use ark_ec::{
+    hashing::curve_maps::{swu::SWUParameters, wb::IsogenyMap},
+    models::{ModelParameters, SWModelParameters},
+    short_weierstrass_jacobian::GroupAffine,
+};
+use ark_ff::field_new;
+
+pub type G2SWUIsoAffine = GroupAffine<SWUIsoParameters>;
+
+/// The curve `E': y^2 = x^3 + A' * x + B'`, which is 3-isogenous to G2, and
+/// to which BLS12-381 G2 hashes first; see RFC 9380, Section 8.8.2.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SWUIsoParameters;
+
+impl ModelParameters for SWUIsoParameters {
+    type BaseField = Fq2;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for SWUIsoParameters {
+    /// COEFF_A = 240 * u
+    const COEFF_A: Fq2 = field_new!(Fq2, field_new!(Fq, "0"), field_new!(Fq, "240"));
+
+    /// COEFF_B = 1012 * (1 + u)
+    const COEFF_B: Fq2 = field_new!(Fq2, field_new!(Fq, "1012"), field_new!(Fq, "1012"));
+
+    /// COFACTOR, the same as for G2
+    const COFACTOR: &'static [u64] = g2::Parameters::COFACTOR;
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    const COFACTOR_INV: Fr = g2::Parameters::COFACTOR_INV;
+
+    /// AFFINE_GENERATOR_COEFFS = (G2_SWU_ISO_GENERATOR_X, G2_SWU_ISO_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (G2_SWU_ISO_GENERATOR_X, G2_SWU_ISO_GENERATOR_Y);
+}
+
+impl SWUParameters for SWUIsoParameters {
+    /// ZETA = -(2 + u)
+    const ZETA: Fq2 = field_new!(Fq2, field_new!(Fq, "-2"), field_new!(Fq, "-1"));
+}
+
+/// The point with the smallest valid x-coordinate, 1, and `sgn0(y) == 0`,
+/// multiplied by the cofactor.
+pub const G2_SWU_ISO_GENERATOR_X: Fq2 =
+    field_new!(Fq2, G2_SWU_ISO_GENERATOR_X_C0, G2_SWU_ISO_GENERATOR_X_C1);
+pub const G2_SWU_ISO_GENERATOR_Y: Fq2 =
+    field_new!(Fq2, G2_SWU_ISO_GENERATOR_Y_C0, G2_SWU_ISO_GENERATOR_Y_C1);
+
+/// G2_SWU_ISO_GENERATOR_X_C0 =
+/// 2595569946714414516067015540153643524656442638788025933727967960306287756885400469291119095920626560658971252184199
+#[rustfmt::skip]
+pub const G2_SWU_ISO_GENERATOR_X_C0: Fq = field_new!(Fq, "2595569946714414516067015540153643524656442638788025933727967960306287756885400469291119095920626560658971252184199");
+
+/// G2_SWU_ISO_GENERATOR_X_C1 =
+/// 1037079738597573406765355774006601850633656296583542639082316151670128374872040593053087014315526494961765370307992
+#[rustfmt::skip]
+pub const G2_SWU_ISO_GENERATOR_X_C1: Fq = field_new!(Fq, "1037079738597573406765355774006601850633656296583542639082316151670128374872040593053087014315526494961765370307992");
+
+/// G2_SWU_ISO_GENERATOR_Y_C0 =
+/// 74480082227005738379067770238572711381750951260377338410582752833319840089542203192014419701049757383464915445300
+#[rustfmt::skip]
+pub const G2_SWU_ISO_GENERATOR_Y_C0: Fq = field_new!(Fq, "74480082227005738379067770238572711381750951260377338410582752833319840089542203192014419701049757383464915445300");
+
+/// G2_SWU_ISO_GENERATOR_Y_C1 =
+/// 702083236876097377659150492526714988680564498553784099825961638526469739667836533609722852421641401659291481334898
+#[rustfmt::skip]
+pub const G2_SWU_ISO_GENERATOR_Y_C1: Fq = field_new!(Fq, "702083236876097377659150492526714988680564498553784099825961638526469739667836533609722852421641401659291481334898");
+
+/// The 3-isogeny from `E'` to G2 of RFC 9380, Appendix E.3.
+#[rustfmt::skip]
+pub const ISOGENY_MAP_TO_G2: IsogenyMap<'static, Fq2> = IsogenyMap {
+    x_map_numerator: &[
+        field_new!(Fq2, field_new!(Fq, "889424345604814976315064405719089812568196182208668418962679585805340366775741747653930584250892369786198727235542"), field_new!(Fq, "889424345604814976315064405719089812568196182208668418962679585805340366775741747653930584250892369786198727235542")),
+        field_new!(Fq2, field_new!(Fq, "0"), field_new!(Fq, "2668273036814444928945193217157269437704588546626005256888038757416021100327225242961791752752677109358596181706522")),
+        field_new!(Fq2, field_new!(Fq, "2668273036814444928945193217157269437704588546626005256888038757416021100327225242961791752752677109358596181706526"), field_new!(Fq, "1334136518407222464472596608578634718852294273313002628444019378708010550163612621480895876376338554679298090853261")),
+        field_new!(Fq2, field_new!(Fq, "3557697382419259905260257622876359250272784728834673675850718343221361467102966990615722337003569479144794908942033"), field_new!(Fq, "0")),
+    ],
+    x_map_denominator: &[
+        field_new!(Fq2, field_new!(Fq, "0"), field_new!(Fq, "-72")),
+        field_new!(Fq2, field_new!(Fq, "12"), field_new!(Fq, "-12")),
+        field_new!(Fq2, field_new!(Fq, "1"), field_new!(Fq, "0")),
+    ],
+    y_map_numerator: &[
+        field_new!(Fq2, field_new!(Fq, "3261222600550988246488569487636662646083386001431784202863158481286248011511053074731078808919938689216061999863558"), field_new!(Fq, "3261222600550988246488569487636662646083386001431784202863158481286248011511053074731078808919938689216061999863558")),
+        field_new!(Fq2, field_new!(Fq, "0"), field_new!(Fq, "889424345604814976315064405719089812568196182208668418962679585805340366775741747653930584250892369786198727235518")),
+        field_new!(Fq2, field_new!(Fq, "2668273036814444928945193217157269437704588546626005256888038757416021100327225242961791752752677109358596181706524"), field_new!(Fq, "1334136518407222464472596608578634718852294273313002628444019378708010550163612621480895876376338554679298090853263")),
+        field_new!(Fq2, field_new!(Fq, "2816510427748580758331037284777117739799287910327449993381818688383577828123182200904113516794492504322962636245776"), field_new!(Fq, "0")),
+    ],
+    y_map_denominator: &[
+        field_new!(Fq2, field_new!(Fq, "-432"), field_new!(Fq, "-432")),
+        field_new!(Fq2, field_new!(Fq, "0"), field_new!(Fq, "-216")),
+        field_new!(Fq2, field_new!(Fq, "18"), field_new!(Fq, "-18")),
+        field_new!(Fq2, field_new!(Fq, "1"), field_new!(Fq, "0")),
+    ],
+};
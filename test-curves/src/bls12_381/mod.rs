@@ -4,12 +4,54 @@ pub use fr::*;
 #[cfg(feature = "bls12_381_curve")]
 pub mod fq;
 #[cfg(feature = "bls12_381_curve")]
+pub mod fq12;
+#[cfg(feature = "bls12_381_curve")]
+pub mod fq2;
+#[cfg(feature = "bls12_381_curve")]
+pub mod fq6;
+#[cfg(feature = "bls12_381_curve")]
 pub mod g1;
+#[cfg(feature = "bls12_381_curve")]
+pub mod g1_swu_iso;
+#[cfg(feature = "bls12_381_curve")]
+pub mod g2;
+#[cfg(feature = "bls12_381_curve")]
+pub mod g2_swu_iso;
 
 #[cfg(feature = "bls12_381_curve")]
 pub use fq::*;
 #[cfg(feature = "bls12_381_curve")]
+pub use fq12::*;
+#[cfg(feature = "bls12_381_curve")]
+pub use fq2::*;
+#[cfg(feature = "bls12_381_curve")]
+pub use fq6::*;
+#[cfg(feature = "bls12_381_curve")]
 pub use g1::*;
+#[cfg(feature = "bls12_381_curve")]
+pub use g2::{G2Affine, G2Projective};
+
+#[cfg(feature = "bls12_381_curve")]
+use ark_ec::bls12::{Bls12, Bls12Parameters, TwistType};
+
+#[cfg(feature = "bls12_381_curve")]
+pub type Bls12_381 = Bls12<Parameters>;
+
+#[cfg(feature = "bls12_381_curve")]
+pub struct Parameters;
+
+#[cfg(feature = "bls12_381_curve")]
+impl Bls12Parameters for Parameters {
+    const X: &'static [u64] = &[0xd201000000010000];
+    const X_IS_NEGATIVE: bool = true;
+    const TWIST_TYPE: TwistType = TwistType::M;
+    type Fp = Fq;
+    type Fp2Params = Fq2Parameters;
+    type Fp6Params = Fq6Parameters;
+    type Fp12Params = Fq12Parameters;
+    type G1Parameters = self::g1::Parameters;
+    type G2Parameters = self::g2::Parameters;
+}
 
 #[cfg(test)]
 mod tests;
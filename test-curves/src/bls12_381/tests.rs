@@ -1,9 +1,15 @@
 #![allow(unused_imports)]
-use ark_ec::{models::SWModelParameters, AffineCurve, PairingEngine, ProjectiveCurve};
-use ark_ff::{One, UniformRand, Zero};
+use ark_ec::{
+    models::SWModelParameters, short_weierstrass_projective, AffineCurve, PairingEngine,
+    ProjectiveCurve,
+};
+use ark_ff::{fields::FftParameters, Field, FpParameters, One, UniformRand, Zero};
 
-use crate::bls12_381::{g1, Fq, FqParameters, Fr, G1Affine, G1Projective};
-use ark_algebra_test_templates::{curves::*, fields::*, groups::*};
+use crate::bls12_381::{
+    g1, g2, Bls12_381, Fq, Fq12, Fq2, Fq6, FqParameters, Fr, G1Affine, G1Projective, G2Affine,
+    G2Projective,
+};
+use ark_algebra_test_templates::{curves::*, fields::*, groups::*, msm::*};
 use ark_std::rand::Rng;
 
 pub(crate) const ITERATIONS: usize = 5;
@@ -38,6 +44,11 @@ fn test_g1_projective_curve() {
     sw_tests::<g1::Parameters>();
 }
 
+#[test]
+fn test_g1_homogeneous_projective_curve() {
+    sw_projective_tests::<g1::Parameters>();
+}
+
 #[test]
 fn test_g1_projective_group() {
     let mut rng = ark_std::test_rng();
@@ -52,3 +63,442 @@ fn test_g1_generator() {
     assert!(generator.is_on_curve());
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
+
+#[test]
+fn test_g1_msm() {
+    test_var_base_msm::<G1Affine>();
+    test_var_base_msm_edge_cases::<G1Affine>();
+    test_wnaf_msm::<G1Projective>();
+    test_precomputed_bases_msm::<G1Affine>();
+    test_chunked_msm::<G1Affine>();
+    test_var_base_msm_edge_cases::<short_weierstrass_projective::GroupAffine<g1::Parameters>>();
+}
+
+#[test]
+fn test_g1_glv() {
+    sw_glv_test::<g1::Parameters>();
+}
+
+#[test]
+fn test_g1_subgroup_check() {
+    sw_subgroup_check_test::<g1::Parameters>();
+}
+
+#[test]
+fn test_fq2() {
+    let mut rng = ark_std::test_rng();
+    let a: Fq2 = UniformRand::rand(&mut rng);
+    let b: Fq2 = UniformRand::rand(&mut rng);
+    field_test(a, b);
+    sqrt_field_test(a);
+    frobenius_test::<Fq2, _>(Fq::characteristic(), 13);
+}
+
+#[test]
+fn test_fq6() {
+    let mut rng = ark_std::test_rng();
+    let a: Fq6 = UniformRand::rand(&mut rng);
+    let b: Fq6 = UniformRand::rand(&mut rng);
+    field_test(a, b);
+    frobenius_test::<Fq6, _>(Fq::characteristic(), 13);
+}
+
+#[test]
+fn test_fq12() {
+    let mut rng = ark_std::test_rng();
+    let a: Fq12 = UniformRand::rand(&mut rng);
+    let b: Fq12 = UniformRand::rand(&mut rng);
+    field_test(a, b);
+    frobenius_test::<Fq12, _>(Fq::characteristic(), 13);
+}
+
+#[test]
+fn test_g2_projective_curve() {
+    curve_tests::<G2Projective>();
+    sw_tests::<g2::Parameters>();
+}
+
+#[test]
+fn test_g2_homogeneous_projective_curve() {
+    sw_projective_tests::<g2::Parameters>();
+}
+
+#[test]
+fn test_g2_projective_group() {
+    let mut rng = ark_std::test_rng();
+    let a: G2Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_g2_msm() {
+    test_var_base_msm::<G2Affine>();
+    test_var_base_msm_edge_cases::<G2Affine>();
+    test_wnaf_msm::<G2Projective>();
+}
+
+#[test]
+fn test_g2_generator() {
+    let generator = G2Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_subgroup_check() {
+    sw_subgroup_check_test::<g2::Parameters>();
+}
+
+#[test]
+fn test_g2_clear_cofactor() {
+    sw_clear_cofactor_test::<g2::Parameters>();
+
+    // The effective cofactor of G2 in RFC 9380, Section 8.8.2.
+    #[rustfmt::skip]
+    const H_EFF: &[u64] = &[
+        0xe8020005aaa95551, 0x59894c0adebbf6b4, 0xe954cbc06689f6a3, 0x2ec0ec69d7477c1a,
+        0x6d82bf015d1212b0, 0x329c2f178731db95, 0x9986ff031508ffe1, 0x88e2a8e9145ad768,
+        0x584c6a0ea91b3528, 0x0bc69f08f2ee75b3,
+    ];
+    let mut rng = ark_std::test_rng();
+    for _ in 0..ITERATIONS {
+        let x = Fq2::rand(&mut rng);
+        if let Some(p) = G2Affine::get_point_from_x(x, rng.gen()) {
+            assert_eq!(
+                g2::Parameters::clear_cofactor(&p).into_projective(),
+                p.into_projective().mul(H_EFF)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_bilinearity() {
+    use ark_ff::PrimeField;
+
+    let mut rng = ark_std::test_rng();
+    let a: G1Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    let s: Fr = rng.gen();
+
+    let sa = a.mul(s.into_repr());
+    let sb = b.mul(s.into_repr());
+
+    let ans1 = Bls12_381::pairing(sa, b);
+    let ans2 = Bls12_381::pairing(a, sb);
+    let ans3 = Bls12_381::pairing(a, b).pow(s.into_repr());
+
+    assert_eq!(ans1, ans2);
+    assert_eq!(ans2, ans3);
+
+    assert_ne!(ans1, Fq12::one());
+    assert_eq!(ans1.pow(Fr::characteristic()), Fq12::one());
+}
+
+#[test]
+#[cfg(feature = "ct")]
+fn test_g1_ct_mul() {
+    sw_ct_mul_test::<g1::Parameters>();
+}
+
+/// The messages of the test vectors of RFC 9380, Appendix J.
+fn rfc9380_msgs() -> [ark_std::vec::Vec<u8>; 5] {
+    use ark_std::iter;
+
+    [
+        b"".to_vec(),
+        b"abc".to_vec(),
+        b"abcdef0123456789".to_vec(),
+        b"q128_"
+            .iter()
+            .copied()
+            .chain(iter::repeat(b'q').take(128))
+            .collect(),
+        b"a512_"
+            .iter()
+            .copied()
+            .chain(iter::repeat(b'a').take(512))
+            .collect(),
+    ]
+}
+
+/// The `u` values of the BLS12381G1_XMD:SHA-256_SSWU_RO_ test vectors of
+/// RFC 9380, Appendix J.9.1.
+#[test]
+fn test_fq_hash_to_field() {
+    use ark_ff::field_hashers::{DefaultFieldHasher, ExpanderXmd, HashToField};
+    use ark_std::str::FromStr;
+
+    let hasher = <DefaultFieldHasher<ExpanderXmd<sha2::Sha256>, Fq> as HashToField<Fq>>::new(
+        b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_",
+    );
+    let msgs = rfc9380_msgs();
+    #[rustfmt::skip]
+    let expected = [
+        ["1790030616568561980207134218344899338736900885118493183248255875682123737756800213955590674957414534085508415116879", "247470258331762152370823329280394074170045058453263356372873997375166908584899100434893060702108665825589810322121"],
+        ["2088728490498894818688784437928579501848367107744050576780266498473771518428420173373487118890161663886009635645777", "32138924938310862093169606408734331410171587925844216752733293543601988453843327878077294514665889481436558332217"],
+        ["950597030816464821778971015673486129641410344078861474750527508537804549386058612983484048401731236595379325781716", "1979385000937648348925653198641340374887185657649818450486460034420643425685140133042050299078521896600910613745210"],
+        ["156598384884054652954707150757138355079410210785113857376825014810441188545548595465313883035731540725116276838022", "1709027689043323463259398100486189187238532958310276339146988040422594808842792053521671901476006506290292962489454"],
+        ["1625704516324785166230868561544190006281306318060308039760768255839116494270087378351796462565313509233883467016390", "897347619044039892426123073051050824113615337090860431730602102178645855045832565883684732229117125155988066429111"],
+    ];
+    for (msg, expected) in msgs.iter().zip(expected.iter()) {
+        let u = hasher.hash_to_field(msg, 2).unwrap();
+        assert_eq!(u[0], Fq::from_str(expected[0]).unwrap());
+        assert_eq!(u[1], Fq::from_str(expected[1]).unwrap());
+    }
+}
+
+/// The BLS12381G1_XMD:SHA-256_SSWU_RO_ and BLS12381G1_XMD:SHA-256_SSWU_NU_
+/// test vectors of RFC 9380, Appendices J.9.1 and J.9.2.
+#[test]
+fn test_g1_hash_to_curve() {
+    use ark_ec::hashing::{curve_maps::wb::WBMap, HashToCurve, MapToCurveBasedHasher};
+    use ark_ff::field_hashers::{DefaultFieldHasher, ExpanderXmd};
+    use ark_std::str::FromStr;
+
+    type Hasher = MapToCurveBasedHasher<
+        G1Affine,
+        DefaultFieldHasher<ExpanderXmd<sha2::Sha256>, Fq>,
+        WBMap<g1::Parameters>,
+    >;
+
+    let ro = Hasher::new(b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_").unwrap();
+    let nu = Hasher::new(b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_NU_").unwrap();
+    #[rustfmt::skip]
+    let expected_ro = [
+        ["794311575721400831362957049303781044852006323422624111893352859557450008308620925451441746926395141598720928151969", "1343412193624222137939591894701031123123641958980729764240763391191550653712890272928110356903136085217047453540965"],
+        ["513738460217615943921285247703448567647875874745567372796164155472383127756567780059136521508428662765965997467907", "1786897908129645780825838873875416513994655004408749907941296449131605892957529391590865627492442562626458913769565"],
+        ["2751628761372137084683207295437105268166375184027748372156952770986741873369176463286511518644061904904607431667096", "563036982304416203921640398061260377444881693369806087719971277317609936727208012968659302318886963927918562170633"],
+        ["3380432694887674439773082418192083720584748080704959172978586229921475315220434165460350679208315690319508336723080", "3698526739072864408749571082270628561764415577445404115596990919801523793138348254443092179877354467167123794222392"],
+        ["1256967425542823069694513550918025689490036478501181600525944653952846100887848729514132077573887342346961531624702", "880372082403694543476959909256504267215588055450016885103797700856746532134585942561958795215862304181527267736264"],
+    ];
+    #[rustfmt::skip]
+    let expected_nu = [
+        ["3739458466830661468953341342129567279875926523016247087039101662181447483287198459699466062199208589244562592909498", "654425034689574044304586010629415640496365028285092416770785394355619662021746513529290942525870066619776789679091"],
+        ["91034094472056383692706137559754790750936786811267714512454747683448064940685964827936886876674608837412761677421", "3262708204940680298593486727902884375314071044036767031306544098760616289716019290723937653785127388882209803980076"],
+        ["3918110515263021569156838325975385602906931501438509710012263730189551451958739015420063008152606759808672288590698", "3382224226088426982129660146942769297198581120319544944732393282631896743286522576243638863706476746354874890851235"],
+        ["1612501083407774966453757573593970505409749366346382267757441965865568674145406062634799052220380212766653491184252", "3003538739192449858379712245267605133184028042067049472047599897623147924956067171685512472101895969629657131089097"],
+        ["2228200118365403973924156235337480773190784510052019969773342533440848652265071410734021214748955659617752089947921", "1678997527039238552709391269781651118674918078761656423788884172917931134963428604441685470428166338106630279811803"],
+    ];
+    let msgs = rfc9380_msgs();
+    for ((msg, ro_xy), nu_xy) in msgs.iter().zip(&expected_ro).zip(&expected_nu) {
+        let p = ro.hash(msg).unwrap();
+        assert_eq!(p.x, Fq::from_str(ro_xy[0]).unwrap());
+        assert_eq!(p.y, Fq::from_str(ro_xy[1]).unwrap());
+
+        let p = nu.encode(msg).unwrap();
+        assert_eq!(p.x, Fq::from_str(nu_xy[0]).unwrap());
+        assert_eq!(p.y, Fq::from_str(nu_xy[1]).unwrap());
+    }
+}
+
+/// The BLS12381G2_XMD:SHA-256_SSWU_RO_ and BLS12381G2_XMD:SHA-256_SSWU_NU_
+/// test vectors of RFC 9380, Appendices J.10.1 and J.10.2, as
+/// `[x.c0, x.c1, y.c0, y.c1]`.
+#[test]
+fn test_g2_hash_to_curve() {
+    use crate::bls12_381::g2_swu_iso::G2SWUIsoAffine;
+    use ark_ec::hashing::{curve_maps::wb::WBMap, HashToCurve, MapToCurveBasedHasher};
+    use ark_ff::field_hashers::{DefaultFieldHasher, ExpanderXmd};
+    use ark_std::str::FromStr;
+
+    let generator = G2SWUIsoAffine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+
+    type Hasher = MapToCurveBasedHasher<
+        G2Affine,
+        DefaultFieldHasher<ExpanderXmd<sha2::Sha256>, Fq2>,
+        WBMap<g2::Parameters>,
+    >;
+
+    let ro = Hasher::new(b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_").unwrap();
+    let nu = Hasher::new(b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_NU_").unwrap();
+    #[rustfmt::skip]
+    let expected_ro = [
+        ["193548053368451749411421515628510806626565736652086807419354395577367693778571452628423727082668900187036482254730", "891930009643099423308102777951250899694559203647724988361022851024990473423938537113948850338098230396747396259901", "771717272055834152378281705972671257005357145478800908373659404991537354153455452961747174765859335819766715637138", "2810310118582126634041133454180705304393079139103252956502404531123692847658283858246402311867775854528543237781718"],
+        ["424958340463073975547762735517193206833255107941790909009827635556634414746056077714431786321247871628515967727334", "3018679803970127877262826393814472528557413504329194740495363852840690589001358162447917674089074634504498585239512", "3621308185128395459888995526527127556614768604472132176060423302734876099689739385100475320409412954617897892887112", "102447784096837908713257069727879782642075240724579670654226801345708452018676587771714457671432122751958633012502"],
+        ["2785790728239146617702443308248535381016035748520698399690132325213972292102741627498014391457605127656937478044880", "3855709393631831880910167818276435187147963371126198799654803099743427431977934703201153169947378798970358200024876", "821938378705205565995357931232097952117504537366318395539093959918654729488074273868834599496909844419980823111624", "1802420335575779950982935580421454302087567926385222707947527353462942499437987207287862072369052390195154530059198"],
+        ["3949041098513688455491231180749724794697192943196730030853285011755806989731870696216017360514887069032515603535834", "1416893694506131976809002935212216317132941942570763849323065381335907430566747765697423320407614734575486820936593", "3227453710863835032992962605851449401391399355135442728893790186263669279022343042444878900124369614767241382891922", "1498738834073759871886466122933996764471889514532827927202777922460876335493588931070034160657995151627624577390178"],
+        ["254155017921606149907129844368549510385368618440139550318910532874259603395336903946742408725761795820224536519988", "2768431459296730426779166218544149791601585986233130583011501727704972362141149700714785450629498506208393873593705", "1755339344744337457318565116062025669984750617937721245220711425551575490663761638802010265668157125441634554205566", "560643043433789571968941329642646582974304556331567393300563909451776257854214387388500126524984624222885267024722"],
+    ];
+    #[rustfmt::skip]
+    let expected_nu = [
+        ["139457252492287253420333391079728092845241414540297668058749058891142059410046438604886693868839824907456144636855", "2835098063307186537089251975781595941938165673676121238542909894591764744309084848783043649864398134737543751512923", "1951988964470017489854131662590472591411225738750085807962813718277873750870600304634550241695120136348903191284290", "3170069502467919779795485939844154998687841195649960263706253080909456488923641810384056831758439129424538647486013"],
+        ["2548501313290414092324545986106519301569263277739455444325557006347513650998000486312887241589944348660200950350879", "398095716312110958809940920713192069790831600925745891781536662396377997176788610306265574833443205316957858346365", "499960002019884352256187274233493506255420356106994096101247409030696207406109767081104570585952538066832079119958", "3264677963677031410356710870881292942979394331087204757312042090010361491115483362661675669312335169830774641982527"],
+        ["545282276898017956440665462907109730960372243881725738620866961047542964347802449729725859649543533318052577303539", "2101503847616272019291282197016839372181824372186551264605666804855208793969628568442424626938546836125979100538267", "3954440294508654403381055791750063186813788311461040777712156137172427373107266534050046899725055491244244933164532", "704010856691126552331048936277239314135477198842467117209923776325808770149442711237752283912650582697039082400862"],
+        ["1901622302798477526396785744742180163463863832444288060970975862228924934351675730434225280307010896383271207307513", "2891150706158039611085732885924729734954391038269249707900957910352930502705506757554379360380127983266952843473325", "754835340174150649859808657870236972614515843557732412744571459255293076325126937852601121410919975962885831042409", "2735668759157573649708311144095478538277214006080225212949431703526468764668639051056515877660853416538935801009734"],
+        ["2253942730121534022726158544126357928882820046124093443651056821344779429755985913631020167479320037755739638549473", "3293377613208254633930547334883740930350353521943112365809151208393434488678341388213273502997705875950761838635149", "651390301947711193352907895556823378264761186003654552834294401613425919188063600209971238777523111470581268450600", "2391421857040228102844739456756309649122030282571951168284400164354815309034526937251231665573779567469793123897927"],
+    ];
+    let fq2 = |c0: &str, c1: &str| Fq2::new(Fq::from_str(c0).unwrap(), Fq::from_str(c1).unwrap());
+    let msgs = rfc9380_msgs();
+    for ((msg, ro_xy), nu_xy) in msgs.iter().zip(&expected_ro).zip(&expected_nu) {
+        let p = ro.hash(msg).unwrap();
+        assert_eq!(p.x, fq2(ro_xy[0], ro_xy[1]));
+        assert_eq!(p.y, fq2(ro_xy[2], ro_xy[3]));
+
+        let p = nu.encode(msg).unwrap();
+        assert_eq!(p.x, fq2(nu_xy[0], nu_xy[1]));
+        assert_eq!(p.y, fq2(nu_xy[2], nu_xy[3]));
+    }
+}
+
+/// RFC 9380 has no test vectors for the SvdW suites, so these
+/// BLS12381G1_XMD:SHA-256_SVDW_RO_ and BLS12381G1_XMD:SHA-256_SVDW_NU_ vectors
+/// were computed with a separate implementation of the straight-line
+/// `map_to_curve_svdw` of Appendix F.1 with `Z = -3`.
+#[test]
+fn test_g1_hash_to_curve_svdw() {
+    use ark_ec::hashing::{curve_maps::svdw::SvdWMap, HashToCurve, MapToCurveBasedHasher};
+    use ark_ff::field_hashers::{DefaultFieldHasher, ExpanderXmd};
+    use ark_std::str::FromStr;
+
+    type Hasher = MapToCurveBasedHasher<
+        G1Affine,
+        DefaultFieldHasher<ExpanderXmd<sha2::Sha256>, Fq>,
+        SvdWMap<g1::Parameters>,
+    >;
+
+    let ro = Hasher::new(b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SVDW_RO_").unwrap();
+    let nu = Hasher::new(b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SVDW_NU_").unwrap();
+    #[rustfmt::skip]
+    let expected_ro = [
+        ["42321736120739975009562324261473090801353703227287431622470951532078200773377616114693503431153626148455752941994", "529605306958600210357359862202325440761219098014313829655775654720804028323190052392345426081275815288198581738347"],
+        ["1840852913232205354428513115300661623824003010178190707065891473650940513554462495670259726578318232898705638723477", "604804078194194232203939332749394399572033802294330641937111809208706314703130379166960827851308688633842715718790"],
+        ["3593208395046275559866340534269368544169700634503869945843269519735665973805437885634417230152972420697677930317363", "197934653264673912954063798099076451264208541356940762579855815840800886518261572901051230025978447703530351595398"],
+        ["2310463720867816567781554741258558270152020129809950366595548431054677460755517787819887714086908027380092619843688", "3857558475472917163882471412863539020094570918326319364875280596402834390150288827199057588491157302922810712784124"],
+        ["777592963601256945891710583486774490608676367345411301347666581475264694262809940262869180270731552600567825105004", "236781594042948937196007797223362031319993555483354937214808356476580387123742260254459060068721699791300376314023"],
+    ];
+    #[rustfmt::skip]
+    let expected_nu = [
+        ["107729694038897453565604891488712112699501680192643847161358433563356125991244382590834790638591611090005041147400", "2989466268756789988322907993737707816847160571011307114265461248354352313539156899955067185825005370266287433311537"],
+        ["182365748578746066181101107052107488017438142387504990022021817456155507308606533618375321336254248799474521421795", "472634531319745398021709665948660095107298579838820107237665250601694190793101336469361517046624843115581225741227"],
+        ["2792239554526573277490587176799543631342951524753511804141922727171921801966145178404319890406013116656462618417423", "2924201037102521475738809503126685196355193400079506299404824519396227817071516325947776145331712446225924146868299"],
+        ["1649062269221476274579890518817747368613792102101385739298158132257742807308308148976457234582942290099369392759823", "370577810413461935708091893735323355610224733940832272855945460784631271265392510965348183995587498912627033663980"],
+        ["2803587281164762890908913248006189634386391598662172418305061252138573518200440411779739779064931814337459190494576", "2291753498960521634950475990396345748254190884695704838780304625202649262920772663039482161192944886025972757233954"],
+    ];
+    let msgs = rfc9380_msgs();
+    for ((msg, ro_xy), nu_xy) in msgs.iter().zip(&expected_ro).zip(&expected_nu) {
+        let p = ro.hash(msg).unwrap();
+        assert_eq!(p.x, Fq::from_str(ro_xy[0]).unwrap());
+        assert_eq!(p.y, Fq::from_str(ro_xy[1]).unwrap());
+
+        let p = nu.encode(msg).unwrap();
+        assert_eq!(p.x, Fq::from_str(nu_xy[0]).unwrap());
+        assert_eq!(p.y, Fq::from_str(nu_xy[1]).unwrap());
+    }
+}
+
+#[derive(FpParameters)]
+#[modulus = "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559787"]
+#[generator = "2"]
+struct DerivedFqParameters;
+
+#[test]
+fn test_derived_fq_parameters() {
+    type P = FqParameters;
+    type D = DerivedFqParameters;
+    assert_eq!(P::TWO_ADICITY, D::TWO_ADICITY);
+    assert_eq!(P::TWO_ADIC_ROOT_OF_UNITY, D::TWO_ADIC_ROOT_OF_UNITY);
+    assert_eq!(P::SMALL_SUBGROUP_BASE, D::SMALL_SUBGROUP_BASE);
+    assert_eq!(P::MODULUS, D::MODULUS);
+    assert_eq!(P::MODULUS_BITS, D::MODULUS_BITS);
+    assert_eq!(P::CAPACITY, D::CAPACITY);
+    assert_eq!(P::REPR_SHAVE_BITS, D::REPR_SHAVE_BITS);
+    assert_eq!(P::R, D::R);
+    assert_eq!(P::R2, D::R2);
+    assert_eq!(P::INV, D::INV);
+    assert_eq!(P::GENERATOR, D::GENERATOR);
+    assert_eq!(P::T, D::T);
+    assert_eq!(P::T_MINUS_ONE_DIV_TWO, D::T_MINUS_ONE_DIV_TWO);
+    assert_eq!(P::MODULUS_MINUS_ONE_DIV_TWO, D::MODULUS_MINUS_ONE_DIV_TWO);
+}
+
+#[derive(FpParameters)]
+#[modulus = "418993997810706159361688281193932691483730181893512293053861295116305125939798343025058571817715732115313495568327689089179808837873330310828864735019009"]
+#[generator = "11"]
+struct Fp512Parameters;
+type Fp512 = ark_ff::Fp<Fp512Parameters, 8>;
+
+#[derive(FpParameters)]
+#[modulus = "11235582092889474423308157442431404585112356118389416079589380072358292237843810195794279832650471001320007117491962084853674360550901038905802964414967132773610493339054092829768888725077880882465817684505312860552384417646403930092119569408801702322709406917786643639996702871154982269052209771232874201089"]
+#[generator = "3"]
+struct Fp1024Parameters;
+type Fp1024 = ark_ff::Fp<Fp1024Parameters, 16>;
+
+#[test]
+fn test_fp512() {
+    let mut rng = ark_std::test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fp512 = UniformRand::rand(&mut rng);
+        let b: Fp512 = UniformRand::rand(&mut rng);
+        field_test(a, b);
+        primefield_test::<Fp512>();
+        sqrt_field_test(a);
+    }
+    fft_field_test::<Fp512>();
+    field_serialization_test::<Fp512>(64);
+}
+
+#[test]
+fn test_fp1024() {
+    let mut rng = ark_std::test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fp1024 = UniformRand::rand(&mut rng);
+        let b: Fp1024 = UniformRand::rand(&mut rng);
+        field_test(a, b);
+        primefield_test::<Fp1024>();
+        sqrt_field_test(a);
+    }
+    fft_field_test::<Fp1024>();
+    field_serialization_test::<Fp1024>(128);
+}
+
+#[test]
+fn test_pairing_output() {
+    pairing_output_test::<Bls12_381>();
+}
+
+#[test]
+fn test_pairing_check() {
+    pairing_check_test::<Bls12_381>();
+}
+
+#[test]
+fn test_homogeneous_projective_g1_pairing() {
+    use ark_ec::{bls12::Bls12, SWProjective};
+    type E = Bls12<crate::bls12_381::Parameters, SWProjective>;
+
+    pairing_output_test::<E>();
+    pairing_check_test::<E>();
+
+    let mut rng = ark_std::test_rng();
+    for _ in 0..ITERATIONS {
+        let p = G1Projective::rand(&mut rng).into_affine();
+        let q = G2Projective::rand(&mut rng).into_affine();
+        let p_homogeneous = short_weierstrass_projective::GroupAffine::from(p);
+        assert_eq!(E::pairing(p_homogeneous, q), Bls12_381::pairing(p, q));
+    }
+}
+
+#[test]
+fn test_prepared_serialization() {
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::vec::Vec;
+
+    prepared_serialization_test::<Bls12_381>();
+
+    let deserialize = |q: &<Bls12_381 as PairingEngine>::G2Prepared| {
+        let mut bytes = Vec::new();
+        q.serialize(&mut bytes).unwrap();
+        <Bls12_381 as PairingEngine>::G2Prepared::deserialize(&bytes[..])
+    };
+    let q: <Bls12_381 as PairingEngine>::G2Prepared = G2Affine::prime_subgroup_generator().into();
+    let mut truncated = q.clone();
+    truncated.ell_coeffs.pop();
+    assert!(deserialize(&truncated).is_err());
+    let mut infinity = q;
+    infinity.infinity = true;
+    assert!(deserialize(&infinity).is_err());
+}
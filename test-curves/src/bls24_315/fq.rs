@@ -0,0 +1,15 @@
+use ark_ff::{
+    field_new,
+    fields::{Fp320, FpParameters},
+};
+
+pub type Fq = Fp320<FqParameters>;
+
+/// The base field of BLS24-315.
+#[derive(FpParameters)]
+#[modulus = "39705142709513438335025689890408969744933502416914749335064285505637884093126342347073617133569"]
+#[generator = "13"]
+pub struct FqParameters;
+
+pub const FQ_ONE: Fq = field_new!(Fq, "1");
+pub const FQ_ZERO: Fq = field_new!(Fq, "0");
@@ -0,0 +1,76 @@
+use crate::bls24_315::*;
+use ark_ff::{
+    field_new,
+    fields::fp12_3over2over2::{Fp12, Fp12Parameters},
+};
+
+pub type Fq12 = Fp12<Fq12Parameters>;
+
+#[derive(Clone, Copy)]
+pub struct Fq12Parameters;
+
+impl Fp12Parameters for Fq12Parameters {
+    type Fp4Params = Fq4Parameters;
+
+    /// NONRESIDUE = V
+    const NONRESIDUE: Fq4 = field_new!(Fq4, FQ2_ZERO, FQ2_ONE);
+
+    #[rustfmt::skip]
+    const FROBENIUS_COEFF_FP12_C1: &'static [Fq] = &[
+        // Fp2::NONRESIDUE^(((q^0) - 1) / 12)
+        field_new!(Fq, "1"),
+        // Fp2::NONRESIDUE^(((q^1) - 1) / 12)
+        field_new!(Fq, "17432737665785421589107433512831558061649422754130449334965277047994983947893909429238815314776"),
+        // Fp2::NONRESIDUE^(((q^2) - 1) / 12)
+        field_new!(Fq, "-37014442673353839783463348892746893664389658635873267609916377398480286678854893830142"),
+        // Fp2::NONRESIDUE^(((q^3) - 1) / 12)
+        field_new!(Fq, "14265754707630841383590096931465005402246260064523506653409458152869013672931584279153351926943"),
+        // Fp2::NONRESIDUE^(((q^4) - 1) / 12)
+        field_new!(Fq, "-37014442673353839783463348892746893664389658635873267609916377398480286678854893830143"),
+        // Fp2::NONRESIDUE^(((q^5) - 1) / 12)
+        field_new!(Fq, "-3166982958154580205517336581366552659403162689606942681555818895125970274962325150085463387833"),
+        // Fp2::NONRESIDUE^(((q^6) - 1) / 12)
+        field_new!(Fq, "-1"),
+        // Fp2::NONRESIDUE^(((q^7) - 1) / 12)
+        field_new!(Fq, "-17432737665785421589107433512831558061649422754130449334965277047994983947893909429238815314776"),
+        // Fp2::NONRESIDUE^(((q^8) - 1) / 12)
+        field_new!(Fq, "37014442673353839783463348892746893664389658635873267609916377398480286678854893830142"),
+        // Fp2::NONRESIDUE^(((q^9) - 1) / 12)
+        field_new!(Fq, "-14265754707630841383590096931465005402246260064523506653409458152869013672931584279153351926943"),
+        // Fp2::NONRESIDUE^(((q^10) - 1) / 12)
+        field_new!(Fq, "37014442673353839783463348892746893664389658635873267609916377398480286678854893830143"),
+        // Fp2::NONRESIDUE^(((q^11) - 1) / 12)
+        field_new!(Fq, "3166982958154580205517336581366552659403162689606942681555818895125970274962325150085463387833"),
+    ];
+
+    #[rustfmt::skip]
+    const FROBENIUS_COEFF_FP12_C2: &'static [Fq] = &[
+        // Fp2::NONRESIDUE^(((2q^0) - 2) / 12)
+        field_new!(Fq, "1"),
+        // Fp2::NONRESIDUE^(((2q^1) - 2) / 12)
+        field_new!(Fq, "-37014442673353839783463348892746893664389658635873267609916377398480286678854893830142"),
+        // Fp2::NONRESIDUE^(((2q^2) - 2) / 12)
+        field_new!(Fq, "-37014442673353839783463348892746893664389658635873267609916377398480286678854893830143"),
+        // Fp2::NONRESIDUE^(((2q^3) - 2) / 12)
+        field_new!(Fq, "-1"),
+        // Fp2::NONRESIDUE^(((2q^4) - 2) / 12)
+        field_new!(Fq, "37014442673353839783463348892746893664389658635873267609916377398480286678854893830142"),
+        // Fp2::NONRESIDUE^(((2q^5) - 2) / 12)
+        field_new!(Fq, "37014442673353839783463348892746893664389658635873267609916377398480286678854893830143"),
+        // Fp2::NONRESIDUE^(((2q^6) - 2) / 12)
+        field_new!(Fq, "1"),
+        // Fp2::NONRESIDUE^(((2q^7) - 2) / 12)
+        field_new!(Fq, "-37014442673353839783463348892746893664389658635873267609916377398480286678854893830142"),
+        // Fp2::NONRESIDUE^(((2q^8) - 2) / 12)
+        field_new!(Fq, "-37014442673353839783463348892746893664389658635873267609916377398480286678854893830143"),
+        // Fp2::NONRESIDUE^(((2q^9) - 2) / 12)
+        field_new!(Fq, "-1"),
+        // Fp2::NONRESIDUE^(((2q^10) - 2) / 12)
+        field_new!(Fq, "37014442673353839783463348892746893664389658635873267609916377398480286678854893830142"),
+        // Fp2::NONRESIDUE^(((2q^11) - 2) / 12)
+        field_new!(Fq, "37014442673353839783463348892746893664389658635873267609916377398480286678854893830143"),
+    ];
+}
+
+pub const FQ12_ZERO: Fq12 = field_new!(Fq12, FQ4_ZERO, FQ4_ZERO, FQ4_ZERO);
+pub const FQ12_ONE: Fq12 = field_new!(Fq12, FQ4_ONE, FQ4_ZERO, FQ4_ZERO);
@@ -0,0 +1,66 @@
+use crate::bls24_315::*;
+use ark_ff::{field_new, fields::*};
+
+pub type Fq24 = Fp24<Fq24Parameters>;
+
+#[derive(Clone, Copy)]
+pub struct Fq24Parameters;
+
+impl Fp24Parameters for Fq24Parameters {
+    type Fp12Params = Fq12Parameters;
+
+    /// NONRESIDUE = W
+    const NONRESIDUE: Fq12 = field_new!(Fq12, FQ4_ZERO, FQ4_ONE, FQ4_ZERO);
+
+    #[rustfmt::skip]
+    const FROBENIUS_COEFF_FP24_C1: &'static [Fq2] = &[
+        // Fp2::NONRESIDUE^(((q^0) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "1"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^1) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "-1985506990638640885858524879104865540064569524861753878449577723469379577830716338716791460546"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^2) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "17432737665785421589107433512831558061649422754130449334965277047994983947893909429238815314776"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^3) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "13266452002786802757645810648664867986567631927642464177452792960815113608167203350720036682455"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^4) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "-37014442673353839783463348892746893664389658635873267609916377398480286678854893830142"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^5) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "-12671185780699459162044992073759470856696012635828778515525961597519010445486684117523178053390"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^6) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "14265754707630841383590096931465005402246260064523506653409458152869013672931584279153351926943"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^7) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "-6362276145764275807267116963245867451695009708067100613911562389934244299112650072812415901472"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^8) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "-37014442673353839783463348892746893664389658635873267609916377398480286678854893830143"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^9) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "-10685678790060818276186467194654605316631443110967024637076383874049630867655967778806386592844"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^10) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "-3166982958154580205517336581366552659403162689606942681555818895125970274962325150085463387833"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^11) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "-19628728148551078564912927611910735438262641635709564791364355350749357907279853423532452583927"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^12) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "-1"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^13) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "1985506990638640885858524879104865540064569524861753878449577723469379577830716338716791460546"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^14) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "-17432737665785421589107433512831558061649422754130449334965277047994983947893909429238815314776"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^15) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "-13266452002786802757645810648664867986567631927642464177452792960815113608167203350720036682455"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^16) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "37014442673353839783463348892746893664389658635873267609916377398480286678854893830142"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^17) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "12671185780699459162044992073759470856696012635828778515525961597519010445486684117523178053390"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^18) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "-14265754707630841383590096931465005402246260064523506653409458152869013672931584279153351926943"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^19) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "6362276145764275807267116963245867451695009708067100613911562389934244299112650072812415901472"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^20) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "37014442673353839783463348892746893664389658635873267609916377398480286678854893830143"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^21) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "10685678790060818276186467194654605316631443110967024637076383874049630867655967778806386592844"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^22) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "3166982958154580205517336581366552659403162689606942681555818895125970274962325150085463387833"), FQ_ZERO),
+        // Fp2::NONRESIDUE^(((q^23) - 1) / 24)
+        field_new!(Fq2, field_new!(Fq, "19628728148551078564912927611910735438262641635709564791364355350749357907279853423532452583927"), FQ_ZERO),
+    ];
+}
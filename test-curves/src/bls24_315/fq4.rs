@@ -0,0 +1,28 @@
+use crate::bls24_315::*;
+use ark_ff::{field_new, fields::*};
+
+pub type Fq4 = Fp4<Fq4Parameters>;
+
+pub struct Fq4Parameters;
+
+impl Fp4Parameters for Fq4Parameters {
+    type Fp2Params = Fq2Parameters;
+
+    /// NONRESIDUE = U
+    const NONRESIDUE: Fq2 = field_new!(Fq2, FQ_ZERO, FQ_ONE);
+
+    #[rustfmt::skip]
+    const FROBENIUS_COEFF_FP4_C1: &'static [Fq] = &[
+        // Fp2::NONRESIDUE^(((q^0) - 1) / 4)
+        field_new!(Fq, "1"),
+        // Fp2::NONRESIDUE^(((q^1) - 1) / 4)
+        field_new!(Fq, "14265754707630841383590096931465005402246260064523506653409458152869013672931584279153351926943"),
+        // Fp2::NONRESIDUE^(((q^2) - 1) / 4)
+        field_new!(Fq, "-1"),
+        // Fp2::NONRESIDUE^(((q^3) - 1) / 4)
+        field_new!(Fq, "-14265754707630841383590096931465005402246260064523506653409458152869013672931584279153351926943"),
+    ];
+}
+
+pub const FQ4_ZERO: Fq4 = field_new!(Fq4, FQ2_ZERO, FQ2_ZERO);
+pub const FQ4_ONE: Fq4 = field_new!(Fq4, FQ2_ONE, FQ2_ZERO);
@@ -0,0 +1,9 @@
+use ark_ff::fields::{Fp256, FpParameters};
+
+pub type Fr = Fp256<FrParameters>;
+
+/// The scalar field of BLS24-315.
+#[derive(FpParameters)]
+#[modulus = "11502027791375260645628074404575422495959608200132055716665986169834464870401"]
+#[generator = "7"]
+pub struct FrParameters;
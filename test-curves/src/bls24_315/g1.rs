@@ -0,0 +1,52 @@
+use ark_ec::{
+    models::{ModelParameters, SWModelParameters},
+    short_weierstrass_jacobian::*,
+};
+use ark_ff::{field_new, Zero};
+
+use crate::bls24_315::{Fq, Fr};
+
+pub type G1Affine = GroupAffine<Parameters>;
+pub type G1Projective = GroupProjective<Parameters>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq = field_new!(Fq, "0");
+
+    /// COEFF_B = 1
+    const COEFF_B: Fq = field_new!(Fq, "1");
+
+    /// COFACTOR = (x - 1)^2 / 3 = 3452012412914368512
+    const COFACTOR: &'static [u64] = &[0x2fe8030000000000];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, "11502027748484983061746766725372608623013042341922603258659095701186171371503");
+
+    /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (G1_GENERATOR_X, G1_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// G1_GENERATOR_X =
+/// 34223510504517033132712852754388476272837911830964394866541204856091481856889569724484362330263
+#[rustfmt::skip]
+pub const G1_GENERATOR_X: Fq = field_new!(Fq, "34223510504517033132712852754388476272837911830964394866541204856091481856889569724484362330263");
+
+/// G1_GENERATOR_Y =
+/// 24215295174889464585413596429561903295150472552154479431771837786124301185073987899223459122783
+#[rustfmt::skip]
+pub const G1_GENERATOR_Y: Fq = field_new!(Fq, "24215295174889464585413596429561903295150472552154479431771837786124301185073987899223459122783");
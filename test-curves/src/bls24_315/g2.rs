@@ -0,0 +1,116 @@
+use ark_ec::{
+    models::{ModelParameters, SWModelParameters},
+    short_weierstrass_jacobian::*,
+};
+use ark_ff::{field_new, Zero};
+
+use crate::bls24_315::{Fq, Fq2, Fq4, Fr, FQ2_ZERO, FQ_ZERO};
+
+pub type G2Affine = GroupAffine<Parameters>;
+pub type G2Projective = GroupProjective<Parameters>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq4;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq4 = field_new!(Fq4, FQ2_ZERO, FQ2_ZERO);
+
+    /// COEFF_B = 1/V = (U/13) * V
+    #[rustfmt::skip]
+    const COEFF_B: Fq4 = field_new!(Fq4,
+        FQ2_ZERO,
+        field_new!(Fq2, FQ_ZERO, field_new!(Fq, "6108483493771298205388567675447533806912846525679192205394505462405828322019437284165171866703")),
+    );
+
+    /// COFACTOR = #E'(Fq4) / r
+    ///          = 216079035500590602943546242140422432107555648541092228905249925297233022840522997069049628086159486821981928133195442045258836056038368698198752015929588430502672406127261882483243231901352617383373863699144968206692699635819037532045432968648848220192219321417343498967027189130043882684380082463571969
+    #[rustfmt::skip]
+    const COFACTOR: &'static [u64] = &[
+        0xdf101e0000000001,
+        0xc6fe9ac0018b940a,
+        0x15b52c02797e31a2,
+        0xcd34eace3e7e7012,
+        0x83eb09e6f58e9055,
+        0x98dd4b373acdeee1,
+        0xfe00fe2b41efdc36,
+        0xbf73a8d2cc439fff,
+        0x469c774cb87cd287,
+        0x7a0dded7e599b628,
+        0xb006921322861513,
+        0x446602b3ec97c88d,
+        0x9f9a3e8f21811c01,
+        0xa3d20ecd4e893c62,
+        0xcf9c5e2d1e9744e1,
+        0x142a76791a4e,
+    ];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, "4554155043303784494090402065353399334353191703800238427501238319613167445417");
+
+    /// AFFINE_GENERATOR_COEFFS = (G2_GENERATOR_X, G2_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (G2_GENERATOR_X, G2_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+#[rustfmt::skip]
+pub const G2_GENERATOR_X: Fq4 = field_new!(Fq4,
+    field_new!(Fq2, G2_GENERATOR_X_C0_C0, G2_GENERATOR_X_C0_C1),
+    field_new!(Fq2, G2_GENERATOR_X_C1_C0, G2_GENERATOR_X_C1_C1),
+);
+#[rustfmt::skip]
+pub const G2_GENERATOR_Y: Fq4 = field_new!(Fq4,
+    field_new!(Fq2, G2_GENERATOR_Y_C0_C0, G2_GENERATOR_Y_C0_C1),
+    field_new!(Fq2, G2_GENERATOR_Y_C1_C0, G2_GENERATOR_Y_C1_C1),
+);
+
+/// G2_GENERATOR_X_C0_C0 =
+/// 15605658976347003168291848932656096441905066952688754301085580303209342647111141992523236791520
+#[rustfmt::skip]
+pub const G2_GENERATOR_X_C0_C0: Fq = field_new!(Fq, "15605658976347003168291848932656096441905066952688754301085580303209342647111141992523236791520");
+
+/// G2_GENERATOR_X_C0_C1 =
+/// 8645511139255804615067076249654799459513316958383498118692359217377707113502159309355594107619
+#[rustfmt::skip]
+pub const G2_GENERATOR_X_C0_C1: Fq = field_new!(Fq, "8645511139255804615067076249654799459513316958383498118692359217377707113502159309355594107619");
+
+/// G2_GENERATOR_X_C1_C0 =
+/// 16389786743616472668063775817236989769102480422944306423498320994490181311154376335374641486035
+#[rustfmt::skip]
+pub const G2_GENERATOR_X_C1_C0: Fq = field_new!(Fq, "16389786743616472668063775817236989769102480422944306423498320994490181311154376335374641486035");
+
+/// G2_GENERATOR_X_C1_C1 =
+/// 29747240458302449336873095474421823584104079003120007488496889916560035849795631027301266279344
+#[rustfmt::skip]
+pub const G2_GENERATOR_X_C1_C1: Fq = field_new!(Fq, "29747240458302449336873095474421823584104079003120007488496889916560035849795631027301266279344");
+
+/// G2_GENERATOR_Y_C0_C0 =
+/// 15991695339745267702590731539904193595870321983363172134131607727301143769935045816021382744067
+#[rustfmt::skip]
+pub const G2_GENERATOR_Y_C0_C0: Fq = field_new!(Fq, "15991695339745267702590731539904193595870321983363172134131607727301143769935045816021382744067");
+
+/// G2_GENERATOR_Y_C0_C1 =
+/// 10845051767159054591176005570170963604609704017152805469721733182937558857710141165619273437282
+#[rustfmt::skip]
+pub const G2_GENERATOR_Y_C0_C1: Fq = field_new!(Fq, "10845051767159054591176005570170963604609704017152805469721733182937558857710141165619273437282");
+
+/// G2_GENERATOR_Y_C1_C0 =
+/// 37305389331044030710316253239802576103234723713513833289798728998854083772999024903812836470785
+#[rustfmt::skip]
+pub const G2_GENERATOR_Y_C1_C0: Fq = field_new!(Fq, "37305389331044030710316253239802576103234723713513833289798728998854083772999024903812836470785");
+
+/// G2_GENERATOR_Y_C1_C1 =
+/// 6754783681072325245504985595934713452173478436599118316068534033518088549495043117487430292821
+#[rustfmt::skip]
+pub const G2_GENERATOR_Y_C1_C1: Fq = field_new!(Fq, "6754783681072325245504985595934713452173478436599118316068534033518088549495043117487430292821");
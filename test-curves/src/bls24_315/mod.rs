@@ -0,0 +1,59 @@
+pub mod fr;
+pub use fr::*;
+
+#[cfg(feature = "bls24_315_curve")]
+pub mod fq;
+#[cfg(feature = "bls24_315_curve")]
+pub mod fq12;
+#[cfg(feature = "bls24_315_curve")]
+pub mod fq2;
+#[cfg(feature = "bls24_315_curve")]
+pub mod fq24;
+#[cfg(feature = "bls24_315_curve")]
+pub mod fq4;
+#[cfg(feature = "bls24_315_curve")]
+pub mod g1;
+#[cfg(feature = "bls24_315_curve")]
+pub mod g2;
+
+#[cfg(feature = "bls24_315_curve")]
+pub use fq::*;
+#[cfg(feature = "bls24_315_curve")]
+pub use fq12::*;
+#[cfg(feature = "bls24_315_curve")]
+pub use fq2::*;
+#[cfg(feature = "bls24_315_curve")]
+pub use fq24::*;
+#[cfg(feature = "bls24_315_curve")]
+pub use fq4::*;
+#[cfg(feature = "bls24_315_curve")]
+pub use g1::{G1Affine, G1Projective};
+#[cfg(feature = "bls24_315_curve")]
+pub use g2::{G2Affine, G2Projective};
+
+#[cfg(feature = "bls24_315_curve")]
+use ark_ec::bls24::{Bls24, Bls24Parameters, TwistType};
+
+#[cfg(feature = "bls24_315_curve")]
+pub type Bls24_315 = Bls24<Parameters>;
+
+#[cfg(feature = "bls24_315_curve")]
+pub struct Parameters;
+
+#[cfg(feature = "bls24_315_curve")]
+impl Bls24Parameters for Parameters {
+    const X: &'static [u64] = &[0xbfcfffff];
+    /// `x` is negative.
+    const X_IS_NEGATIVE: bool = true;
+    const TWIST_TYPE: TwistType = TwistType::D;
+    type Fp = Fq;
+    type Fp2Params = Fq2Parameters;
+    type Fp4Params = Fq4Parameters;
+    type Fp12Params = Fq12Parameters;
+    type Fp24Params = Fq24Parameters;
+    type G1Parameters = g1::Parameters;
+    type G2Parameters = g2::Parameters;
+}
+
+#[cfg(test)]
+mod tests;
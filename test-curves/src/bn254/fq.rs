@@ -0,0 +1,15 @@
+use ark_ff::{
+    field_new,
+    fields::{Fp256, FpParameters},
+};
+
+pub type Fq = Fp256<FqParameters>;
+
+/// The base field of BN254.
+#[derive(FpParameters)]
+#[modulus = "21888242871839275222246405745257275088696311157297823662689037894645226208583"]
+#[generator = "3"]
+pub struct FqParameters;
+
+pub const FQ_ONE: Fq = field_new!(Fq, "1");
+pub const FQ_ZERO: Fq = field_new!(Fq, "0");
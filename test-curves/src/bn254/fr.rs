@@ -0,0 +1,9 @@
+use ark_ff::fields::{Fp256, FpParameters};
+
+pub type Fr = Fp256<FrParameters>;
+
+/// The scalar field of BN254.
+#[derive(FpParameters)]
+#[modulus = "21888242871839275222246405745257275088548364400416034343698204186575808495617"]
+#[generator = "5"]
+pub struct FrParameters;
@@ -0,0 +1,157 @@
+#![allow(unused_imports)]
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{Field, One, PrimeField, UniformRand};
+use ark_std::rand::Rng;
+
+use crate::bn254::{
+    g1, g2, Bn254, Fq, Fq12, Fq2, Fq6, Fr, G1Affine, G1Projective, G2Affine, G2Projective,
+};
+use ark_algebra_test_templates::{curves::*, fields::*, groups::*};
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_fr() {
+    let mut rng = ark_std::test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fr = UniformRand::rand(&mut rng);
+        let b: Fr = UniformRand::rand(&mut rng);
+        field_test(a, b);
+        primefield_test::<Fr>();
+        sqrt_field_test(b);
+    }
+}
+
+#[test]
+fn test_fq() {
+    let mut rng = ark_std::test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq = UniformRand::rand(&mut rng);
+        let b: Fq = UniformRand::rand(&mut rng);
+        field_test(a, b);
+        primefield_test::<Fq>();
+        sqrt_field_test(a);
+    }
+}
+
+#[test]
+fn test_fq2() {
+    let mut rng = ark_std::test_rng();
+    let a: Fq2 = UniformRand::rand(&mut rng);
+    let b: Fq2 = UniformRand::rand(&mut rng);
+    field_test(a, b);
+    sqrt_field_test(a);
+    frobenius_test::<Fq2, _>(Fq::characteristic(), 13);
+}
+
+#[test]
+fn test_fq6() {
+    let mut rng = ark_std::test_rng();
+    let a: Fq6 = UniformRand::rand(&mut rng);
+    let b: Fq6 = UniformRand::rand(&mut rng);
+    field_test(a, b);
+    frobenius_test::<Fq6, _>(Fq::characteristic(), 13);
+}
+
+#[test]
+fn test_fq12() {
+    let mut rng = ark_std::test_rng();
+    let a: Fq12 = UniformRand::rand(&mut rng);
+    let b: Fq12 = UniformRand::rand(&mut rng);
+    field_test(a, b);
+    frobenius_test::<Fq12, _>(Fq::characteristic(), 13);
+}
+
+#[test]
+fn test_g1_projective_curve() {
+    curve_tests::<G1Projective>();
+    sw_tests::<g1::Parameters>();
+}
+
+#[test]
+fn test_g1_homogeneous_projective_curve() {
+    sw_projective_tests::<g1::Parameters>();
+}
+
+#[test]
+fn test_g1_generator() {
+    let generator = G1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_projective_curve() {
+    curve_tests::<G2Projective>();
+    sw_tests::<g2::Parameters>();
+}
+
+#[test]
+fn test_g2_homogeneous_projective_curve() {
+    sw_projective_tests::<g2::Parameters>();
+}
+
+#[test]
+fn test_g2_projective_group() {
+    let mut rng = ark_std::test_rng();
+    let a: G2Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_g2_generator() {
+    let generator = G2Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_clear_cofactor() {
+    sw_clear_cofactor_test::<g2::Parameters>();
+}
+
+#[test]
+fn test_bilinearity() {
+    let mut rng = ark_std::test_rng();
+    let a: G1Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    let s: Fr = rng.gen();
+
+    let sa = a.mul(s.into_repr());
+    let sb = b.mul(s.into_repr());
+
+    let ans1 = Bn254::pairing(sa, b);
+    let ans2 = Bn254::pairing(a, sb);
+    let ans3 = Bn254::pairing(a, b).pow(s.into_repr());
+
+    assert_eq!(ans1, ans2);
+    assert_eq!(ans2, ans3);
+
+    assert_ne!(ans1, Fq12::one());
+    assert_eq!(ans1.pow(Fr::characteristic()), Fq12::one());
+}
+
+#[test]
+fn test_pairing_output() {
+    pairing_output_test::<Bn254>();
+}
+
+#[test]
+fn test_pairing_check() {
+    pairing_check_test::<Bn254>();
+}
+
+#[test]
+fn test_prepared_serialization() {
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::vec::Vec;
+
+    prepared_serialization_test::<Bn254>();
+
+    let mut q: <Bn254 as PairingEngine>::G2Prepared = G2Affine::prime_subgroup_generator().into();
+    q.ell_coeffs.push(q.ell_coeffs[0]);
+    let mut bytes = Vec::new();
+    q.serialize(&mut bytes).unwrap();
+    assert!(<Bn254 as PairingEngine>::G2Prepared::deserialize(&bytes[..]).is_err());
+}
@@ -31,17 +31,18 @@ impl FftParameters for FqParameters {
     const SMALL_SUBGROUP_BASE: Option<u32> = Some(3);
     const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some(2);
 
-    // LARGE_SUBGROUP_ROOT_OF_UNITY = GENERATOR ^ (t * 3 ^ 2) =
-    // 203100967768496856767841701771526315192814992286543641883928020883407386213917566206874176054653008117753458021037
+    // LARGE_SUBGROUP_ROOT_OF_UNITY = GENERATOR ^ ((MODULUS - 1) / (2^s * 3^2)) =
+    // 683178638573601328773157856456805978879544571597638024782973348263890473503149166479735097908137632291366958273030
+    // This number needs to be in the Montgomery residue form.
     // I.e., write LARGE_SUBGROUP_ROOT_OF_UNITY * R
-    // = 81125788721017958531970004711554176763707237538772656640376499392204495132484005854811881368159718832226698073199
+    // = 2467166059460327870362850088339081067893027083760556529164409944411944504024066980319019379611040508782603993292191
     const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<BigInteger> = Some(BigInteger([
-        6225018931355915375u64,
-        180290822448891806u64,
-        14465855242330424160u64,
-        8575642455718703211u64,
-        8320153096556229121u64,
-        37980468681094481u64,
+        12596209759870983583u64,
+        13865507424437716517u64,
+        12587117905349862023u64,
+        6772958519958959183u64,
+        1732260903825098394u64,
+        1155047300367454321u64,
     ]));
 }
 
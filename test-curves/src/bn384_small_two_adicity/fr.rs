@@ -33,18 +33,18 @@ impl FftParameters for FrParameters {
     const SMALL_SUBGROUP_BASE: Option<u32> = Some(3);
     const SMALL_SUBGROUP_BASE_ADICITY: Option<u32> = Some(2);
 
-    // LARGE_SUBGROUP_ROOT_OF_UNITY = GENERATOR ^ (T * 3 ^ 2)
-    // = 3524614118565436050820346784762407349815771892452866211429575895239855511309348587252928054123237406857164753350910
+    // LARGE_SUBGROUP_ROOT_OF_UNITY = GENERATOR ^ ((MODULUS - 1) / (2^s * 3^2)) =
+    // 4782263695849493583247475447966822177504391850435302761245199662598017278280257369536403760936410198993405011225872
     // This number needs to be in the Montgomery residue form.
     // I.e., write LARGE_SUBGROUP_ROOT_OF_UNITY * R
-    // = 2243640460791708394678669425369274565832631199871689254948845545672204516674256702673568780556211844984291473787379
+    // = 683891372292783601979683915697224705492434840735001519224578346450004437583059499759550011708393122365515087634199
     const LARGE_SUBGROUP_ROOT_OF_UNITY: Option<BigInteger> = Some(BigInteger([
-        16448235414327691763u64,
-        6101416213029103415u64,
-        1714659905601749299u64,
-        18157817127906248745u64,
-        7986749858655934624u64,
-        1050399849371924948u64,
+        14185297672081137431u64,
+        4023117034263877366u64,
+        14797910662837268061u64,
+        4435561295436815649u64,
+        14913485721870979586u64,
+        320175806684112421u64,
     ]));
 }
 impl FpParameters for FrParameters {
@@ -1,4 +1,5 @@
 use ark_ec::{
+    glv::GLVParameters,
     models::{ModelParameters, SWModelParameters},
     short_weierstrass_jacobian::*,
 };
@@ -38,6 +39,55 @@ impl SWModelParameters for Parameters {
     fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    #[inline]
+    fn glv_split_affine(p: &G1Affine, k: &Fr) -> Option<[(G1Affine, Fr); 2]> {
+        Some(Self::split_affine(p, k))
+    }
+
+    #[inline]
+    fn glv_split_projective(p: &G1Projective, k: &Fr) -> Option<[(G1Projective, Fr); 2]> {
+        Some(Self::split_projective(p, k))
+    }
+}
+
+impl GLVParameters for Parameters {
+    /// ENDO_COEFF = a primitive cube root of unity in Fq
+    #[rustfmt::skip]
+    const ENDO_COEFF: Fq = field_new!(Fq, "147471332104730393994417791325428547448205677943063222241738346762252641774101437126657");
+
+    /// LAMBDA = the cube root of unity in Fr matching ENDO_COEFF
+    #[rustfmt::skip]
+    const LAMBDA: Fr = field_new!(Fr, "294942664209460787988835582643541843811912171987568857197210177109356094422825599963137");
+
+    /// SCALAR_DECOMP_COEFFS = [n11, n12, n21, n22], a reduced basis of the
+    /// lattice of pairs `(a, b)` with `a + b * LAMBDA = 0 mod r`
+    const SCALAR_DECOMP_COEFFS: [(bool, &'static [u64]); 4] = [
+        (false, &[0x14247bc1016bb001, 0x8246fd04]),
+        (
+            true,
+            &[0x436692c3e6ebb000, 0xab7cd58705a1a103, 0x637242fb3b21b246],
+        ),
+        (
+            false,
+            &[0x578b0e84e8576001, 0xab7cd58787e89e07, 0x637242fb3b21b246],
+        ),
+        (false, &[0x14247bc1016bb001, 0x8246fd04]),
+    ];
+
+    /// SCALAR_DECOMP_ROUNDING = [round(n22 * 2^384 / r), round(-n12 * 2^384 / r)]
+    const SCALAR_DECOMP_ROUNDING: [(bool, &'static [u64]); 2] = [
+        (false, &[0x702b2e6af16d6e1d, 0x35f5191ca]),
+        (
+            false,
+            &[
+                0x402294ae02384cae,
+                0x396f3443eb1b0cd0,
+                0x93023a640ce60665,
+                0x2,
+            ],
+        ),
+    ];
 }
 
 /// G1_GENERATOR_X = -1
@@ -1,9 +1,11 @@
 #![allow(unused_imports)]
 use ark_ec::{models::SWModelParameters, AffineCurve, PairingEngine, ProjectiveCurve};
-use ark_ff::{One, UniformRand, Zero};
+use ark_ff::{fields::FftParameters, FpParameters, One, UniformRand, Zero};
 use ark_std::rand::Rng;
 
-use crate::bn384_small_two_adicity::{g1, Fq, FqParameters, Fr, G1Affine, G1Projective};
+use crate::bn384_small_two_adicity::{
+    g1, Fq, FqParameters, Fr, FrParameters, G1Affine, G1Projective,
+};
 use ark_algebra_test_templates::{curves::*, fields::*, groups::*};
 
 pub(crate) const ITERATIONS: usize = 5;
@@ -52,3 +54,42 @@ fn test_g1_generator() {
     assert!(generator.is_on_curve());
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
+
+#[test]
+fn test_g1_glv() {
+    sw_glv_test::<g1::Parameters>();
+}
+
+#[derive(FpParameters)]
+#[modulus = "5945877603251831796258517492029536515488649313567122628445038208291596545947608789992834434053176523624102324539393"]
+#[generator = "5"]
+#[small_subgroup_base = "3"]
+#[limbs = 6]
+struct DerivedFrParameters;
+
+#[test]
+fn test_derived_fr_parameters() {
+    type P = FrParameters;
+    type D = DerivedFrParameters;
+    assert_eq!(P::TWO_ADICITY, D::TWO_ADICITY);
+    assert_eq!(P::TWO_ADIC_ROOT_OF_UNITY, D::TWO_ADIC_ROOT_OF_UNITY);
+    assert_eq!(P::SMALL_SUBGROUP_BASE, D::SMALL_SUBGROUP_BASE);
+    assert_eq!(
+        P::SMALL_SUBGROUP_BASE_ADICITY,
+        D::SMALL_SUBGROUP_BASE_ADICITY
+    );
+    assert_eq!(
+        P::LARGE_SUBGROUP_ROOT_OF_UNITY,
+        D::LARGE_SUBGROUP_ROOT_OF_UNITY
+    );
+    assert_eq!(P::MODULUS, D::MODULUS);
+    assert_eq!(P::MODULUS_BITS, D::MODULUS_BITS);
+    assert_eq!(P::REPR_SHAVE_BITS, D::REPR_SHAVE_BITS);
+    assert_eq!(P::R, D::R);
+    assert_eq!(P::R2, D::R2);
+    assert_eq!(P::INV, D::INV);
+    assert_eq!(P::GENERATOR, D::GENERATOR);
+    assert_eq!(P::T, D::T);
+    assert_eq!(P::T_MINUS_ONE_DIV_TWO, D::T_MINUS_ONE_DIV_TWO);
+    assert_eq!(P::MODULUS_MINUS_ONE_DIV_TWO, D::MODULUS_MINUS_ONE_DIV_TWO);
+}
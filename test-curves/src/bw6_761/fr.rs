@@ -0,0 +1,9 @@
+use ark_ff::fields::{Fp384, FpParameters};
+
+pub type Fr = Fp384<FrParameters>;
+
+/// The scalar field of BW6-761, which is the base field of BLS12-377.
+#[derive(FpParameters)]
+#[modulus = "258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458177"]
+#[generator = "15"]
+pub struct FrParameters;
@@ -0,0 +1,132 @@
+use ark_ec::{
+    bw6,
+    glv::GLVParameters,
+    models::{ModelParameters, SWModelParameters},
+    short_weierstrass_jacobian::*,
+};
+use ark_ff::{field_new, Zero};
+
+use crate::bw6_761::{Fq, Fr};
+
+pub type G1Affine = GroupAffine<Parameters>;
+pub type G1Projective = GroupProjective<Parameters>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq = field_new!(Fq, "0");
+
+    /// COEFF_B = -1
+    const COEFF_B: Fq = field_new!(Fq, "-1");
+
+    /// COFACTOR =
+    /// 26642435879335816683987677701488073867751118270052650655942102502312977592501693353047140953112195348280268661194876
+    #[rustfmt::skip]
+    const COFACTOR: &'static [u64] = &[
+        0x3de580000000007c,
+        0x832ba4061000003b,
+        0xc61c554757551c0c,
+        0xc856a0853c9db94c,
+        0x2c77d5ac34cb12ef,
+        0xad1972339049ce76,
+    ];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r =
+    /// 91141326767669940707819291241958318717982251277713150053234367522357946997763584490607453720072232540829942217804
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, "91141326767669940707819291241958318717982251277713150053234367522357946997763584490607453720072232540829942217804");
+
+    /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (G1_GENERATOR_X, G1_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G1Affine) -> bool {
+        bw6::g1::is_in_correct_subgroup_assuming_on_curve::<crate::bw6_761::Parameters>(p)
+    }
+
+    #[inline]
+    fn glv_split_affine(p: &G1Affine, k: &Fr) -> Option<[(G1Affine, Fr); 2]> {
+        Some(Self::split_affine(p, k))
+    }
+
+    #[inline]
+    fn glv_split_projective(p: &G1Projective, k: &Fr) -> Option<[(G1Projective, Fr); 2]> {
+        Some(Self::split_projective(p, k))
+    }
+}
+
+impl GLVParameters for Parameters {
+    /// ENDO_COEFF = a primitive cube root of unity in Fq
+    #[rustfmt::skip]
+    const ENDO_COEFF: Fq = field_new!(Fq, "1968985824090209297278610739700577151397666382303825728450741611566800370218827257750865013421937292370006175842381275743914023380727582819905021229583192207421122272650305267822868639090213645505120388400344940985710520836292650");
+
+    /// LAMBDA = the cube root of unity in Fr matching ENDO_COEFF, which
+    /// satisfies (x + 1) + (x^3 - x^2 + 1) * LAMBDA = 0
+    #[rustfmt::skip]
+    const LAMBDA: Fr = field_new!(Fr, "80949648264912719408558363140637477264845294720710499478137287262712535938301461879813459410945");
+
+    /// SCALAR_DECOMP_COEFFS = [n11, n12, n21, n22], a reduced basis of the
+    /// lattice of pairs `(a, b)` with `a + b * LAMBDA = 0 mod r`
+    const SCALAR_DECOMP_COEFFS: [(bool, &'static [u64]); 4] = [
+        (
+            false,
+            &[0xd3a7bfffffffffff, 0x2e16ba885fffffff, 0xbf9b117dd04a400],
+        ),
+        (
+            true,
+            &[0x58b0800000000001, 0x2e16ba8860000000, 0xbf9b117dd04a400],
+        ),
+        (
+            false,
+            &[0x2c58400000000000, 0x5c2d7510c0000000, 0x17f3622fba094800],
+        ),
+        (
+            false,
+            &[0xd3a7bfffffffffff, 0x2e16ba885fffffff, 0xbf9b117dd04a400],
+        ),
+    ];
+
+    /// SCALAR_DECOMP_ROUNDING = [round(n22 * 2^384 / r), round(-n12 * 2^384 / r)]
+    const SCALAR_DECOMP_ROUNDING: [(bool, &'static [u64]); 2] = [
+        (
+            false,
+            &[
+                0x7ccf39ddb5613b70,
+                0x42fb73b015bd4e9e,
+                0x2030ba8ee9c06430,
+                0x7,
+            ],
+        ),
+        (
+            false,
+            &[
+                0xa5ba6bfab7176f0b,
+                0x42fb73b015bd4eed,
+                0x2030ba8ee9c06430,
+                0x7,
+            ],
+        ),
+    ];
+}
+
+/// G1_GENERATOR_X =
+/// 6238772257594679368032145693622812838779005809760824733138787810501188623461307351759238099287535516224314149266511977132140828635950940021790489507611754366317801811090811367945064510304504157188661901055903167026722666149426237
+#[rustfmt::skip]
+pub const G1_GENERATOR_X: Fq = field_new!(Fq, "6238772257594679368032145693622812838779005809760824733138787810501188623461307351759238099287535516224314149266511977132140828635950940021790489507611754366317801811090811367945064510304504157188661901055903167026722666149426237");
+
+/// G1_GENERATOR_Y =
+/// 2101735126520897423911504562215834951148127555913367997162789335052900271653517958562461315794228241561913734371411178226936527683203879553093934185950470971848972085321797958124416462268292467002957525517188485984766314758624099
+#[rustfmt::skip]
+pub const G1_GENERATOR_Y: Fq = field_new!(Fq, "2101735126520897423911504562215834951148127555913367997162789335052900271653517958562461315794228241561913734371411178226936527683203879553093934185950470971848972085321797958124416462268292467002957525517188485984766314758624099");
@@ -0,0 +1,132 @@
+use ark_ec::{
+    bw6,
+    glv::GLVParameters,
+    models::{ModelParameters, SWModelParameters},
+    short_weierstrass_jacobian::*,
+};
+use ark_ff::{field_new, Zero};
+
+use crate::bw6_761::{Fq, Fr};
+
+pub type G2Affine = GroupAffine<Parameters>;
+pub type G2Projective = GroupProjective<Parameters>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq = field_new!(Fq, "0");
+
+    /// COEFF_B = 4
+    const COEFF_B: Fq = field_new!(Fq, "4");
+
+    /// COFACTOR =
+    /// 26642435879335816683987677701488073867751118270052650655942102502312977592501693353047140953112195348280268661194869
+    #[rustfmt::skip]
+    const COFACTOR: &'static [u64] = &[
+        0x3de5800000000075,
+        0x832ba4061000003b,
+        0xc61c554757551c0c,
+        0xc856a0853c9db94c,
+        0x2c77d5ac34cb12ef,
+        0xad1972339049ce76,
+    ];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r =
+    /// 214911522365886453591244899095480747723790054550866810551297776298664428889000553861210287833206024638187939842124
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, "214911522365886453591244899095480747723790054550866810551297776298664428889000553861210287833206024638187939842124");
+
+    /// AFFINE_GENERATOR_COEFFS = (G2_GENERATOR_X, G2_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (G2_GENERATOR_X, G2_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G2Affine) -> bool {
+        bw6::g2::is_in_correct_subgroup_assuming_on_curve::<crate::bw6_761::Parameters>(p)
+    }
+
+    #[inline]
+    fn glv_split_affine(p: &G2Affine, k: &Fr) -> Option<[(G2Affine, Fr); 2]> {
+        Some(Self::split_affine(p, k))
+    }
+
+    #[inline]
+    fn glv_split_projective(p: &G2Projective, k: &Fr) -> Option<[(G2Projective, Fr); 2]> {
+        Some(Self::split_projective(p, k))
+    }
+}
+
+impl GLVParameters for Parameters {
+    /// ENDO_COEFF = a primitive cube root of unity in Fq
+    #[rustfmt::skip]
+    const ENDO_COEFF: Fq = field_new!(Fq, "4922464560225523242118178942575080391082002530232324381063048548642823052024664478336818169867474395270858391911405337707247735739826664939444490469542109391530482826728203582549674992333383150446779312029624171857054392282775648");
+
+    /// LAMBDA = the cube root of unity in Fr matching ENDO_COEFF, which
+    /// satisfies (x + 1) + (x^3 - x^2 + 1) * LAMBDA = 0
+    #[rustfmt::skip]
+    const LAMBDA: Fr = field_new!(Fr, "80949648264912719408558363140637477264845294720710499478137287262712535938301461879813459410945");
+
+    /// SCALAR_DECOMP_COEFFS = [n11, n12, n21, n22], a reduced basis of the
+    /// lattice of pairs `(a, b)` with `a + b * LAMBDA = 0 mod r`
+    const SCALAR_DECOMP_COEFFS: [(bool, &'static [u64]); 4] = [
+        (
+            false,
+            &[0xd3a7bfffffffffff, 0x2e16ba885fffffff, 0xbf9b117dd04a400],
+        ),
+        (
+            true,
+            &[0x58b0800000000001, 0x2e16ba8860000000, 0xbf9b117dd04a400],
+        ),
+        (
+            false,
+            &[0x2c58400000000000, 0x5c2d7510c0000000, 0x17f3622fba094800],
+        ),
+        (
+            false,
+            &[0xd3a7bfffffffffff, 0x2e16ba885fffffff, 0xbf9b117dd04a400],
+        ),
+    ];
+
+    /// SCALAR_DECOMP_ROUNDING = [round(n22 * 2^384 / r), round(-n12 * 2^384 / r)]
+    const SCALAR_DECOMP_ROUNDING: [(bool, &'static [u64]); 2] = [
+        (
+            false,
+            &[
+                0x7ccf39ddb5613b70,
+                0x42fb73b015bd4e9e,
+                0x2030ba8ee9c06430,
+                0x7,
+            ],
+        ),
+        (
+            false,
+            &[
+                0xa5ba6bfab7176f0b,
+                0x42fb73b015bd4eed,
+                0x2030ba8ee9c06430,
+                0x7,
+            ],
+        ),
+    ];
+}
+
+/// G2_GENERATOR_X =
+/// 6445332910596979336035888152774071626898886139774101364933948236926875073754470830732273879639675437155036544153105017729592600560631678554299562762294743927912429096636156401171909259073181112518725201388196280039960074422214428
+#[rustfmt::skip]
+pub const G2_GENERATOR_X: Fq = field_new!(Fq, "6445332910596979336035888152774071626898886139774101364933948236926875073754470830732273879639675437155036544153105017729592600560631678554299562762294743927912429096636156401171909259073181112518725201388196280039960074422214428");
+
+/// G2_GENERATOR_Y =
+/// 562923658089539719386922163444547387757586534741080263946953401595155211934630598999300396317104182598044793758153214972605680357108252243146746187917218885078195819486220416605630144001533548163105316661692978285266378674355041
+#[rustfmt::skip]
+pub const G2_GENERATOR_Y: Fq = field_new!(Fq, "562923658089539719386922163444547387757586534741080263946953401595155211934630598999300396317104182598044793758153214972605680357108252243146746187917218885078195819486220416605630144001533548163105316661692978285266378674355041");
@@ -0,0 +1,73 @@
+#[cfg(feature = "bw6_761_base_field")]
+pub mod fq;
+#[cfg(feature = "bw6_761_base_field")]
+pub use fq::*;
+
+#[cfg(feature = "bw6_761_scalar_field")]
+pub mod fr;
+#[cfg(feature = "bw6_761_scalar_field")]
+pub use fr::*;
+
+#[cfg(feature = "bw6_761_curve")]
+pub mod fq3;
+#[cfg(feature = "bw6_761_curve")]
+pub mod fq6;
+#[cfg(feature = "bw6_761_curve")]
+pub mod g1;
+#[cfg(feature = "bw6_761_curve")]
+pub mod g2;
+
+#[cfg(feature = "bw6_761_curve")]
+pub use fq3::*;
+#[cfg(feature = "bw6_761_curve")]
+pub use fq6::*;
+#[cfg(feature = "bw6_761_curve")]
+pub use g1::{G1Affine, G1Projective};
+#[cfg(feature = "bw6_761_curve")]
+pub use g2::{G2Affine, G2Projective};
+
+#[cfg(feature = "bw6_761_curve")]
+use ark_ec::bw6::{BW6Parameters, TwistType, BW6};
+#[cfg(feature = "bw6_761_curve")]
+use ark_ff::biginteger::BigInteger768 as BigInteger;
+
+#[cfg(feature = "bw6_761_curve")]
+pub type BW6_761 = BW6<Parameters>;
+
+#[cfg(feature = "bw6_761_curve")]
+#[derive(PartialEq, Eq)]
+pub struct Parameters;
+
+#[cfg(feature = "bw6_761_curve")]
+impl BW6Parameters for Parameters {
+    /// X = 0x8508c00000000001, the parameter of the inner curve BLS12-377
+    #[rustfmt::skip]
+    const X: BigInteger = BigInteger::new([
+        0x8508c00000000001, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ]);
+    const X_IS_NEGATIVE: bool = false;
+    /// ATE_LOOP_COUNT_1 = X + 1
+    const ATE_LOOP_COUNT_1: &'static [u64] = &[0x8508c00000000002];
+    const ATE_LOOP_COUNT_1_IS_NEGATIVE: bool = false;
+    /// ATE_LOOP_COUNT_2 = X^3 - X^2 - X, in NAF
+    #[rustfmt::skip]
+    const ATE_LOOP_COUNT_2: &'static [i8] = &[
+        -1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 1, 0, 0, 1, 0, 0, -1, 0, 1, 0, -1, 0, 0, 0, 0, -1, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1,
+        0, 0, 1, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -1, 0, -1, 0, 0, 0, 0, -1, 0, 0,
+        1, 0, 0, 0, -1, 0, 0, -1, 0, 1, 0, -1, 0, 0, 0, 1, 0, 0, 1, 0, -1, 0, 1, 0, 1, 0, 0, 0, 1,
+        0, -1, 0, -1, 0, 0, 0, 0, 0, 1, 0, 0, 1,
+    ];
+    const ATE_LOOP_COUNT_2_IS_NEGATIVE: bool = false;
+    const TWIST_TYPE: TwistType = TwistType::M;
+    type Fp = Fq;
+    type Fp3Params = Fq3Parameters;
+    type Fp6Params = Fq6Parameters;
+    type G1Parameters = g1::Parameters;
+    type G2Parameters = g2::Parameters;
+}
+
+#[cfg(all(test, feature = "bw6_761_curve"))]
+mod tests;
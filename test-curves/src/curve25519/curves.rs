@@ -0,0 +1,69 @@
+use crate::curve25519::{Fq, Fr};
+use ark_ec::{
+    hashing::curve_maps::elligator2::Elligator2Parameters,
+    models::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
+    twisted_edwards_extended::{GroupAffine, GroupProjective},
+};
+use ark_ff::field_new;
+
+pub type EdwardsAffine = GroupAffine<EdwardsParameters>;
+pub type EdwardsProjective = GroupProjective<EdwardsParameters>;
+
+/// The twisted Edwards curve `486664 * x^2 + y^2 = 1 + 486660 * x^2 * y^2`,
+/// which is birationally equivalent to Curve25519, `t^2 = s^3 + 486662 * s^2 + s`,
+/// through the map of RFC 9380, Appendix D.1. This is not the usual
+/// Edwards25519 curve, whose map to Curve25519 needs an extra scaling of `x`.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct EdwardsParameters;
+
+impl ModelParameters for EdwardsParameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl TEModelParameters for EdwardsParameters {
+    /// COEFF_A = 486664
+    const COEFF_A: Fq = field_new!(Fq, "486664");
+
+    /// COEFF_D = 486660
+    const COEFF_D: Fq = field_new!(Fq, "486660");
+
+    /// COFACTOR = 8
+    const COFACTOR: &'static [u64] = &[8];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    /// = 2713877091499598330239944961141122840321418634767465352250731601857045344121
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, "2713877091499598330239944961141122840321418634767465352250731601857045344121");
+
+    /// AFFINE_GENERATOR_COEFFS = (GENERATOR_X, GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (GENERATOR_X, GENERATOR_Y);
+
+    type MontgomeryModelParameters = EdwardsParameters;
+}
+
+impl MontgomeryModelParameters for EdwardsParameters {
+    /// COEFF_A = 486662
+    const COEFF_A: Fq = field_new!(Fq, "486662");
+
+    /// COEFF_B = 1
+    const COEFF_B: Fq = field_new!(Fq, "1");
+
+    type TEModelParameters = EdwardsParameters;
+}
+
+impl Elligator2Parameters for EdwardsParameters {
+    /// ZETA = 2
+    const ZETA: Fq = field_new!(Fq, "2");
+}
+
+/// The image of the Curve25519 base point, whose `s`-coordinate is 9.
+/// GENERATOR_X =
+/// 38213832894368730265794714087330135568483813637251082400757400312561599933396
+#[rustfmt::skip]
+pub const GENERATOR_X: Fq = field_new!(Fq, "38213832894368730265794714087330135568483813637251082400757400312561599933396");
+
+/// GENERATOR_Y =
+/// 46316835694926478169428394003475163141307993866256225615783033603165251855960
+#[rustfmt::skip]
+pub const GENERATOR_Y: Fq = field_new!(Fq, "46316835694926478169428394003475163141307993866256225615783033603165251855960");
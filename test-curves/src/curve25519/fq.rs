@@ -0,0 +1,9 @@
+use ark_ff::fields::{Fp256, FpParameters};
+
+pub type Fq = Fp256<FqParameters>;
+
+/// The base field of Curve25519, of order `2^255 - 19`.
+#[derive(FpParameters)]
+#[modulus = "57896044618658097711785492504343953926634992332820282019728792003956564819949"]
+#[generator = "2"]
+pub struct FqParameters;
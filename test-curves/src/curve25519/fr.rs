@@ -0,0 +1,10 @@
+use ark_ff::fields::{Fp256, FpParameters};
+
+pub type Fr = Fp256<FrParameters>;
+
+/// The scalar field of Curve25519, of order
+/// `2^252 + 27742317777372353535851937790883648493`.
+#[derive(FpParameters)]
+#[modulus = "7237005577332262213973186563042994240857116359379907606001950938285454250989"]
+#[generator = "2"]
+pub struct FrParameters;
@@ -0,0 +1,17 @@
+#[cfg(feature = "curve25519_curve")]
+pub mod fq;
+#[cfg(feature = "curve25519_curve")]
+pub use fq::*;
+
+#[cfg(feature = "curve25519_scalar_field")]
+pub mod fr;
+#[cfg(feature = "curve25519_scalar_field")]
+pub use fr::*;
+
+#[cfg(feature = "curve25519_curve")]
+pub mod curves;
+#[cfg(feature = "curve25519_curve")]
+pub use curves::*;
+
+#[cfg(all(test, feature = "curve25519_curve"))]
+mod tests;
@@ -0,0 +1,121 @@
+#![allow(unused_imports)]
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::UniformRand;
+
+use crate::curve25519::{EdwardsAffine, EdwardsParameters, EdwardsProjective, Fq, Fr};
+use ark_algebra_test_templates::{curves::*, fields::*, groups::*};
+use ark_std::rand::Rng;
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_fr() {
+    let mut rng = ark_std::test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fr = UniformRand::rand(&mut rng);
+        let b: Fr = UniformRand::rand(&mut rng);
+        field_test(a, b);
+        primefield_test::<Fr>();
+        sqrt_field_test(b);
+    }
+}
+
+#[test]
+fn test_fq() {
+    let mut rng = ark_std::test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq = UniformRand::rand(&mut rng);
+        let b: Fq = UniformRand::rand(&mut rng);
+        field_test(a, b);
+        primefield_test::<Fq>();
+        sqrt_field_test(a);
+    }
+}
+
+#[test]
+fn test_projective_curve() {
+    curve_tests::<EdwardsProjective>();
+    edwards_tests::<EdwardsParameters>();
+}
+
+#[test]
+fn test_projective_group() {
+    let mut rng = ark_std::test_rng();
+    let a: EdwardsProjective = rng.gen();
+    let b: EdwardsProjective = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_generator() {
+    let generator = EdwardsAffine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_montgomery_conversion() {
+    montgomery_conversion_test::<EdwardsParameters>();
+}
+
+/// The curve25519_XMD:SHA-512_ELL2_RO_ and curve25519_XMD:SHA-512_ELL2_NU_
+/// test vectors of RFC 9380, Appendices J.4.1 and J.4.2, as `[s, t]` on
+/// Curve25519.
+#[test]
+fn test_elligator2_hash_to_curve() {
+    use ark_ec::{
+        hashing::{curve_maps::elligator2::Elligator2Map, HashToCurve, MapToCurveBasedHasher},
+        models::montgomery,
+    };
+    use ark_ff::field_hashers::{DefaultFieldHasher, ExpanderXmd};
+    use ark_std::{iter, str::FromStr, vec::Vec};
+
+    type Hasher = MapToCurveBasedHasher<
+        EdwardsAffine,
+        DefaultFieldHasher<ExpanderXmd<sha2::Sha512>, Fq>,
+        Elligator2Map<EdwardsParameters>,
+    >;
+
+    let ro = Hasher::new(b"QUUX-V01-CS02-with-curve25519_XMD:SHA-512_ELL2_RO_").unwrap();
+    let nu = Hasher::new(b"QUUX-V01-CS02-with-curve25519_XMD:SHA-512_ELL2_NU_").unwrap();
+    #[rustfmt::skip]
+    let expected_ro = [
+        ["20755980968848905962648296223990034442845239978893603531157795489810611935424", "26852118219728031323091995148190119438110435709034478870970585906135281625208"],
+        ["19569777156064218054534198942882526166999676911599658275579453249261387141741", "12442509356051212157815338548416431200300600320773297574944588861824022939613"],
+        ["47397650878079532494227509431417029996387031535819449866217359742935803150390", "19094947021255684298011553164078194253087471165107202249630710190707581158227"],
+        ["4266249252606483986742403089465725364716722706517591009737387044691313405018", "13890331492573542690000664920356684539709768382865283190759386200053484741461"],
+        ["12562450149701646148146288404797240662221927718297742227617046127126572156414", "44434477500711011338132193547453233746236458248461054299163307869577452887025"],
+    ];
+    #[rustfmt::skip]
+    let expected_nu = [
+        ["12539451243557818414595396572629338516468413111037595496520783561588964478216", "31337176098866805550608601510753444537096714898240174352541917442363449360068"],
+        ["56147894694515882141922303073573969405722062048221926815396376255924104962086", "38573335823580533525281063385658555291887788247064329139432166281428506445121"],
+        ["22469053884655194657705993661751603966860255154065348414971927777475050433618", "29090148315335814945351827824339087026524949684907391568538042134482691231153"],
+        ["1117471823611782112913702915548849045747381869912369909717875090058093186730", "38373388409243665940725927971974797275119067732806280661537335185112021355544"],
+        ["43352372428428407440976921031623044376318992806497103086386158862902038846913", "52939954227537550680683990321035432428006203776147416138414942658474664186040"],
+    ];
+    let msgs: [Vec<u8>; 5] = [
+        b"".to_vec(),
+        b"abc".to_vec(),
+        b"abcdef0123456789".to_vec(),
+        b"q128_"
+            .iter()
+            .copied()
+            .chain(iter::repeat(b'q').take(128))
+            .collect(),
+        b"a512_"
+            .iter()
+            .copied()
+            .chain(iter::repeat(b'a').take(512))
+            .collect(),
+    ];
+    for ((msg, ro_st), nu_st) in msgs.iter().zip(&expected_ro).zip(&expected_nu) {
+        let p = montgomery::GroupAffine::<EdwardsParameters>::from(ro.hash(msg).unwrap());
+        assert_eq!(p.x, Fq::from_str(ro_st[0]).unwrap());
+        assert_eq!(p.y, Fq::from_str(ro_st[1]).unwrap());
+
+        let p = montgomery::GroupAffine::<EdwardsParameters>::from(nu.encode(msg).unwrap());
+        assert_eq!(p.x, Fq::from_str(nu_st[0]).unwrap());
+        assert_eq!(p.y, Fq::from_str(nu_st[1]).unwrap());
+    }
+}
@@ -0,0 +1,73 @@
+use crate::ed_on_bls12_381::{Fq, Fr};
+use ark_ec::{
+    hashing::curve_maps::elligator2::Elligator2Parameters,
+    models::{ModelParameters, MontgomeryModelParameters, TEModelParameters},
+    twisted_edwards_extended::{GroupAffine, GroupProjective},
+};
+use ark_ff::field_new;
+
+pub type EdwardsAffine = GroupAffine<EdwardsParameters>;
+pub type EdwardsProjective = GroupProjective<EdwardsParameters>;
+
+/// The Jubjub curve, `-x^2 + y^2 = 1 - (10240/10241) * x^2 * y^2`, defined
+/// over the scalar field of BLS12-381.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct EdwardsParameters;
+
+impl ModelParameters for EdwardsParameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl TEModelParameters for EdwardsParameters {
+    /// COEFF_A = -1
+    const COEFF_A: Fq = field_new!(Fq, "-1");
+
+    /// COEFF_D = -(10240/10241)
+    #[rustfmt::skip]
+    const COEFF_D: Fq = field_new!(Fq, "19257038036680949359750312669786877991949435402254120286184196891950884077233");
+
+    /// COFACTOR = 8
+    const COFACTOR: &'static [u64] = &[8];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    /// = 819310549611346726241370945440405716213240158234039660170669895299022906775
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, "819310549611346726241370945440405716213240158234039660170669895299022906775");
+
+    /// AFFINE_GENERATOR_COEFFS = (GENERATOR_X, GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) = (GENERATOR_X, GENERATOR_Y);
+
+    type MontgomeryModelParameters = EdwardsParameters;
+
+    /// Multiplication by `a` is simply negation here.
+    #[inline(always)]
+    fn mul_by_a(elem: &Self::BaseField) -> Self::BaseField {
+        -(*elem)
+    }
+}
+
+impl MontgomeryModelParameters for EdwardsParameters {
+    /// COEFF_A = 40962
+    const COEFF_A: Fq = field_new!(Fq, "40962");
+
+    /// COEFF_B = -40964
+    const COEFF_B: Fq = field_new!(Fq, "-40964");
+
+    type TEModelParameters = EdwardsParameters;
+}
+
+impl Elligator2Parameters for EdwardsParameters {
+    /// ZETA = 5
+    const ZETA: Fq = field_new!(Fq, "5");
+}
+
+/// GENERATOR_X =
+/// 8076246640662884909881801758704306714034609987455869804520522091855516602923
+#[rustfmt::skip]
+pub const GENERATOR_X: Fq = field_new!(Fq, "8076246640662884909881801758704306714034609987455869804520522091855516602923");
+
+/// GENERATOR_Y =
+/// 13262374693698910701929044844600465831413122818447359594527400194675274060458
+#[rustfmt::skip]
+pub const GENERATOR_Y: Fq = field_new!(Fq, "13262374693698910701929044844600465831413122818447359594527400194675274060458");
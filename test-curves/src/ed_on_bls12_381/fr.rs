@@ -0,0 +1,9 @@
+use ark_ff::fields::{Fp256, FpParameters};
+
+pub type Fr = Fp256<FrParameters>;
+
+/// The scalar field of the Jubjub curve.
+#[derive(FpParameters)]
+#[modulus = "6554484396890773809930967563523245729705921265872317281365359162392183254199"]
+#[generator = "6"]
+pub struct FrParameters;
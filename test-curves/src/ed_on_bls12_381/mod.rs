@@ -0,0 +1,15 @@
+#[cfg(feature = "ed_on_bls12_381_curve")]
+pub use crate::bls12_381::{Fr as Fq, FrParameters as FqParameters};
+
+#[cfg(feature = "ed_on_bls12_381_scalar_field")]
+pub mod fr;
+#[cfg(feature = "ed_on_bls12_381_scalar_field")]
+pub use fr::*;
+
+#[cfg(feature = "ed_on_bls12_381_curve")]
+pub mod curves;
+#[cfg(feature = "ed_on_bls12_381_curve")]
+pub use curves::*;
+
+#[cfg(all(test, feature = "ed_on_bls12_381_curve"))]
+mod tests;
@@ -0,0 +1,110 @@
+#![allow(unused_imports)]
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{FpParameters, UniformRand};
+
+use crate::ed_on_bls12_381::{EdwardsAffine, EdwardsParameters, EdwardsProjective, Fr};
+use ark_algebra_test_templates::{curves::*, fields::*, groups::*, msm::*};
+use ark_std::rand::Rng;
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_fr() {
+    let mut rng = ark_std::test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fr = UniformRand::rand(&mut rng);
+        let b: Fr = UniformRand::rand(&mut rng);
+        field_test(a, b);
+        primefield_test::<Fr>();
+        sqrt_field_test(b);
+    }
+}
+
+#[test]
+fn test_projective_curve() {
+    curve_tests::<EdwardsProjective>();
+    edwards_tests::<EdwardsParameters>();
+}
+
+#[test]
+fn test_projective_group() {
+    let mut rng = ark_std::test_rng();
+    let a: EdwardsProjective = rng.gen();
+    let b: EdwardsProjective = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_msm() {
+    test_var_base_msm::<EdwardsAffine>();
+    test_var_base_msm_edge_cases::<EdwardsAffine>();
+    test_wnaf_msm::<EdwardsProjective>();
+    test_precomputed_bases_msm::<EdwardsAffine>();
+    test_chunked_msm::<EdwardsAffine>();
+}
+
+#[test]
+fn test_generator() {
+    let generator = EdwardsAffine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_montgomery_conversion() {
+    montgomery_conversion_test::<EdwardsParameters>();
+}
+
+#[test]
+fn test_montgomery_curve() {
+    montgomery_tests::<EdwardsParameters>();
+}
+
+#[test]
+#[cfg(feature = "ct")]
+fn test_ct_mul() {
+    edwards_ct_mul_test::<EdwardsParameters>();
+    montgomery_ct_mul_test::<EdwardsParameters>();
+}
+
+#[test]
+fn test_elligator2_hash_to_curve() {
+    use crate::ed_on_bls12_381::Fq;
+    use ark_ec::hashing::{
+        curve_maps::elligator2::Elligator2Map, HashToCurve, MapToCurve, MapToCurveBasedHasher,
+    };
+    use ark_ff::{
+        field_hashers::{DefaultFieldHasher, ExpanderXmd},
+        Zero,
+    };
+    use ark_std::vec::Vec;
+
+    let map = Elligator2Map::<EdwardsParameters>::new().unwrap();
+    let mut rng = ark_std::test_rng();
+    for _ in 0..ITERATIONS {
+        let p: EdwardsAffine = map.map_to_curve(Fq::rand(&mut rng));
+        assert!(p.is_on_curve());
+        assert!(p
+            .clear_cofactor()
+            .is_in_correct_subgroup_assuming_on_curve());
+    }
+
+    let hasher = MapToCurveBasedHasher::<
+        EdwardsAffine,
+        DefaultFieldHasher<ExpanderXmd<sha2::Sha256>, Fq>,
+        Elligator2Map<EdwardsParameters>,
+    >::new(b"QUUX-V01-CS02-with-jubjub_XMD:SHA-256_ELL2_RO_")
+    .unwrap();
+    let msgs: [&[u8]; 3] = [b"", b"abc", b"abcdef0123456789"];
+    let points = msgs
+        .iter()
+        .map(|msg| hasher.hash(msg).unwrap())
+        .collect::<Vec<_>>();
+    for (i, p) in points.iter().enumerate() {
+        assert!(p.is_on_curve());
+        assert!(p.is_in_correct_subgroup_assuming_on_curve());
+        assert!(!p.is_zero());
+        assert!(points[..i].iter().all(|q| q != p));
+        assert_eq!(*p, hasher.hash(msgs[i]).unwrap());
+    }
+}
@@ -0,0 +1,15 @@
+use ark_ff::{
+    field_new,
+    fields::{Fp384, FpParameters},
+};
+
+pub type Fq = Fp384<FqParameters>;
+
+/// The base field of KSS18-340.
+#[derive(FpParameters)]
+#[modulus = "1931821924193516082532685240967508528130855364011619808118021480367223509899390210427926372194869089551"]
+#[generator = "3"]
+pub struct FqParameters;
+
+pub const FQ_ONE: Fq = field_new!(Fq, "1");
+pub const FQ_ZERO: Fq = field_new!(Fq, "0");
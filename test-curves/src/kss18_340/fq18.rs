@@ -0,0 +1,54 @@
+use crate::kss18_340::*;
+use ark_ff::{field_new, fields::*};
+
+pub type Fq18 = Fp18<Fq18Parameters>;
+
+#[derive(Clone, Copy)]
+pub struct Fq18Parameters;
+
+impl Fp18Parameters for Fq18Parameters {
+    type Fp9Params = Fq9Parameters;
+
+    /// NONRESIDUE = V
+    const NONRESIDUE: Fq9 = field_new!(Fq9, FQ3_ZERO, FQ3_ONE, FQ3_ZERO);
+
+    #[rustfmt::skip]
+    const FROBENIUS_COEFF_FP18_C1: &'static [Fq3] = &[
+        // U^(((q^0) - 1) / 6)
+        FQ3_ONE,
+        // U^(((q^1) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "0"), field_new!(Fq, "24693001828610125373511983473054803171810830913554181865090360968769183971983061767798009422165093138"), field_new!(Fq, "0")),
+        // U^(((q^2) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "0"), field_new!(Fq, "0"), field_new!(Fq, "944094714395728732229475713536717106128925423313340402153518725976341921383854978284883607957720694933")),
+        // U^(((q^3) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "189213380716722046877857367867109462794717813706194944627105812132881527042519361163362491"), field_new!(Fq, "0"), field_new!(Fq, "0")),
+        // U^(((q^4) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "0"), field_new!(Fq, "197817243849183613800517700593597197087811043144898192744198170933538585354727203169128164204321927675"), field_new!(Fq, "0")),
+        // U^(((q^5) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "0"), field_new!(Fq, "0"), field_new!(Fq, "279230213752206353200886016354955722554314239393321665659765582519787538130672404272105407632835497531")),
+        // U^(((q^6) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "189213380716722046877857367867109462794717813706194944627105812132881527042519361163362490"), field_new!(Fq, "0"), field_new!(Fq, "0")),
+        // U^(((q^7) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "0"), field_new!(Fq, "173124242020573488427005717120542393916000212231344010879107809964769401382744141401330154782156834537"), field_new!(Fq, "0")),
+        // U^(((q^8) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "0"), field_new!(Fq, "0"), field_new!(Fq, "-664864500643522379028589697181761383574611183920018736493753143456554383253182574012778200324885197402")),
+        // U^(((q^9) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "-1"), field_new!(Fq, "0"), field_new!(Fq, "0")),
+        // U^(((q^10) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "0"), field_new!(Fq, "-24693001828610125373511983473054803171810830913554181865090360968769183971983061767798009422165093138"), field_new!(Fq, "0")),
+        // U^(((q^11) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "0"), field_new!(Fq, "0"), field_new!(Fq, "-944094714395728732229475713536717106128925423313340402153518725976341921383854978284883607957720694933")),
+        // U^(((q^12) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "-189213380716722046877857367867109462794717813706194944627105812132881527042519361163362491"), field_new!(Fq, "0"), field_new!(Fq, "0")),
+        // U^(((q^13) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "0"), field_new!(Fq, "-197817243849183613800517700593597197087811043144898192744198170933538585354727203169128164204321927675"), field_new!(Fq, "0")),
+        // U^(((q^14) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "0"), field_new!(Fq, "0"), field_new!(Fq, "-279230213752206353200886016354955722554314239393321665659765582519787538130672404272105407632835497531")),
+        // U^(((q^15) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "-189213380716722046877857367867109462794717813706194944627105812132881527042519361163362490"), field_new!(Fq, "0"), field_new!(Fq, "0")),
+        // U^(((q^16) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "0"), field_new!(Fq, "-173124242020573488427005717120542393916000212231344010879107809964769401382744141401330154782156834537"), field_new!(Fq, "0")),
+        // U^(((q^17) - 1) / 6)
+        field_new!(Fq3, field_new!(Fq, "0"), field_new!(Fq, "0"), field_new!(Fq, "664864500643522379028589697181761383574611183920018736493753143456554383253182574012778200324885197402")),
+    ];
+}
@@ -0,0 +1,68 @@
+use crate::kss18_340::*;
+use ark_ff::{field_new, fields::*};
+
+pub type Fq3 = Fp3<Fq3Parameters>;
+
+pub struct Fq3Parameters;
+
+impl Fp3Parameters for Fq3Parameters {
+    type Fp = Fq;
+
+    /// NONRESIDUE = 3
+    const NONRESIDUE: Fq = field_new!(Fq, "3");
+
+    /// (q^3 - 1) = 2^TWO_ADICITY * T
+    const TWO_ADICITY: u32 = 1;
+
+    /// (T - 1) / 2
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: &'static [u64] = &[
+        0x32b5857668667b0b,
+        0x87208daca210227b,
+        0x82e0d7e1c5da59bf,
+        0x3282cfc679546147,
+        0x7bdfc462be29755d,
+        0xd0d7b2cc5755ee31,
+        0x7fa5035b3f1194c7,
+        0x7a2cbb5f0044b16b,
+        0x77aa606c86d515ae,
+        0x69a6af8d2508af25,
+        0x559e2ccea1df91a2,
+        0xd4986472b0d749be,
+        0x9cef55b482eabb10,
+        0x46b805206c05b29b,
+        0x74624c87dbb60be3,
+        0x2910f99cd7e5434,
+    ];
+
+    /// U^T, where U is a quadratic nonresidue.
+    #[rustfmt::skip]
+    const QUADRATIC_NONRESIDUE_TO_T: (Fq, Fq, Fq) = (
+        field_new!(Fq, "-1"),
+        field_new!(Fq, "0"),
+        field_new!(Fq, "0"),
+    );
+
+    #[rustfmt::skip]
+    const FROBENIUS_COEFF_FP3_C1: &'static [Fq] = &[
+        // NONRESIDUE^(((q^0) - 1) / 3)
+        field_new!(Fq, "1"),
+        // NONRESIDUE^(((q^1) - 1) / 3)
+        field_new!(Fq, "189213380716722046877857367867109462794717813706194944627105812132881527042519361163362490"),
+        // NONRESIDUE^(((q^2) - 1) / 3)
+        field_new!(Fq, "-189213380716722046877857367867109462794717813706194944627105812132881527042519361163362491"),
+    ];
+
+    #[rustfmt::skip]
+    const FROBENIUS_COEFF_FP3_C2: &'static [Fq] = &[
+        // NONRESIDUE^(((2q^0) - 2) / 3)
+        field_new!(Fq, "1"),
+        // NONRESIDUE^(((2q^1) - 2) / 3)
+        field_new!(Fq, "-189213380716722046877857367867109462794717813706194944627105812132881527042519361163362491"),
+        // NONRESIDUE^(((2q^2) - 2) / 3)
+        field_new!(Fq, "189213380716722046877857367867109462794717813706194944627105812132881527042519361163362490"),
+    ];
+}
+
+pub const FQ3_ZERO: Fq3 = field_new!(Fq3, FQ_ZERO, FQ_ZERO, FQ_ZERO);
+pub const FQ3_ONE: Fq3 = field_new!(Fq3, FQ_ONE, FQ_ZERO, FQ_ZERO);
@@ -0,0 +1,9 @@
+use ark_ff::fields::{Fp256, FpParameters};
+
+pub type Fr = Fp256<FrParameters>;
+
+/// The scalar field of KSS18-340.
+#[derive(FpParameters)]
+#[modulus = "1481991350470368983133187767790145594677016426748567821035532988910674911449"]
+#[generator = "7"]
+pub struct FrParameters;
@@ -0,0 +1,52 @@
+use ark_ec::{
+    models::{ModelParameters, SWModelParameters},
+    short_weierstrass_jacobian::*,
+};
+use ark_ff::{field_new, Zero};
+
+use crate::kss18_340::{Fq, Fr};
+
+pub type G1Affine = GroupAffine<Parameters>;
+pub type G1Projective = GroupProjective<Parameters>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq = field_new!(Fq, "0");
+
+    /// COEFF_B = 3
+    const COEFF_B: Fq = field_new!(Fq, "3");
+
+    /// COFACTOR = 1303531173498668160223543639
+    const COFACTOR: &'static [u64] = &[0x82af966012600157, 0x4364176];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, "1129983458101753250856208415393477769660701140575587392512846219729071038734");
+
+    /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (G1_GENERATOR_X, G1_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+/// G1_GENERATOR_X =
+/// 1525751279239433550025332428580463297242104161334568174986142631273844241229519274841206421635200635731
+#[rustfmt::skip]
+pub const G1_GENERATOR_X: Fq = field_new!(Fq, "1525751279239433550025332428580463297242104161334568174986142631273844241229519274841206421635200635731");
+
+/// G1_GENERATOR_Y =
+/// 414704844917568989992522947509900768560526499165892379210820024645696076625208379034999278177466545301
+#[rustfmt::skip]
+pub const G1_GENERATOR_Y: Fq = field_new!(Fq, "414704844917568989992522947509900768560526499165892379210820024645696076625208379034999278177466545301");
@@ -0,0 +1,93 @@
+use ark_ec::{
+    models::{ModelParameters, SWModelParameters},
+    short_weierstrass_jacobian::*,
+};
+use ark_ff::{field_new, Zero};
+
+use crate::kss18_340::{Fq, Fq3, Fr, FQ_ONE, FQ_ZERO};
+
+pub type G2Affine = GroupAffine<Parameters>;
+pub type G2Projective = GroupProjective<Parameters>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq3;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = 0
+    const COEFF_A: Fq3 = field_new!(Fq3, FQ_ZERO, FQ_ZERO, FQ_ZERO);
+
+    /// COEFF_B = 3/U = U^2
+    const COEFF_B: Fq3 = field_new!(Fq3, FQ_ZERO, FQ_ZERO, FQ_ONE);
+
+    /// COFACTOR = #E'(Fq3) / r
+    ///          = 4864694844147209348696426674237994425709677016745187923581136853281758715850226337994923182080995109271661093671367702542901645145970043073453545921427624622947863990303608158518715041129083893493447869282746991461242766461728380927
+    #[rustfmt::skip]
+    const COFACTOR: &'static [u64] = &[
+        0x4afc9ccc126d7ff,
+        0xb7f9aef153c30f16,
+        0xee52cb49c159c6,
+        0x1935aa4e95db1eec,
+        0xc477228fd34114ee,
+        0x78f254cf4ec65530,
+        0xf59406ba59cd5f5e,
+        0xf2179f55268b64b3,
+        0x6f06e3b2675d661f,
+        0x8600824ecfaa79a4,
+        0x1a15a27afac4b2e1,
+        0x2227f7192f3e8b7a,
+        0x3,
+    ];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, "754183613142007729732253597785019677785431065039337294503637460479283719242");
+
+    /// AFFINE_GENERATOR_COEFFS = (G2_GENERATOR_X, G2_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (G2_GENERATOR_X, G2_GENERATOR_Y);
+
+    #[inline(always)]
+    fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
+        Self::BaseField::zero()
+    }
+}
+
+pub const G2_GENERATOR_X: Fq3 =
+    field_new!(Fq3, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1, G2_GENERATOR_X_C2);
+pub const G2_GENERATOR_Y: Fq3 =
+    field_new!(Fq3, G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1, G2_GENERATOR_Y_C2);
+
+/// G2_GENERATOR_X_C0 =
+/// 234675377444007645250304532773976467762897744190219023784828650750989991121769264473973158664474931930
+#[rustfmt::skip]
+pub const G2_GENERATOR_X_C0: Fq = field_new!(Fq, "234675377444007645250304532773976467762897744190219023784828650750989991121769264473973158664474931930");
+
+/// G2_GENERATOR_X_C1 =
+/// 1403757039683884148810112351415154401677239840059815523925493718167837136976308125731269873282000121109
+#[rustfmt::skip]
+pub const G2_GENERATOR_X_C1: Fq = field_new!(Fq, "1403757039683884148810112351415154401677239840059815523925493718167837136976308125731269873282000121109");
+
+/// G2_GENERATOR_X_C2 =
+/// 1797461258050368826002641140014155678655922042661388607970491542527524884658801979753207074087084291690
+#[rustfmt::skip]
+pub const G2_GENERATOR_X_C2: Fq = field_new!(Fq, "1797461258050368826002641140014155678655922042661388607970491542527524884658801979753207074087084291690");
+
+/// G2_GENERATOR_Y_C0 =
+/// 608069829541779619338148506003708446454726951675936744148159435257940349741363504756795075998386245250
+#[rustfmt::skip]
+pub const G2_GENERATOR_Y_C0: Fq = field_new!(Fq, "608069829541779619338148506003708446454726951675936744148159435257940349741363504756795075998386245250");
+
+/// G2_GENERATOR_Y_C1 =
+/// 565456102837089410360496423308978007025606414556093579262736122779537191294099140039941747357194908472
+#[rustfmt::skip]
+pub const G2_GENERATOR_Y_C1: Fq = field_new!(Fq, "565456102837089410360496423308978007025606414556093579262736122779537191294099140039941747357194908472");
+
+/// G2_GENERATOR_Y_C2 =
+/// 1418730405591132689695806501491681610134864344454080888483904562260297093295388129897647474521916544119
+#[rustfmt::skip]
+pub const G2_GENERATOR_Y_C2: Fq = field_new!(Fq, "1418730405591132689695806501491681610134864344454080888483904562260297093295388129897647474521916544119");
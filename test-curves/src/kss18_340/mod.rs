@@ -0,0 +1,68 @@
+pub mod fr;
+pub use fr::*;
+
+#[cfg(feature = "kss18_340_curve")]
+pub mod fq;
+#[cfg(feature = "kss18_340_curve")]
+pub mod fq18;
+#[cfg(feature = "kss18_340_curve")]
+pub mod fq3;
+#[cfg(feature = "kss18_340_curve")]
+pub mod fq9;
+#[cfg(feature = "kss18_340_curve")]
+pub mod g1;
+#[cfg(feature = "kss18_340_curve")]
+pub mod g2;
+
+#[cfg(feature = "kss18_340_curve")]
+pub use fq::*;
+#[cfg(feature = "kss18_340_curve")]
+pub use fq18::*;
+#[cfg(feature = "kss18_340_curve")]
+pub use fq3::*;
+#[cfg(feature = "kss18_340_curve")]
+pub use fq9::*;
+#[cfg(feature = "kss18_340_curve")]
+pub use g1::{G1Affine, G1Projective};
+#[cfg(feature = "kss18_340_curve")]
+pub use g2::{G2Affine, G2Projective};
+
+#[cfg(feature = "kss18_340_curve")]
+use ark_ec::kss18::{Kss18, Kss18Parameters, TwistType};
+#[cfg(feature = "kss18_340_curve")]
+use ark_ff::field_new;
+
+#[cfg(feature = "kss18_340_curve")]
+pub type Kss18_340 = Kss18<Parameters>;
+
+#[cfg(feature = "kss18_340_curve")]
+pub struct Parameters;
+
+#[cfg(feature = "kss18_340_curve")]
+impl Kss18Parameters for Parameters {
+    /// X = 2^43 + 2^37 + 2^21 + 2
+    const X: &'static [u64] = &[0x82000200002];
+    /// `x` is positive.
+    const X_IS_NEGATIVE: bool = false;
+    const ATE_LOOP_COUNT: &'static [i8] = &[
+        0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 1,
+    ];
+
+    const TWIST_TYPE: TwistType = TwistType::D;
+    /// U^((q - 1) / 3)
+    #[rustfmt::skip]
+    const TWIST_MUL_BY_Q_X: Fq3 = field_new!(Fq3, field_new!(Fq, "0"), field_new!(Fq, "0"), field_new!(Fq, "-279230213752206353200886016354955722554314239393321665659765582519787538130672404272105407632835497531"));
+    /// U^((q - 1) / 2)
+    #[rustfmt::skip]
+    const TWIST_MUL_BY_Q_Y: Fq3 = field_new!(Fq3, field_new!(Fq, "189213380716722046877857367867109462794717813706194944627105812132881527042519361163362491"), field_new!(Fq, "0"), field_new!(Fq, "0"));
+    type Fp = Fq;
+    type Fp3Params = Fq3Parameters;
+    type Fp9Params = Fq9Parameters;
+    type Fp18Params = Fq18Parameters;
+    type G1Parameters = g1::Parameters;
+    type G2Parameters = g2::Parameters;
+}
+
+#[cfg(test)]
+mod tests;
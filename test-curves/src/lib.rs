@@ -10,9 +10,37 @@ pub mod bls12_381;
 ))]
 pub mod mnt4_753;
 
+#[cfg(any(feature = "mnt6_114_scalar_field", feature = "mnt6_114_curve"))]
+pub mod mnt6_114;
+
 #[cfg(any(
     feature = "bn384_small_two_adicity_scalar_field",
     feature = "bn384_small_two_adicity_base_field",
     feature = "bn384_small_two_adicity_curve"
 ))]
 pub mod bn384_small_two_adicity;
+
+#[cfg(any(
+    feature = "ed_on_bls12_381_scalar_field",
+    feature = "ed_on_bls12_381_curve"
+))]
+pub mod ed_on_bls12_381;
+
+#[cfg(any(feature = "curve25519_scalar_field", feature = "curve25519_curve"))]
+pub mod curve25519;
+
+#[cfg(any(feature = "bn254_scalar_field", feature = "bn254_curve"))]
+pub mod bn254;
+
+#[cfg(any(
+    feature = "bw6_761_scalar_field",
+    feature = "bw6_761_base_field",
+    feature = "bw6_761_curve"
+))]
+pub mod bw6_761;
+
+#[cfg(any(feature = "bls24_315_scalar_field", feature = "bls24_315_curve"))]
+pub mod bls24_315;
+
+#[cfg(any(feature = "kss18_340_scalar_field", feature = "kss18_340_curve"))]
+pub mod kss18_340;
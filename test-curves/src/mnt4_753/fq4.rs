@@ -0,0 +1,23 @@
+use crate::mnt4_753::*;
+use ark_ff::{field_new, fields::fp4::*};
+
+pub type Fq4 = Fp4<Fq4Parameters>;
+
+pub struct Fq4Parameters;
+
+impl Fp4Parameters for Fq4Parameters {
+    type Fp2Params = Fq2Parameters;
+
+    /// NONRESIDUE = (0, 1)
+    #[rustfmt::skip]
+    const NONRESIDUE: Fq2 = field_new!(Fq2, FQ_ZERO, FQ_ONE);
+
+    /// Fq2::NONRESIDUE^((q^i - 1) / 4) for i = 0, 1, 2, 3
+    #[rustfmt::skip]
+    const FROBENIUS_COEFF_FP4_C1: &'static [Fq] = &[
+        field_new!(Fq, "1"),
+        field_new!(Fq, "18691656569803771296244054523431852464958959799019013859007259692542121208304602539555350517075508287829753932558576476751900235650227380562700444433662761577027341858128610410779088384480737679672900770810745291515010467307990"),
+        field_new!(Fq, "-1"),
+        field_new!(Fq, "23206834398115182106100160267808784663211750120934935212776243228483231604266504233503543246714830633588317039329677309362453490879357004638891161288350364891904062489821230132228897943262725174047727280881395973788104254381611"),
+    ];
+}
@@ -0,0 +1,81 @@
+use ark_ec::{
+    mnt4,
+    models::{ModelParameters, SWModelParameters},
+};
+use ark_ff::field_new;
+
+use crate::mnt4_753::*;
+
+pub type G2Affine = mnt4::G2Affine<crate::mnt4_753::Parameters>;
+pub type G2Projective = mnt4::G2Projective<crate::mnt4_753::Parameters>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq2;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = g1::Parameters::COEFF_A * TWIST^2 = (26, 0)
+    #[rustfmt::skip]
+    const COEFF_A: Fq2 = field_new!(Fq2, field_new!(Fq, "26"), field_new!(Fq, "0"));
+
+    /// COEFF_B = g1::Parameters::COEFF_B * TWIST^3 = (0, 13 * g1::Parameters::COEFF_B)
+    #[rustfmt::skip]
+    const COEFF_B: Fq2 = field_new!(Fq2,
+        field_new!(Fq, "0"),
+        field_new!(Fq, "39196523001581428369576759982967177918859161321667605855515469914917622337081756705006832951954384669101573360625169461998308377011601613979275218690841934572954991361632773738259652003389826903175898479855893660378722437317212"),
+    );
+
+    /// COFACTOR = #E'(Fq2) / r =
+    /// 41898490967918953402344214791240637128170709919953949071783502921025352812571106773058893763790338921418070971888049094905534395567574915333486969589229856772141392370549616644545554517640527237829320384324374366385444967219201
+    #[rustfmt::skip]
+    const COFACTOR: &'static [u64] = &[
+        0xe41950da08bd0001,
+        0x789a0f8d4a18e8ee,
+        0xf04c9f26f687f44a,
+        0x16d5a05cb84b6ea3,
+        0x313250b76d85d63a,
+        0xafc372c51bd661a0,
+        0x99d124d9a15af79d,
+        0x7fdb925e8a0ed8d,
+        0x5eb7e8f96c97d873,
+        0xb7f997505b8fafed,
+        0x10229022eee2cdad,
+        0x1c4c62d92c411,
+    ];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r =
+    /// 102345604409665481004734934052318066391634848395005988700111949231215905051467807945653833683883449458834877235200
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, "102345604409665481004734934052318066391634848395005988700111949231215905051467807945653833683883449458834877235200");
+
+    /// AFFINE_GENERATOR_COEFFS = (G2_GENERATOR_X, G2_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (G2_GENERATOR_X, G2_GENERATOR_Y);
+}
+
+pub const G2_GENERATOR_X: Fq2 = field_new!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
+pub const G2_GENERATOR_Y: Fq2 = field_new!(Fq2, G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1);
+
+/// G2_GENERATOR_X_C0 =
+/// 23245671200104738104424448805806199448301218736669820624524326039128322776338990045282349159139657477425569407746363850174004511125061676383071313345896427737454900907279286500492769364967307582133995196768227124000678801147815
+#[rustfmt::skip]
+pub const G2_GENERATOR_X_C0: Fq = field_new!(Fq, "23245671200104738104424448805806199448301218736669820624524326039128322776338990045282349159139657477425569407746363850174004511125061676383071313345896427737454900907279286500492769364967307582133995196768227124000678801147815");
+
+/// G2_GENERATOR_X_C1 =
+/// 535046262038412319414953262975763860295844156769700104036220348285335203836003550974174230039491369358208409224960624685551614546461958276914373513210874539295780646384998814363912662299694663257100007747808403090015346842956
+#[rustfmt::skip]
+pub const G2_GENERATOR_X_C1: Fq = field_new!(Fq, "535046262038412319414953262975763860295844156769700104036220348285335203836003550974174230039491369358208409224960624685551614546461958276914373513210874539295780646384998814363912662299694663257100007747808403090015346842956");
+
+/// G2_GENERATOR_Y_C0 =
+/// 18036029907703112256875478668368480942607103305290847298533702500925232026408383230840964776993834021700904491478667012648469739697429897061819911611069817913729955409840209767797201820299960198384138856329953327811983361689408
+#[rustfmt::skip]
+pub const G2_GENERATOR_Y_C0: Fq = field_new!(Fq, "18036029907703112256875478668368480942607103305290847298533702500925232026408383230840964776993834021700904491478667012648469739697429897061819911611069817913729955409840209767797201820299960198384138856329953327811983361689408");
+
+/// G2_GENERATOR_Y_C1 =
+/// 37969793179828304709732988212521375781098839017854898913811534981375805692815055740404330009466734730760041635401011538796537831403556469000135372386243030327691337326537446063627464113998661025061622537146005523279285294704665
+#[rustfmt::skip]
+pub const G2_GENERATOR_Y_C1: Fq = field_new!(Fq, "37969793179828304709732988212521375781098839017854898913811534981375805692815055740404330009466734730760041635401011538796537831403556469000135372386243030327691337326537446063627464113998661025061622537146005523279285294704665");
@@ -8,7 +8,84 @@ pub mod fr;
 #[cfg(feature = "mnt4_753_scalar_field")]
 pub use fr::*;
 
+#[cfg(feature = "mnt4_753_curve")]
+pub mod fq2;
+#[cfg(feature = "mnt4_753_curve")]
+pub mod fq4;
 #[cfg(feature = "mnt4_753_curve")]
 pub mod g1;
 #[cfg(feature = "mnt4_753_curve")]
+pub mod g2;
+
+#[cfg(feature = "mnt4_753_curve")]
+pub use fq2::*;
+#[cfg(feature = "mnt4_753_curve")]
+pub use fq4::*;
+#[cfg(feature = "mnt4_753_curve")]
 pub use g1::*;
+#[cfg(feature = "mnt4_753_curve")]
+pub use g2::{G2Affine, G2Projective};
+
+#[cfg(feature = "mnt4_753_curve")]
+use ark_ec::{
+    mnt4::{MNT4Parameters, MNT4},
+    SWModelParameters,
+};
+#[cfg(feature = "mnt4_753_curve")]
+use ark_ff::{biginteger::BigInteger768 as BigInteger, field_new};
+
+#[cfg(feature = "mnt4_753_curve")]
+pub type MNT4_753 = MNT4<Parameters>;
+
+#[cfg(feature = "mnt4_753_curve")]
+pub struct Parameters;
+
+#[cfg(feature = "mnt4_753_curve")]
+impl MNT4Parameters for Parameters {
+    /// TWIST = (0, 1)
+    const TWIST: Fq2 = field_new!(Fq2, FQ_ZERO, FQ_ONE);
+    /// TWIST_COEFF_A = g1::Parameters::COEFF_A * TWIST^2
+    const TWIST_COEFF_A: Fq2 = g2::Parameters::COEFF_A;
+    /// ATE_LOOP_COUNT = |t - 1| =
+    /// 204691208819330962009469868104636132783269696790011977400223898462431810102935615891307667367766898917669754470400
+    #[rustfmt::skip]
+    const ATE_LOOP_COUNT: &'static [u64] = &[
+        0x7a7713041ba18000,
+        0x6b0344c4e2c428b0,
+        0x733b714aa43c31a6,
+        0x51852c8cbe26e600,
+        0x86dcbcee5dcda7fe,
+        0x15474b1d641a3fd,
+    ];
+    const ATE_IS_LOOP_COUNT_NEG: bool = true;
+    /// (q^2 + 1) / r = W1 * q + W0, with W1 = 1 and W0 = t
+    #[rustfmt::skip]
+    const FINAL_EXPONENT_LAST_CHUNK_1: BigInteger = BigInteger::new([
+        0x1, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0,
+    ]);
+    const FINAL_EXPONENT_LAST_CHUNK_W0_IS_NEG: bool = true;
+    #[rustfmt::skip]
+    const FINAL_EXPONENT_LAST_CHUNK_ABS_OF_W0: BigInteger = BigInteger::new([
+        0x7a7713041ba17fff,
+        0x6b0344c4e2c428b0,
+        0x733b714aa43c31a6,
+        0x51852c8cbe26e600,
+        0x86dcbcee5dcda7fe,
+        0x15474b1d641a3fd,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+        0x0,
+    ]);
+    type Fp = Fq;
+    type Fr = Fr;
+    type Fp2Params = Fq2Parameters;
+    type Fp4Params = Fq4Parameters;
+    type G1Parameters = g1::Parameters;
+    type G2Parameters = g2::Parameters;
+}
+
+#[cfg(all(test, feature = "mnt4_753_curve"))]
+mod tests;
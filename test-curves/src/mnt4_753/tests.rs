@@ -0,0 +1,112 @@
+#![allow(unused_imports)]
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{Field, One, PrimeField, UniformRand};
+use ark_std::rand::Rng;
+
+use crate::mnt4_753::{
+    g1, g2, Fq, Fq2, Fq4, Fr, G1Affine, G1Projective, G2Affine, G2Projective, MNT4_753,
+};
+use ark_algebra_test_templates::{curves::*, fields::*, groups::*, msm::*};
+
+pub(crate) const ITERATIONS: usize = 5;
+
+#[test]
+fn test_fq2() {
+    let mut rng = ark_std::test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq2 = UniformRand::rand(&mut rng);
+        let b: Fq2 = UniformRand::rand(&mut rng);
+        field_test(a, b);
+        sqrt_field_test(a);
+    }
+    frobenius_test::<Fq2, _>(Fq::characteristic(), 13);
+}
+
+#[test]
+fn test_fq4() {
+    let mut rng = ark_std::test_rng();
+    for _ in 0..ITERATIONS {
+        let a: Fq4 = UniformRand::rand(&mut rng);
+        let b: Fq4 = UniformRand::rand(&mut rng);
+        field_test(a, b);
+    }
+    frobenius_test::<Fq4, _>(Fq::characteristic(), 13);
+}
+
+#[test]
+fn test_g1_projective_curve() {
+    curve_tests::<G1Projective>();
+    sw_tests::<g1::Parameters>();
+}
+
+#[test]
+fn test_g1_homogeneous_projective_curve() {
+    sw_projective_tests::<g1::Parameters>();
+}
+
+#[test]
+fn test_g1_projective_group() {
+    let mut rng = ark_std::test_rng();
+    let a: G1Projective = rng.gen();
+    let b: G1Projective = rng.gen();
+    group_test(a, b);
+}
+
+#[test]
+fn test_g1_msm() {
+    test_var_base_msm::<G1Affine>();
+    test_var_base_msm_edge_cases::<G1Affine>();
+    test_wnaf_msm::<G1Projective>();
+    test_precomputed_bases_msm::<G1Affine>();
+}
+
+#[test]
+fn test_g1_generator() {
+    let generator = G1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_projective_curve() {
+    curve_tests::<G2Projective>();
+    sw_tests::<g2::Parameters>();
+}
+
+#[test]
+fn test_g2_generator() {
+    let generator = G2Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_bilinearity() {
+    let mut rng = ark_std::test_rng();
+    let a: G1Projective = rng.gen();
+    let b: G2Projective = rng.gen();
+    let s: Fr = rng.gen();
+
+    let sa = a.mul(s.into_repr());
+    let sb = b.mul(s.into_repr());
+
+    let ans1 = MNT4_753::pairing(sa, b);
+    let ans2 = MNT4_753::pairing(a, sb);
+    let ans3 = MNT4_753::pairing(a, b).pow(s.into_repr());
+
+    assert_eq!(ans1, ans2);
+    assert_eq!(ans2, ans3);
+
+    assert_ne!(ans1, Fq4::one());
+    assert_eq!(ans1.pow(Fr::characteristic()), Fq4::one());
+}
+
+#[test]
+fn test_pairing_output() {
+    pairing_output_test::<MNT4_753>();
+}
+
+#[test]
+fn test_prepared_serialization() {
+    prepared_serialization_test::<MNT4_753>();
+}
@@ -0,0 +1,15 @@
+use ark_ff::{
+    field_new,
+    fields::{Fp, FpParameters},
+};
+
+pub type Fq = Fp<FqParameters, 2>;
+
+/// The base field of MNT6-114.
+#[derive(FpParameters)]
+#[modulus = "12076088289594082667150216839518097"]
+#[generator = "7"]
+pub struct FqParameters;
+
+pub const FQ_ONE: Fq = field_new!(Fq, "1");
+pub const FQ_ZERO: Fq = field_new!(Fq, "0");
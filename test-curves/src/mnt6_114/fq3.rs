@@ -0,0 +1,58 @@
+use crate::mnt6_114::*;
+use ark_ff::{field_new, fields::*};
+
+pub type Fq3 = Fp3<Fq3Parameters>;
+
+pub struct Fq3Parameters;
+
+impl Fp3Parameters for Fq3Parameters {
+    type Fp = Fq;
+
+    /// NONRESIDUE = 7
+    const NONRESIDUE: Fq = field_new!(Fq, "7");
+
+    /// (q^3 - 1) = 2^TWO_ADICITY * T
+    const TWO_ADICITY: u32 = 4;
+
+    /// (T - 1) / 2
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: &'static [u64] = &[
+        0xe433602ac62f314d,
+        0xafe81aff757b318,
+        0x1e3fc9e97be83cb6,
+        0xbcee0b7ab0dda4d0,
+        0x188c3711958b68a,
+        0x64a5,
+    ];
+
+    /// NONRESIDUE^T
+    #[rustfmt::skip]
+    const QUADRATIC_NONRESIDUE_TO_T: (Fq, Fq, Fq) = (
+        field_new!(Fq, "1341187651364396151644561785390117"),
+        field_new!(Fq, "0"),
+        field_new!(Fq, "0"),
+    );
+
+    #[rustfmt::skip]
+    const FROBENIUS_COEFF_FP3_C1: &'static [Fq] = &[
+        // NONRESIDUE^(((q^0) - 1) / 3)
+        field_new!(Fq, "1"),
+        // NONRESIDUE^(((q^1) - 1) / 3)
+        field_new!(Fq, "11604192129379069909014088399019714"),
+        // NONRESIDUE^(((q^2) - 1) / 3)
+        field_new!(Fq, "471896160215012758136128440498382"),
+    ];
+
+    #[rustfmt::skip]
+    const FROBENIUS_COEFF_FP3_C2: &'static [Fq] = &[
+        // NONRESIDUE^(((2q^0) - 2) / 3)
+        field_new!(Fq, "1"),
+        // NONRESIDUE^(((2q^1) - 2) / 3)
+        field_new!(Fq, "471896160215012758136128440498382"),
+        // NONRESIDUE^(((2q^2) - 2) / 3)
+        field_new!(Fq, "11604192129379069909014088399019714"),
+    ];
+}
+
+pub const FQ3_ZERO: Fq3 = field_new!(Fq3, FQ_ZERO, FQ_ZERO, FQ_ZERO);
+pub const FQ3_ONE: Fq3 = field_new!(Fq3, FQ_ONE, FQ_ZERO, FQ_ZERO);
@@ -0,0 +1,30 @@
+use crate::mnt6_114::*;
+use ark_ff::{field_new, fields::fp6_2over3::*};
+
+pub type Fq6 = Fp6<Fq6Parameters>;
+
+pub struct Fq6Parameters;
+
+impl Fp6Parameters for Fq6Parameters {
+    type Fp3Params = Fq3Parameters;
+
+    /// NONRESIDUE = (0, 1, 0)
+    #[rustfmt::skip]
+    const NONRESIDUE: Fq3 = field_new!(Fq3, FQ_ZERO, FQ_ONE, FQ_ZERO);
+
+    #[rustfmt::skip]
+    const FROBENIUS_COEFF_FP6_C1: &'static [Fq] = &[
+        // NONRESIDUE^(((q^0) - 1) / 6)
+        field_new!(Fq, "1"),
+        // NONRESIDUE^(((q^1) - 1) / 6)
+        field_new!(Fq, "11604192129379069909014088399019715"),
+        // NONRESIDUE^(((q^2) - 1) / 6)
+        field_new!(Fq, "11604192129379069909014088399019714"),
+        // NONRESIDUE^(((q^3) - 1) / 6)
+        field_new!(Fq, "-1"),
+        // NONRESIDUE^(((q^4) - 1) / 6)
+        field_new!(Fq, "471896160215012758136128440498382"),
+        // NONRESIDUE^(((q^5) - 1) / 6)
+        field_new!(Fq, "471896160215012758136128440498383"),
+    ];
+}
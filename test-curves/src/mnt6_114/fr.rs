@@ -0,0 +1,9 @@
+use ark_ff::fields::{Fp, FpParameters};
+
+pub type Fr = Fp<FrParameters, 2>;
+
+/// The scalar field of MNT6-114.
+#[derive(FpParameters)]
+#[modulus = "12076088289594082777041473496242533"]
+#[generator = "6"]
+pub struct FrParameters;
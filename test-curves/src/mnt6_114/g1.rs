@@ -0,0 +1,42 @@
+use ark_ec::{
+    mnt6,
+    models::{ModelParameters, SWModelParameters},
+};
+use ark_ff::field_new;
+
+use crate::mnt6_114::{Fq, Fr};
+
+pub type G1Affine = mnt6::G1Affine<crate::mnt6_114::Parameters>;
+pub type G1Projective = mnt6::G1Projective<crate::mnt6_114::Parameters>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = 1
+    const COEFF_A: Fq = field_new!(Fq, "1");
+
+    /// COEFF_B = 10538772870290469803892552963531311
+    const COEFF_B: Fq = field_new!(Fq, "10538772870290469803892552963531311");
+
+    /// COFACTOR = 1
+    const COFACTOR: &'static [u64] = &[0x1];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r = 1
+    const COFACTOR_INV: Fr = field_new!(Fr, "1");
+
+    /// AFFINE_GENERATOR_COEFFS = (G1_GENERATOR_X, G1_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (G1_GENERATOR_X, G1_GENERATOR_Y);
+}
+
+/// G1_GENERATOR_X = 3
+pub const G1_GENERATOR_X: Fq = field_new!(Fq, "3");
+
+/// G1_GENERATOR_Y = 1436335928961319912493363106053902
+pub const G1_GENERATOR_Y: Fq = field_new!(Fq, "1436335928961319912493363106053902");
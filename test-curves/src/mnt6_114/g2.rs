@@ -0,0 +1,65 @@
+use ark_ec::{
+    mnt6,
+    models::{ModelParameters, SWModelParameters},
+};
+use ark_ff::field_new;
+
+use crate::mnt6_114::*;
+
+pub type G2Affine = mnt6::G2Affine<crate::mnt6_114::Parameters>;
+pub type G2Projective = mnt6::G2Projective<crate::mnt6_114::Parameters>;
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Parameters;
+
+impl ModelParameters for Parameters {
+    type BaseField = Fq3;
+    type ScalarField = Fr;
+}
+
+impl SWModelParameters for Parameters {
+    /// COEFF_A = g1::Parameters::COEFF_A * TWIST^2 = (0, 0, 1)
+    #[rustfmt::skip]
+    const COEFF_A: Fq3 = field_new!(Fq3, FQ_ZERO, FQ_ZERO, g1::Parameters::COEFF_A);
+
+    /// COEFF_B = g1::Parameters::COEFF_B * TWIST^3 = (7 * g1::Parameters::COEFF_B, 0, 0)
+    #[rustfmt::skip]
+    const COEFF_B: Fq3 = field_new!(Fq3,
+        field_new!(Fq, "1314880354468792624346569707610595"),
+        FQ_ZERO,
+        FQ_ZERO,
+    );
+
+    /// COFACTOR = #E'(Fq3) / r =
+    /// 145831908378071335673237653733390749046095487125410985479955922625648
+    #[rustfmt::skip]
+    const COFACTOR: &'static [u64] = &[
+        0x4afbd4051b512470,
+        0x1f1cad95f057c56d,
+        0x36208edeede52b5f,
+        0x568c1bb62,
+    ];
+
+    /// COFACTOR_INV = COFACTOR^{-1} mod r =
+    /// 2012681381599013814488788358827828
+    #[rustfmt::skip]
+    const COFACTOR_INV: Fr = field_new!(Fr, "2012681381599013814488788358827828");
+
+    /// AFFINE_GENERATOR_COEFFS = (G2_GENERATOR_X, G2_GENERATOR_Y)
+    const AFFINE_GENERATOR_COEFFS: (Self::BaseField, Self::BaseField) =
+        (G2_GENERATOR_X, G2_GENERATOR_Y);
+}
+
+#[rustfmt::skip]
+pub const G2_GENERATOR_X: Fq3 = field_new!(Fq3,
+    field_new!(Fq, "6236013317042382359470689315060911"),
+    field_new!(Fq, "4490483246751785429964686400955893"),
+    field_new!(Fq, "4668266702240576456114388827985346"),
+);
+
+#[rustfmt::skip]
+pub const G2_GENERATOR_Y: Fq3 = field_new!(Fq3,
+    field_new!(Fq, "6878669616756787469982706795593741"),
+    field_new!(Fq, "3825061828581525040778453772173482"),
+    field_new!(Fq, "7675524881908477154204845202058868"),
+);
@@ -0,0 +1,66 @@
+//! An MNT6 curve with a 114-bit base field and CM discriminant -947, which
+//! exercises the MNT6 pairing at a size where the tests run quickly. It is
+//! far too small to be secure.
+pub mod fr;
+pub use fr::*;
+
+#[cfg(feature = "mnt6_114_curve")]
+pub mod fq;
+#[cfg(feature = "mnt6_114_curve")]
+pub mod fq3;
+#[cfg(feature = "mnt6_114_curve")]
+pub mod fq6;
+#[cfg(feature = "mnt6_114_curve")]
+pub mod g1;
+#[cfg(feature = "mnt6_114_curve")]
+pub mod g2;
+
+#[cfg(feature = "mnt6_114_curve")]
+pub use fq::*;
+#[cfg(feature = "mnt6_114_curve")]
+pub use fq3::*;
+#[cfg(feature = "mnt6_114_curve")]
+pub use fq6::*;
+#[cfg(feature = "mnt6_114_curve")]
+pub use g1::{G1Affine, G1Projective};
+#[cfg(feature = "mnt6_114_curve")]
+pub use g2::{G2Affine, G2Projective};
+
+#[cfg(feature = "mnt6_114_curve")]
+use ark_ec::{
+    mnt6::{MNT6Parameters, MNT6},
+    SWModelParameters,
+};
+#[cfg(feature = "mnt6_114_curve")]
+use ark_ff::{biginteger::BigInteger128 as BigInteger, field_new};
+
+#[cfg(feature = "mnt6_114_curve")]
+pub type MNT6_114 = MNT6<Parameters>;
+
+#[cfg(feature = "mnt6_114_curve")]
+pub struct Parameters;
+
+#[cfg(feature = "mnt6_114_curve")]
+impl MNT6Parameters for Parameters {
+    /// TWIST = (0, 1, 0)
+    const TWIST: Fq3 = field_new!(Fq3, FQ_ZERO, FQ_ONE, FQ_ZERO);
+    /// TWIST_COEFF_A = g1::Parameters::COEFF_A * TWIST^2
+    const TWIST_COEFF_A: Fq3 = g2::Parameters::COEFF_A;
+    /// ATE_LOOP_COUNT = |t - 1| = 109891256656724436
+    const ATE_LOOP_COUNT: &'static [u64] = &[0x186698404f201d4];
+    const ATE_IS_LOOP_COUNT_NEG: bool = true;
+    /// (q^2 - q + 1) / r = W1 * q + W0, with W1 = 1 and W0 = t - 1
+    const FINAL_EXPONENT_LAST_CHUNK_1: BigInteger = BigInteger::new([0x1, 0x0]);
+    const FINAL_EXPONENT_LAST_CHUNK_W0_IS_NEG: bool = true;
+    const FINAL_EXPONENT_LAST_CHUNK_ABS_OF_W0: BigInteger =
+        BigInteger::new([0x186698404f201d4, 0x0]);
+    type Fp = Fq;
+    type Fr = Fr;
+    type Fp3Params = Fq3Parameters;
+    type Fp6Params = Fq6Parameters;
+    type G1Parameters = g1::Parameters;
+    type G2Parameters = g2::Parameters;
+}
+
+#[cfg(all(test, feature = "mnt6_114_curve"))]
+mod tests;
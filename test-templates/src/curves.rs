@@ -2,9 +2,12 @@
 use ark_ec::twisted_edwards_extended::GroupProjective;
 use ark_ec::wnaf::WnafContext;
 use ark_ec::{
-    AffineCurve, MontgomeryModelParameters, ProjectiveCurve, SWModelParameters, TEModelParameters,
+    AffineCurve, MontgomeryModelParameters, PairingEngine, ProjectiveCurve, SWModelParameters,
+    TEModelParameters,
+};
+use ark_ff::{
+    BigInteger, BitIteratorBE, Field, One, PrimeField, SquareRootField, UniformRand, Zero,
 };
-use ark_ff::{Field, One, PrimeField, UniformRand, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SWFlags, SerializationError};
 use ark_std::{io::Cursor, vec::Vec};
 
@@ -434,6 +437,71 @@ pub fn sw_affine_sum_test<P: SWModelParameters>() {
     }
 }
 
+/// Checks that the complete formulae of `short_weierstrass_projective` agree
+/// with the Jacobian formulae, including on the inputs for which the latter
+/// need special cases.
+pub fn sw_projective_tests<P: SWModelParameters>() {
+    use ark_ec::models::{
+        short_weierstrass_jacobian as jacobian,
+        short_weierstrass_projective::{GroupAffine, GroupProjective},
+    };
+
+    curve_tests::<GroupProjective<P>>();
+
+    let mut rng = ark_std::test_rng();
+
+    let zero = GroupProjective::<P>::zero();
+    assert!(zero.double().is_zero());
+    assert!((zero + &zero).is_zero());
+    assert!(zero.add_mixed(&GroupAffine::zero()).is_zero());
+
+    for _ in 0..ITERATIONS {
+        let a = jacobian::GroupProjective::<P>::rand(&mut rng);
+        let b = jacobian::GroupProjective::<P>::rand(&mut rng);
+        let a_affine = GroupAffine::from(a.into_affine());
+        let b_affine = GroupAffine::from(b.into_affine());
+        let c = GroupProjective::from(a_affine);
+        let d = GroupProjective::from(b_affine);
+        assert!(a_affine.is_on_curve());
+        assert!(a_affine.is_in_correct_subgroup_assuming_on_curve());
+
+        assert_eq!(
+            (c + &d).into_affine(),
+            GroupAffine::from((a + &b).into_affine())
+        );
+        assert_eq!(c.add_mixed(&b_affine), c + &d);
+        assert_eq!(
+            c.double().into_affine(),
+            GroupAffine::from(a.double().into_affine())
+        );
+
+        // The complete formulae handle doubling, opposite points and the
+        // identity without special cases.
+        assert_eq!(c + &c, c.double());
+        assert_eq!(c.add_mixed(&a_affine), c.double());
+        assert!((c - &c).is_zero());
+        assert!(c.add_mixed(&-a_affine).is_zero());
+        assert_eq!(zero + &c, c);
+        assert_eq!(c + &zero, c);
+        assert_eq!(zero.add_mixed(&a_affine), c);
+        assert_eq!((c + &d).double(), c.double() + &d.double());
+
+        let s = P::ScalarField::rand(&mut rng);
+        assert_eq!(
+            c.mul(s.into_repr()).into_affine(),
+            GroupAffine::from(a.mul(s.into_repr()).into_affine())
+        );
+
+        // The encoding is that of the Jacobian model.
+        let mut bytes = Vec::new();
+        c.serialize(&mut bytes).unwrap();
+        let mut expected = Vec::new();
+        a.serialize(&mut expected).unwrap();
+        assert_eq!(bytes, expected);
+        assert_eq!(GroupProjective::<P>::deserialize(&bytes[..]).unwrap(), c);
+    }
+}
+
 pub fn montgomery_conversion_test<P>()
 where
     P: TEModelParameters,
@@ -449,6 +517,95 @@ where
     assert_eq!(b, P::MontgomeryModelParameters::COEFF_B);
 }
 
+pub fn montgomery_tests<P>()
+where
+    P: TEModelParameters,
+    P::BaseField: SquareRootField,
+    P::MontgomeryModelParameters:
+        MontgomeryModelParameters<TEModelParameters = P, ScalarField = P::ScalarField>,
+{
+    use ark_ec::models::{
+        montgomery::{GroupAffine, XZProjective},
+        twisted_edwards_extended::GroupAffine as TEAffine,
+    };
+    use ark_std::convert::TryFrom;
+    type M<P> = <P as TEModelParameters>::MontgomeryModelParameters;
+
+    let mut rng = ark_std::test_rng();
+
+    // The point (0, -1) of order two, which maps to (0, 0).
+    let t = TEAffine::<P>::new(P::BaseField::zero(), -P::BaseField::one());
+    let tm = GroupAffine::<M<P>>::from(t);
+    assert_eq!(
+        tm,
+        GroupAffine::new(P::BaseField::zero(), P::BaseField::zero(), false)
+    );
+    assert!(tm.is_on_curve());
+    assert!(tm.double().is_zero());
+    assert_eq!(TEAffine::<P>::try_from(tm).unwrap(), t);
+    assert!(GroupAffine::<M<P>>::from(TEAffine::<P>::zero()).is_zero());
+    assert!(TEAffine::<P>::try_from(GroupAffine::<M<P>>::zero())
+        .unwrap()
+        .is_zero());
+
+    // The points with `u = -1`, and those with `v = 0` other than (0, 0),
+    // have no affine image on the twisted Edwards curve.
+    let a = <M<P> as MontgomeryModelParameters>::COEFF_A;
+    let b = <M<P> as MontgomeryModelParameters>::COEFF_B;
+    let minus_one = -P::BaseField::one();
+    if let Some(v) = ((a - &P::BaseField::one().double()) / &b).sqrt() {
+        let p = GroupAffine::<M<P>>::new(minus_one, v, false);
+        assert!(p.is_on_curve());
+        assert!(TEAffine::<P>::try_from(p).is_err());
+    }
+    if let Some(root) = (a.square() - &P::BaseField::from(4u64)).sqrt() {
+        let u = (root - &a) / &P::BaseField::one().double();
+        let p = GroupAffine::<M<P>>::new(u, P::BaseField::zero(), false);
+        assert!(p.is_on_curve());
+        assert!(TEAffine::<P>::try_from(p).is_err());
+    }
+
+    for _ in 0..ITERATIONS {
+        let p = GroupProjective::<P>::rand(&mut rng).into_affine();
+        let q = GroupProjective::<P>::rand(&mut rng).into_affine() + &t;
+        let pm = GroupAffine::<M<P>>::from(p);
+        let qm = GroupAffine::<M<P>>::from(q);
+        assert!(pm.is_on_curve());
+        assert!(qm.is_on_curve());
+        assert_eq!(TEAffine::<P>::try_from(pm).unwrap(), p);
+        assert_eq!(TEAffine::<P>::try_from(qm).unwrap(), q);
+
+        assert_eq!(TEAffine::<P>::try_from(pm + &qm).unwrap(), p + &q);
+        assert_eq!(TEAffine::<P>::try_from(pm - &qm).unwrap(), p - &q);
+        assert_eq!(TEAffine::<P>::try_from(pm.double()).unwrap(), p + &p);
+        assert_eq!(TEAffine::<P>::try_from(-pm).unwrap(), -p);
+        assert_eq!(pm + &tm, GroupAffine::from(p + &t));
+        assert!((pm - &pm).is_zero());
+        assert_eq!(pm + &GroupAffine::zero(), pm);
+
+        let x = XZProjective::from(pm);
+        assert_eq!(x.double(), XZProjective::from(pm.double()));
+        assert_eq!(
+            x.differential_add(&XZProjective::from(qm), &XZProjective::from(pm - &qm)),
+            XZProjective::from(pm + &qm)
+        );
+
+        for s in mul_scalars::<P::ScalarField>() {
+            let expected = GroupAffine::<M<P>>::from(q.mul(s.into_repr()).into_affine());
+            assert_eq!(qm.mul(s.into_repr()), expected);
+            assert_eq!(
+                XZProjective::from(qm).mul(s.into_repr()),
+                XZProjective::from(expected)
+            );
+            assert_eq!(
+                XZProjective::from(tm).mul(s.into_repr()),
+                XZProjective::from(tm.mul(s.into_repr()))
+            );
+            assert!(XZProjective::<M<P>>::zero().mul(s.into_repr()).is_zero());
+        }
+    }
+}
+
 pub fn edwards_tests<P: TEModelParameters>()
 where
     P::BaseField: PrimeField,
@@ -560,3 +717,422 @@ pub fn edwards_curve_serialization_test<P: TEModelParameters>() {
         }
     }
 }
+
+/// Edge-case scalars followed by random ones.
+fn mul_scalars<F: PrimeField>() -> Vec<F> {
+    let mut rng = ark_std::test_rng();
+    let mut scalars = vec![F::zero(), F::one(), F::one().double(), -F::one()];
+    scalars.extend((0..ITERATIONS).map(|_| F::rand(&mut rng)));
+    scalars
+}
+
+#[cfg(feature = "ct")]
+pub fn sw_ct_mul_test<P: SWModelParameters>()
+where
+    P::BaseField: ark_ff::ConstantTimeField,
+{
+    use ark_ec::models::{
+        short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+        short_weierstrass_projective as projective,
+    };
+    let mut rng = ark_std::test_rng();
+
+    let zero = GroupProjective::<P>::zero();
+    let g = GroupProjective::<P>::rand(&mut rng);
+    for s in mul_scalars::<P::ScalarField>() {
+        let expected = g.mul(s.into_repr());
+        assert_eq!(g.ct_mul(s.into_repr()), expected);
+        assert_eq!(g.into_affine().ct_mul(s.into_repr()), expected);
+        assert!(zero.ct_mul(s.into_repr()).is_zero());
+        assert!(GroupAffine::<P>::zero().ct_mul(s.into_repr()).is_zero());
+    }
+
+    let g = projective::GroupProjective::<P>::from(projective::GroupAffine::from(g.into_affine()));
+    for s in mul_scalars::<P::ScalarField>() {
+        let expected = g.mul(s.into_repr());
+        assert_eq!(g.ct_mul(s.into_repr()), expected);
+        assert_eq!(g.into_affine().ct_mul(s.into_repr()), expected);
+        assert!(projective::GroupProjective::<P>::zero()
+            .ct_mul(s.into_repr())
+            .is_zero());
+    }
+}
+
+#[cfg(feature = "ct")]
+pub fn edwards_ct_mul_test<P: TEModelParameters>()
+where
+    P::BaseField: ark_ff::ConstantTimeField,
+{
+    let mut rng = ark_std::test_rng();
+
+    let zero = GroupProjective::<P>::zero();
+    let g = GroupProjective::<P>::rand(&mut rng);
+    for s in mul_scalars::<P::ScalarField>() {
+        let expected = g.mul(s.into_repr());
+        assert_eq!(g.ct_mul(s.into_repr()), expected);
+        assert_eq!(g.into_affine().ct_mul(s.into_repr()), expected);
+        assert!(zero.ct_mul(s.into_repr()).is_zero());
+    }
+}
+
+#[cfg(feature = "ct")]
+pub fn montgomery_ct_mul_test<P: MontgomeryModelParameters>()
+where
+    P::BaseField: ark_ff::ConstantTimeField,
+{
+    use ark_ec::models::montgomery::{GroupAffine, XZProjective};
+
+    let mut rng = ark_std::test_rng();
+
+    let zero = XZProjective::<P>::zero();
+    let order_two = XZProjective::<P>::new(P::BaseField::zero(), P::BaseField::one());
+    let p = GroupAffine::<P>::from(
+        GroupProjective::<P::TEModelParameters>::rand(&mut rng).into_affine(),
+    );
+    let x = XZProjective::from(p);
+    for s in mul_scalars::<P::ScalarField>() {
+        assert_eq!(x.ct_mul(s.into_repr()), x.mul(s.into_repr()));
+        assert_eq!(
+            order_two.ct_mul(s.into_repr()),
+            order_two.mul(s.into_repr())
+        );
+        assert!(zero.ct_mul(s.into_repr()).is_zero());
+    }
+}
+
+pub fn sw_glv_test<P: ark_ec::glv::GLVParameters>() {
+    use ark_ec::{
+        models::short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+        msm::VariableBaseMSM,
+    };
+    use ark_std::rand::Rng;
+    let mut rng = ark_std::test_rng();
+
+    let half_bits = (P::ScalarField::size_in_bits() + 1) / 2 + 1;
+    let mut scalars = mul_scalars::<P::ScalarField>();
+    scalars.push(P::LAMBDA);
+    for k in scalars.iter() {
+        let [(s1, k1), (s2, k2)] = P::scalar_decomposition(k);
+        assert!(k1.into_repr().num_bits() as usize <= half_bits);
+        assert!(k2.into_repr().num_bits() as usize <= half_bits);
+        let k1 = if s1 { -k1 } else { k1 };
+        let k2 = if s2 { -k2 } else { k2 };
+        assert_eq!(k1 + &(k2 * &P::LAMBDA), *k);
+    }
+
+    let g = GroupProjective::<P>::rand(&mut rng);
+    let g_affine = g.into_affine();
+    assert_eq!(
+        P::endomorphism(&g_affine).into_projective(),
+        g.mul(P::LAMBDA.into_repr())
+    );
+    assert_eq!(
+        P::endomorphism_projective(&g).into_affine(),
+        P::endomorphism(&g_affine)
+    );
+
+    let wnaf = WnafContext::new(4);
+    let mut bases = Vec::new();
+    let mut expected_msm = GroupProjective::<P>::zero();
+    for k in scalars.iter() {
+        let expected = g.mul(k.into_repr());
+        assert_eq!(g_affine.glv_mul(k), expected);
+        assert_eq!(wnaf.glv_mul(g, k), expected);
+
+        let base = GroupAffine::<P>::from(GroupProjective::<P>::rand(&mut rng));
+        expected_msm += &base.into_projective().mul(k.into_repr());
+        bases.push(base);
+    }
+    let projective_bases: Vec<_> = bases.iter().map(|b| b.into_projective()).collect();
+    assert_eq!(
+        wnaf.glv_multi_scalar_mul(&projective_bases, &scalars),
+        expected_msm
+    );
+    let repr_scalars: Vec<_> = scalars.iter().map(|k| k.into_repr()).collect();
+    assert_eq!(
+        VariableBaseMSM::glv_multi_scalar_mul(&bases, &repr_scalars),
+        expected_msm
+    );
+    // Large enough for Pippenger's algorithm.
+    let many_bases: Vec<_> = bases
+        .iter()
+        .cycle()
+        .take(2 * bases.len())
+        .copied()
+        .collect();
+    let many_scalars: Vec<_> = repr_scalars
+        .iter()
+        .cycle()
+        .take(2 * bases.len())
+        .copied()
+        .collect();
+    assert!(many_bases.len() > VariableBaseMSM::WNAF_THRESHOLD);
+    assert_eq!(
+        VariableBaseMSM::glv_multi_scalar_mul(&many_bases, &many_scalars),
+        expected_msm.double()
+    );
+
+    // Outside the prime order subgroup the endomorphism is not a scalar
+    // multiplication, so the plain methods must not use it.
+    if P::COFACTOR == [1] {
+        return;
+    }
+    let mut bases = Vec::new();
+    while bases.len() < scalars.len() {
+        let x = P::BaseField::rand(&mut rng);
+        if let Some(p) = GroupAffine::<P>::get_point_from_x(x, rng.gen()) {
+            if !p.is_in_correct_subgroup_assuming_on_curve() {
+                bases.push(p);
+            }
+        }
+    }
+    let mut expected_msm = GroupProjective::<P>::zero();
+    for (p, k) in bases.iter().zip(&scalars) {
+        // Plain double-and-add, which cannot use the endomorphism.
+        let expected =
+            BitIteratorBE::new(k.into_repr()).fold(GroupProjective::<P>::zero(), |acc, bit| {
+                let mut acc = acc.double();
+                if bit {
+                    acc.add_assign_mixed(p);
+                }
+                acc
+            });
+        assert_eq!(p.mul(*k), expected);
+        assert_eq!(p.into_projective().mul(k.into_repr()), expected);
+        assert_eq!(wnaf.mul(p.into_projective(), k), expected);
+        expected_msm += &expected;
+    }
+    let projective_bases: Vec<_> = bases.iter().map(|b| b.into_projective()).collect();
+    assert_eq!(
+        wnaf.multi_scalar_mul(&projective_bases, &scalars),
+        expected_msm
+    );
+    assert_eq!(
+        VariableBaseMSM::multi_scalar_mul(&bases, &repr_scalars),
+        expected_msm
+    );
+    // Large enough for Pippenger's algorithm.
+    let many_bases: Vec<_> = bases
+        .iter()
+        .cycle()
+        .take(4 * bases.len())
+        .copied()
+        .collect();
+    let many_scalars: Vec<_> = repr_scalars
+        .iter()
+        .cycle()
+        .take(4 * bases.len())
+        .copied()
+        .collect();
+    assert_eq!(
+        VariableBaseMSM::multi_scalar_mul(&many_bases, &many_scalars),
+        expected_msm + &expected_msm + &expected_msm + &expected_msm
+    );
+}
+
+/// Checks `SWModelParameters::is_in_correct_subgroup_assuming_on_curve`
+/// against multiplication by the group order, on points both inside and
+/// outside the prime order subgroup.
+pub fn sw_subgroup_check_test<P: SWModelParameters>() {
+    use ark_ec::models::short_weierstrass_jacobian::{GroupAffine, GroupProjective};
+    use ark_std::rand::Rng;
+    let mut rng = ark_std::test_rng();
+
+    let slow_check = |p: &GroupAffine<P>| {
+        p.into_projective()
+            .mul(P::ScalarField::characteristic())
+            .is_zero()
+    };
+
+    let mut points = vec![GroupAffine::<P>::zero()];
+    while points.len() <= ITERATIONS {
+        let x = P::BaseField::rand(&mut rng);
+        if let Some(p) = GroupAffine::<P>::get_point_from_x(x, rng.gen()) {
+            points.push(p);
+            points.push(p.mul_by_cofactor());
+        }
+    }
+    points.extend((0..ITERATIONS).map(|_| GroupProjective::<P>::rand(&mut rng).into_affine()));
+
+    if P::COFACTOR != [1] {
+        assert!(points.iter().any(|p| !slow_check(p)));
+    }
+    for p in points {
+        assert!(p.is_on_curve());
+        assert_eq!(
+            P::is_in_correct_subgroup_assuming_on_curve(&p),
+            slow_check(&p)
+        );
+    }
+}
+
+/// Checks that `SWModelParameters::clear_cofactor` maps random curve points
+/// to nonzero points of the prime order subgroup, compatibly with addition.
+pub fn sw_clear_cofactor_test<P: SWModelParameters>() {
+    use ark_ec::models::short_weierstrass_jacobian::GroupAffine;
+    use ark_std::rand::Rng;
+    let mut rng = ark_std::test_rng();
+
+    assert!(P::clear_cofactor(&GroupAffine::<P>::zero()).is_zero());
+
+    let mut points = Vec::new();
+    while points.len() < ITERATIONS {
+        let x = P::BaseField::rand(&mut rng);
+        if let Some(p) = GroupAffine::<P>::get_point_from_x(x, rng.gen()) {
+            points.push(p);
+        }
+    }
+    for pair in points.windows(2) {
+        let (p, q) = (pair[0], pair[1]);
+        let cleared = P::clear_cofactor(&p);
+        assert!(cleared.is_on_curve());
+        assert!(!cleared.is_zero());
+        assert!(cleared
+            .into_projective()
+            .mul(P::ScalarField::characteristic())
+            .is_zero());
+
+        // Clearing the cofactor is a group homomorphism.
+        let sum = (p.into_projective() + &q.into_projective()).into_affine();
+        assert_eq!(
+            P::clear_cofactor(&sum).into_projective(),
+            cleared.into_projective() + &P::clear_cofactor(&q).into_projective()
+        );
+    }
+}
+
+/// Checks the group law, bilinearity and serialization of the target group
+/// `PairingOutput<E>`.
+pub fn pairing_output_test<E: PairingEngine>() {
+    use ark_ec::{group::Group, PairingOutput};
+    use ark_ff::CyclotomicMultSubgroup;
+    let mut rng = ark_std::test_rng();
+
+    let a = PairingOutput::<E>::rand(&mut rng);
+    let b = PairingOutput::<E>::rand(&mut rng);
+    crate::groups::group_test(a, b);
+
+    let p = E::G1Projective::rand(&mut rng);
+    let q = E::G2Projective::rand(&mut rng);
+    let s = E::Fr::rand(&mut rng);
+    let e = PairingOutput::<E>(E::pairing(p, q));
+    assert_eq!(
+        PairingOutput::<E>(E::pairing(p.mul(s.into_repr()), q)),
+        e.mul(&s)
+    );
+    assert_eq!(
+        PairingOutput::<E>(E::pairing(p, q)) + &PairingOutput(E::pairing(-p, q)),
+        PairingOutput::zero()
+    );
+
+    for g in [PairingOutput::<E>::zero(), a, b, e] {
+        assert!(g.is_in_correct_subgroup());
+
+        let mut serialized = vec![0; g.serialized_size()];
+        g.serialize(&mut serialized[..]).unwrap();
+        assert_eq!(2 * g.serialized_size(), g.uncompressed_size());
+        let deserialized = PairingOutput::<E>::deserialize(&serialized[..]).unwrap();
+        assert_eq!(g, deserialized);
+
+        let mut serialized = vec![0; g.uncompressed_size()];
+        g.serialize_uncompressed(&mut serialized[..]).unwrap();
+        let deserialized = PairingOutput::<E>::deserialize_uncompressed(&serialized[..]).unwrap();
+        assert_eq!(g, deserialized);
+    }
+
+    // Random field elements are almost never in the target group.
+    let f = E::Fqk::rand(&mut rng);
+    assert!(!PairingOutput::<E>(f).is_in_correct_subgroup());
+    let mut serialized = vec![0; f.serialized_size()];
+    f.serialize(&mut serialized[..]).unwrap();
+    assert!(PairingOutput::<E>::deserialize_uncompressed(&serialized[..]).is_err());
+    assert_eq!(
+        PairingOutput::<E>::deserialize_unchecked(&serialized[..]).unwrap(),
+        PairingOutput(f)
+    );
+
+    let m = f.torus_compress();
+    let mut serialized = vec![0; m.serialized_size()];
+    m.serialize(&mut serialized[..]).unwrap();
+    assert!(PairingOutput::<E>::deserialize(&serialized[..]).is_err());
+}
+
+/// Checks `PairingEngine::pairing_check` and
+/// `PairingEngine::multi_pairing_is_one` against `PairingEngine::pairing`.
+pub fn pairing_check_test<E: PairingEngine>() {
+    let mut rng = ark_std::test_rng();
+
+    let p = E::G1Projective::rand(&mut rng);
+    let q = E::G2Projective::rand(&mut rng);
+    let s = E::Fr::rand(&mut rng);
+    let sp = p.mul(s.into_repr());
+    let sq = q.mul(s.into_repr());
+
+    assert!(E::pairing_check(sp, q, p, sq));
+    assert!(E::pairing_check(p, q, p, q));
+    assert!(!E::pairing_check(sp, q, p, q));
+    assert!(!E::pairing_check(p, sq, sp, sq));
+    assert!(E::pairing_check(
+        E::G1Projective::zero(),
+        q,
+        p,
+        E::G2Projective::zero()
+    ));
+
+    let prepare =
+        |p: E::G1Projective, q: E::G2Projective| (p.into_affine().into(), q.into_affine().into());
+    let pairs = [prepare(sp, q), prepare(-p, sq), prepare(p, q)];
+    assert!(E::multi_pairing_is_one(&pairs[..2]));
+    assert!(!E::multi_pairing_is_one(&pairs));
+    assert!(!E::multi_pairing_is_one(&pairs[2..]));
+    assert!(E::multi_pairing_is_one(&[]));
+    assert_eq!(
+        E::multi_pairing_is_one(&pairs),
+        E::product_of_pairings(&pairs).is_one()
+    );
+}
+
+/// Checks that `PairingEngine::G1Prepared` and `PairingEngine::G2Prepared`
+/// round-trip through compressed, uncompressed and unchecked serialization,
+/// that truncated input is rejected, and that the decoded values still pair
+/// like the points they were prepared from.
+pub fn prepared_serialization_test<E: PairingEngine>() {
+    fn round_trip<T: CanonicalSerialize + CanonicalDeserialize>(a: &T) -> T {
+        let mut compressed = Vec::new();
+        a.serialize(&mut compressed).unwrap();
+        assert_eq!(compressed.len(), a.serialized_size());
+        let b = T::deserialize(&compressed[..]).unwrap();
+
+        let mut uncompressed = Vec::new();
+        a.serialize_uncompressed(&mut uncompressed).unwrap();
+        assert_eq!(uncompressed.len(), a.uncompressed_size());
+        let c = T::deserialize_uncompressed(&uncompressed[..]).unwrap();
+        let d = T::deserialize_unchecked(&uncompressed[..]).unwrap();
+
+        // The three decodings agree, since they serialize to the same bytes.
+        for e in &[&c, &d] {
+            let mut bytes = Vec::new();
+            e.serialize(&mut bytes).unwrap();
+            assert_eq!(bytes, compressed);
+        }
+
+        assert!(T::deserialize(&compressed[..compressed.len() - 1]).is_err());
+        b
+    }
+
+    let mut rng = ark_std::test_rng();
+
+    for _ in 0..ITERATIONS {
+        let p = E::G1Projective::rand(&mut rng).into_affine();
+        let q = E::G2Projective::rand(&mut rng).into_affine();
+        let p_prepared = round_trip(&E::G1Prepared::from(p));
+        let q_prepared = round_trip(&E::G2Prepared::from(q));
+        assert_eq!(
+            E::product_of_pairings(&[(p_prepared, q_prepared)]),
+            E::pairing(p, q)
+        );
+    }
+
+    round_trip(&E::G1Prepared::from(E::G1Affine::zero()));
+    round_trip(&E::G2Prepared::from(E::G2Affine::zero()));
+}
@@ -1,6 +1,11 @@
 #![allow(unused)]
 #![allow(clippy::eq_op)]
-use ark_ff::fields::{FftField, FftParameters, Field, LegendreSymbol, PrimeField, SquareRootField};
+use ark_ff::{
+    fields::{
+        FftField, FftParameters, Field, FpParameters, LegendreSymbol, PrimeField, SquareRootField,
+    },
+    BigInteger,
+};
 use ark_serialize::{buffer_bit_byte_size, Flags, SWFlags};
 use ark_std::io::Cursor;
 use ark_std::rand::Rng;
@@ -302,6 +307,10 @@ pub fn field_test<F: Field>(a: F, b: F) {
     );
     // (a - b)^2 = (-(b - a))^2
     assert_eq!((a - &b).square(), (-(b - &a)).square());
+    // a == from_base_prime_field_elems(to_base_prime_field_elems(a))
+    let a_elems = a.to_base_prime_field_elems();
+    assert_eq!(a_elems.len() as u64, F::extension_degree());
+    assert_eq!(F::from_base_prime_field_elems(&a_elems), Some(a));
     random_field_tests::<F>();
 }
 
@@ -336,11 +345,51 @@ pub fn fft_field_test<F: FftField>() {
     }
 }
 
+/// Checks multiplication and squaring against the double-width product of the
+/// canonical representations, reduced bit by bit. This does not depend on
+/// Montgomery reduction, so it also covers the assembly backends.
+pub fn mul_reference_test<F: PrimeField>() {
+    let modulus = F::Params::MODULUS;
+    let reduce = |(lo, hi): (F::BigInt, F::BigInt)| {
+        let mut r = F::BigInt::from(0u64);
+        for i in (0..128 * F::BigInt::NUM_LIMBS).rev() {
+            let bit = if i < 64 * F::BigInt::NUM_LIMBS {
+                lo.get_bit(i)
+            } else {
+                hi.get_bit(i - 64 * F::BigInt::NUM_LIMBS)
+            };
+            // `r < modulus`, so `2 * r + bit` is reduced by a single subtraction.
+            let mut doubled = r.wrapping_add(&r);
+            if bit {
+                doubled = doubled.wrapping_add(&F::BigInt::from(1u64));
+            }
+            r = if doubled < r || doubled >= modulus {
+                doubled.wrapping_sub(&modulus)
+            } else {
+                doubled
+            };
+        }
+        r
+    };
+
+    let mut rng = ark_std::test_rng();
+    let mut elements = vec![F::zero(), F::one(), -F::one()];
+    elements.extend((0..ITERATIONS).map(|_| F::rand(&mut rng)));
+    for (a, b) in elements.iter().zip(elements.iter().rev()) {
+        let product = reduce(a.into_repr().mul_wide(&b.into_repr()));
+        assert_eq!((*a * b).into_repr(), product);
+        let square = reduce(a.into_repr().mul_wide(&a.into_repr()));
+        assert_eq!(a.square().into_repr(), square);
+    }
+}
+
 pub fn primefield_test<F: PrimeField>() {
     from_str_test::<F>();
     let one = F::one();
     assert_eq!(F::from(one.into_repr()), one);
 
+    mul_reference_test::<F>();
+
     fft_field_test::<F>();
 }
 
@@ -1,5 +1,10 @@
-use ark_ec::{msm::VariableBaseMSM, AffineCurve, ProjectiveCurve};
-use ark_ff::{PrimeField, UniformRand, Zero};
+use ark_ec::{
+    msm::{PrecomputedBasesMSM, VariableBaseMSM},
+    wnaf::{self, WnafContext},
+    AffineCurve, ProjectiveCurve,
+};
+use ark_ff::{BigInteger, One, PrimeField, UniformRand, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 
 fn naive_var_base_msm<G: AffineCurve>(
     bases: &[G],
@@ -31,3 +36,254 @@ pub fn test_var_base_msm<G: AffineCurve>() {
 
     assert_eq!(naive.into_affine(), fast.into_affine());
 }
+
+/// Checks `VariableBaseMSM` against the naive method on inputs that exercise
+/// the signed-digit recoding: scalars whose windows carry into the next one,
+/// repeated and opposite bases, and the point at infinity.
+pub fn test_var_base_msm_edge_cases<G: AffineCurve>() {
+    let mut rng = ark_std::test_rng();
+
+    let mut scalars = vec![
+        G::ScalarField::zero(),
+        G::ScalarField::one(),
+        -G::ScalarField::one(),
+        G::ScalarField::from(2u64),
+        -G::ScalarField::from(2u64),
+    ];
+    // Runs of set bits of every length, which make each window negative and
+    // carry into the next one.
+    for i in 1..<G::ScalarField as PrimeField>::size_in_bits() {
+        let mut repr = <G::ScalarField as PrimeField>::BigInt::from(1u64);
+        repr.muln(i as u32);
+        let pow = G::ScalarField::from_repr(repr).unwrap();
+        scalars.push(pow);
+        scalars.push(pow - G::ScalarField::one());
+        scalars.push(-pow);
+    }
+    let scalars = scalars
+        .into_iter()
+        .map(|s| s.into_repr())
+        .collect::<Vec<_>>();
+
+    let base = G::Projective::rand(&mut rng).into_affine();
+    let mut bases = (0..scalars.len())
+        .map(|_| G::Projective::rand(&mut rng))
+        .collect::<Vec<_>>();
+    bases[1] = G::Projective::zero();
+    let mut bases = <G::Projective as ProjectiveCurve>::batch_normalization_into_affine(&bases);
+    bases[2] = base;
+    bases[3] = base;
+    bases[4] = -base;
+
+    for size in [0, 1, 2, 3, 5, 31, 32, 33, scalars.len()].iter() {
+        let naive = naive_var_base_msm(&bases[..*size], &scalars[..*size]);
+        let fast = VariableBaseMSM::multi_scalar_mul(&bases[..*size], &scalars[..*size]);
+        assert_eq!(naive.into_affine(), fast.into_affine(), "size {}", size);
+    }
+
+    // The same base with the same scalar ends up in the same bucket twice.
+    let bases = vec![base; 40];
+    let scalars = vec![G::ScalarField::rand(&mut rng).into_repr(); 40];
+    let naive = naive_var_base_msm(&bases, &scalars);
+    let fast = VariableBaseMSM::multi_scalar_mul(&bases, &scalars);
+    assert_eq!(naive.into_affine(), fast.into_affine());
+
+    // Unit scalars skip the buckets.
+    let bases = (0..40)
+        .map(|_| G::Projective::rand(&mut rng).into_affine())
+        .collect::<Vec<_>>();
+    let mut scalars = vec![G::ScalarField::one().into_repr(); 40];
+    scalars[0] = G::ScalarField::rand(&mut rng).into_repr();
+    let naive = naive_var_base_msm(&bases, &scalars);
+    let fast = VariableBaseMSM::multi_scalar_mul(&bases, &scalars);
+    assert_eq!(naive.into_affine(), fast.into_affine());
+}
+
+pub fn test_precomputed_bases_msm<G: AffineCurve>() {
+    const SAMPLES: usize = 100;
+
+    let mut rng = ark_std::test_rng();
+
+    let mut v = (0..SAMPLES - 1)
+        .map(|_| G::ScalarField::rand(&mut rng).into_repr())
+        .collect::<Vec<_>>();
+    v[0] = G::ScalarField::zero().into_repr();
+    v[1] = G::ScalarField::one().into_repr();
+    v[2] = (-G::ScalarField::one()).into_repr();
+    let mut g = (0..SAMPLES)
+        .map(|_| G::Projective::rand(&mut rng))
+        .collect::<Vec<_>>();
+    g[3] = G::Projective::zero();
+    let g = <G::Projective as ProjectiveCurve>::batch_normalization_into_affine(&g);
+
+    let naive = naive_var_base_msm(g.as_slice(), v.as_slice());
+    let window_sizes = [1, 2, 5, PrecomputedBasesMSM::<G>::get_window_size(SAMPLES)];
+    for window in window_sizes.iter() {
+        let table = PrecomputedBasesMSM::new(g.as_slice(), *window);
+        assert_eq!(table.num_bases(), SAMPLES);
+        assert_eq!(table.window(), *window);
+        let fast = table.multi_scalar_mul(v.as_slice());
+        assert_eq!(naive.into_affine(), fast.into_affine(), "window {}", window);
+        let prefix = table.multi_scalar_mul(&v[..10]);
+        assert_eq!(
+            naive_var_base_msm(&g[..10], &v[..10]).into_affine(),
+            prefix.into_affine()
+        );
+        assert!(table.multi_scalar_mul(&[]).is_zero());
+    }
+
+    let table = PrecomputedBasesMSM::new(g.as_slice(), 4);
+    let mut serialized = vec![0; table.serialized_size()];
+    table.serialize(&mut serialized[..]).unwrap();
+    let deserialized = PrecomputedBasesMSM::<G>::deserialize(&serialized[..]).unwrap();
+    assert_eq!(table, deserialized);
+
+    let mut serialized = vec![0; table.uncompressed_size()];
+    table.serialize_uncompressed(&mut serialized[..]).unwrap();
+    let deserialized = PrecomputedBasesMSM::<G>::deserialize_uncompressed(&serialized[..]).unwrap();
+    assert_eq!(table, deserialized);
+    assert_eq!(
+        naive.into_affine(),
+        deserialized.multi_scalar_mul(v.as_slice()).into_affine()
+    );
+
+    // A window size that does not match the length of the table.
+    let mut serialized = vec![0; table.serialized_size()];
+    table.serialize(&mut serialized[..]).unwrap();
+    serialized[0] = 5;
+    assert!(matches!(
+        PrecomputedBasesMSM::<G>::deserialize(&serialized[..]),
+        Err(SerializationError::InvalidData)
+    ));
+    serialized[0] = 0;
+    assert!(matches!(
+        PrecomputedBasesMSM::<G>::deserialize(&serialized[..]),
+        Err(SerializationError::InvalidData)
+    ));
+
+    // Oversized windows are rejected before any bucket is allocated, even
+    // for an empty table, whose length matches every window size.
+    let empty = PrecomputedBasesMSM::<G>::new(&[], 4);
+    for window in [
+        PrecomputedBasesMSM::<G>::MAX_WINDOW as u64 + 1,
+        63,
+        u64::MAX,
+    ] {
+        let mut serialized = vec![0; empty.serialized_size()];
+        empty.serialize(&mut serialized[..]).unwrap();
+        serialized[..8].copy_from_slice(&window.to_le_bytes());
+        assert!(matches!(
+            PrecomputedBasesMSM::<G>::deserialize(&serialized[..]),
+            Err(SerializationError::InvalidData)
+        ));
+    }
+}
+
+pub fn test_chunked_msm<G: AffineCurve>() {
+    const SAMPLES: usize = 100;
+
+    let mut rng = ark_std::test_rng();
+
+    let v = (0..SAMPLES)
+        .map(|_| G::ScalarField::rand(&mut rng).into_repr())
+        .collect::<Vec<_>>();
+    let g = (0..SAMPLES)
+        .map(|_| G::Projective::rand(&mut rng))
+        .collect::<Vec<_>>();
+    let g = <G::Projective as ProjectiveCurve>::batch_normalization_into_affine(&g);
+
+    let naive = naive_var_base_msm(g.as_slice(), v.as_slice()).into_affine();
+    for chunk_size in [1, 7, SAMPLES, 2 * SAMPLES].iter() {
+        let chunked = VariableBaseMSM::multi_scalar_mul_chunked::<G, _, _>(&g, &v, *chunk_size);
+        assert_eq!(naive, chunked.into_affine(), "chunk size {}", chunk_size);
+    }
+    // Extra bases or scalars are ignored.
+    let chunked = VariableBaseMSM::multi_scalar_mul_chunked(g.iter().copied(), &v[..10], 3);
+    assert_eq!(
+        naive_var_base_msm(&g[..10], &v[..10]).into_affine(),
+        chunked.into_affine()
+    );
+    let chunked = VariableBaseMSM::multi_scalar_mul_chunked::<G, _, _>(&g[..0], &v, 3);
+    assert!(chunked.is_zero());
+
+    let mut serialized = vec![0; g.uncompressed_size()];
+    g.serialize_uncompressed(&mut serialized[..]).unwrap();
+    // Skip the length prefix of the vector.
+    let bases = &serialized[8..];
+    for chunk_size in [1, 7, SAMPLES].iter() {
+        let streamed =
+            VariableBaseMSM::multi_scalar_mul_from_reader::<G, _, _>(bases, &v, *chunk_size)
+                .unwrap();
+        assert_eq!(naive, streamed.into_affine());
+        let streamed = VariableBaseMSM::multi_scalar_mul_from_reader_unchecked::<G, _, _>(
+            bases,
+            &v,
+            *chunk_size,
+        )
+        .unwrap();
+        assert_eq!(naive, streamed.into_affine());
+    }
+
+    // The reader runs out of bases before the scalars run out.
+    let short = &bases[..bases.len() / 2];
+    assert!(VariableBaseMSM::multi_scalar_mul_from_reader::<G, _, _>(short, &v, 7).is_err());
+    assert!(
+        VariableBaseMSM::multi_scalar_mul_from_reader_unchecked::<G, _, _>(short, &v, 7).is_err()
+    );
+}
+
+pub fn test_wnaf_msm<G: ProjectiveCurve>() {
+    let mut rng = ark_std::test_rng();
+
+    let mut scalars = (0..10)
+        .map(|_| G::ScalarField::rand(&mut rng))
+        .collect::<Vec<_>>();
+    scalars[1] = G::ScalarField::zero();
+    scalars[2] = -G::ScalarField::one();
+    let mut bases = (0..10).map(|_| G::rand(&mut rng)).collect::<Vec<_>>();
+    bases[3] = G::zero();
+    bases[4] = bases[5];
+
+    for size in 0..=scalars.len() {
+        let expected = bases[..size]
+            .iter()
+            .zip(&scalars)
+            .map(|(base, scalar)| base.mul(scalar.into_repr()))
+            .sum::<G>();
+        for w in 2..=5 {
+            let context = WnafContext::new(w);
+            assert_eq!(
+                context.multi_scalar_mul(&bases[..size], &scalars[..size]),
+                expected,
+                "size {}, window {}",
+                size,
+                w
+            );
+        }
+    }
+
+    let p = G::rand(&mut rng);
+    let q = G::rand(&mut rng);
+    let one = G::ScalarField::one();
+    let edge_scalars = [
+        G::ScalarField::zero(),
+        one,
+        -one,
+        G::ScalarField::from(3u64),
+        -G::ScalarField::from(5u64),
+        G::ScalarField::rand(&mut rng),
+        G::ScalarField::rand(&mut rng),
+    ];
+    for a in edge_scalars.iter() {
+        for b in edge_scalars.iter() {
+            let expected = p.mul(a.into_repr()) + q.mul(b.into_repr());
+            assert_eq!(wnaf::mul_add(a, p, b, q), expected);
+            // Equal and opposite points make `p + q` or `p - q` vanish.
+            let expected = p.mul((*a + b).into_repr());
+            assert_eq!(wnaf::mul_add(a, p, b, p), expected);
+            let expected = p.mul((*a - b).into_repr());
+            assert_eq!(wnaf::mul_add(a, p, b, -p), expected);
+        }
+    }
+    assert!(wnaf::mul_add(&one, G::zero(), &one, G::zero()).is_zero());
+}